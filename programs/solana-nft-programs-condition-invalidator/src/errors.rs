@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Invalid token account")]
+    InvalidTokenAccount,
+    #[msg("Invalid issuer")]
+    InvalidIssuer,
+    #[msg("Invalid user")]
+    InvalidUser,
+    #[msg("Invalid token manager for this invalidator")]
+    InvalidTokenManager,
+    #[msg("Invalid condition invalidator")]
+    InvalidConditionInvalidator,
+    #[msg("Invalid collector")]
+    InvalidCollector,
+    #[msg("Too many conditions")]
+    TooManyConditions,
+    #[msg("Invalid conditions")]
+    InvalidConditions,
+    #[msg("Account referenced by a condition not found")]
+    MissingConditionAccount,
+    #[msg("Conditions are not met")]
+    ConditionsNotMet,
+}
@@ -0,0 +1,30 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+pub struct IncrementUsagesCtx<'info> {
+    #[account(constraint = token_manager.key() == condition_invalidator.token_manager && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut)]
+    condition_invalidator: Box<Account<'info, ConditionInvalidator>>,
+
+    #[account(constraint = token_manager.recipient_token_account == recipient_token_account.key() @ ErrorCode::InvalidTokenAccount)]
+    recipient_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = (condition_invalidator.use_authority.is_some() && user.key() == condition_invalidator.use_authority.unwrap())
+        || (condition_invalidator.use_authority.is_none() && user.key() == recipient_token_account.owner)
+        @ ErrorCode::InvalidUser
+    )]
+    user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<IncrementUsagesCtx>, num_usages: u64) -> Result<()> {
+    let condition_invalidator = &mut ctx.accounts.condition_invalidator;
+    condition_invalidator.usages = condition_invalidator.usages.checked_add(num_usages).expect("Add error");
+    Ok(())
+}
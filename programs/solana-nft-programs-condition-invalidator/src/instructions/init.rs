@@ -0,0 +1,46 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::assert_valid_conditions;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitIx {
+    pub collector: Pubkey,
+    pub use_authority: Option<Pubkey>,
+    pub conditions: Vec<Condition>,
+}
+
+#[derive(Accounts)]
+pub struct InitCtx<'info> {
+    #[account(constraint = token_manager.state == TokenManagerState::Initialized as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CONDITION_INVALIDATOR_SIZE,
+        seeds = [CONDITION_INVALIDATOR_SEED.as_bytes(), token_manager.key().as_ref()], bump,
+    )]
+    condition_invalidator: Box<Account<'info, ConditionInvalidator>>,
+
+    #[account(mut, constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+    assert_valid_conditions(&ix.conditions)?;
+
+    let condition_invalidator = &mut ctx.accounts.condition_invalidator;
+    condition_invalidator.bump = *ctx.bumps.get("condition_invalidator").unwrap();
+    condition_invalidator.token_manager = ctx.accounts.token_manager.key();
+    condition_invalidator.collector = ix.collector;
+    condition_invalidator.usages = 0;
+    condition_invalidator.use_authority = ix.use_authority;
+    condition_invalidator.conditions = ix.conditions;
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::condition_accounts_length;
+use crate::utils::evaluate_conditions;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+
+#[derive(Accounts)]
+pub struct InvalidateCtx<'info> {
+    #[account(mut)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, constraint = condition_invalidator.token_manager == token_manager.key() @ ErrorCode::InvalidConditionInvalidator)]
+    condition_invalidator: Box<Account<'info, ConditionInvalidator>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    invalidator: AccountInfo<'info>,
+
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    token_program: UncheckedAccount<'info>,
+
+    // cpi accounts
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    token_manager_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    recipient_token_account: UncheckedAccount<'info>,
+    rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateCtx<'info>>) -> Result<()> {
+    let token_manager_key = ctx.accounts.token_manager.key();
+    let condition_invalidator_seeds = &[CONDITION_INVALIDATOR_SEED.as_bytes(), token_manager_key.as_ref(), &[ctx.accounts.condition_invalidator.bump]];
+    let condition_invalidator_signer = &[&condition_invalidator_seeds[..]];
+    let now = Clock::get().unwrap().unix_timestamp;
+
+    // remaining accounts are the condition accounts followed by the token manager invalidate accounts
+    let condition_accounts_length = condition_accounts_length(&ctx.accounts.condition_invalidator.conditions);
+    if ctx.remaining_accounts.len() < condition_accounts_length {
+        return Err(error!(ErrorCode::MissingConditionAccount));
+    }
+    let (condition_accounts, invalidate_accounts) = ctx.remaining_accounts.split_at(condition_accounts_length);
+    if !evaluate_conditions(&ctx.accounts.condition_invalidator, &ctx.accounts.token_manager, now, condition_accounts)? {
+        return Err(error!(ErrorCode::ConditionsNotMet));
+    }
+    ctx.accounts.condition_invalidator.usages = 0;
+
+    // invalidate
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::InvalidateCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        token_manager_token_account: ctx.accounts.token_manager_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        recipient_token_account: ctx.accounts.recipient_token_account.to_account_info(),
+        invalidator: ctx.accounts.condition_invalidator.to_account_info(),
+        collector: ctx.accounts.invalidator.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts)
+        .with_remaining_accounts(invalidate_accounts.to_vec())
+        .with_signer(condition_invalidator_signer);
+    solana_nft_programs_token_manager::cpi::invalidate(cpi_ctx)?;
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+pub mod close;
+pub mod increment_usages;
+pub mod init;
+pub mod invalidate;
+
+pub use close::*;
+pub use increment_usages::*;
+pub use init::*;
+pub use invalidate::*;
@@ -0,0 +1,30 @@
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod utils;
+
+use anchor_lang::prelude::*;
+use instructions::*;
+
+declare_id!("cndaCNu31VN7mMj5Wxrb3KnNL8VAC6MJBbF3ZJ6JFxz");
+
+#[program]
+pub mod solana_nft_programs_condition_invalidator {
+    use super::*;
+
+    pub fn init(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+        init::handler(ctx, ix)
+    }
+
+    pub fn increment_usages(ctx: Context<IncrementUsagesCtx>, num_usages: u64) -> Result<()> {
+        increment_usages::handler(ctx, num_usages)
+    }
+
+    pub fn invalidate<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateCtx<'info>>) -> Result<()> {
+        invalidate::handler(ctx)
+    }
+
+    pub fn close(ctx: Context<CloseCtx>) -> Result<()> {
+        close::handler(ctx)
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum Condition {
+    // Token manager is claimed and current time is at or after the timestamp
+    TimeAfter { timestamp: i64 },
+    // Token manager has been claimed for at least the given number of seconds
+    DurationElapsed { seconds: i64 },
+    // Usage counter on the condition invalidator reached the given number of usages
+    UsagesReached { usages: u64 },
+    // Token account balance is below the given amount
+    TokenBalanceBelow { token_account: Pubkey, amount: u64 },
+    // Token account balance is at or above the given amount
+    TokenBalanceAtLeast { token_account: Pubkey, amount: u64 },
+    // Account exists and holds data
+    AccountExists { account: Pubkey },
+    // Both referenced conditions are met
+    And { left: u8, right: u8 },
+    // Either referenced condition is met
+    Or { left: u8, right: u8 },
+    // Referenced condition is not met
+    Not { child: u8 },
+}
+
+// largest variant plus the enum tag
+pub const CONDITION_SIZE: usize = 1 + 32 + 8;
+pub const MAX_CONDITIONS: usize = 16;
+
+pub const CONDITION_INVALIDATOR_SEED: &str = "condition-invalidator";
+pub const CONDITION_INVALIDATOR_SIZE: usize = 8 + std::mem::size_of::<ConditionInvalidator>() + MAX_CONDITIONS * CONDITION_SIZE + 8;
+#[account]
+pub struct ConditionInvalidator {
+    pub bump: u8,
+    pub token_manager: Pubkey,
+    pub collector: Pubkey,
+    pub usages: u64,
+    pub use_authority: Option<Pubkey>,
+    // flattened expression tree where the last condition is the root
+    pub conditions: Vec<Condition>,
+}
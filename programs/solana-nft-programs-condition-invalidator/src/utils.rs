@@ -0,0 +1,74 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+// children must reference earlier nodes so the tree is acyclic and can be evaluated in order
+pub fn assert_valid_conditions(conditions: &[Condition]) -> Result<()> {
+    if conditions.len() > MAX_CONDITIONS {
+        return Err(error!(ErrorCode::TooManyConditions));
+    }
+    if conditions.is_empty() {
+        return Err(error!(ErrorCode::InvalidConditions));
+    }
+    for (i, condition) in conditions.iter().enumerate() {
+        let valid = match condition {
+            Condition::And { left, right } | Condition::Or { left, right } => (*left as usize) < i && (*right as usize) < i,
+            Condition::Not { child } => (*child as usize) < i,
+            _ => true,
+        };
+        if !valid {
+            return Err(error!(ErrorCode::InvalidConditions));
+        }
+    }
+    Ok(())
+}
+
+// number of distinct accounts referenced by the conditions, these lead the remaining accounts of invalidate
+pub fn condition_accounts_length(conditions: &[Condition]) -> usize {
+    let mut keys: Vec<Pubkey> = Vec::new();
+    for condition in conditions.iter() {
+        let key = match condition {
+            Condition::TokenBalanceBelow { token_account, .. } | Condition::TokenBalanceAtLeast { token_account, .. } => token_account,
+            Condition::AccountExists { account } => account,
+            _ => continue,
+        };
+        if !keys.contains(key) {
+            keys.push(*key);
+        }
+    }
+    keys.len()
+}
+
+fn find_account<'a, 'info>(accounts: &'a [AccountInfo<'info>], key: &Pubkey) -> Result<&'a AccountInfo<'info>> {
+    accounts.iter().find(|account| account.key() == *key).ok_or_else(|| error!(ErrorCode::MissingConditionAccount))
+}
+
+fn token_balance(accounts: &[AccountInfo], token_account: &Pubkey) -> Result<u64> {
+    let token_account_info = find_account(accounts, token_account)?;
+    Ok(Account::<TokenAccount>::try_from(token_account_info)?.amount)
+}
+
+pub fn evaluate_conditions(condition_invalidator: &ConditionInvalidator, token_manager: &TokenManager, now: i64, accounts: &[AccountInfo]) -> Result<bool> {
+    let mut results: Vec<bool> = Vec::with_capacity(condition_invalidator.conditions.len());
+    for condition in condition_invalidator.conditions.iter() {
+        let result = match condition {
+            Condition::TimeAfter { timestamp } => token_manager.state == TokenManagerState::Claimed as u8 && now >= *timestamp,
+            Condition::DurationElapsed { seconds } => token_manager.state == TokenManagerState::Claimed as u8 && now >= token_manager.state_changed_at.checked_add(*seconds).expect("Add error"),
+            Condition::UsagesReached { usages } => condition_invalidator.usages >= *usages,
+            Condition::TokenBalanceBelow { token_account, amount } => token_balance(accounts, token_account)? < *amount,
+            Condition::TokenBalanceAtLeast { token_account, amount } => token_balance(accounts, token_account)? >= *amount,
+            Condition::AccountExists { account } => {
+                let account_info = find_account(accounts, account)?;
+                account_info.lamports() > 0 && !account_info.data_is_empty()
+            }
+            Condition::And { left, right } => results[*left as usize] && results[*right as usize],
+            Condition::Or { left, right } => results[*left as usize] || results[*right as usize],
+            Condition::Not { child } => !results[*child as usize],
+        };
+        results.push(result);
+    }
+    Ok(*results.last().expect("No conditions"))
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Invalid issuer")]
+    InvalidIssuer,
+    #[msg("Invalid token manager for this invalidator")]
+    InvalidTokenManager,
+    #[msg("Invalid floor price invalidator")]
+    InvalidFloorPriceInvalidator,
+    #[msg("Invalid collector")]
+    InvalidCollector,
+    #[msg("Invalid instruction")]
+    InvalidInstruction,
+    #[msg("Invalid price feed")]
+    InvalidPriceFeed,
+    #[msg("Price feed is stale")]
+    StalePriceFeed,
+    #[msg("Floor price is above the threshold")]
+    FloorPriceAboveThreshold,
+    #[msg("Invalid price exponent")]
+    InvalidExponent,
+}
@@ -0,0 +1,55 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitIx {
+    pub collector: Pubkey,
+    pub price_feed: Pubkey,
+    pub oracle_program: Pubkey,
+    pub threshold_price: i64,
+    pub threshold_exponent: i32,
+    pub max_staleness_seconds: i64,
+}
+
+#[derive(Accounts)]
+pub struct InitCtx<'info> {
+    #[account(constraint = token_manager.state == TokenManagerState::Initialized as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = FLOOR_PRICE_INVALIDATOR_SIZE,
+        seeds = [FLOOR_PRICE_INVALIDATOR_SEED.as_bytes(), token_manager.key().as_ref()], bump,
+    )]
+    floor_price_invalidator: Box<Account<'info, FloorPriceInvalidator>>,
+
+    #[account(mut, constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+    if ix.threshold_price <= 0 || ix.max_staleness_seconds <= 0 {
+        return Err(error!(ErrorCode::InvalidInstruction));
+    }
+    if !(-MAX_EXPONENT..=MAX_EXPONENT).contains(&ix.threshold_exponent) {
+        return Err(error!(ErrorCode::InvalidExponent));
+    }
+
+    let floor_price_invalidator = &mut ctx.accounts.floor_price_invalidator;
+    floor_price_invalidator.bump = *ctx.bumps.get("floor_price_invalidator").unwrap();
+    floor_price_invalidator.token_manager = ctx.accounts.token_manager.key();
+    floor_price_invalidator.collector = ix.collector;
+    floor_price_invalidator.price_feed = ix.price_feed;
+    floor_price_invalidator.oracle_program = ix.oracle_program;
+    floor_price_invalidator.threshold_price = ix.threshold_price;
+    floor_price_invalidator.threshold_exponent = ix.threshold_exponent;
+    floor_price_invalidator.max_staleness_seconds = ix.max_staleness_seconds;
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::price_below;
+use crate::utils::read_price_feed;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+pub struct InvalidateCtx<'info> {
+    #[account(mut, constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, constraint = floor_price_invalidator.token_manager == token_manager.key() @ ErrorCode::InvalidFloorPriceInvalidator)]
+    floor_price_invalidator: Box<Account<'info, FloorPriceInvalidator>>,
+
+    /// CHECK: This is not dangerous because the key and owner are checked against the floor price invalidator
+    price_feed: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    invalidator: AccountInfo<'info>,
+
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    token_program: UncheckedAccount<'info>,
+
+    // cpi accounts
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    token_manager_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    recipient_token_account: UncheckedAccount<'info>,
+    rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateCtx<'info>>) -> Result<()> {
+    let token_manager_key = ctx.accounts.token_manager.key();
+    let floor_price_invalidator_seeds = &[FLOOR_PRICE_INVALIDATOR_SEED.as_bytes(), token_manager_key.as_ref(), &[ctx.accounts.floor_price_invalidator.bump]];
+    let floor_price_invalidator_signer = &[&floor_price_invalidator_seeds[..]];
+    let price_feed = read_price_feed(&ctx.accounts.price_feed, &ctx.accounts.floor_price_invalidator)?;
+    let floor_price_invalidator = &ctx.accounts.floor_price_invalidator;
+    if Clock::get().unwrap().unix_timestamp.checked_sub(price_feed.publish_time).expect("Sub error") > floor_price_invalidator.max_staleness_seconds {
+        return Err(error!(ErrorCode::StalePriceFeed));
+    }
+    if !price_below(
+        price_feed.price,
+        price_feed.exponent,
+        floor_price_invalidator.threshold_price,
+        floor_price_invalidator.threshold_exponent,
+    )? {
+        return Err(error!(ErrorCode::FloorPriceAboveThreshold));
+    }
+
+    // invalidate
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::InvalidateCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        token_manager_token_account: ctx.accounts.token_manager_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        recipient_token_account: ctx.accounts.recipient_token_account.to_account_info(),
+        invalidator: ctx.accounts.floor_price_invalidator.to_account_info(),
+        collector: ctx.accounts.invalidator.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts)
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec())
+        .with_signer(floor_price_invalidator_signer);
+    solana_nft_programs_token_manager::cpi::invalidate(cpi_ctx)?;
+
+    Ok(())
+}
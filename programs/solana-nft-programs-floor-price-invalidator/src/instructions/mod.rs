@@ -0,0 +1,7 @@
+pub mod close;
+pub mod init;
+pub mod invalidate;
+
+pub use close::*;
+pub use init::*;
+pub use invalidate::*;
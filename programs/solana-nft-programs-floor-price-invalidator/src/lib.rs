@@ -0,0 +1,26 @@
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod utils;
+
+use anchor_lang::prelude::*;
+use instructions::*;
+
+declare_id!("fpr4GCNqSLchrzyES2ZnMKAkjHMKkP6YpqkRhKyHNrZ");
+
+#[program]
+pub mod solana_nft_programs_floor_price_invalidator {
+    use super::*;
+
+    pub fn init(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+        init::handler(ctx, ix)
+    }
+
+    pub fn invalidate<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateCtx<'info>>) -> Result<()> {
+        invalidate::handler(ctx)
+    }
+
+    pub fn close(ctx: Context<CloseCtx>) -> Result<()> {
+        close::handler(ctx)
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+pub const FLOOR_PRICE_INVALIDATOR_SEED: &str = "floor-price-invalidator";
+pub const FLOOR_PRICE_INVALIDATOR_SIZE: usize = 8 + std::mem::size_of::<FloorPriceInvalidator>() + 8;
+// exponents are bounded so 10^(exponent difference) fits in an i128
+pub const MAX_EXPONENT: i32 = 18;
+#[account]
+pub struct FloorPriceInvalidator {
+    pub bump: u8,
+    pub token_manager: Pubkey,
+    pub collector: Pubkey,
+    // price feed account and the program that owns it
+    pub price_feed: Pubkey,
+    pub oracle_program: Pubkey,
+    // invalidate when the price falls below threshold_price * 10^threshold_exponent
+    pub threshold_price: i64,
+    pub threshold_exponent: i32,
+    pub max_staleness_seconds: i64,
+}
+
+// generic price feed format stored after the 8 byte account discriminator
+pub const PRICE_FEED_DATA_SIZE: usize = 32 + 8 + 4 + 8;
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PriceFeedData {
+    pub owner: Pubkey,
+    pub price: i64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
@@ -0,0 +1,29 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+pub fn read_price_feed(price_feed_info: &AccountInfo, floor_price_invalidator: &FloorPriceInvalidator) -> Result<PriceFeedData> {
+    if price_feed_info.key() != floor_price_invalidator.price_feed || *price_feed_info.owner != floor_price_invalidator.oracle_program {
+        return Err(error!(ErrorCode::InvalidPriceFeed));
+    }
+    let data = price_feed_info.try_borrow_data()?;
+    if data.len() < 8 + PRICE_FEED_DATA_SIZE {
+        return Err(error!(ErrorCode::InvalidPriceFeed));
+    }
+    let price_feed = PriceFeedData::deserialize(&mut &data[8..8 + PRICE_FEED_DATA_SIZE]).map_err(|_| error!(ErrorCode::InvalidPriceFeed))?;
+    Ok(price_feed)
+}
+
+// compare price * 10^exponent < threshold_price * 10^threshold_exponent by scaling both to the smaller exponent
+// the side at the smaller exponent is never scaled, so saturating the other side keeps the comparison exact
+pub fn price_below(price: i64, exponent: i32, threshold_price: i64, threshold_exponent: i32) -> Result<bool> {
+    if !(-MAX_EXPONENT..=MAX_EXPONENT).contains(&exponent) || !(-MAX_EXPONENT..=MAX_EXPONENT).contains(&threshold_exponent) {
+        return Err(error!(ErrorCode::InvalidExponent));
+    }
+    let min_exponent = exponent.min(threshold_exponent);
+    let scale = |value: i64, expo: i32| -> i128 {
+        let factor = 10_i128.pow((expo - min_exponent) as u32);
+        (value as i128).saturating_mul(factor)
+    };
+    Ok(scale(price, exponent) < scale(threshold_price, threshold_exponent))
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Token account not owned by the claim approver")]
+    InvalidPaymentTokenAccount,
+    #[msg("Token account not owned by the payer")]
+    InvalidPayerTokenAccount,
+    #[msg("Invalid issuer")]
+    InvalidIssuer,
+    #[msg("Invalid token manager for this invalidator")]
+    InvalidTokenManager,
+    #[msg("Invalid installment invalidator")]
+    InvalidInstallmentInvalidator,
+    #[msg("Invalid instruction")]
+    InvalidInstruction,
+    #[msg("Invalid payment mint on installment invalidator")]
+    InvalidPaymentMint,
+    #[msg("Invalid collector")]
+    InvalidCollector,
+    #[msg("Invalid payment manager program")]
+    InvalidPaymentManagerProgram,
+    #[msg("Invalid payment manager")]
+    InvalidPaymentManager,
+    #[msg("Invalid mint")]
+    InvalidMint,
+    #[msg("All installments have been paid")]
+    InstallmentsPaidOff,
+    #[msg("Installment is overdue")]
+    InstallmentOverdue,
+    #[msg("Installments are neither missed nor paid off")]
+    InstallmentsInProgress,
+}
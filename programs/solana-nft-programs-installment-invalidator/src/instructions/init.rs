@@ -0,0 +1,76 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::InvalidationType;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitIx {
+    pub collector: Pubkey,
+    pub payment_manager: Pubkey,
+    pub payment_mint: Pubkey,
+    pub installment_amount: u64,
+    pub installment_period_seconds: i64,
+    pub num_installments: u64,
+}
+
+#[derive(Accounts)]
+pub struct InitCtx<'info> {
+    #[account(mut, constraint =
+        token_manager.state == TokenManagerState::Initialized as u8
+        && token_manager.invalidation_type == InvalidationType::Return as u8
+        @ ErrorCode::InvalidTokenManager
+    )]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = INSTALLMENT_INVALIDATOR_SIZE,
+        seeds = [INSTALLMENT_INVALIDATOR_SEED.as_bytes(), token_manager.key().as_ref()], bump,
+    )]
+    installment_invalidator: Box<Account<'info, InstallmentInvalidator>>,
+
+    #[account(mut, constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+    if ix.num_installments == 0 || ix.installment_period_seconds <= 0 {
+        return Err(error!(ErrorCode::InvalidInstruction));
+    }
+    // installment invalidator must be the only invalidator so nobody else can return or release the token
+    if ctx.accounts.token_manager.invalidators != vec![ctx.accounts.installment_invalidator.key()] {
+        return Err(error!(ErrorCode::InvalidTokenManager));
+    }
+
+    let installment_invalidator = &mut ctx.accounts.installment_invalidator;
+    installment_invalidator.bump = *ctx.bumps.get("installment_invalidator").unwrap();
+    installment_invalidator.token_manager = ctx.accounts.token_manager.key();
+    installment_invalidator.payment_manager = ix.payment_manager;
+    installment_invalidator.collector = ix.collector;
+    installment_invalidator.payment_mint = ix.payment_mint;
+    installment_invalidator.installment_amount = ix.installment_amount;
+    installment_invalidator.installment_period_seconds = ix.installment_period_seconds;
+    installment_invalidator.num_installments = ix.num_installments;
+    installment_invalidator.installments_paid = 0;
+
+    // let the installment invalidator release the token once paid off and lock the token manager to return
+    let token_manager_key = ctx.accounts.token_manager.key();
+    let installment_invalidator_seeds = &[INSTALLMENT_INVALIDATOR_SEED.as_bytes(), token_manager_key.as_ref(), &[installment_invalidator.bump]];
+    let installment_invalidator_signer = &[&installment_invalidator_seeds[..]];
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::AllowReleaseCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        invalidator: installment_invalidator.to_account_info(),
+        issuer: ctx.accounts.issuer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts).with_signer(installment_invalidator_signer);
+    solana_nft_programs_token_manager::cpi::allow_release(cpi_ctx)?;
+    Ok(())
+}
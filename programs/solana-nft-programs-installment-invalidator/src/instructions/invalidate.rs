@@ -0,0 +1,73 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::InvalidationType;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+pub struct InvalidateCtx<'info> {
+    #[account(mut, constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut,
+        constraint = installment_invalidator.token_manager == token_manager.key() @ ErrorCode::InvalidInstallmentInvalidator,
+        constraint = installment_invalidator.is_paid_off() || Clock::get().unwrap().unix_timestamp > installment_invalidator.next_due_at(token_manager.state_changed_at) @ ErrorCode::InstallmentsInProgress,
+    )]
+    installment_invalidator: Box<Account<'info, InstallmentInvalidator>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    invalidator: AccountInfo<'info>,
+
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    token_program: UncheckedAccount<'info>,
+
+    // cpi accounts
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    token_manager_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    recipient_token_account: UncheckedAccount<'info>,
+    rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateCtx<'info>>) -> Result<()> {
+    let token_manager_key = ctx.accounts.token_manager.key();
+    let installment_invalidator_seeds = &[INSTALLMENT_INVALIDATOR_SEED.as_bytes(), token_manager_key.as_ref(), &[ctx.accounts.installment_invalidator.bump]];
+    let installment_invalidator_signer = &[&installment_invalidator_seeds[..]];
+
+    // paid off so hand the token to the renter outright
+    if ctx.accounts.installment_invalidator.is_paid_off() {
+        let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::UpdateInvalidationTypeByInvalidatorCtx {
+            token_manager: ctx.accounts.token_manager.to_account_info(),
+            invalidator: ctx.accounts.installment_invalidator.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts).with_signer(installment_invalidator_signer);
+        solana_nft_programs_token_manager::cpi::update_invalidation_type_by_invalidator(cpi_ctx, InvalidationType::Release as u8)?;
+    }
+
+    // invalidate
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::InvalidateCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        token_manager_token_account: ctx.accounts.token_manager_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        recipient_token_account: ctx.accounts.recipient_token_account.to_account_info(),
+        invalidator: ctx.accounts.installment_invalidator.to_account_info(),
+        collector: ctx.accounts.invalidator.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts)
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec())
+        .with_signer(installment_invalidator_signer);
+    solana_nft_programs_token_manager::cpi::invalidate(cpi_ctx)?;
+
+    Ok(())
+}
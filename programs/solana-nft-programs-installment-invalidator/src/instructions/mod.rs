@@ -0,0 +1,9 @@
+pub mod close;
+pub mod init;
+pub mod invalidate;
+pub mod pay_installment;
+
+pub use close::*;
+pub use init::*;
+pub use invalidate::*;
+pub use pay_installment::*;
@@ -0,0 +1,99 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use solana_nft_programs_payment_manager::program::SolanaNftProgramsPaymentManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+use solana_nft_programs_token_manager::utils::assert_payment_token_account;
+
+#[derive(Accounts)]
+pub struct PayInstallmentCtx<'info> {
+    #[account(constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, constraint = installment_invalidator.token_manager == token_manager.key() @ ErrorCode::InvalidInstallmentInvalidator)]
+    installment_invalidator: Box<Account<'info, InstallmentInvalidator>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut, constraint = payment_manager.key() == installment_invalidator.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = payment_token_account.mint == installment_invalidator.payment_mint @ ErrorCode::InvalidPaymentTokenAccount)]
+    payment_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = fee_collector_token_account.mint == installment_invalidator.payment_mint @ ErrorCode::InvalidPaymentMint)]
+    fee_collector_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(mut, constraint =
+        payer_token_account.owner == payer.key()
+        && payer_token_account.mint == installment_invalidator.payment_mint
+        @ ErrorCode::InvalidPayerTokenAccount
+    )]
+    payer_token_account: Box<Account<'info, TokenAccount>>,
+
+    token_program: Program<'info, Token>,
+    solana_nft_programs_payment_manager: Program<'info, SolanaNftProgramsPaymentManager>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, PayInstallmentCtx<'info>>) -> Result<()> {
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    assert_payment_token_account(&ctx.accounts.payment_token_account, &ctx.accounts.token_manager, remaining_accs)?;
+
+    let token_manager = &ctx.accounts.token_manager;
+    let installment_invalidator = &mut ctx.accounts.installment_invalidator;
+    if installment_invalidator.is_paid_off() {
+        return Err(error!(ErrorCode::InstallmentsPaidOff));
+    }
+    if Clock::get().unwrap().unix_timestamp > installment_invalidator.next_due_at(token_manager.state_changed_at) {
+        return Err(error!(ErrorCode::InstallmentOverdue));
+    }
+    let price_to_pay = installment_invalidator.installment_amount;
+
+    if ctx.accounts.payment_manager.owner.key() == ctx.accounts.solana_nft_programs_payment_manager.key() {
+        let payment_mint_info = next_account_info(remaining_accs)?;
+        let payment_mint = Account::<Mint>::try_from(payment_mint_info)?;
+        if installment_invalidator.payment_mint != payment_mint.key() {
+            return Err(error!(ErrorCode::InvalidPaymentMint));
+        }
+
+        let mint_info = next_account_info(remaining_accs)?;
+        let mint = Account::<Mint>::try_from(mint_info)?;
+        if token_manager.mint != mint.key() {
+            return Err(error!(ErrorCode::InvalidMint));
+        }
+        let mint_metadata_info = next_account_info(remaining_accs)?;
+
+        let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandlePaymentWithRoyaltiesCtx {
+            payment_manager: ctx.accounts.payment_manager.to_account_info(),
+            payer_token_account: ctx.accounts.payer_token_account.to_account_info(),
+            fee_collector_token_account: ctx.accounts.fee_collector_token_account.to_account_info(),
+            payment_token_account: ctx.accounts.payment_token_account.to_account_info(),
+            payment_mint: payment_mint.to_account_info(),
+            mint: mint.to_account_info(),
+            mint_metadata: mint_metadata_info.to_account_info(),
+            payer: ctx.accounts.payer.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_ctx =
+            CpiContext::new(ctx.accounts.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts).with_remaining_accounts(remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>());
+        solana_nft_programs_payment_manager::cpi::handle_payment_with_royalties(cpi_ctx, price_to_pay)?;
+    } else {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.payer_token_account.to_account_info(),
+            to: ctx.accounts.payment_token_account.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_context, price_to_pay)?;
+    }
+
+    installment_invalidator.installments_paid = installment_invalidator.installments_paid.checked_add(1).expect("Add error");
+    Ok(())
+}
@@ -0,0 +1,29 @@
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+use anchor_lang::prelude::*;
+use instructions::*;
+
+declare_id!("instRN6K1bvL2WLtfUevmTQb7Ntm7quj4NXt6Bsi4y3");
+
+#[program]
+pub mod solana_nft_programs_installment_invalidator {
+    use super::*;
+
+    pub fn init(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+        init::handler(ctx, ix)
+    }
+
+    pub fn pay_installment<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, PayInstallmentCtx<'info>>) -> Result<()> {
+        pay_installment::handler(ctx)
+    }
+
+    pub fn invalidate<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateCtx<'info>>) -> Result<()> {
+        invalidate::handler(ctx)
+    }
+
+    pub fn close(ctx: Context<CloseCtx>) -> Result<()> {
+        close::handler(ctx)
+    }
+}
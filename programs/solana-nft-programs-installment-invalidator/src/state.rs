@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+pub const INSTALLMENT_INVALIDATOR_SEED: &str = "installment-invalidator";
+pub const INSTALLMENT_INVALIDATOR_SIZE: usize = 8 + std::mem::size_of::<InstallmentInvalidator>() + 8;
+#[account]
+pub struct InstallmentInvalidator {
+    pub bump: u8,
+    pub token_manager: Pubkey,
+    pub payment_manager: Pubkey,
+    pub collector: Pubkey,
+    pub payment_mint: Pubkey,
+    pub installment_amount: u64,
+    pub installment_period_seconds: i64,
+    pub num_installments: u64,
+    pub installments_paid: u64,
+}
+
+impl InstallmentInvalidator {
+    // due date of the next unpaid installment relative to when the token manager was claimed
+    pub fn next_due_at(&self, claimed_at: i64) -> i64 {
+        let periods = self.installments_paid.checked_add(1).expect("Add error") as i64;
+        claimed_at
+            .checked_add(self.installment_period_seconds.checked_mul(periods).expect("Multiplication error"))
+            .expect("Add error")
+    }
+
+    pub fn is_paid_off(&self) -> bool {
+        self.installments_paid >= self.num_installments
+    }
+}
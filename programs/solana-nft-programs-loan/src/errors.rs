@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Invalid token manager for this loan")]
+    InvalidTokenManager,
+    #[msg("Invalid borrower")]
+    InvalidBorrower,
+    #[msg("Invalid lender")]
+    InvalidLender,
+    #[msg("Invalid loan state")]
+    InvalidLoanState,
+    #[msg("Invalid instruction")]
+    InvalidInstruction,
+    #[msg("Invalid receipt token account")]
+    InvalidReceiptTokenAccount,
+    #[msg("Invalid payment token account")]
+    InvalidPaymentTokenAccount,
+    #[msg("Invalid recipient token account")]
+    InvalidRecipientTokenAccount,
+    #[msg("Loan is not yet due")]
+    LoanNotDue,
+    #[msg("Loan is overdue")]
+    LoanOverdue,
+    #[msg("Repayment amount overflows")]
+    RepaymentAmountOverflow,
+}
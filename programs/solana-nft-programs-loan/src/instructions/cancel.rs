@@ -0,0 +1,81 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::InvalidationType;
+use solana_nft_programs_token_manager::state::TokenManager;
+
+#[derive(Accounts)]
+pub struct CancelCtx<'info> {
+    #[account(mut, constraint = token_manager.key() == loan.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, close = borrower, constraint = loan.state == LoanState::Requested as u8 @ ErrorCode::InvalidLoanState)]
+    loan: Box<Account<'info, Loan>>,
+
+    #[account(mut, constraint = loan_receipt_token_account.owner == loan.key() && Some(loan_receipt_token_account.mint) == token_manager.receipt_mint @ ErrorCode::InvalidReceiptTokenAccount)]
+    loan_receipt_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = borrower_receipt_token_account.owner == borrower.key() && borrower_receipt_token_account.mint == loan_receipt_token_account.mint @ ErrorCode::InvalidReceiptTokenAccount)]
+    borrower_receipt_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, constraint = borrower.key() == loan.borrower @ ErrorCode::InvalidBorrower)]
+    borrower: Signer<'info>,
+
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    token_program: Program<'info, Token>,
+
+    // cpi accounts
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    token_manager_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    recipient_token_account: UncheckedAccount<'info>,
+    rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, CancelCtx<'info>>) -> Result<()> {
+    let token_manager_key = ctx.accounts.token_manager.key();
+    let loan_seeds = &[LOAN_SEED.as_bytes(), token_manager_key.as_ref(), &[ctx.accounts.loan.bump]];
+    let loan_signer = &[&loan_seeds[..]];
+
+    // return escrowed receipt
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.loan_receipt_token_account.to_account_info(),
+        to: ctx.accounts.borrower_receipt_token_account.to_account_info(),
+        authority: ctx.accounts.loan.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(loan_signer);
+    token::transfer(cpi_context, ctx.accounts.loan_receipt_token_account.amount)?;
+
+    // release collateral to borrower
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::UpdateInvalidationTypeByInvalidatorCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        invalidator: ctx.accounts.loan.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts).with_signer(loan_signer);
+    solana_nft_programs_token_manager::cpi::update_invalidation_type_by_invalidator(cpi_ctx, InvalidationType::Release as u8)?;
+
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::InvalidateCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        token_manager_token_account: ctx.accounts.token_manager_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        recipient_token_account: ctx.accounts.recipient_token_account.to_account_info(),
+        invalidator: ctx.accounts.loan.to_account_info(),
+        collector: ctx.accounts.borrower.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts)
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec())
+        .with_signer(loan_signer);
+    solana_nft_programs_token_manager::cpi::invalidate(cpi_ctx)?;
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use solana_nft_programs_token_manager::state::TokenManager;
+
+#[derive(Accounts)]
+pub struct FundCtx<'info> {
+    #[account(constraint = token_manager.key() == loan.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, constraint = loan.state == LoanState::Requested as u8 @ ErrorCode::InvalidLoanState)]
+    loan: Box<Account<'info, Loan>>,
+
+    #[account(mut, constraint =
+        loan_receipt_token_account.owner == loan.key()
+        && Some(loan_receipt_token_account.mint) == token_manager.receipt_mint
+        && loan_receipt_token_account.amount > 0
+        @ ErrorCode::InvalidReceiptTokenAccount
+    )]
+    loan_receipt_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = lender_receipt_token_account.owner == lender.key() && lender_receipt_token_account.mint == loan_receipt_token_account.mint @ ErrorCode::InvalidReceiptTokenAccount)]
+    lender_receipt_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, constraint = lender_payment_token_account.owner == lender.key() && lender_payment_token_account.mint == loan.payment_mint @ ErrorCode::InvalidPaymentTokenAccount)]
+    lender_payment_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = borrower_payment_token_account.owner == loan.borrower && borrower_payment_token_account.mint == loan.payment_mint @ ErrorCode::InvalidPaymentTokenAccount)]
+    borrower_payment_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    lender: Signer<'info>,
+    token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<FundCtx>) -> Result<()> {
+    let loan = &mut ctx.accounts.loan;
+    loan.lender = Some(ctx.accounts.lender.key());
+    loan.state = LoanState::Active as u8;
+    loan.due_at = Some(Clock::get().unwrap().unix_timestamp.checked_add(loan.duration_seconds).expect("Add error"));
+
+    // principal to borrower
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.lender_payment_token_account.to_account_info(),
+        to: ctx.accounts.borrower_payment_token_account.to_account_info(),
+        authority: ctx.accounts.lender.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_context, loan.principal)?;
+
+    // receipt to lender so a return sends the collateral to them
+    let token_manager_key = loan.token_manager;
+    let loan_seeds = &[LOAN_SEED.as_bytes(), token_manager_key.as_ref(), &[loan.bump]];
+    let loan_signer = &[&loan_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.loan_receipt_token_account.to_account_info(),
+        to: ctx.accounts.lender_receipt_token_account.to_account_info(),
+        authority: loan.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(loan_signer);
+    token::transfer(cpi_context, ctx.accounts.loan_receipt_token_account.amount)?;
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::InvalidationType;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerKind;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitIx {
+    pub payment_mint: Pubkey,
+    pub principal: u64,
+    pub interest_bps: u64,
+    pub duration_seconds: i64,
+}
+
+#[derive(Accounts)]
+pub struct InitCtx<'info> {
+    #[account(mut, constraint =
+        token_manager.issuer == borrower.key()
+        && token_manager.state == TokenManagerState::Claimed as u8
+        && (token_manager.kind == TokenManagerKind::Managed as u8 || token_manager.kind == TokenManagerKind::Programmable as u8)
+        && token_manager.invalidation_type == InvalidationType::Return as u8
+        && token_manager.receipt_mint.is_some()
+        @ ErrorCode::InvalidTokenManager
+    )]
+    token_manager: Box<Account<'info, TokenManager>>,
+    #[account(constraint = recipient_token_account.key() == token_manager.recipient_token_account && recipient_token_account.owner == borrower.key() @ ErrorCode::InvalidRecipientTokenAccount)]
+    recipient_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = borrower,
+        space = LOAN_SIZE,
+        seeds = [LOAN_SEED.as_bytes(), token_manager.key().as_ref()], bump,
+    )]
+    loan: Box<Account<'info, Loan>>,
+
+    #[account(mut, constraint =
+        borrower_receipt_token_account.owner == borrower.key()
+        && borrower_receipt_token_account.mint == token_manager.receipt_mint.unwrap()
+        @ ErrorCode::InvalidReceiptTokenAccount
+    )]
+    borrower_receipt_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint =
+        loan_receipt_token_account.owner == loan.key()
+        && loan_receipt_token_account.mint == token_manager.receipt_mint.unwrap()
+        @ ErrorCode::InvalidReceiptTokenAccount
+    )]
+    loan_receipt_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    borrower: Signer<'info>,
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+    if ix.principal == 0 || ix.duration_seconds <= 0 {
+        return Err(error!(ErrorCode::InvalidInstruction));
+    }
+    // loan must be the only invalidator so nobody else can return or release the collateral
+    if ctx.accounts.token_manager.invalidators != vec![ctx.accounts.loan.key()] {
+        return Err(error!(ErrorCode::InvalidTokenManager));
+    }
+
+    let loan = &mut ctx.accounts.loan;
+    loan.bump = *ctx.bumps.get("loan").unwrap();
+    loan.token_manager = ctx.accounts.token_manager.key();
+    loan.borrower = ctx.accounts.borrower.key();
+    loan.lender = None;
+    loan.state = LoanState::Requested as u8;
+    loan.payment_mint = ix.payment_mint;
+    loan.principal = ix.principal;
+    loan.interest_bps = ix.interest_bps;
+    loan.duration_seconds = ix.duration_seconds;
+    loan.due_at = None;
+    // a loan that can never be repaid would lock the collateral until it is overdue
+    loan.repayment_amount()?;
+
+    // escrow receipt until the loan is funded
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.borrower_receipt_token_account.to_account_info(),
+        to: ctx.accounts.loan_receipt_token_account.to_account_info(),
+        authority: ctx.accounts.borrower.to_account_info(),
+    };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_context, 1)?;
+
+    // let the loan release the collateral and lock the token manager to return
+    let token_manager_key = ctx.accounts.token_manager.key();
+    let loan_seeds = &[LOAN_SEED.as_bytes(), token_manager_key.as_ref(), &[ctx.accounts.loan.bump]];
+    let loan_signer = &[&loan_seeds[..]];
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::AllowReleaseCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        invalidator: ctx.accounts.loan.to_account_info(),
+        issuer: ctx.accounts.borrower.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts).with_signer(loan_signer);
+    solana_nft_programs_token_manager::cpi::allow_release(cpi_ctx)?;
+    Ok(())
+}
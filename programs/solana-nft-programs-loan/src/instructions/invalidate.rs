@@ -0,0 +1,62 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+
+#[derive(Accounts)]
+pub struct InvalidateCtx<'info> {
+    #[account(mut, constraint = token_manager.key() == loan.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, close = borrower,
+        constraint = loan.state == LoanState::Active as u8 @ ErrorCode::InvalidLoanState,
+        constraint = Clock::get().unwrap().unix_timestamp >= loan.due_at.expect("No due date") @ ErrorCode::LoanNotDue,
+    )]
+    loan: Box<Account<'info, Loan>>,
+
+    /// CHECK: This is not dangerous because we only send lamports to this account
+    #[account(mut, constraint = borrower.key() == loan.borrower @ ErrorCode::InvalidBorrower)]
+    borrower: UncheckedAccount<'info>,
+    #[account(constraint = loan.lender == Some(lender.key()) @ ErrorCode::InvalidLender)]
+    lender: Signer<'info>,
+
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    token_program: UncheckedAccount<'info>,
+
+    // cpi accounts
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    token_manager_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    recipient_token_account: UncheckedAccount<'info>,
+    rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateCtx<'info>>) -> Result<()> {
+    let token_manager_key = ctx.accounts.token_manager.key();
+    let loan_seeds = &[LOAN_SEED.as_bytes(), token_manager_key.as_ref(), &[ctx.accounts.loan.bump]];
+    let loan_signer = &[&loan_seeds[..]];
+
+    // return collateral to the receipt holder
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::InvalidateCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        token_manager_token_account: ctx.accounts.token_manager_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        recipient_token_account: ctx.accounts.recipient_token_account.to_account_info(),
+        invalidator: ctx.accounts.loan.to_account_info(),
+        collector: ctx.accounts.borrower.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts)
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec())
+        .with_signer(loan_signer);
+    solana_nft_programs_token_manager::cpi::invalidate(cpi_ctx)?;
+    Ok(())
+}
@@ -0,0 +1,11 @@
+pub mod cancel;
+pub mod fund;
+pub mod init;
+pub mod invalidate;
+pub mod repay;
+
+pub use cancel::*;
+pub use fund::*;
+pub use init::*;
+pub use invalidate::*;
+pub use repay::*;
@@ -0,0 +1,33 @@
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+use anchor_lang::prelude::*;
+use instructions::*;
+
+declare_id!("Loanfn4Vpk1yJvACWVJiCo5r5tEFRyVYp5fEz3KEH8v");
+
+#[program]
+pub mod solana_nft_programs_loan {
+    use super::*;
+
+    pub fn init(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+        init::handler(ctx, ix)
+    }
+
+    pub fn fund(ctx: Context<FundCtx>) -> Result<()> {
+        fund::handler(ctx)
+    }
+
+    pub fn repay<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, RepayCtx<'info>>) -> Result<()> {
+        repay::handler(ctx)
+    }
+
+    pub fn cancel<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, CancelCtx<'info>>) -> Result<()> {
+        cancel::handler(ctx)
+    }
+
+    pub fn invalidate<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateCtx<'info>>) -> Result<()> {
+        invalidate::handler(ctx)
+    }
+}
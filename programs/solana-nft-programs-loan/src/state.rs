@@ -0,0 +1,43 @@
+use crate::errors::ErrorCode;
+use anchor_lang::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+#[repr(u8)]
+pub enum LoanState {
+    /// Loan is waiting for a lender
+    Requested = 0,
+    /// Loan is funded and the collateral is escrowed
+    Active = 1,
+}
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+pub const LOAN_SEED: &str = "loan";
+pub const LOAN_SIZE: usize = 8 + std::mem::size_of::<Loan>() + 8;
+#[account]
+pub struct Loan {
+    pub bump: u8,
+    pub token_manager: Pubkey,
+    pub borrower: Pubkey,
+    pub lender: Option<Pubkey>,
+    pub state: u8,
+    // terms
+    pub payment_mint: Pubkey,
+    pub principal: u64,
+    // flat interest for the loan duration in basis points of the principal
+    pub interest_bps: u64,
+    pub duration_seconds: i64,
+    pub due_at: Option<i64>,
+}
+
+impl Loan {
+    pub fn repayment_amount(&self) -> Result<u64> {
+        let interest = (self.principal as u128)
+            .checked_mul(self.interest_bps as u128)
+            .expect("Multiplication error")
+            .checked_div(BPS_DENOMINATOR as u128)
+            .expect("Division error");
+        let interest = u64::try_from(interest).map_err(|_| error!(ErrorCode::RepaymentAmountOverflow))?;
+        self.principal.checked_add(interest).ok_or_else(|| error!(ErrorCode::RepaymentAmountOverflow))
+    }
+}
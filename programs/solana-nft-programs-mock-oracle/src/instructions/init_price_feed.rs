@@ -0,0 +1,28 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitPriceFeedIx {
+    pub price: i64,
+    pub exponent: i32,
+}
+
+#[derive(Accounts)]
+pub struct InitPriceFeedCtx<'info> {
+    #[account(init, payer = payer, space = PRICE_FEED_SIZE)]
+    price_feed: Box<Account<'info, PriceFeed>>,
+
+    owner: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitPriceFeedCtx>, ix: InitPriceFeedIx) -> Result<()> {
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.owner = ctx.accounts.owner.key();
+    price_feed.price = ix.price;
+    price_feed.exponent = ix.exponent;
+    price_feed.publish_time = Clock::get().unwrap().unix_timestamp;
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod init_price_feed;
+pub mod set_price;
+
+pub use init_price_feed::*;
+pub use set_price::*;
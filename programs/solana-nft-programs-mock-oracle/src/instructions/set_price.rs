@@ -0,0 +1,26 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetPriceIx {
+    pub price: i64,
+    pub exponent: i32,
+    // override the publish time to simulate stale feeds
+    pub publish_time: Option<i64>,
+}
+
+#[derive(Accounts)]
+pub struct SetPriceCtx<'info> {
+    #[account(mut, constraint = price_feed.owner == owner.key() @ ErrorCode::InvalidOwner)]
+    price_feed: Box<Account<'info, PriceFeed>>,
+    owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPriceCtx>, ix: SetPriceIx) -> Result<()> {
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.price = ix.price;
+    price_feed.exponent = ix.exponent;
+    price_feed.publish_time = ix.publish_time.unwrap_or(Clock::get().unwrap().unix_timestamp);
+    Ok(())
+}
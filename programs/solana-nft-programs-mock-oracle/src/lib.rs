@@ -0,0 +1,21 @@
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+use anchor_lang::prelude::*;
+use instructions::*;
+
+declare_id!("orcvQGVAjzAQcXpeiSX1NpmWBVoHSaddcXjG16syVHm");
+
+#[program]
+pub mod solana_nft_programs_mock_oracle {
+    use super::*;
+
+    pub fn init_price_feed(ctx: Context<InitPriceFeedCtx>, ix: InitPriceFeedIx) -> Result<()> {
+        init_price_feed::handler(ctx, ix)
+    }
+
+    pub fn set_price(ctx: Context<SetPriceCtx>, ix: SetPriceIx) -> Result<()> {
+        set_price::handler(ctx, ix)
+    }
+}
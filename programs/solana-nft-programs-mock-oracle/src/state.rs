@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+pub const PRICE_FEED_SIZE: usize = 8 + std::mem::size_of::<PriceFeed>() + 8;
+#[account]
+pub struct PriceFeed {
+    // Authority allowed to publish prices
+    pub owner: Pubkey,
+    // Price is price * 10^exponent
+    pub price: i64,
+    pub exponent: i32,
+    // Timestamp of the last published price
+    pub publish_time: i64,
+}
@@ -24,4 +24,28 @@ pub enum ErrorCode {
     InvalidPaymentMint,
     #[msg("Invalid mint")]
     InvalidMint,
+    #[msg("Claim requires a security deposit")]
+    SecurityDepositRequired,
+    #[msg("Invalid security deposit")]
+    InvalidSecurityDeposit,
+    #[msg("Security deposit already funded")]
+    SecurityDepositAlreadyFunded,
+    #[msg("Invalid deposit token account")]
+    InvalidDepositTokenAccount,
+    #[msg("Invalid security deposit authority")]
+    InvalidDepositAuthority,
+    #[msg("Rental has not ended")]
+    RentalNotEnded,
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+    #[msg("Dispute window is still open")]
+    DisputeWindowOpen,
+    #[msg("Invalid deposit amount")]
+    InvalidDepositAmount,
+    #[msg("Invalid depositor")]
+    InvalidDepositor,
+    #[msg("Invalid instruction")]
+    InvalidInstruction,
+    #[msg("Invalid token manager arbiter")]
+    InvalidTokenManagerArbiter,
 }
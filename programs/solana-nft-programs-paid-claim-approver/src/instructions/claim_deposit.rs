@@ -0,0 +1,46 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::observe_rental_end;
+use crate::utils::transfer_from_deposit;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+
+#[derive(Accounts)]
+pub struct ClaimDepositCtx<'info> {
+    /// CHECK: This is not dangerous because we expect it to potentially be empty
+    #[account(constraint = token_manager.key() == security_deposit.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = security_deposit.depositor.is_some() @ ErrorCode::InvalidSecurityDeposit)]
+    security_deposit: Box<Account<'info, SecurityDeposit>>,
+    /// CHECK: This is not dangerous because it is only used for spl deposits and checked in the handler
+    #[account(mut)]
+    deposit_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because it only receives the claimed deposit and is checked in the handler
+    #[account(mut)]
+    destination: UncheckedAccount<'info>,
+
+    #[account(constraint = authority.key() == security_deposit.issuer || security_deposit.arbiter == Some(authority.key()) @ ErrorCode::InvalidDepositAuthority)]
+    authority: Signer<'info>,
+    token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimDepositCtx>, amount: u64) -> Result<()> {
+    let now = Clock::get().unwrap().unix_timestamp;
+    let security_deposit = &mut ctx.accounts.security_deposit;
+    observe_rental_end(security_deposit, &ctx.accounts.token_manager, now)?;
+    let ended_at = security_deposit.ended_at.ok_or_else(|| error!(ErrorCode::RentalNotEnded))?;
+    if now > ended_at.checked_add(security_deposit.dispute_window_seconds).expect("Add error") {
+        return Err(error!(ErrorCode::DisputeWindowClosed));
+    }
+    if amount == 0 || amount > security_deposit.remaining_amount {
+        return Err(error!(ErrorCode::InvalidDepositAmount));
+    }
+    security_deposit.remaining_amount = security_deposit.remaining_amount.checked_sub(amount).expect("Sub error");
+
+    let deposit_token_account = ctx.accounts.deposit_token_account.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    transfer_from_deposit(security_deposit, &deposit_token_account, &ctx.accounts.destination, security_deposit.issuer, &token_program, amount)?;
+    Ok(())
+}
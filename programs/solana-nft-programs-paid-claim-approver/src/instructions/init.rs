@@ -40,5 +40,6 @@ pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
     claim_approver.payment_manager = ix.payment_manager;
     claim_approver.token_manager = ctx.accounts.token_manager.key();
     claim_approver.collector = ix.collector;
+    claim_approver.requires_deposit = None;
     Ok(())
 }
@@ -0,0 +1,64 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::InvalidationType;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitSecurityDepositIx {
+    pub payment_mint: Option<Pubkey>,
+    pub amount: u64,
+    pub arbiter: Option<Pubkey>,
+    pub dispute_window_seconds: i64,
+    pub max_expiration: Option<i64>,
+}
+
+#[derive(Accounts)]
+pub struct InitSecurityDepositCtx<'info> {
+    #[account(constraint =
+        token_manager.state == TokenManagerState::Initialized as u8
+        && (token_manager.invalidation_type == InvalidationType::Return as u8 || token_manager.invalidation_type == InvalidationType::Reissue as u8)
+        @ ErrorCode::InvalidTokenManager
+    )]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, constraint = claim_approver.token_manager == token_manager.key() @ ErrorCode::InvalidTokenManager)]
+    claim_approver: Box<Account<'info, PaidClaimApprover>>,
+
+    #[account(
+        init,
+        payer = issuer,
+        space = SECURITY_DEPOSIT_SIZE,
+        seeds = [SECURITY_DEPOSIT_SEED.as_bytes(), token_manager.key().as_ref()], bump,
+    )]
+    security_deposit: Box<Account<'info, SecurityDeposit>>,
+
+    #[account(mut, constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitSecurityDepositCtx>, ix: InitSecurityDepositIx) -> Result<()> {
+    if ix.amount == 0 || ix.dispute_window_seconds < 0 {
+        return Err(error!(ErrorCode::InvalidInstruction));
+    }
+    ctx.accounts.claim_approver.requires_deposit = Some(true);
+
+    let security_deposit = &mut ctx.accounts.security_deposit;
+    security_deposit.bump = *ctx.bumps.get("security_deposit").unwrap();
+    security_deposit.token_manager = ctx.accounts.token_manager.key();
+    security_deposit.mint_count = ctx.accounts.token_manager.count;
+    security_deposit.issuer = ctx.accounts.issuer.key();
+    security_deposit.arbiter = ix.arbiter;
+    security_deposit.payment_mint = ix.payment_mint;
+    security_deposit.amount = ix.amount;
+    security_deposit.dispute_window_seconds = ix.dispute_window_seconds;
+    security_deposit.max_expiration = ix.max_expiration;
+    security_deposit.depositor = None;
+    security_deposit.deposited_at = None;
+    security_deposit.remaining_amount = 0;
+    security_deposit.ended_at = None;
+    security_deposit.token_manager_closed = None;
+    Ok(())
+}
@@ -1,7 +1,19 @@
+pub mod claim_deposit;
 pub mod close;
 pub mod init;
+pub mod init_security_deposit;
+pub mod observe_rental_end;
 pub mod pay;
+pub mod pay_with_deposit;
+pub mod refund_deposit;
+pub mod split_deposit;
 
+pub use claim_deposit::*;
 pub use close::*;
 pub use init::*;
+pub use init_security_deposit::*;
+pub use observe_rental_end::*;
 pub use pay::*;
+pub use pay_with_deposit::*;
+pub use refund_deposit::*;
+pub use split_deposit::*;
@@ -0,0 +1,24 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::observe_rental_end;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ObserveRentalEndCtx<'info> {
+    /// CHECK: This is not dangerous because we expect it to potentially be empty
+    #[account(constraint = token_manager.key() == security_deposit.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = security_deposit.depositor.is_some() @ ErrorCode::InvalidSecurityDeposit)]
+    security_deposit: Box<Account<'info, SecurityDeposit>>,
+}
+
+/**
+ * permissionless so the end of the rental can be saved in the same transaction as the invalidation
+ * starting the dispute window at the invalidation rather than at the first claim or refund
+ */
+pub fn handler(ctx: Context<ObserveRentalEndCtx>) -> Result<()> {
+    let now = Clock::get().unwrap().unix_timestamp;
+    observe_rental_end(&mut ctx.accounts.security_deposit, &ctx.accounts.token_manager, now)?;
+    Ok(())
+}
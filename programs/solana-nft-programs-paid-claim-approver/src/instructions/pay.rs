@@ -50,6 +50,9 @@ pub struct PayCtx<'info> {
 }
 
 pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, PayCtx<'info>>) -> Result<()> {
+    if ctx.accounts.claim_approver.requires_deposit == Some(true) {
+        return Err(error!(ErrorCode::SecurityDepositRequired));
+    }
     let remaining_accs = &mut ctx.remaining_accounts.iter();
     assert_payment_token_account(&ctx.accounts.payment_token_account, &ctx.accounts.token_manager, remaining_accs)?;
 
@@ -0,0 +1,79 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::observe_rental_end;
+use crate::utils::transfer_from_deposit;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+use anchor_spl::token::CloseAccount;
+use anchor_spl::token::Token;
+use anchor_spl::token::{self};
+
+#[derive(Accounts)]
+pub struct RefundDepositCtx<'info> {
+    /// CHECK: This is not dangerous because we expect it to potentially be empty
+    #[account(constraint = token_manager.key() == security_deposit.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = security_deposit.depositor.is_some() @ ErrorCode::InvalidSecurityDeposit)]
+    security_deposit: Box<Account<'info, SecurityDeposit>>,
+    /// CHECK: This is not dangerous because it is only used for spl deposits and checked in the handler
+    #[account(mut)]
+    deposit_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because it only receives the refund and is checked in the handler
+    #[account(mut)]
+    destination: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because this is just the pubkey that collects the closing account lamports
+    #[account(mut, constraint = issuer.key() == security_deposit.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+}
+
+/**
+ * permissionless once the dispute window has passed
+ * refunds the depositor if the rental ended before max_expiration and otherwise forfeits the deposit to the issuer
+ * a returned token manager is closed on invalidation so the renter could not have held it past max_expiration
+ */
+pub fn handler(ctx: Context<RefundDepositCtx>) -> Result<()> {
+    let now = Clock::get().unwrap().unix_timestamp;
+    let security_deposit = &mut ctx.accounts.security_deposit;
+    observe_rental_end(security_deposit, &ctx.accounts.token_manager, now)?;
+    let ended_at = security_deposit.ended_at.ok_or_else(|| error!(ErrorCode::RentalNotEnded))?;
+    if now <= ended_at.checked_add(security_deposit.dispute_window_seconds).expect("Add error") {
+        return Err(error!(ErrorCode::DisputeWindowOpen));
+    }
+    let refund_to = match security_deposit.max_expiration {
+        Some(max_expiration) if ended_at >= max_expiration && security_deposit.token_manager_closed != Some(true) => security_deposit.issuer,
+        _ => security_deposit.depositor.expect("No depositor"),
+    };
+    let amount = security_deposit.remaining_amount;
+
+    let deposit_token_account = ctx.accounts.deposit_token_account.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    transfer_from_deposit(security_deposit, &deposit_token_account, &ctx.accounts.destination, refund_to, &token_program, amount)?;
+
+    if ctx.accounts.token_manager.data_is_empty() {
+        if security_deposit.payment_mint.is_some() {
+            let token_manager_key = security_deposit.token_manager;
+            let security_deposit_seeds = &[SECURITY_DEPOSIT_SEED.as_bytes(), token_manager_key.as_ref(), &[security_deposit.bump]];
+            let security_deposit_signer = &[&security_deposit_seeds[..]];
+            let cpi_accounts = CloseAccount {
+                account: deposit_token_account,
+                destination: ctx.accounts.issuer.to_account_info(),
+                authority: security_deposit.to_account_info(),
+            };
+            let cpi_context = CpiContext::new(token_program, cpi_accounts).with_signer(security_deposit_signer);
+            token::close_account(cpi_context)?;
+        }
+        security_deposit.close(ctx.accounts.issuer.to_account_info())?;
+    } else {
+        // reset for the next rental of a reissued token manager
+        security_deposit.depositor = None;
+        security_deposit.deposited_at = None;
+        security_deposit.remaining_amount = 0;
+        security_deposit.ended_at = None;
+        security_deposit.token_manager_closed = None;
+    }
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::observe_rental_end;
+use crate::utils::transfer_from_deposit;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+use anchor_spl::token::CloseAccount;
+use anchor_spl::token::Token;
+use anchor_spl::token::{self};
+use solana_nft_programs_token_manager::state::TokenManagerArbiter;
+
+#[derive(Accounts)]
+pub struct SplitDepositCtx<'info> {
+    /// CHECK: This is not dangerous because we expect it to potentially be empty
+    #[account(constraint = token_manager.key() == security_deposit.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = security_deposit.depositor.is_some() @ ErrorCode::InvalidSecurityDeposit)]
+    security_deposit: Box<Account<'info, SecurityDeposit>>,
+    /// CHECK: This is not dangerous because it is only used for spl deposits and checked in the handler
+    #[account(mut)]
+    deposit_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because it only receives the issuer share and is checked in the handler
+    #[account(mut)]
+    issuer_destination: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it only receives the depositor share and is checked in the handler
+    #[account(mut)]
+    depositor_destination: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because this is just the pubkey that collects the closing account lamports
+    #[account(mut, constraint = issuer.key() == security_deposit.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: UncheckedAccount<'info>,
+    #[account(constraint =
+        token_manager_arbiter.token_manager == security_deposit.token_manager
+        && token_manager_arbiter.mint_count == security_deposit.mint_count
+        @ ErrorCode::InvalidTokenManagerArbiter
+    )]
+    token_manager_arbiter: Box<Account<'info, TokenManagerArbiter>>,
+    #[account(constraint = arbiter.key() == token_manager_arbiter.arbiter @ ErrorCode::InvalidDepositAuthority)]
+    arbiter: Signer<'info>,
+    token_program: Program<'info, Token>,
+}
+
+/**
+ * settlement of a disputed deposit by the token manager arbiter once the rental has ended, regardless of the dispute window
+ * issuer_amount goes to the issuer and whatever remains is returned to the depositor
+ */
+pub fn handler(ctx: Context<SplitDepositCtx>, issuer_amount: u64) -> Result<()> {
+    let now = Clock::get().unwrap().unix_timestamp;
+    let security_deposit = &mut ctx.accounts.security_deposit;
+    observe_rental_end(security_deposit, &ctx.accounts.token_manager, now)?;
+    if security_deposit.ended_at.is_none() {
+        return Err(error!(ErrorCode::RentalNotEnded));
+    }
+    if issuer_amount > security_deposit.remaining_amount {
+        return Err(error!(ErrorCode::InvalidDepositAmount));
+    }
+    let depositor_amount = security_deposit.remaining_amount.checked_sub(issuer_amount).expect("Sub error");
+    let depositor = security_deposit.depositor.expect("No depositor");
+
+    let deposit_token_account = ctx.accounts.deposit_token_account.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    transfer_from_deposit(
+        security_deposit,
+        &deposit_token_account,
+        &ctx.accounts.issuer_destination,
+        security_deposit.issuer,
+        &token_program,
+        issuer_amount,
+    )?;
+    transfer_from_deposit(
+        security_deposit,
+        &deposit_token_account,
+        &ctx.accounts.depositor_destination,
+        depositor,
+        &token_program,
+        depositor_amount,
+    )?;
+
+    if ctx.accounts.token_manager.data_is_empty() {
+        if security_deposit.payment_mint.is_some() {
+            let token_manager_key = security_deposit.token_manager;
+            let security_deposit_seeds = &[SECURITY_DEPOSIT_SEED.as_bytes(), token_manager_key.as_ref(), &[security_deposit.bump]];
+            let security_deposit_signer = &[&security_deposit_seeds[..]];
+            let cpi_accounts = CloseAccount {
+                account: deposit_token_account,
+                destination: ctx.accounts.issuer.to_account_info(),
+                authority: security_deposit.to_account_info(),
+            };
+            let cpi_context = CpiContext::new(token_program, cpi_accounts).with_signer(security_deposit_signer);
+            token::close_account(cpi_context)?;
+        }
+        security_deposit.close(ctx.accounts.issuer.to_account_info())?;
+    } else {
+        // reset for the next rental of a reissued token manager
+        security_deposit.depositor = None;
+        security_deposit.deposited_at = None;
+        security_deposit.remaining_amount = 0;
+        security_deposit.ended_at = None;
+        security_deposit.token_manager_closed = None;
+    }
+    Ok(())
+}
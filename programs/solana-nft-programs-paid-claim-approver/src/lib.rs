@@ -1,6 +1,7 @@
 pub mod errors;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
 use instructions::*;
@@ -19,6 +20,30 @@ pub mod solana_nft_programs_paid_claim_approver {
         pay::handler(ctx)
     }
 
+    pub fn init_security_deposit(ctx: Context<InitSecurityDepositCtx>, ix: InitSecurityDepositIx) -> Result<()> {
+        init_security_deposit::handler(ctx, ix)
+    }
+
+    pub fn pay_with_deposit<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, PayWithDepositCtx<'info>>) -> Result<()> {
+        pay_with_deposit::handler(ctx)
+    }
+
+    pub fn claim_deposit(ctx: Context<ClaimDepositCtx>, amount: u64) -> Result<()> {
+        claim_deposit::handler(ctx, amount)
+    }
+
+    pub fn observe_rental_end(ctx: Context<ObserveRentalEndCtx>) -> Result<()> {
+        observe_rental_end::handler(ctx)
+    }
+
+    pub fn refund_deposit(ctx: Context<RefundDepositCtx>) -> Result<()> {
+        refund_deposit::handler(ctx)
+    }
+
+    pub fn split_deposit(ctx: Context<SplitDepositCtx>, issuer_amount: u64) -> Result<()> {
+        split_deposit::handler(ctx, issuer_amount)
+    }
+
     pub fn close(ctx: Context<CloseCtx>) -> Result<()> {
         close::handler(ctx)
     }
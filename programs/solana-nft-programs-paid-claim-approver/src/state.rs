@@ -10,4 +10,31 @@ pub struct PaidClaimApprover {
     pub payment_manager: Pubkey,
     pub token_manager: Pubkey,
     pub collector: Pubkey,
+    // set once a security deposit is configured so claims must go through pay_with_deposit
+    pub requires_deposit: Option<bool>,
+}
+
+pub const SECURITY_DEPOSIT_SEED: &str = "security-deposit";
+pub const SECURITY_DEPOSIT_SIZE: usize = 8 + std::mem::size_of::<SecurityDeposit>() + 8;
+#[account]
+pub struct SecurityDeposit {
+    pub bump: u8,
+    pub token_manager: Pubkey,
+    // count of the token manager so a token manager arbiter of a later token manager cannot settle this deposit
+    pub mint_count: u64,
+    pub issuer: Pubkey,
+    // optional neutral party that can claim the deposit alongside the issuer
+    pub arbiter: Option<Pubkey>,
+    // spl mint of the deposit or none for SOL held directly in this account
+    pub payment_mint: Option<Pubkey>,
+    pub amount: u64,
+    pub dispute_window_seconds: i64,
+    // deposit is forfeited to the issuer if the rental ends at or after this time
+    pub max_expiration: Option<i64>,
+    pub depositor: Option<Pubkey>,
+    pub deposited_at: Option<i64>,
+    pub remaining_amount: u64,
+    pub ended_at: Option<i64>,
+    // set when ended_at is the time a returned and closed token manager was observed rather than its own timestamp
+    pub token_manager_closed: Option<bool>,
 }
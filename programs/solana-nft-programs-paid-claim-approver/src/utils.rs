@@ -0,0 +1,70 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+// record when the rental backing a funded deposit ended
+// closed token managers have no timestamp left so the time of observation is used, which is exact when
+// observe_rental_end runs in the same transaction as the invalidation
+pub fn observe_rental_end(security_deposit: &mut SecurityDeposit, token_manager_info: &AccountInfo, now: i64) -> Result<()> {
+    if security_deposit.ended_at.is_some() || security_deposit.depositor.is_none() {
+        return Ok(());
+    }
+    if token_manager_info.data_is_empty() {
+        security_deposit.ended_at = Some(now);
+        security_deposit.token_manager_closed = Some(true);
+    } else {
+        let token_manager = Account::<TokenManager>::try_from(token_manager_info)?;
+        if token_manager.state != TokenManagerState::Claimed as u8 && token_manager.state_changed_at > security_deposit.deposited_at.expect("No deposit time") {
+            security_deposit.ended_at = Some(token_manager.state_changed_at);
+        }
+    }
+    Ok(())
+}
+
+// move part of a funded deposit to a recipient, either as spl tokens or lamports held by the deposit account
+pub fn transfer_from_deposit<'info>(
+    security_deposit: &Account<'info, SecurityDeposit>,
+    deposit_token_account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    recipient: Pubkey,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    if let Some(deposit_mint) = security_deposit.payment_mint {
+        let deposit_token_account_data = Account::<TokenAccount>::try_from(deposit_token_account)?;
+        if deposit_token_account_data.owner != security_deposit.key() || deposit_token_account_data.mint != deposit_mint {
+            return Err(error!(ErrorCode::InvalidDepositTokenAccount));
+        }
+        let destination_token_account = Account::<TokenAccount>::try_from(destination)?;
+        if destination_token_account.owner != recipient || destination_token_account.mint != deposit_mint {
+            return Err(error!(ErrorCode::InvalidDepositTokenAccount));
+        }
+
+        let token_manager_key = security_deposit.token_manager;
+        let security_deposit_seeds = &[SECURITY_DEPOSIT_SEED.as_bytes(), token_manager_key.as_ref(), &[security_deposit.bump]];
+        let security_deposit_signer = &[&security_deposit_seeds[..]];
+        let cpi_accounts = Transfer {
+            from: deposit_token_account.to_account_info(),
+            to: destination.to_account_info(),
+            authority: security_deposit.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(token_program.to_account_info(), cpi_accounts).with_signer(security_deposit_signer);
+        token::transfer(cpi_context, amount)?;
+    } else {
+        if destination.key() != recipient {
+            return Err(error!(ErrorCode::InvalidDepositor));
+        }
+        let security_deposit_info = security_deposit.to_account_info();
+        **security_deposit_info.try_borrow_mut_lamports()? = security_deposit_info.lamports().checked_sub(amount).expect("Sub error");
+        **destination.try_borrow_mut_lamports()? = destination.lamports().checked_add(amount).expect("Add error");
+    }
+    Ok(())
+}
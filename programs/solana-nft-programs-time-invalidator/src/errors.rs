@@ -40,4 +40,8 @@ pub enum ErrorCode {
     InvalidMint,
     #[msg("Invalid new max expiration")]
     InvalidNewMaxExpiration,
+    #[msg("Sublease cannot outlast the rental expiration")]
+    SubleaseExceedsExpiration,
+    #[msg("Token manager is under dispute")]
+    TokenManagerDisputed,
 }
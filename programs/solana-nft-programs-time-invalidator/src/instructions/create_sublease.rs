@@ -0,0 +1,84 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSubleaseIx {
+    pub expiration: i64,
+}
+
+#[derive(Accounts)]
+pub struct CreateSubleaseCtx<'info> {
+    #[account(mut)]
+    token_manager: Box<Account<'info, TokenManager>>,
+    #[account(constraint = time_invalidator.token_manager == token_manager.key() @ ErrorCode::InvalidTokenManager)]
+    time_invalidator: Box<Account<'info, TimeInvalidator>>,
+
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+
+    // cpi accounts
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is initialized by the token manager program
+    #[account(mut)]
+    sublease_token_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    lessor_token_account: UncheckedAccount<'info>,
+    lessor: Signer<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    lessee_token_account: UncheckedAccount<'info>,
+    lessee: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    token_program: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * approves a sublease of the token manager as its invalidator, capping the sublease at this rental's expiration
+ */
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, CreateSubleaseCtx<'info>>, ix: CreateSubleaseIx) -> Result<()> {
+    let time_invalidator = &ctx.accounts.time_invalidator;
+    let expiration = match time_invalidator.expiration {
+        Some(expiration) => Some(expiration),
+        None => time_invalidator
+            .duration_seconds
+            .map(|duration_seconds| ctx.accounts.token_manager.state_changed_at.checked_add(duration_seconds).expect("Addition error")),
+    };
+    let parent_expiration = match (expiration, time_invalidator.max_expiration) {
+        (Some(expiration), Some(max_expiration)) => Some(expiration.min(max_expiration)),
+        (expiration, max_expiration) => expiration.or(max_expiration),
+    };
+    if parent_expiration.is_some() && ix.expiration > parent_expiration.unwrap() {
+        return Err(error!(ErrorCode::SubleaseExceedsExpiration));
+    }
+
+    let token_manager_key = ctx.accounts.token_manager.key();
+    let time_invalidator_seeds = &[TIME_INVALIDATOR_SEED.as_bytes(), token_manager_key.as_ref(), &[time_invalidator.bump]];
+    let time_invalidator_signer = &[&time_invalidator_seeds[..]];
+
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::CreateSubleaseCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        sublease_token_manager: ctx.accounts.sublease_token_manager.to_account_info(),
+        approver: time_invalidator.to_account_info(),
+        lessor_token_account: ctx.accounts.lessor_token_account.to_account_info(),
+        lessor: ctx.accounts.lessor.to_account_info(),
+        lessee_token_account: ctx.accounts.lessee_token_account.to_account_info(),
+        lessee: ctx.accounts.lessee.to_account_info(),
+        payer: ctx.accounts.payer.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts)
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec())
+        .with_signer(time_invalidator_signer);
+    solana_nft_programs_token_manager::cpi::create_sublease(cpi_ctx, solana_nft_programs_token_manager::instructions::CreateSubleaseIx { expiration: ix.expiration })?;
+
+    Ok(())
+}
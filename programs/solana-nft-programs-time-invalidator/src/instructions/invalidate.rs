@@ -7,7 +7,7 @@ use solana_nft_programs_token_manager::state::TokenManagerState;
 
 #[derive(Accounts)]
 pub struct InvalidateCtx<'info> {
-    #[account(mut)]
+    #[account(mut, constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed)]
     token_manager: Box<Account<'info, TokenManager>>,
 
     #[account(mut,
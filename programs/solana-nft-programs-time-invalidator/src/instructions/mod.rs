@@ -1,4 +1,5 @@
 pub mod close;
+pub mod create_sublease;
 pub mod extend_expiration;
 pub mod init;
 pub mod invalidate;
@@ -6,6 +7,7 @@ pub mod reset_expiration;
 pub mod update_max_expiration;
 
 pub use close::*;
+pub use create_sublease::*;
 pub use extend_expiration::*;
 pub use init::*;
 pub use invalidate::*;
@@ -31,6 +31,10 @@ pub mod solana_nft_programs_time_invalidator {
         invalidate::handler(ctx)
     }
 
+    pub fn create_sublease<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, CreateSubleaseCtx<'info>>, ix: CreateSubleaseIx) -> Result<()> {
+        create_sublease::handler(ctx, ix)
+    }
+
     pub fn close(ctx: Context<CloseCtx>) -> Result<()> {
         close::handler(ctx)
     }
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Token account not owned by the claim approver")]
+    InvalidPaymentTokenAccount,
+    #[msg("Token account not owned by the issuer")]
+    InvalidPayerTokenAccount,
+    #[msg("Invalid token account")]
+    InvalidTokenAccount,
+    #[msg("Invalid issuer")]
+    InvalidIssuer,
+    #[msg("Invalid user")]
+    InvalidUser,
+    #[msg("Invalid token manager for this invalidator")]
+    InvalidTokenManager,
+    #[msg("Invalid time use invalidator")]
+    InvalidTimeUseInvalidator,
+    #[msg("Invalid instruction")]
+    InvalidInstruction,
+    #[msg("Usages at the maximum")]
+    InsufficientUsages,
+    #[msg("Max expiration exceeded")]
+    InvalidExtendExpiration,
+    #[msg("Max usages reached")]
+    MaxUsagesReached,
+    #[msg("Invalid extension amount")]
+    InvalidExtensionAmount,
+    #[msg("Invalid payment mint on time use invalidator")]
+    InvalidPaymentMint,
+    #[msg("Invalid collector")]
+    InvalidCollector,
+    #[msg("Invalid payment manager program")]
+    InvalidPaymentManagerProgram,
+    #[msg("Invalid payment manager")]
+    InvalidPaymentManager,
+    #[msg("Invalid mint")]
+    InvalidMint,
+    #[msg("Token manager is under dispute")]
+    TokenManagerDisputed,
+}
@@ -0,0 +1,118 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::current_expiration;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use solana_nft_programs_payment_manager::program::SolanaNftProgramsPaymentManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+use solana_nft_programs_token_manager::utils::assert_payment_token_account;
+
+#[derive(Accounts)]
+pub struct ExtendCtx<'info> {
+    #[account(constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, constraint = time_use_invalidator.token_manager == token_manager.key() @ ErrorCode::InvalidTimeUseInvalidator)]
+    time_use_invalidator: Box<Account<'info, TimeUseInvalidator>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut, constraint = payment_manager.key() == time_use_invalidator.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = payment_token_account.mint == time_use_invalidator.extension_payment_mint.expect("No extension mint") @ ErrorCode::InvalidPaymentTokenAccount)]
+    payment_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = fee_collector_token_account.mint == time_use_invalidator.extension_payment_mint.unwrap() @ ErrorCode::InvalidPaymentMint)]
+    fee_collector_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(mut, constraint =
+        payer_token_account.owner == payer.key()
+        && payer_token_account.mint == time_use_invalidator.extension_payment_mint.expect("No extension mint")
+        @ ErrorCode::InvalidPayerTokenAccount
+    )]
+    payer_token_account: Box<Account<'info, TokenAccount>>,
+
+    token_program: Program<'info, Token>,
+    solana_nft_programs_payment_manager: Program<'info, SolanaNftProgramsPaymentManager>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ExtendCtx<'info>>, num_extensions: u64) -> Result<()> {
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    assert_payment_token_account(&ctx.accounts.payment_token_account, &ctx.accounts.token_manager, remaining_accs)?;
+
+    let token_manager = &ctx.accounts.token_manager;
+    let time_use_invalidator = &mut ctx.accounts.time_use_invalidator;
+    if time_use_invalidator.extension_payment_amount.is_none() || time_use_invalidator.extension_payment_mint.is_none() {
+        return Err(error!(ErrorCode::InvalidTimeUseInvalidator));
+    }
+    if num_extensions == 0 {
+        return Err(error!(ErrorCode::InvalidExtensionAmount));
+    }
+    let price_to_pay = time_use_invalidator.extension_payment_amount.unwrap().checked_mul(num_extensions).expect("Multiplication error");
+
+    // one extension extends both the time and the usage conditions
+    if let Some(extension_duration_seconds) = time_use_invalidator.extension_duration_seconds {
+        let seconds_to_add = extension_duration_seconds.checked_mul(num_extensions).expect("Multiplication error");
+        let expiration = current_expiration(time_use_invalidator, token_manager).expect("No duration set");
+        let new_expiration = expiration.checked_add(seconds_to_add as i64).expect("Add error");
+        if time_use_invalidator.max_expiration.is_some() && new_expiration > time_use_invalidator.max_expiration.unwrap() {
+            return Err(error!(ErrorCode::InvalidExtendExpiration));
+        }
+        time_use_invalidator.expiration = Some(new_expiration);
+    }
+    if let Some(extension_usages) = time_use_invalidator.extension_usages {
+        let usages_to_add = extension_usages.checked_mul(num_extensions).expect("Multiplication error");
+        let new_total_usages = time_use_invalidator.total_usages.expect("No total usages").checked_add(usages_to_add).expect("Add error");
+        if time_use_invalidator.max_usages.is_some() && new_total_usages > time_use_invalidator.max_usages.unwrap() {
+            return Err(error!(ErrorCode::MaxUsagesReached));
+        }
+        time_use_invalidator.total_usages = Some(new_total_usages);
+    }
+    msg!("Extending {:?} times by paying {:?}", num_extensions, price_to_pay);
+
+    if ctx.accounts.payment_manager.owner.key() == ctx.accounts.solana_nft_programs_payment_manager.key() {
+        let payment_mint_info = next_account_info(remaining_accs)?;
+        let payment_mint = Account::<Mint>::try_from(payment_mint_info)?;
+        if time_use_invalidator.extension_payment_mint.unwrap() != payment_mint.key() {
+            return Err(error!(ErrorCode::InvalidPaymentMint));
+        }
+
+        let mint_info = next_account_info(remaining_accs)?;
+        let mint = Account::<Mint>::try_from(mint_info)?;
+        if token_manager.mint != mint.key() {
+            return Err(error!(ErrorCode::InvalidMint));
+        }
+        let mint_metadata_info = next_account_info(remaining_accs)?;
+
+        let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandlePaymentWithRoyaltiesCtx {
+            payment_manager: ctx.accounts.payment_manager.to_account_info(),
+            payer_token_account: ctx.accounts.payer_token_account.to_account_info(),
+            fee_collector_token_account: ctx.accounts.fee_collector_token_account.to_account_info(),
+            payment_token_account: ctx.accounts.payment_token_account.to_account_info(),
+            payment_mint: payment_mint.to_account_info(),
+            mint: mint.to_account_info(),
+            mint_metadata: mint_metadata_info.to_account_info(),
+            payer: ctx.accounts.payer.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_ctx =
+            CpiContext::new(ctx.accounts.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts).with_remaining_accounts(remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>());
+        solana_nft_programs_payment_manager::cpi::handle_payment_with_royalties(cpi_ctx, price_to_pay)?;
+    } else {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.payer_token_account.to_account_info(),
+            to: ctx.accounts.payment_token_account.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_context, price_to_pay)?;
+    }
+    Ok(())
+}
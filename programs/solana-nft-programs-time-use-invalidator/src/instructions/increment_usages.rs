@@ -0,0 +1,31 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+#[instruction(num_usages: u64)]
+pub struct IncrementUsagesCtx<'info> {
+    #[account(constraint = token_manager.key() == time_use_invalidator.token_manager && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager, constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, constraint = time_use_invalidator.total_usages.is_none() || time_use_invalidator.usages.checked_add(num_usages).expect("Add error") <= time_use_invalidator.total_usages.unwrap() @ ErrorCode::InsufficientUsages)]
+    time_use_invalidator: Box<Account<'info, TimeUseInvalidator>>,
+
+    #[account(constraint = token_manager.recipient_token_account == recipient_token_account.key() @ ErrorCode::InvalidTokenAccount)]
+    recipient_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = (time_use_invalidator.use_authority.is_some() && user.key() == time_use_invalidator.use_authority.unwrap())
+        || (time_use_invalidator.use_authority.is_none() && user.key() == recipient_token_account.owner)
+        @ ErrorCode::InvalidUser
+    )]
+    user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<IncrementUsagesCtx>, num_usages: u64) -> Result<()> {
+    let time_use_invalidator = &mut ctx.accounts.time_use_invalidator;
+    time_use_invalidator.usages = time_use_invalidator.usages.checked_add(num_usages).expect("Add error");
+    Ok(())
+}
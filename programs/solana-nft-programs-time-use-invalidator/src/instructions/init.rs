@@ -0,0 +1,74 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitIx {
+    pub collector: Pubkey,
+    pub payment_manager: Pubkey,
+    pub mode: u8,
+    pub duration_seconds: Option<i64>,
+    pub max_expiration: Option<i64>,
+    pub total_usages: Option<u64>,
+    pub max_usages: Option<u64>,
+    pub use_authority: Option<Pubkey>,
+    pub extension_payment_amount: Option<u64>,
+    pub extension_payment_mint: Option<Pubkey>,
+    pub extension_duration_seconds: Option<u64>,
+    pub extension_usages: Option<u64>,
+}
+
+#[derive(Accounts)]
+pub struct InitCtx<'info> {
+    #[account(constraint = token_manager.state == TokenManagerState::Initialized as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TIME_USE_INVALIDATOR_SIZE,
+        seeds = [TIME_USE_INVALIDATOR_SEED.as_bytes(), token_manager.key().as_ref()], bump,
+    )]
+    time_use_invalidator: Box<Account<'info, TimeUseInvalidator>>,
+
+    #[account(mut, constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+    if ix.mode != ConditionMode::Or as u8 && ix.mode != ConditionMode::And as u8 {
+        return Err(error!(ErrorCode::InvalidInstruction));
+    } else if ix.duration_seconds.is_none() && ix.total_usages.is_none() {
+        return Err(error!(ErrorCode::InvalidInstruction));
+    } else if ix.mode == ConditionMode::And as u8 && (ix.duration_seconds.is_none() || ix.total_usages.is_none()) {
+        return Err(error!(ErrorCode::InvalidInstruction));
+    } else if ix.extension_payment_amount.is_some() && (ix.extension_payment_mint.is_none() || (ix.extension_duration_seconds.is_none() && ix.extension_usages.is_none())) {
+        return Err(error!(ErrorCode::InvalidInstruction));
+    } else if (ix.extension_duration_seconds.is_some() && ix.duration_seconds.is_none()) || (ix.extension_usages.is_some() && ix.total_usages.is_none()) {
+        return Err(error!(ErrorCode::InvalidInstruction));
+    }
+
+    let time_use_invalidator = &mut ctx.accounts.time_use_invalidator;
+    time_use_invalidator.bump = *ctx.bumps.get("time_use_invalidator").unwrap();
+    time_use_invalidator.token_manager = ctx.accounts.token_manager.key();
+    time_use_invalidator.collector = ix.collector;
+    time_use_invalidator.payment_manager = ix.payment_manager;
+    time_use_invalidator.mode = ix.mode;
+    time_use_invalidator.expiration = None;
+    time_use_invalidator.duration_seconds = ix.duration_seconds;
+    time_use_invalidator.max_expiration = ix.max_expiration;
+    time_use_invalidator.usages = 0;
+    time_use_invalidator.total_usages = ix.total_usages;
+    time_use_invalidator.max_usages = ix.max_usages;
+    time_use_invalidator.use_authority = ix.use_authority;
+    time_use_invalidator.extension_payment_amount = ix.extension_payment_amount;
+    time_use_invalidator.extension_payment_mint = ix.extension_payment_mint;
+    time_use_invalidator.extension_duration_seconds = ix.extension_duration_seconds;
+    time_use_invalidator.extension_usages = ix.extension_usages;
+    Ok(())
+}
@@ -0,0 +1,11 @@
+pub mod close;
+pub mod extend;
+pub mod increment_usages;
+pub mod init;
+pub mod invalidate;
+
+pub use close::*;
+pub use extend::*;
+pub use increment_usages::*;
+pub use init::*;
+pub use invalidate::*;
@@ -0,0 +1,34 @@
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod utils;
+
+use anchor_lang::prelude::*;
+use instructions::*;
+
+declare_id!("tuseXXvAtJB9wR1Q2VepvnKHK9Mdfho2ufLgvj5d6nA");
+
+#[program]
+pub mod solana_nft_programs_time_use_invalidator {
+    use super::*;
+
+    pub fn init(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+        init::handler(ctx, ix)
+    }
+
+    pub fn increment_usages(ctx: Context<IncrementUsagesCtx>, num_usages: u64) -> Result<()> {
+        increment_usages::handler(ctx, num_usages)
+    }
+
+    pub fn extend<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ExtendCtx<'info>>, num_extensions: u64) -> Result<()> {
+        extend::handler(ctx, num_extensions)
+    }
+
+    pub fn invalidate<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateCtx<'info>>) -> Result<()> {
+        invalidate::handler(ctx)
+    }
+
+    pub fn close(ctx: Context<CloseCtx>) -> Result<()> {
+        close::handler(ctx)
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+#[repr(u8)]
+pub enum ConditionMode {
+    // Invalidate as soon as either the time or the usage condition is met
+    Or = 0,
+    // Invalidate only once both the time and the usage conditions are met
+    And = 1,
+}
+
+pub const TIME_USE_INVALIDATOR_SEED: &str = "time-use-invalidator";
+pub const TIME_USE_INVALIDATOR_SIZE: usize = 8 + std::mem::size_of::<TimeUseInvalidator>() + 8;
+#[account]
+pub struct TimeUseInvalidator {
+    pub bump: u8,
+    pub token_manager: Pubkey,
+    pub payment_manager: Pubkey,
+    pub collector: Pubkey,
+    // how the time and usage conditions are combined
+    pub mode: u8,
+    // time
+    pub expiration: Option<i64>,
+    pub duration_seconds: Option<i64>,
+    pub max_expiration: Option<i64>,
+    // usages
+    pub usages: u64,
+    pub total_usages: Option<u64>,
+    pub max_usages: Option<u64>,
+    pub use_authority: Option<Pubkey>,
+    // extension
+    pub extension_payment_amount: Option<u64>,
+    pub extension_payment_mint: Option<Pubkey>,
+    pub extension_duration_seconds: Option<u64>,
+    pub extension_usages: Option<u64>,
+}
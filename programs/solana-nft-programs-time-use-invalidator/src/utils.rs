@@ -0,0 +1,38 @@
+use crate::state::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+use std::cmp::max;
+
+// current expiration of a claimed token manager, if a time condition is set
+pub fn current_expiration(time_use_invalidator: &TimeUseInvalidator, token_manager: &TokenManager) -> Option<i64> {
+    let duration_expiration = time_use_invalidator
+        .duration_seconds
+        .map(|duration_seconds| token_manager.state_changed_at.checked_add(duration_seconds).expect("Add error"));
+    match (time_use_invalidator.expiration, duration_expiration) {
+        (Some(expiration), Some(duration_expiration)) => Some(max(expiration, duration_expiration)),
+        (Some(expiration), None) => Some(expiration),
+        (None, duration_expiration) => duration_expiration,
+    }
+}
+
+pub fn time_condition_met(time_use_invalidator: &TimeUseInvalidator, token_manager: &TokenManager, now: i64) -> bool {
+    token_manager.state == TokenManagerState::Claimed as u8 && current_expiration(time_use_invalidator, token_manager).map(|expiration| now >= expiration).unwrap_or(false)
+}
+
+pub fn usage_condition_met(time_use_invalidator: &TimeUseInvalidator) -> bool {
+    time_use_invalidator.total_usages.map(|total_usages| time_use_invalidator.usages >= total_usages).unwrap_or(false)
+}
+
+pub fn should_invalidate(time_use_invalidator: &TimeUseInvalidator, token_manager: &TokenManager, now: i64) -> bool {
+    // max expiration is a hard cap regardless of mode
+    if time_use_invalidator.max_expiration.is_some() && now >= time_use_invalidator.max_expiration.unwrap() {
+        return true;
+    }
+    let time_met = time_condition_met(time_use_invalidator, token_manager, now);
+    let usage_met = usage_condition_met(time_use_invalidator);
+    if time_use_invalidator.mode == ConditionMode::And as u8 {
+        time_met && usage_met
+    } else {
+        time_met || usage_met
+    }
+}
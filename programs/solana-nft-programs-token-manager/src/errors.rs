@@ -82,4 +82,34 @@ pub enum ErrorCode {
     CannotMigrateDelegatedToken,
     #[msg("Invalid return target")]
     InvalidReturnTarget,
+    #[msg("Claim receipt has expired")]
+    ClaimReceiptExpired,
+    #[msg("Claim receipt is neither expired nor stale")]
+    ClaimReceiptStillValid,
+    #[msg("Invalid claim receipt payer")]
+    InvalidClaimReceiptPayer,
+    #[msg("Token manager is under dispute")]
+    TokenManagerDisputed,
+    #[msg("Token manager is not under dispute")]
+    TokenManagerNotDisputed,
+    #[msg("Invalid arbiter")]
+    InvalidArbiter,
+    #[msg("Dispute outcome must be return or release")]
+    InvalidDisputeOutcome,
+    #[msg("Invalid token manager arbiter")]
+    InvalidTokenManagerArbiter,
+    #[msg("Token manager arbiter is still in use")]
+    TokenManagerArbiterStillValid,
+    #[msg("Token manager is subleased")]
+    TokenManagerSubleased,
+    #[msg("Invalid sublease")]
+    InvalidSublease,
+    #[msg("Invalid sublease expiration")]
+    InvalidSubleaseExpiration,
+    #[msg("Sublease has not expired")]
+    SubleaseNotExpired,
+    #[msg("Sublease is still active")]
+    SubleaseStillValid,
+    #[msg("Dispute outcome must be enforced by an invalidation in the same transaction")]
+    DisputeOutcomeNotEnforced,
 }
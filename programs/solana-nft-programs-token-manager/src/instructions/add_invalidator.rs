@@ -15,6 +15,10 @@ pub struct AddInvalidatorCtx<'info> {
 pub fn handler(ctx: Context<AddInvalidatorCtx>, invalidator: Pubkey) -> Result<()> {
     // set token manager data
     let token_manager = &mut ctx.accounts.token_manager;
+    // the sole invalidator of a releasable token manager must stay the only one
+    if token_manager.releasable == Some(true) {
+        return Err(error!(ErrorCode::InvalidInvalidator));
+    }
     if token_manager.invalidators.len() as u8 >= token_manager.num_invalidators {
         return Err(error!(ErrorCode::MaximumInvalidatorsReached));
     }
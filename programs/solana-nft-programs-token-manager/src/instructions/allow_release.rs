@@ -0,0 +1,29 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AllowReleaseCtx<'info> {
+    #[account(mut, constraint =
+        (token_manager.state == TokenManagerState::Initialized as u8 || token_manager.state == TokenManagerState::Claimed as u8)
+        && token_manager.invalidation_type == InvalidationType::Return as u8
+        @ ErrorCode::InvalidTokenManagerState
+    )]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(constraint = token_manager.invalidators == vec![invalidator.key()] @ ErrorCode::InvalidInvalidator)]
+    invalidator: Signer<'info>,
+
+    #[account(constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: Signer<'info>,
+}
+
+/**
+ * the issuer and the sole invalidator agree that the invalidator may switch return => release
+ * the invalidation type is locked from then on so the issuer cannot switch away from return underneath the invalidator
+ */
+pub fn handler(ctx: Context<AllowReleaseCtx>) -> Result<()> {
+    let token_manager = &mut ctx.accounts.token_manager;
+    token_manager.releasable = Some(true);
+    Ok(())
+}
@@ -375,6 +375,11 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         if claim_receipt.target != ctx.accounts.recipient.key() {
             return Err(error!(ErrorCode::InvalidClaimReceipt));
         }
+        if let Some(expires_at) = claim_receipt.expires_at {
+            if Clock::get().unwrap().unix_timestamp >= expires_at {
+                return Err(error!(ErrorCode::ClaimReceiptExpired));
+            }
+        }
         claim_receipt.close(token_manager.to_account_info())?;
     }
     Ok(())
@@ -0,0 +1,43 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+
+#[derive(Accounts)]
+pub struct CloseClaimReceiptCtx<'info> {
+    /// CHECK: This is not dangerous because we expect it to potentially be empty
+    #[account(constraint = token_manager.key() == claim_receipt.token_manager @ ErrorCode::InvalidClaimReceipt)]
+    token_manager: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    claim_receipt: Box<Account<'info, ClaimReceipt>>,
+
+    // legacy claim receipts did not record their payer and cannot be closed here
+    /// CHECK: This is not dangerous because this is just the pubkey that collects the closing account lamports
+    #[account(mut, constraint = Some(payer.key()) == claim_receipt.payer @ ErrorCode::InvalidClaimReceiptPayer)]
+    payer: UncheckedAccount<'info>,
+}
+
+/**
+ * permissionless close of a claim receipt that is either expired or no longer matches its token manager
+ */
+pub fn handler(ctx: Context<CloseClaimReceiptCtx>) -> Result<()> {
+    let claim_receipt = &ctx.accounts.claim_receipt;
+    let expired = match claim_receipt.expires_at {
+        Some(expires_at) => Clock::get().unwrap().unix_timestamp >= expires_at,
+        None => false,
+    };
+
+    let stale = if ctx.accounts.token_manager.data_is_empty() {
+        true
+    } else {
+        let token_manager = Account::<TokenManager>::try_from(&ctx.accounts.token_manager)?;
+        claim_receipt.mint_count != token_manager.count
+    };
+
+    if !expired && !stale {
+        return Err(error!(ErrorCode::ClaimReceiptStillValid));
+    }
+    ctx.accounts.claim_receipt.close(ctx.accounts.payer.to_account_info())?;
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+
+#[derive(Accounts)]
+pub struct CloseTokenManagerArbiterCtx<'info> {
+    /// CHECK: This is not dangerous because we expect it to potentially be empty
+    #[account(constraint = token_manager.key() == token_manager_arbiter.token_manager @ ErrorCode::InvalidTokenManagerArbiter)]
+    token_manager: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    token_manager_arbiter: Box<Account<'info, TokenManagerArbiter>>,
+
+    /// CHECK: This is not dangerous because this is just the pubkey that collects the closing account lamports
+    #[account(mut, constraint = payer.key() == token_manager_arbiter.payer @ ErrorCode::InvalidTokenManagerArbiter)]
+    payer: UncheckedAccount<'info>,
+}
+
+/**
+ * permissionless close of an arbiter account whose token manager has been closed or recreated
+ */
+pub fn handler(ctx: Context<CloseTokenManagerArbiterCtx>) -> Result<()> {
+    let stale = if ctx.accounts.token_manager.data_is_empty() {
+        true
+    } else {
+        let token_manager = Account::<TokenManager>::try_from(&ctx.accounts.token_manager)?;
+        ctx.accounts.token_manager_arbiter.mint_count != token_manager.count
+    };
+
+    if !stale {
+        return Err(error!(ErrorCode::TokenManagerArbiterStillValid));
+    }
+    ctx.accounts.token_manager_arbiter.close(ctx.accounts.payer.to_account_info())?;
+    Ok(())
+}
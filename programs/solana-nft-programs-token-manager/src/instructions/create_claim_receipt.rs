@@ -30,5 +30,7 @@ pub fn handler(ctx: Context<CreateClaimReceiptCtx>, target: Pubkey) -> Result<()
     claim_receipt.mint_count = ctx.accounts.token_manager.count;
     claim_receipt.token_manager = ctx.accounts.token_manager.key();
     claim_receipt.target = target;
+    claim_receipt.expires_at = None;
+    claim_receipt.payer = Some(ctx.accounts.payer.key());
     Ok(())
 }
@@ -0,0 +1,45 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateClaimReceiptWithExpirationIx {
+    pub target: Pubkey,
+    pub expires_at: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: CreateClaimReceiptWithExpirationIx)]
+pub struct CreateClaimReceiptWithExpirationCtx<'info> {
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(constraint =
+        token_manager.claim_approver.expect("No claim approver") == claim_approver.key()
+        @ ErrorCode::InvalidIssuer
+    )]
+    claim_approver: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [CLAIM_RECEIPT_SEED.as_bytes(), token_manager.key().as_ref(), ix.target.as_ref()], bump,
+        space = CLAIM_RECEIPT_SIZE,
+    )]
+    claim_receipt: Box<Account<'info, ClaimReceipt>>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateClaimReceiptWithExpirationCtx>, ix: CreateClaimReceiptWithExpirationIx) -> Result<()> {
+    if ix.expires_at <= Clock::get().unwrap().unix_timestamp {
+        return Err(error!(ErrorCode::ClaimReceiptExpired));
+    }
+    let claim_receipt = &mut ctx.accounts.claim_receipt;
+    claim_receipt.mint_count = ctx.accounts.token_manager.count;
+    claim_receipt.token_manager = ctx.accounts.token_manager.key();
+    claim_receipt.target = ix.target;
+    claim_receipt.expires_at = Some(ix.expires_at);
+    claim_receipt.payer = Some(ctx.accounts.payer.key());
+    Ok(())
+}
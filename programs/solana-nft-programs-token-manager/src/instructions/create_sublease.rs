@@ -0,0 +1,155 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Approve;
+use anchor_spl::token::FreezeAccount;
+use anchor_spl::token::Mint;
+use anchor_spl::token::ThawAccount;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use mpl_utils::assert_derivation;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSubleaseIx {
+    pub expiration: i64,
+}
+
+#[derive(Accounts)]
+pub struct CreateSubleaseCtx<'info> {
+    #[account(mut,
+        constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState,
+        constraint = token_manager.kind == TokenManagerKind::Managed as u8 @ ErrorCode::InvalidTokenManagerKind,
+        constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed,
+        constraint = token_manager.subleased != Some(true) && token_manager.parent.is_none() @ ErrorCode::TokenManagerSubleased
+    )]
+    token_manager: Box<Account<'info, TokenManager>>,
+    #[account(constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
+    mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [SUBLEASE_SEED.as_bytes(), token_manager.key().as_ref()], bump,
+        space = token_manager_size(1),
+    )]
+    sublease_token_manager: Box<Account<'info, TokenManager>>,
+
+    // invalidator of the parent that is responsible for capping the sublease expiration
+    #[account(constraint = token_manager.invalidators.contains(&approver.key()) @ ErrorCode::InvalidInvalidator)]
+    approver: Signer<'info>,
+
+    // lessor
+    #[account(mut, constraint =
+        lessor_token_account.key() == token_manager.recipient_token_account
+        && lessor_token_account.owner == lessor.key()
+        @ ErrorCode::InvalidCurrentTokenAccount
+    )]
+    lessor_token_account: Box<Account<'info, TokenAccount>>,
+    lessor: Signer<'info>,
+
+    // lessee
+    #[account(mut, constraint =
+        lessee_token_account.key() != lessor_token_account.key()
+        && lessee_token_account.owner == lessee.key()
+        && lessee_token_account.mint == token_manager.mint
+        @ ErrorCode::InvalidRecipientTokenAccount
+    )]
+    lessee_token_account: Box<Account<'info, TokenAccount>>,
+    lessee: Signer<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * issues a child token manager from the holder of a claimed token manager to a lessee until the sublease expires
+ * the parent invalidator approving the sublease is trusted to cap the expiration at its own
+ */
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, CreateSubleaseCtx<'info>>, ix: CreateSubleaseIx) -> Result<()> {
+    if ix.expiration <= Clock::get().unwrap().unix_timestamp {
+        return Err(error!(ErrorCode::InvalidSubleaseExpiration));
+    }
+
+    let token_manager = &mut ctx.accounts.token_manager;
+    token_manager.subleased = Some(true);
+
+    // the lessor issues the child and gets the token back when it is invalidated
+    let sublease_token_manager = &mut ctx.accounts.sublease_token_manager;
+    sublease_token_manager.bump = *ctx.bumps.get("sublease_token_manager").unwrap();
+    sublease_token_manager.count = token_manager.count;
+    sublease_token_manager.num_invalidators = 1;
+    sublease_token_manager.issuer = ctx.accounts.lessor.key();
+    sublease_token_manager.mint = token_manager.mint;
+    sublease_token_manager.amount = token_manager.amount;
+    sublease_token_manager.kind = token_manager.kind;
+    sublease_token_manager.state = TokenManagerState::Claimed as u8;
+    sublease_token_manager.state_changed_at = Clock::get().unwrap().unix_timestamp;
+    sublease_token_manager.invalidation_type = InvalidationType::Return as u8;
+    sublease_token_manager.recipient_token_account = ctx.accounts.lessee_token_account.key();
+    sublease_token_manager.invalidators = vec![ctx.accounts.approver.key()];
+    sublease_token_manager.parent = Some(token_manager.key());
+    sublease_token_manager.expiration = Some(ix.expiration);
+
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    let mint_manager_info = next_account_info(remaining_accs)?;
+    let mint = ctx.accounts.mint.key();
+    let path = &[MINT_MANAGER_SEED.as_bytes(), mint.as_ref()];
+    let bump_seed = assert_derivation(ctx.program_id, mint_manager_info, path, error!(ErrorCode::PublicKeyMismatch))?;
+    let mint_manager_seeds = &[MINT_MANAGER_SEED.as_bytes(), mint.as_ref(), &[bump_seed]];
+    let mint_manager_signer = &[&mint_manager_seeds[..]];
+
+    // thaw lessor account
+    let cpi_accounts = ThawAccount {
+        account: ctx.accounts.lessor_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: mint_manager_info.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
+    token::thaw_account(cpi_context)?;
+
+    // transfer as the lessor so the token manager delegate on the lessor account is kept for the return
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.lessor_token_account.to_account_info(),
+        to: ctx.accounts.lessee_token_account.to_account_info(),
+        authority: ctx.accounts.lessor.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_context, token_manager.amount)?;
+
+    // re-freeze lessor account so it stays locked until the token comes back
+    let cpi_accounts = FreezeAccount {
+        account: ctx.accounts.lessor_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: mint_manager_info.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
+    token::freeze_account(cpi_context)?;
+
+    // set account delegate of lessee token account to the child token manager PDA
+    let cpi_accounts = Approve {
+        to: ctx.accounts.lessee_token_account.to_account_info(),
+        delegate: sublease_token_manager.to_account_info(),
+        authority: ctx.accounts.lessee.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+    token::approve(cpi_context, token_manager.amount)?;
+
+    // freeze lessee token account
+    let cpi_accounts = FreezeAccount {
+        account: ctx.accounts.lessee_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: mint_manager_info.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
+    token::freeze_account(cpi_context)?;
+    Ok(())
+}
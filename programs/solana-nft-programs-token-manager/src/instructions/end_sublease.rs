@@ -0,0 +1,104 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::FreezeAccount;
+use anchor_spl::token::Mint;
+use anchor_spl::token::ThawAccount;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use mpl_utils::assert_derivation;
+
+#[derive(Accounts)]
+pub struct EndSubleaseCtx<'info> {
+    #[account(mut,
+        constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState,
+        constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed,
+        constraint = token_manager.subleased == Some(true) @ ErrorCode::InvalidSublease
+    )]
+    token_manager: Box<Account<'info, TokenManager>>,
+    #[account(constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
+    mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, close = lessor,
+        seeds = [SUBLEASE_SEED.as_bytes(), token_manager.key().as_ref()], bump = sublease_token_manager.bump,
+        constraint = Clock::get().unwrap().unix_timestamp >= sublease_token_manager.expiration.expect("No sublease expiration") @ ErrorCode::SubleaseNotExpired
+    )]
+    sublease_token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, constraint = lessee_token_account.key() == sublease_token_manager.recipient_token_account @ ErrorCode::InvalidCurrentTokenAccount)]
+    lessee_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = lessor_token_account.key() == token_manager.recipient_token_account @ ErrorCode::InvalidRecipientTokenAccount)]
+    lessor_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This is not dangerous because this is just the pubkey that collects the closing account lamports
+    #[account(mut, constraint = lessor.key() == sublease_token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    lessor: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+}
+
+/**
+ * permissionless once the sublease has expired, invalidates the child token manager and returns the token to the lessor
+ */
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, EndSubleaseCtx<'info>>) -> Result<()> {
+    let token_manager = &mut ctx.accounts.token_manager;
+    token_manager.subleased = None;
+    let sublease_token_manager = &mut ctx.accounts.sublease_token_manager;
+    sublease_token_manager.state = TokenManagerState::Invalidated as u8;
+    sublease_token_manager.state_changed_at = Clock::get().unwrap().unix_timestamp;
+
+    // get PDA seeds to sign with
+    let token_manager_key = token_manager.key();
+    let sublease_token_manager_seeds = &[SUBLEASE_SEED.as_bytes(), token_manager_key.as_ref(), &[sublease_token_manager.bump]];
+    let sublease_token_manager_signer = &[&sublease_token_manager_seeds[..]];
+
+    let remaining_accs = &mut ctx.remaining_accounts.iter();
+    let mint_manager_info = next_account_info(remaining_accs)?;
+    let mint = ctx.accounts.mint.key();
+    let path = &[MINT_MANAGER_SEED.as_bytes(), mint.as_ref()];
+    let bump_seed = assert_derivation(ctx.program_id, mint_manager_info, path, error!(ErrorCode::PublicKeyMismatch))?;
+    let mint_manager_seeds = &[MINT_MANAGER_SEED.as_bytes(), mint.as_ref(), &[bump_seed]];
+    let mint_manager_signer = &[&mint_manager_seeds[..]];
+
+    // thaw lessee account
+    let cpi_accounts = ThawAccount {
+        account: ctx.accounts.lessee_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: mint_manager_info.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
+    token::thaw_account(cpi_context)?;
+
+    // thaw lessor account
+    let cpi_accounts = ThawAccount {
+        account: ctx.accounts.lessor_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: mint_manager_info.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
+    token::thaw_account(cpi_context)?;
+
+    // transfer back to lessor token account
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.lessee_token_account.to_account_info(),
+        to: ctx.accounts.lessor_token_account.to_account_info(),
+        authority: sublease_token_manager.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(sublease_token_manager_signer);
+    token::transfer(cpi_context, sublease_token_manager.amount)?;
+
+    // freeze lessor token account
+    let cpi_accounts = FreezeAccount {
+        account: ctx.accounts.lessor_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: mint_manager_info.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
+    token::freeze_account(cpi_context)?;
+    Ok(())
+}
@@ -21,7 +21,10 @@ use anchor_spl::token::{self};
 
 #[derive(Accounts)]
 pub struct InvalidateCtx<'info> {
-    #[account(mut)]
+    #[account(mut,
+        constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed,
+        constraint = token_manager.parent.is_none() @ ErrorCode::InvalidSublease
+    )]
     token_manager: Box<Account<'info, TokenManager>>,
     #[account(mut, constraint =
         token_manager_token_account.owner == token_manager.key()
@@ -39,7 +42,8 @@ pub struct InvalidateCtx<'info> {
     // invalidator
     #[account(constraint =
         token_manager.invalidators.contains(&invalidator.key())
-        || ((token_manager.invalidation_type == InvalidationType::Return as u8
+        || (token_manager.subleased != Some(true)
+        && (token_manager.invalidation_type == InvalidationType::Return as u8
             || token_manager.invalidation_type == InvalidationType::Reissue as u8)
         && recipient_token_account.owner == invalidator.key())
         @ ErrorCode::InvalidInvalidator
@@ -102,6 +106,50 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
                 let cpi_program = ctx.accounts.token_program.to_account_info();
                 let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
                 token::thaw_account(cpi_context)?;
+
+                // invalidation cascades to the sublease, the child token manager returns the token from the lessee before it is closed
+                if token_manager.subleased == Some(true) {
+                    let sublease_token_manager_info = next_account_info(remaining_accs)?;
+                    let token_manager_key = token_manager.key();
+                    let path = &[SUBLEASE_SEED.as_bytes(), token_manager_key.as_ref()];
+                    assert_derivation(ctx.program_id, sublease_token_manager_info, path, error!(ErrorCode::InvalidSublease))?;
+                    let mut sublease_token_manager = Account::<TokenManager>::try_from(sublease_token_manager_info)?;
+                    let lessee_token_account_info = next_account_info(remaining_accs)?;
+                    if lessee_token_account_info.key() != sublease_token_manager.recipient_token_account {
+                        return Err(error!(ErrorCode::InvalidSublease));
+                    }
+                    let lessor_info = next_account_info(remaining_accs)?;
+                    if lessor_info.key() != sublease_token_manager.issuer {
+                        return Err(error!(ErrorCode::InvalidIssuer));
+                    }
+                    let sublease_token_manager_seeds = &[SUBLEASE_SEED.as_bytes(), token_manager_key.as_ref(), &[sublease_token_manager.bump]];
+                    let sublease_token_manager_signer = &[&sublease_token_manager_seeds[..]];
+
+                    // thaw lessee account
+                    let cpi_accounts = ThawAccount {
+                        account: lessee_token_account_info.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        authority: mint_manager_info.to_account_info(),
+                    };
+                    let cpi_program = ctx.accounts.token_program.to_account_info();
+                    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
+                    token::thaw_account(cpi_context)?;
+
+                    // transfer back to the lessor who is the recipient of the parent
+                    let cpi_accounts = Transfer {
+                        from: lessee_token_account_info.to_account_info(),
+                        to: ctx.accounts.recipient_token_account.to_account_info(),
+                        authority: sublease_token_manager.to_account_info(),
+                    };
+                    let cpi_program = ctx.accounts.token_program.to_account_info();
+                    let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(sublease_token_manager_signer);
+                    token::transfer(cpi_context, sublease_token_manager.amount)?;
+
+                    sublease_token_manager.state = TokenManagerState::Invalidated as u8;
+                    sublease_token_manager.state_changed_at = Clock::get().unwrap().unix_timestamp;
+                    sublease_token_manager.close(lessor_info.to_account_info())?;
+                    token_manager.subleased = None;
+                }
             }
             k if k == TokenManagerKind::Edition as u8 => {
                 let edition_info = next_account_info(remaining_accs)?;
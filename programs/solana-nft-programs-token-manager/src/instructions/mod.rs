@@ -1,36 +1,56 @@
 pub mod add_invalidator;
+pub mod allow_release;
 pub mod claim;
 pub mod claim_receipt_mint;
+pub mod close_claim_receipt;
 pub mod close_mint_manager;
+pub mod close_token_manager_arbiter;
 pub mod create_claim_receipt;
+pub mod create_claim_receipt_with_expiration;
 pub mod create_mint_manager;
+pub mod create_sublease;
+pub mod end_sublease;
 pub mod init;
 pub mod init_mint_counter;
 pub mod invalidate;
 pub mod issue;
+pub mod open_dispute;
 pub mod replace_invalidator;
+pub mod resolve_dispute;
+pub mod set_arbiter;
 pub mod set_claim_approver;
 pub mod set_transfer_authority;
 pub mod uninit;
 pub mod unissue;
 pub mod update_invalidation_type;
+pub mod update_invalidation_type_by_invalidator;
 
 pub use add_invalidator::*;
+pub use allow_release::*;
 pub use claim::*;
 pub use claim_receipt_mint::*;
+pub use close_claim_receipt::*;
 pub use close_mint_manager::*;
+pub use close_token_manager_arbiter::*;
 pub use create_claim_receipt::*;
+pub use create_claim_receipt_with_expiration::*;
 pub use create_mint_manager::*;
+pub use create_sublease::*;
+pub use end_sublease::*;
 pub use init::*;
 pub use init_mint_counter::*;
 pub use invalidate::*;
 pub use issue::*;
+pub use open_dispute::*;
 pub use replace_invalidator::*;
+pub use resolve_dispute::*;
+pub use set_arbiter::*;
 pub use set_claim_approver::*;
 pub use set_transfer_authority::*;
 pub use uninit::*;
 pub use unissue::*;
 pub use update_invalidation_type::*;
+pub use update_invalidation_type_by_invalidator::*;
 
 pub mod transfers;
 pub use transfers::close_transfer_receipt::*;
@@ -0,0 +1,29 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct OpenDisputeCtx<'info> {
+    #[account(mut, constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_MANAGER_ARBITER_SEED.as_bytes(), token_manager.key().as_ref()], bump = token_manager_arbiter.bump,
+        constraint = token_manager_arbiter.mint_count == token_manager.count @ ErrorCode::InvalidTokenManagerArbiter,
+    )]
+    token_manager_arbiter: Box<Account<'info, TokenManagerArbiter>>,
+
+    #[account(constraint = arbiter.key() == token_manager_arbiter.arbiter @ ErrorCode::InvalidArbiter)]
+    arbiter: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<OpenDisputeCtx>) -> Result<()> {
+    let token_manager = &mut ctx.accounts.token_manager;
+    if token_manager.disputed == Some(true) {
+        return Err(error!(ErrorCode::TokenManagerDisputed));
+    }
+    token_manager.disputed = Some(true);
+    ctx.accounts.token_manager_arbiter.disputed_at = Some(Clock::get().unwrap().unix_timestamp);
+    Ok(())
+}
@@ -13,7 +13,7 @@ use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct DelegateCtx<'info> {
-    #[account(mut, constraint = token_manager.kind == TokenManagerKind::Permissioned as u8 && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState)]
+    #[account(mut, constraint = token_manager.kind == TokenManagerKind::Permissioned as u8 && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState, constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed)]
     token_manager: Box<Account<'info, TokenManager>>,
     #[account(constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
     mint: Box<Account<'info, Mint>>,
@@ -16,7 +16,7 @@ use solana_program::program::invoke_signed;
 
 #[derive(Accounts)]
 pub struct MigrateCtx<'info> {
-    #[account(mut, close = collector, constraint = token_manager.kind == TokenManagerKind::Permissioned as u8 && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState)]
+    #[account(mut, close = collector, constraint = token_manager.kind == TokenManagerKind::Permissioned as u8 && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState, constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed)]
     mint_manager: Box<Account<'info, MintManager>>,
     #[account(mut, close = collector)]
     token_manager: Box<Account<'info, TokenManager>>,
@@ -19,7 +19,7 @@ use spl_associated_token_account::get_associated_token_address;
 
 #[derive(Accounts)]
 pub struct SendCtx<'info> {
-    #[account(mut, constraint = token_manager.kind == TokenManagerKind::Permissioned as u8 && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState)]
+    #[account(mut, constraint = token_manager.kind == TokenManagerKind::Permissioned as u8 && token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState, constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed)]
     token_manager: Box<Account<'info, TokenManager>>,
     #[account(mut, constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
     mint: Box<Account<'info, Mint>>,
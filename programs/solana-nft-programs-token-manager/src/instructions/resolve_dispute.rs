@@ -0,0 +1,85 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked;
+use anchor_lang::solana_program::sysvar::{self};
+use anchor_lang::Discriminator;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResolveDisputeIx {
+    pub invalidation_type: Option<u8>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDisputeCtx<'info> {
+    #[account(mut, constraint = token_manager.disputed == Some(true) @ ErrorCode::TokenManagerNotDisputed)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_MANAGER_ARBITER_SEED.as_bytes(), token_manager.key().as_ref()], bump = token_manager_arbiter.bump,
+        constraint = token_manager_arbiter.mint_count == token_manager.count @ ErrorCode::InvalidTokenManagerArbiter,
+    )]
+    token_manager_arbiter: Box<Account<'info, TokenManagerArbiter>>,
+
+    #[account(constraint = arbiter.key() == token_manager_arbiter.arbiter @ ErrorCode::InvalidArbiter)]
+    arbiter: Signer<'info>,
+
+    /// CHECK: This is not dangerous because the ID is checked with instructions sysvar
+    #[account(address = sysvar::instructions::id())]
+    instructions: UncheckedAccount<'info>,
+}
+
+// invalidate accounts are token_manager, token_manager_token_account, mint, recipient_token_account, invalidator, ...
+const INVALIDATE_TOKEN_MANAGER_INDEX: usize = 0;
+const INVALIDATE_INVALIDATOR_INDEX: usize = 4;
+
+// whether an instruction after this one invalidates the token manager with the arbiter as invalidator
+fn invalidated_later(instructions_info: &AccountInfo, token_manager: &Pubkey, arbiter: &Pubkey) -> Result<bool> {
+    let current_ix = load_current_index_checked(instructions_info)? as usize;
+    let mut i = current_ix + 1;
+    while let Ok(ix) = load_instruction_at_checked(i, instructions_info) {
+        if ix.program_id == crate::id()
+            && ix.data.len() >= 8
+            && ix.data[..8] == crate::instruction::Invalidate::DISCRIMINATOR
+            && ix.accounts.len() > INVALIDATE_INVALIDATOR_INDEX
+            && ix.accounts[INVALIDATE_TOKEN_MANAGER_INDEX].pubkey == *token_manager
+            && ix.accounts[INVALIDATE_INVALIDATOR_INDEX].pubkey == *arbiter
+        {
+            return Ok(true);
+        }
+        i += 1;
+    }
+    Ok(false)
+}
+
+/**
+ * lifts the dispute and optionally picks the outcome of the next invalidation
+ * picking an outcome swaps the arbiter pda slot in the invalidators for the arbiter so it can invalidate in the same transaction to enforce it
+ * the invalidation is required so the arbiter key only lives as long as the token manager it closes
+ */
+pub fn handler(ctx: Context<ResolveDisputeCtx>, ix: ResolveDisputeIx) -> Result<()> {
+    let token_manager = &mut ctx.accounts.token_manager;
+    if let Some(invalidation_type) = ix.invalidation_type {
+        if invalidation_type != InvalidationType::Return as u8 && invalidation_type != InvalidationType::Release as u8 {
+            return Err(error!(ErrorCode::InvalidDisputeOutcome));
+        }
+        token_manager.invalidation_type = invalidation_type;
+
+        let token_manager_arbiter_key = ctx.accounts.token_manager_arbiter.key();
+        let arbiter_slot = token_manager
+            .invalidators
+            .iter()
+            .position(|invalidator| *invalidator == token_manager_arbiter_key)
+            .ok_or_else(|| error!(ErrorCode::InvalidTokenManagerArbiter))?;
+        token_manager.invalidators[arbiter_slot] = ctx.accounts.arbiter.key();
+
+        if !invalidated_later(&ctx.accounts.instructions, &token_manager.key(), &ctx.accounts.arbiter.key())? {
+            return Err(error!(ErrorCode::DisputeOutcomeNotEnforced));
+        }
+    }
+    token_manager.disputed = None;
+    ctx.accounts.token_manager_arbiter.disputed_at = None;
+    Ok(())
+}
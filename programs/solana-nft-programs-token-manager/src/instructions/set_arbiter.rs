@@ -0,0 +1,49 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetArbiterCtx<'info> {
+    #[account(mut, constraint = token_manager.state == TokenManagerState::Initialized as u8 @ ErrorCode::InvalidTokenManagerState)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [TOKEN_MANAGER_ARBITER_SEED.as_bytes(), token_manager.key().as_ref()], bump,
+        space = TOKEN_MANAGER_ARBITER_SIZE,
+    )]
+    token_manager_arbiter: Box<Account<'info, TokenManagerArbiter>>,
+
+    // issuer
+    #[account(mut, constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * the arbiter pda holds an invalidator slot that resolve_dispute hands to the arbiter once it picks an outcome
+ * the pda cannot sign so the arbiter has no invalidation power outside of a dispute
+ */
+pub fn handler(ctx: Context<SetArbiterCtx>, arbiter: Pubkey) -> Result<()> {
+    let token_manager = &mut ctx.accounts.token_manager;
+    // the arbiter slot would sit next to the sole invalidator of a releasable token manager
+    if token_manager.releasable == Some(true) {
+        return Err(error!(ErrorCode::InvalidInvalidator));
+    }
+    if token_manager.invalidators.len() as u8 >= token_manager.num_invalidators {
+        return Err(error!(ErrorCode::MaximumInvalidatorsReached));
+    }
+    let token_manager_arbiter = &mut ctx.accounts.token_manager_arbiter;
+    token_manager.invalidators.push(token_manager_arbiter.key());
+
+    token_manager_arbiter.bump = *ctx.bumps.get("token_manager_arbiter").unwrap();
+    token_manager_arbiter.mint_count = token_manager.count;
+    token_manager_arbiter.token_manager = token_manager.key();
+    token_manager_arbiter.arbiter = arbiter;
+    token_manager_arbiter.payer = ctx.accounts.payer.key();
+    token_manager_arbiter.disputed_at = None;
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
 use anchor_lang::solana_program::program::invoke_signed;
 use anchor_lang::AccountsClose;
 use anchor_spl::token::Approve;
@@ -11,12 +12,22 @@ use anchor_spl::token::Token;
 use anchor_spl::token::TokenAccount;
 use anchor_spl::token::Transfer;
 use anchor_spl::token::{self};
+use mpl_token_metadata::instructions::DelegateLockedTransferV1;
+use mpl_token_metadata::instructions::DelegateLockedTransferV1InstructionArgs;
+use mpl_token_metadata::instructions::LockV1;
+use mpl_token_metadata::instructions::LockV1InstructionArgs;
+use mpl_token_metadata::instructions::TransferV1;
+use mpl_token_metadata::instructions::TransferV1InstructionArgs;
+use mpl_token_metadata::instructions::UnlockV1;
+use mpl_token_metadata::instructions::UnlockV1InstructionArgs;
 use mpl_utils::assert_derivation;
 
 #[derive(Accounts)]
 pub struct TransferCtx<'info> {
     #[account(mut, constraint =
-        token_manager.state == TokenManagerState::Claimed as u8
+        token_manager.state == TokenManagerState::Claimed as u8,
+        constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed,
+        constraint = token_manager.subleased != Some(true) @ ErrorCode::TokenManagerSubleased
     )]
     token_manager: Box<Account<'info, TokenManager>>,
     #[account(constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
@@ -209,6 +220,225 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             let cpi_context = CpiContext::new(cpi_program, cpi_accounts).with_signer(mint_manager_signer);
             token::freeze_account(cpi_context)?;
         }
+        k if k == TokenManagerKind::Programmable as u8 => {
+            let current_holder_info = next_account_info(remaining_accs)?;
+            if current_holder_info.key() != ctx.accounts.current_holder_token_account.owner {
+                return Err(error!(ErrorCode::InvalidCurrentTokenAccount));
+            }
+            let token_manager_token_account_info = next_account_info(remaining_accs)?;
+            let system_program_info = next_account_info(remaining_accs)?;
+            let mint_metadata_info = next_account_info(remaining_accs)?;
+            let mint_edition_info = next_account_info(remaining_accs)?;
+            let current_holder_token_record_info = next_account_info(remaining_accs)?;
+            let token_manager_token_record_info = next_account_info(remaining_accs)?;
+            let recipient_token_record_info = next_account_info(remaining_accs)?;
+            let sysvar_instructions_info = next_account_info(remaining_accs)?;
+            let associated_token_program_info = next_account_info(remaining_accs)?;
+            let authorization_rules_program_info = next_account_info(remaining_accs)?;
+            let authorization_rules_info = next_account_info(remaining_accs)?;
+            let _token_metadata_program = next_account_info(remaining_accs)?;
+
+            // unlock current holder token account
+            invoke_signed(
+                &UnlockV1 {
+                    authority: token_manager.key(),
+                    token_owner: Some(current_holder_info.key()),
+                    token: ctx.accounts.current_holder_token_account.key(),
+                    mint: ctx.accounts.mint.key(),
+                    metadata: mint_metadata_info.key(),
+                    edition: Some(mint_edition_info.key()),
+                    token_record: Some(current_holder_token_record_info.key()),
+                    payer: ctx.accounts.recipient.key(),
+                    system_program: system_program_info.key(),
+                    sysvar_instructions: sysvar_instructions_info.key(),
+                    spl_token_program: Some(ctx.accounts.token_program.key()),
+                    authorization_rules_program: Some(authorization_rules_program_info.key()),
+                    authorization_rules: Some(authorization_rules_info.key()),
+                }
+                .instruction(UnlockV1InstructionArgs { authorization_data: None }),
+                &[
+                    token_manager.to_account_info(),
+                    current_holder_info.to_account_info(),
+                    ctx.accounts.current_holder_token_account.to_account_info(),
+                    ctx.accounts.mint.to_account_info(),
+                    mint_metadata_info.to_account_info(),
+                    mint_edition_info.to_account_info(),
+                    current_holder_token_record_info.to_account_info(),
+                    ctx.accounts.recipient.to_account_info(),
+                    system_program_info.to_account_info(),
+                    sysvar_instructions_info.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    authorization_rules_program_info.to_account_info(),
+                    authorization_rules_info.to_account_info(),
+                ],
+                token_manager_signer,
+            )?;
+
+            // locked transfer delegate can only move the token to the token manager
+            invoke_signed(
+                &TransferV1 {
+                    token: ctx.accounts.current_holder_token_account.key(),
+                    token_owner: current_holder_info.key(),
+                    destination_token: token_manager_token_account_info.key(),
+                    destination_owner: token_manager.key(),
+                    mint: ctx.accounts.mint.key(),
+                    metadata: mint_metadata_info.key(),
+                    edition: Some(mint_edition_info.key()),
+                    token_record: Some(current_holder_token_record_info.key()),
+                    destination_token_record: Some(token_manager_token_record_info.key()),
+                    authority: token_manager.key(),
+                    payer: ctx.accounts.recipient.key(),
+                    system_program: system_program_info.key(),
+                    sysvar_instructions: sysvar_instructions_info.key(),
+                    spl_token_program: ctx.accounts.token_program.key(),
+                    spl_ata_program: associated_token_program_info.key(),
+                    authorization_rules_program: Some(authorization_rules_program_info.key()),
+                    authorization_rules: Some(authorization_rules_info.key()),
+                }
+                .instruction(TransferV1InstructionArgs {
+                    amount: token_manager.amount,
+                    authorization_data: None,
+                }),
+                &[
+                    ctx.accounts.current_holder_token_account.to_account_info(),
+                    current_holder_info.to_account_info(),
+                    token_manager_token_account_info.to_account_info(),
+                    token_manager.to_account_info(),
+                    ctx.accounts.mint.to_account_info(),
+                    mint_metadata_info.to_account_info(),
+                    mint_edition_info.to_account_info(),
+                    current_holder_token_record_info.to_account_info(),
+                    token_manager_token_record_info.to_account_info(),
+                    ctx.accounts.recipient.to_account_info(),
+                    system_program_info.to_account_info(),
+                    sysvar_instructions_info.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    associated_token_program_info.to_account_info(),
+                    authorization_rules_program_info.to_account_info(),
+                    authorization_rules_info.to_account_info(),
+                ],
+                token_manager_signer,
+            )?;
+
+            // transfer from token manager to recipient token account
+            invoke_signed(
+                &TransferV1 {
+                    token: token_manager_token_account_info.key(),
+                    token_owner: token_manager.key(),
+                    destination_token: ctx.accounts.recipient_token_account.key(),
+                    destination_owner: ctx.accounts.recipient.key(),
+                    mint: ctx.accounts.mint.key(),
+                    metadata: mint_metadata_info.key(),
+                    edition: Some(mint_edition_info.key()),
+                    token_record: Some(token_manager_token_record_info.key()),
+                    destination_token_record: Some(recipient_token_record_info.key()),
+                    authority: token_manager.key(),
+                    payer: ctx.accounts.recipient.key(),
+                    system_program: system_program_info.key(),
+                    sysvar_instructions: sysvar_instructions_info.key(),
+                    spl_token_program: ctx.accounts.token_program.key(),
+                    spl_ata_program: associated_token_program_info.key(),
+                    authorization_rules_program: Some(authorization_rules_program_info.key()),
+                    authorization_rules: Some(authorization_rules_info.key()),
+                }
+                .instruction(TransferV1InstructionArgs {
+                    amount: token_manager.amount,
+                    authorization_data: None,
+                }),
+                &[
+                    token_manager_token_account_info.to_account_info(),
+                    token_manager.to_account_info(),
+                    ctx.accounts.recipient_token_account.to_account_info(),
+                    ctx.accounts.recipient.to_account_info(),
+                    ctx.accounts.mint.to_account_info(),
+                    mint_metadata_info.to_account_info(),
+                    mint_edition_info.to_account_info(),
+                    token_manager_token_record_info.to_account_info(),
+                    recipient_token_record_info.to_account_info(),
+                    system_program_info.to_account_info(),
+                    sysvar_instructions_info.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    associated_token_program_info.to_account_info(),
+                    authorization_rules_program_info.to_account_info(),
+                    authorization_rules_info.to_account_info(),
+                ],
+                token_manager_signer,
+            )?;
+
+            // set locked transfer delegate of recipient token account to token manager PDA
+            invoke(
+                &DelegateLockedTransferV1 {
+                    delegate_record: Some(mpl_token_metadata::ID),
+                    delegate: token_manager.key(),
+                    metadata: mint_metadata_info.key(),
+                    master_edition: Some(mint_edition_info.key()),
+                    token_record: Some(recipient_token_record_info.key()),
+                    mint: ctx.accounts.mint.key(),
+                    token: ctx.accounts.recipient_token_account.key(),
+                    authority: ctx.accounts.recipient.key(),
+                    payer: ctx.accounts.recipient.key(),
+                    system_program: system_program_info.key(),
+                    sysvar_instructions: sysvar_instructions_info.key(),
+                    spl_token_program: Some(ctx.accounts.token_program.key()),
+                    authorization_rules_program: Some(authorization_rules_program_info.key()),
+                    authorization_rules: Some(authorization_rules_info.key()),
+                }
+                .instruction(DelegateLockedTransferV1InstructionArgs {
+                    amount: 1,
+                    locked_address: token_manager.key(),
+                    authorization_data: None,
+                }),
+                &[
+                    token_manager.to_account_info(),
+                    mint_metadata_info.to_account_info(),
+                    mint_edition_info.to_account_info(),
+                    recipient_token_record_info.to_account_info(),
+                    ctx.accounts.mint.to_account_info(),
+                    ctx.accounts.recipient_token_account.to_account_info(),
+                    ctx.accounts.recipient.to_account_info(),
+                    system_program_info.to_account_info(),
+                    sysvar_instructions_info.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    authorization_rules_program_info.to_account_info(),
+                    authorization_rules_info.to_account_info(),
+                ],
+            )?;
+
+            // lock recipient token account
+            invoke_signed(
+                &LockV1 {
+                    authority: token_manager.key(),
+                    token_owner: Some(ctx.accounts.recipient.key()),
+                    token: ctx.accounts.recipient_token_account.key(),
+                    mint: ctx.accounts.mint.key(),
+                    metadata: mint_metadata_info.key(),
+                    edition: Some(mint_edition_info.key()),
+                    token_record: Some(recipient_token_record_info.key()),
+                    payer: ctx.accounts.recipient.key(),
+                    system_program: system_program_info.key(),
+                    sysvar_instructions: sysvar_instructions_info.key(),
+                    spl_token_program: Some(ctx.accounts.token_program.key()),
+                    authorization_rules_program: Some(authorization_rules_program_info.key()),
+                    authorization_rules: Some(authorization_rules_info.key()),
+                }
+                .instruction(LockV1InstructionArgs { authorization_data: None }),
+                &[
+                    token_manager.to_account_info(),
+                    ctx.accounts.recipient.to_account_info(),
+                    ctx.accounts.recipient_token_account.to_account_info(),
+                    ctx.accounts.mint.to_account_info(),
+                    mint_metadata_info.to_account_info(),
+                    mint_edition_info.to_account_info(),
+                    recipient_token_record_info.to_account_info(),
+                    system_program_info.to_account_info(),
+                    sysvar_instructions_info.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    authorization_rules_program_info.to_account_info(),
+                    authorization_rules_info.to_account_info(),
+                ],
+                token_manager_signer,
+            )?;
+        }
 
         _ => return Err(error!(ErrorCode::InvalidTokenManagerKind)),
     }
@@ -12,9 +12,14 @@ pub struct UpdateInvalidationTypeCtx<'info> {
 
 /**
  * only allows updates from return => reissue and back
+ * disallowed once a receipt mint exists since the receipt holder relies on the return
+ * and once the token manager is releasable since its invalidator relies on the return
  */
 pub fn handler(ctx: Context<UpdateInvalidationTypeCtx>, invalidation_type: u8) -> Result<()> {
     let token_manager = &mut ctx.accounts.token_manager;
+    if token_manager.receipt_mint.is_some() || token_manager.releasable == Some(true) {
+        return Err(error!(ErrorCode::InvalidationTypeUpdateDisallowed));
+    }
     if token_manager.invalidation_type != InvalidationType::Return as u8 && token_manager.invalidation_type != InvalidationType::Reissue as u8 {
         return Err(error!(ErrorCode::InvalidationTypeUpdateDisallowed));
     }
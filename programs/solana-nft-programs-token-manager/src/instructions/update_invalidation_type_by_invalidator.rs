@@ -0,0 +1,28 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateInvalidationTypeByInvalidatorCtx<'info> {
+    #[account(mut, constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManagerState, constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed)]
+    token_manager: Box<Account<'info, TokenManager>>,
+    #[account(
+        constraint = token_manager.releasable == Some(true)
+        && token_manager.invalidators == vec![invalidator.key()]
+        @ ErrorCode::InvalidInvalidator
+    )]
+    invalidator: Signer<'info>,
+}
+
+/**
+ * only allows updates from return => release so invalidators can hand the token to the recipient outright
+ * restricted to the sole invalidator of token managers the issuer marked releasable so arbitrary invalidators cannot release the token
+ */
+pub fn handler(ctx: Context<UpdateInvalidationTypeByInvalidatorCtx>, invalidation_type: u8) -> Result<()> {
+    let token_manager = &mut ctx.accounts.token_manager;
+    if token_manager.invalidation_type != InvalidationType::Return as u8 || invalidation_type != InvalidationType::Release as u8 {
+        return Err(error!(ErrorCode::InvalidationTypeUpdateDisallowed));
+    }
+    token_manager.invalidation_type = InvalidationType::Release as u8;
+    Ok(())
+}
@@ -44,6 +44,14 @@ pub mod solana_nft_programs_token_manager {
         create_claim_receipt::handler(ctx, target)
     }
 
+    pub fn create_claim_receipt_with_expiration(ctx: Context<CreateClaimReceiptWithExpirationCtx>, ix: CreateClaimReceiptWithExpirationIx) -> Result<()> {
+        create_claim_receipt_with_expiration::handler(ctx, ix)
+    }
+
+    pub fn close_claim_receipt(ctx: Context<CloseClaimReceiptCtx>) -> Result<()> {
+        close_claim_receipt::handler(ctx)
+    }
+
     pub fn claim_receipt_mint(ctx: Context<ClaimReceiptMintCtx>, name: String) -> Result<()> {
         claim_receipt_mint::handler(ctx, name)
     }
@@ -68,6 +76,38 @@ pub mod solana_nft_programs_token_manager {
         update_invalidation_type::handler(ctx, invalidation_type)
     }
 
+    pub fn update_invalidation_type_by_invalidator(ctx: Context<UpdateInvalidationTypeByInvalidatorCtx>, invalidation_type: u8) -> Result<()> {
+        update_invalidation_type_by_invalidator::handler(ctx, invalidation_type)
+    }
+
+    pub fn allow_release(ctx: Context<AllowReleaseCtx>) -> Result<()> {
+        allow_release::handler(ctx)
+    }
+
+    pub fn set_arbiter(ctx: Context<SetArbiterCtx>, arbiter: Pubkey) -> Result<()> {
+        set_arbiter::handler(ctx, arbiter)
+    }
+
+    pub fn open_dispute(ctx: Context<OpenDisputeCtx>) -> Result<()> {
+        open_dispute::handler(ctx)
+    }
+
+    pub fn resolve_dispute(ctx: Context<ResolveDisputeCtx>, ix: ResolveDisputeIx) -> Result<()> {
+        resolve_dispute::handler(ctx, ix)
+    }
+
+    pub fn close_token_manager_arbiter(ctx: Context<CloseTokenManagerArbiterCtx>) -> Result<()> {
+        close_token_manager_arbiter::handler(ctx)
+    }
+
+    pub fn create_sublease<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, CreateSubleaseCtx<'info>>, ix: CreateSubleaseIx) -> Result<()> {
+        create_sublease::handler(ctx, ix)
+    }
+
+    pub fn end_sublease<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, EndSubleaseCtx<'info>>) -> Result<()> {
+        end_sublease::handler(ctx)
+    }
+
     pub fn create_mint_manager(ctx: Context<CreateMintManagerCtx>) -> Result<()> {
         create_mint_manager::handler(ctx)
     }
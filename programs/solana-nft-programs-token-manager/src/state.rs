@@ -48,7 +48,7 @@ pub const PERMISSIONED_REWARD_LAMPORTS: u64 = 10_000_000; // 0.01 SOL issue fee
 pub const PERMISSIONED_REWARD_ADDRESS: &str = "crkdpVWjHWdggGgBuSyAqSmZUmAjYLzD435tcLDRLXr";
 
 pub fn token_manager_size(num_invalidators: usize) -> usize {
-    (8 + 1 + 1 + 8 + 1 + 32 + 32 + 8 + 1 + 1 + 8 + 1 + 32 + 33 + 33 + 33 + num_invalidators * 32) + 8_usize
+    (8 + 1 + 1 + 8 + 1 + 32 + 32 + 8 + 1 + 1 + 8 + 1 + 32 + 33 + 33 + 33 + num_invalidators * 32 + 2 + 2 + 2 + 33 + 9) + 8_usize
 }
 
 pub const MAX_INVALIDATORS: u8 = 5;
@@ -87,8 +87,38 @@ pub struct TokenManager {
     pub transfer_authority: Option<Pubkey>,
     // Public keys that are allowed to invalidate this token manager
     pub invalidators: Vec<Pubkey>,
+    // Set by the arbiter while a dispute is open to freeze transfers and invalidation
+    pub disputed: Option<bool>,
+    // Set while the holder has subleased the token to a child token manager
+    pub subleased: Option<bool>,
+    // Set by the issuer to let the sole invalidator release the token, locks the invalidation type
+    pub releasable: Option<bool>,
+    // Parent token manager of a sublease, child token managers are derived from their parent instead of the mint
+    pub parent: Option<Pubkey>,
+    // Timestamp after which anyone can end a sublease, capped by the parent invalidator that approved it
+    pub expiration: Option<i64>,
 }
 
+pub const TOKEN_MANAGER_ARBITER_SEED: &str = "token-manager-arbiter";
+pub const TOKEN_MANAGER_ARBITER_SIZE: usize = 8 + std::mem::size_of::<TokenManagerArbiter>() + 8;
+#[account]
+pub struct TokenManagerArbiter {
+    // Canonical bump
+    pub bump: u8,
+    // Count of the token manager this arbiter was set for
+    pub mint_count: u64,
+    // Token manager this arbiter is for
+    pub token_manager: Pubkey,
+    // Neutral party that can open and resolve disputes
+    pub arbiter: Pubkey,
+    // Payer who funded this account and is refunded when it is closed
+    pub payer: Pubkey,
+    // Timestamp the current dispute was opened at
+    pub disputed_at: Option<i64>,
+}
+
+pub const SUBLEASE_SEED: &str = "sublease";
+
 pub const MINT_MANAGER_SEED: &str = "mint-manager";
 pub const MINT_MANAGER_SIZE: usize = 8 + std::mem::size_of::<MintManager>() + 8;
 #[account]
@@ -123,6 +153,10 @@ pub struct ClaimReceipt {
     pub token_manager: Pubkey,
     // Target who can use this claim receipt to claim the token manager
     pub target: Pubkey,
+    // Optional timestamp after which this claim receipt can no longer be used
+    pub expires_at: Option<i64>,
+    // Payer who funded this claim receipt and is refunded when it is closed
+    pub payer: Option<Pubkey>,
 }
 
 pub const TRANSFER_RECEIPT_SEED: &str = "transfer-receipt";
@@ -56,4 +56,66 @@ pub enum ErrorCode {
     InvalidRemainingAccountsSize,
     #[msg("Invalid payer payment token account")]
     InvalidPayerPaymentTokenAccount,
+    #[msg("Issuer share must be at most 10000 basis points")]
+    InvalidIssuerShare,
+    #[msg("Invalid issuer payment account")]
+    InvalidIssuerPaymentAccount,
+    #[msg("Listing has expired")]
+    ListingExpired,
+    #[msg("Invalid listing expiration")]
+    InvalidListingExpiration,
+    #[msg("Listing is still active")]
+    ListingNotStale,
+    #[msg("Invalid offer")]
+    InvalidOffer,
+    #[msg("Invalid offer target")]
+    InvalidOfferTarget,
+    #[msg("Offer has already been accepted")]
+    OfferAlreadyAccepted,
+    #[msg("Offer has not been accepted")]
+    OfferNotAccepted,
+    #[msg("Invalid auction end time")]
+    InvalidAuctionEndTime,
+    #[msg("Auction has ended")]
+    AuctionEnded,
+    #[msg("Auction has not ended")]
+    AuctionNotEnded,
+    #[msg("Bid is below the reserve price or minimum increment")]
+    BidTooLow,
+    #[msg("Invalid bidder")]
+    InvalidBidder,
+    #[msg("Auction already has bids")]
+    AuctionHasBids,
+    #[msg("Auction is still active")]
+    AuctionNotStale,
+    #[msg("Invalid escrow token account")]
+    InvalidEscrowTokenAccount,
+    #[msg("Buyer is not allowed to accept this listing")]
+    BuyerNotAllowed,
+    #[msg("Invalid marketplace fee")]
+    InvalidMarketplaceFee,
+    #[msg("Invalid marketplace fee collector")]
+    InvalidMarketplaceFeeCollector,
+    #[msg("Invalid pending authority")]
+    InvalidPendingAuthority,
+    #[msg("Marketplace still has open listings")]
+    MarketplaceHasListings,
+    #[msg("Transfer has expired")]
+    TransferExpired,
+    #[msg("Invalid transfer expiration")]
+    InvalidTransferExpiration,
+    #[msg("Transfer has not expired")]
+    TransferNotExpired,
+    #[msg("Lister must sign to undelegate a listed permissioned token")]
+    ListerSignatureRequired,
+    #[msg("Token has an open auction")]
+    AuctionInProgress,
+    #[msg("Auction has already been settled")]
+    AuctionSettled,
+    #[msg("Auction has not been settled")]
+    AuctionNotSettled,
+    #[msg("Marketplace taker fee is above the accepted maximum")]
+    MarketplaceFeeTooHigh,
+    #[msg("Payment amount overflows the fee calculation")]
+    PaymentAmountOverflow,
 }
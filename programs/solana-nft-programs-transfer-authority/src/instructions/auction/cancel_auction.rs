@@ -0,0 +1,15 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelAuctionCtx<'info> {
+    #[account(mut, close = lister, constraint = auction.top_bidder.is_none() @ ErrorCode::AuctionHasBids)]
+    auction: Box<Account<'info, Auction>>,
+    #[account(mut, constraint = lister.key() == auction.lister @ ErrorCode::InvalidLister)]
+    lister: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<CancelAuctionCtx>) -> Result<()> {
+    Ok(())
+}
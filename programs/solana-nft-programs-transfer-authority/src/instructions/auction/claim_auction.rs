@@ -0,0 +1,258 @@
+use anchor_spl::token::CloseAccount;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::{self};
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_payment_manager::program::SolanaNftProgramsPaymentManager;
+
+#[derive(Accounts)]
+pub struct ClaimAuctionCtx<'info> {
+    #[account(mut, constraint = transfer_authority.key() == token_manager.transfer_authority.expect("No transfer authority for token manager") @ ErrorCode::InvalidTransferAuthority)]
+    transfer_authority: Box<Account<'info, TransferAuthority>>,
+    /// CHECK: This is not dangerous because this is the receipt getting initialized
+    #[account(mut)]
+    transfer_receipt: UncheckedAccount<'info>,
+
+    #[account(mut, close = lister,
+        constraint = auction.token_manager == token_manager.key() @ ErrorCode::InvalidTokenManager,
+        constraint = Clock::get().unwrap().unix_timestamp >= auction.end_time @ ErrorCode::AuctionNotEnded
+    )]
+    auction: Box<Account<'info, Auction>>,
+    /// CHECK: This is not dangerous because it is only used for spl auctions and checked in the handler
+    #[account(mut)]
+    auction_payment_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because account is checked below
+    #[account(mut)]
+    lister_payment_token_account: UncheckedAccount<'info>,
+    #[account(mut, constraint =
+        lister_mint_token_account.key() == auction.lister_token_account &&
+        lister_mint_token_account.owner == lister.key() @ ErrorCode::InvalidListerMintTokenAccount)]
+    lister_mint_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: This is not dangerous because of the auction.lister check
+    #[account(mut, constraint = lister.key() == auction.lister @ ErrorCode::InvalidLister)]
+    lister: UncheckedAccount<'info>,
+
+    #[account(mut, constraint =
+        buyer_mint_token_account.mint == token_manager.mint &&
+        buyer_mint_token_account.owner == buyer.key() @ ErrorCode::InvalidBuyerMintTokenAccount)]
+    buyer_mint_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = auction.top_bidder == Some(buyer.key()) @ ErrorCode::InvalidBidder)]
+    buyer: Signer<'info>,
+    /// CHECK: This is not dangerous because it is only used for spl auctions and checked in the handler
+    #[account(mut)]
+    buyer_payment_token_account: UncheckedAccount<'info>,
+
+    #[account(constraint = marketplace.key() == auction.marketplace @ ErrorCode::InvalidMarketplace)]
+    marketplace: Box<Account<'info, Marketplace>>,
+    #[account(mut,
+        constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager,
+        constraint = token_manager.recipient_token_account == auction.lister_token_account @ ErrorCode::InvalidListerMintTokenAccount
+    )]
+    token_manager: Box<Account<'info, TokenManager>>,
+    /// CHECK: This is not dangerous because of the token_manager.mint check
+    #[account(constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is check in the handler
+    mint_metadata_info: UncheckedAccount<'info>,
+
+    // payment accounts
+    /// CHECK: This is not dangerous because of the marketplace.payment_manager check
+    #[account(mut, constraint = payment_manager.key() == marketplace.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because of the auction.payment_mint check
+    #[account(constraint = payment_mint.key() == auction.payment_mint @ ErrorCode::InvalidPaymentMint)]
+    payment_mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked in the payment manager handle payment with royalties instruction
+    #[account(mut)]
+    fee_collector_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked in the payment manager handle payment with royalties instruction
+    #[account(mut)]
+    fee_collector: UncheckedAccount<'info>,
+
+    solana_nft_programs_payment_manager: Program<'info, SolanaNftProgramsPaymentManager>,
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * completes an ended auction, the lister is paid from the escrowed top bid and the token is transferred to the winner in the same instruction
+ * the token manager transfer needs the recipient's signature so only the winner can claim, until then close_stale_auction refunds the bid if the token manager changes
+ * native SOL escrow is released to the winner right before the payment since the payment manager pays from a system account
+ */
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ClaimAuctionCtx<'info>>) -> Result<()> {
+    assert_lister_fee_bps(&ctx.accounts.transfer_authority, &ctx.accounts.marketplace)?;
+    let auction = &ctx.accounts.auction;
+    let payment_amount = auction.top_bid;
+    let token_manager_key = auction.token_manager;
+    let auction_seeds = &[AUCTION_SEED.as_bytes(), token_manager_key.as_ref(), &[auction.bump]];
+    let auction_signer = &[&auction_seeds[..]];
+
+    // native SOL is paid by the winner out of the released escrow and spl tokens directly from the escrow token account
+    let (payer, payer_payment_token_account, payer_signer): (AccountInfo<'info>, AccountInfo<'info>, &[&[&[u8]]]) = if auction.payment_mint == Pubkey::default() {
+        let auction_info = auction.to_account_info();
+        **auction_info.try_borrow_mut_lamports()? = auction_info.lamports().checked_sub(auction.escrow_amount).expect("Sub error");
+        **ctx.accounts.buyer.try_borrow_mut_lamports()? = ctx.accounts.buyer.lamports().checked_add(auction.escrow_amount).expect("Add error");
+        (ctx.accounts.buyer.to_account_info(), ctx.accounts.buyer_payment_token_account.to_account_info(), &[])
+    } else {
+        let auction_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.auction_payment_token_account)?;
+        if auction_payment_token_account.owner != auction.key() || auction_payment_token_account.mint != auction.payment_mint || auction_payment_token_account.amount < auction.escrow_amount {
+            return Err(error!(ErrorCode::InvalidEscrowTokenAccount));
+        }
+        (auction.to_account_info(), ctx.accounts.auction_payment_token_account.to_account_info(), auction_signer)
+    };
+
+    // issuer share accounts come first in remaining accounts when the transfer authority takes a share
+    let issuer_share_amount = issuer_share_amount(&ctx.accounts.transfer_authority, payment_amount)?;
+    let issuer_share_accounts_length = issuer_share_accounts_length(&ctx.accounts.token_manager, issuer_share_amount);
+    if ctx.remaining_accounts.len() < issuer_share_accounts_length {
+        return Err(error!(ErrorCode::InvalidRemainingAccountsSize));
+    }
+    pay_issuer_share(
+        &ctx.accounts.token_manager,
+        ctx.accounts.auction.payment_mint,
+        &ctx.remaining_accounts[..issuer_share_accounts_length],
+        &payer,
+        &payer_payment_token_account,
+        payer_signer,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        issuer_share_amount,
+    )?;
+
+    // followed by the marketplace fee collector when the marketplace charges a fee
+    let (maker_fee, taker_fee) = marketplace_fee_amounts(&ctx.accounts.marketplace, payment_amount)?;
+    let marketplace_fee = maker_fee.checked_add(taker_fee).expect("Add error");
+    let mut fee_accounts_length = issuer_share_accounts_length;
+    if marketplace_fee > 0 {
+        if ctx.remaining_accounts.len() <= fee_accounts_length {
+            return Err(error!(ErrorCode::InvalidRemainingAccountsSize));
+        }
+        pay_marketplace_fee(
+            &ctx.accounts.marketplace,
+            ctx.accounts.auction.payment_mint,
+            &ctx.remaining_accounts[fee_accounts_length],
+            &payer,
+            &payer_payment_token_account,
+            payer_signer,
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+            marketplace_fee,
+        )?;
+        fee_accounts_length += 1;
+    }
+    let payment_amount = payment_amount
+        .checked_sub(issuer_share_amount)
+        .and_then(|amount| amount.checked_sub(maker_fee))
+        .ok_or_else(|| error!(ErrorCode::InvalidMarketplaceFee))?;
+
+    // token manager transfer accounts for the token manager kind come last in remaining accounts
+    let remaining_accs = &mut ctx.remaining_accounts[fee_accounts_length..].to_vec();
+    let transfer_accounts_length = transfer_accounts_length(&ctx.accounts.token_manager);
+    if remaining_accs.len() < transfer_accounts_length {
+        return Err(error!(ErrorCode::InvalidRemainingAccountsSize));
+    }
+    let (payment_remaining_accounts, transfer_accounts) = remaining_accs.split_at(remaining_accs.len() - transfer_accounts_length);
+
+    // native SOL
+    if ctx.accounts.payment_mint.key() == Pubkey::default() {
+        let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandleNativePaymentWithRoyaltiesCtx {
+            payment_manager: ctx.accounts.payment_manager.to_account_info(),
+            fee_collector: ctx.accounts.fee_collector.to_account_info(),
+            payment_target: ctx.accounts.lister.to_account_info(),
+            payer: ctx.accounts.buyer.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            mint_metadata: ctx.accounts.mint_metadata_info.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts).with_remaining_accounts(payment_remaining_accounts.to_vec());
+        solana_nft_programs_payment_manager::cpi::handle_native_payment_with_royalties(cpi_ctx, payment_amount)?;
+    } else {
+        // any SPL token
+
+        // check on lister token account
+        let lister_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.lister_payment_token_account)?;
+        if lister_payment_token_account.mint != ctx.accounts.auction.payment_mint || lister_payment_token_account.owner != ctx.accounts.lister.key() {
+            return Err(error!(ErrorCode::InvalidListerPaymentTokenAccount));
+        }
+
+        let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandlePaymentWithRoyaltiesCtx {
+            payment_manager: ctx.accounts.payment_manager.to_account_info(),
+            payer_token_account: ctx.accounts.auction_payment_token_account.to_account_info(),
+            fee_collector_token_account: ctx.accounts.fee_collector_token_account.to_account_info(),
+            payment_token_account: ctx.accounts.lister_payment_token_account.to_account_info(),
+            payment_mint: ctx.accounts.payment_mint.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            mint_metadata: ctx.accounts.mint_metadata_info.to_account_info(),
+            payer: ctx.accounts.auction.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts)
+            .with_remaining_accounts(payment_remaining_accounts.to_vec())
+            .with_signer(auction_signer);
+        solana_nft_programs_payment_manager::cpi::handle_payment_with_royalties(cpi_ctx, payment_amount)?;
+
+        // return anything left in escrow to the winner and close the escrow token account
+        let auction_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.auction_payment_token_account)?;
+        if auction_payment_token_account.amount > 0 {
+            transfer_from_escrow(
+                &ctx.accounts.auction.to_account_info(),
+                auction_signer,
+                ctx.accounts.auction.payment_mint,
+                &ctx.accounts.auction_payment_token_account,
+                &ctx.accounts.buyer_payment_token_account,
+                ctx.accounts.buyer.key(),
+                &ctx.accounts.token_program,
+                auction_payment_token_account.amount,
+            )?;
+        }
+        let cpi_accounts = CloseAccount {
+            account: ctx.accounts.auction_payment_token_account.to_account_info(),
+            destination: ctx.accounts.lister.to_account_info(),
+            authority: ctx.accounts.auction.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(auction_signer);
+        token::close_account(cpi_context)?;
+    }
+
+    let transfer_authority_seeds = &[
+        TRANSFER_AUTHORITY_SEED.as_bytes(),
+        ctx.accounts.transfer_authority.name.as_bytes(),
+        &[ctx.accounts.transfer_authority.bump],
+    ];
+    let transfer_authority_signer = &[&transfer_authority_seeds[..]];
+
+    // approve
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::CreateTransferReceiptCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        transfer_authority: ctx.accounts.transfer_authority.to_account_info(),
+        transfer_receipt: ctx.accounts.transfer_receipt.to_account_info(),
+        payer: ctx.accounts.buyer.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts).with_signer(transfer_authority_signer);
+    solana_nft_programs_token_manager::cpi::create_transfer_receipt(cpi_ctx, ctx.accounts.buyer.key())?;
+
+    let mut transfer_remaining_accounts = transfer_accounts.to_vec();
+    transfer_remaining_accounts.push(ctx.accounts.transfer_receipt.to_account_info());
+    let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::TransferCtx {
+        token_manager: ctx.accounts.token_manager.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        current_holder_token_account: ctx.accounts.lister_mint_token_account.to_account_info(),
+        recipient: ctx.accounts.buyer.to_account_info(),
+        recipient_token_account: ctx.accounts.buyer_mint_token_account.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts).with_remaining_accounts(transfer_remaining_accounts);
+    solana_nft_programs_token_manager::cpi::transfer(cpi_ctx)?;
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::transfer_from_escrow;
+use anchor_lang::prelude::*;
+use anchor_spl::token::CloseAccount;
+use anchor_spl::token::Token;
+use anchor_spl::token::{self};
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+pub struct CloseStaleAuctionCtx<'info> {
+    /// CHECK: This is not dangerous because we expect it to potentially be empty
+    #[account(constraint = token_manager.key() == auction.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: UncheckedAccount<'info>,
+
+    #[account(mut, close = lister)]
+    auction: Box<Account<'info, Auction>>,
+    /// CHECK: This is not dangerous because it is only used for spl auctions and checked in the handler
+    #[account(mut)]
+    auction_payment_token_account: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because of the auction.lister check
+    #[account(mut, constraint = lister.key() == auction.lister @ ErrorCode::InvalidLister)]
+    lister: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because it is only refunded when it matches the top bidder
+    #[account(mut)]
+    top_bidder: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is only used for spl auctions and checked in the handler
+    #[account(mut)]
+    top_bidder_payment_token_account: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+}
+
+/**
+ * permissionless close of an auction that ended without bids, or whose token manager is no longer claimed by the lister
+ * the lister is only paid when the winner claims so any escrowed top bid is refunded
+ */
+pub fn handler(ctx: Context<CloseStaleAuctionCtx>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let ended_without_bids = auction.top_bidder.is_none() && Clock::get().unwrap().unix_timestamp >= auction.end_time;
+
+    let stale = if ctx.accounts.token_manager.data_is_empty() {
+        true
+    } else {
+        let token_manager = Account::<TokenManager>::try_from(&ctx.accounts.token_manager)?;
+        token_manager.state != TokenManagerState::Claimed as u8 || token_manager.recipient_token_account != auction.lister_token_account
+    };
+
+    if !ended_without_bids && !stale {
+        return Err(error!(ErrorCode::AuctionNotStale));
+    }
+
+    let token_manager_key = auction.token_manager;
+    let auction_seeds = &[AUCTION_SEED.as_bytes(), token_manager_key.as_ref(), &[auction.bump]];
+    let auction_signer = &[&auction_seeds[..]];
+    if let Some(top_bidder) = auction.top_bidder {
+        if ctx.accounts.top_bidder.key() != top_bidder {
+            return Err(error!(ErrorCode::InvalidBidder));
+        }
+        let destination = if auction.payment_mint == Pubkey::default() {
+            ctx.accounts.top_bidder.to_account_info()
+        } else {
+            ctx.accounts.top_bidder_payment_token_account.to_account_info()
+        };
+        transfer_from_escrow(
+            &auction.to_account_info(),
+            auction_signer,
+            auction.payment_mint,
+            &ctx.accounts.auction_payment_token_account,
+            &destination,
+            top_bidder,
+            &ctx.accounts.token_program,
+            auction.escrow_amount,
+        )?;
+    }
+    if auction.payment_mint != Pubkey::default() && !ctx.accounts.auction_payment_token_account.data_is_empty() {
+        let cpi_accounts = CloseAccount {
+            account: ctx.accounts.auction_payment_token_account.to_account_info(),
+            destination: ctx.accounts.lister.to_account_info(),
+            authority: auction.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(auction_signer);
+        token::close_account(cpi_context)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerKind;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateAuctionIx {
+    pub payment_mint: Pubkey,
+    pub reserve_price: u64,
+    pub min_increment: u64,
+    pub end_time: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: CreateAuctionIx)]
+pub struct CreateAuctionCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = AUCTION_SIZE,
+        seeds = [AUCTION_SEED.as_bytes(), token_manager.key().as_ref()], bump,
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    #[account(constraint = transfer_authority.key() == token_manager.transfer_authority.expect("No transfer authority for token manager") @ ErrorCode::InvalidTransferAuthority)]
+    transfer_authority: Box<Account<'info, TransferAuthority>>,
+    marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(mut, constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    /// CHECK: This is not dangerous because this account is not read in this instruction
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because this account is not read in this instruction
+    #[account(mut)]
+    mint_manager: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = lister_token_account.key() == token_manager.recipient_token_account @ ErrorCode::InvalidListerMintTokenAccount)]
+    lister_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = lister.key() == lister_token_account.owner @ ErrorCode::InvalidLister)]
+    lister: Signer<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateAuctionCtx>, ix: CreateAuctionIx) -> Result<()> {
+    if ix.end_time <= Clock::get().unwrap().unix_timestamp {
+        return Err(error!(ErrorCode::InvalidAuctionEndTime));
+    }
+
+    let auction = &mut ctx.accounts.auction;
+    auction.bump = *ctx.bumps.get("auction").unwrap();
+    auction.lister = ctx.accounts.lister.key();
+    auction.lister_token_account = ctx.accounts.lister_token_account.key();
+    auction.token_manager = ctx.accounts.token_manager.key();
+    auction.marketplace = ctx.accounts.marketplace.key();
+    // payment
+    auction.payment_mint = ix.payment_mint;
+    auction.reserve_price = ix.reserve_price;
+    auction.min_increment = ix.min_increment;
+    auction.end_time = ix.end_time;
+    auction.top_bidder = None;
+    auction.top_bid = 0;
+    auction.escrow_amount = 0;
+
+    if ctx.accounts.marketplace.payment_mints.is_some() && !ctx.accounts.marketplace.payment_mints.as_ref().unwrap().contains(&ix.payment_mint) {
+        return Err(error!(ErrorCode::InvalidPaymentMint));
+    }
+
+    if ctx.accounts.transfer_authority.allowed_marketplaces.is_some() && !ctx.accounts.transfer_authority.allowed_marketplaces.as_ref().unwrap().contains(&ctx.accounts.marketplace.key()) {
+        return Err(error!(ErrorCode::MarketplaceNotAllowed));
+    }
+
+    if ctx.accounts.lister_token_account.delegate.is_none() && ctx.accounts.token_manager.kind == TokenManagerKind::Permissioned as u8 {
+        let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::DelegateCtx {
+            token_manager: ctx.accounts.token_manager.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            mint_manager: ctx.accounts.mint_manager.to_account_info(),
+            recipient: ctx.accounts.lister.to_account_info(),
+            recipient_token_account: ctx.accounts.lister_token_account.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts);
+        solana_nft_programs_token_manager::cpi::delegate(cpi_ctx)?;
+    } else if ctx.accounts.lister_token_account.delegate.is_none()
+        || ctx.accounts.lister_token_account.delegate.expect("Invalid delegate") != ctx.accounts.token_manager.key()
+        || ctx.accounts.lister_token_account.delegated_amount != ctx.accounts.token_manager.amount
+    {
+        return Err(error!(ErrorCode::TokenNotDelegated));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+pub mod cancel_auction;
+pub mod claim_auction;
+pub mod close_stale_auction;
+pub mod create_auction;
+pub mod place_bid;
+
+pub use cancel_auction::*;
+pub use claim_auction::*;
+pub use close_stale_auction::*;
+pub use create_auction::*;
+pub use place_bid::*;
@@ -0,0 +1,106 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::{self};
+use solana_nft_programs_payment_manager::state::PaymentManager;
+
+#[derive(Accounts)]
+pub struct PlaceBidCtx<'info> {
+    #[account(mut, constraint = Clock::get().unwrap().unix_timestamp < auction.end_time @ ErrorCode::AuctionEnded)]
+    auction: Box<Account<'info, Auction>>,
+    /// CHECK: This is not dangerous because it is only used for spl auctions and checked in the handler
+    #[account(mut)]
+    auction_payment_token_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    bidder: Signer<'info>,
+    /// CHECK: This is not dangerous because it is only used for spl auctions and checked in the handler
+    #[account(mut)]
+    bidder_payment_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because it is only refunded when it matches the current top bidder
+    #[account(mut)]
+    previous_bidder: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is only used for spl auctions and checked in the handler
+    #[account(mut)]
+    previous_bidder_payment_token_account: UncheckedAccount<'info>,
+
+    #[account(constraint = marketplace.key() == auction.marketplace @ ErrorCode::InvalidMarketplace)]
+    marketplace: Box<Account<'info, Marketplace>>,
+    #[account(constraint = payment_manager.key() == marketplace.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: Box<Account<'info, PaymentManager>>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * escrows a new top bid together with the marketplace and payment manager taker fees and refunds the bidder it outbids
+ */
+pub fn handler(ctx: Context<PlaceBidCtx>, amount: u64) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let minimum_bid = match auction.top_bidder {
+        Some(_) => auction.top_bid.checked_add(auction.min_increment).expect("Add error"),
+        None => auction.reserve_price,
+    };
+    if amount == 0 || amount < minimum_bid {
+        return Err(error!(ErrorCode::BidTooLow));
+    }
+    let escrow_amount = escrow_amount(&ctx.accounts.marketplace, &ctx.accounts.payment_manager, amount)?;
+
+    // escrow new bid
+    if auction.payment_mint == Pubkey::default() {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.bidder.to_account_info(),
+            to: auction.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_context, escrow_amount)?;
+    } else {
+        let auction_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.auction_payment_token_account)?;
+        if auction_payment_token_account.owner != auction.key() || auction_payment_token_account.mint != auction.payment_mint {
+            return Err(error!(ErrorCode::InvalidEscrowTokenAccount));
+        }
+        let cpi_accounts = token::Transfer {
+            from: ctx.accounts.bidder_payment_token_account.to_account_info(),
+            to: ctx.accounts.auction_payment_token_account.to_account_info(),
+            authority: ctx.accounts.bidder.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_context, escrow_amount)?;
+    }
+
+    // refund outbid bidder
+    if let Some(previous_bidder) = auction.top_bidder {
+        if ctx.accounts.previous_bidder.key() != previous_bidder {
+            return Err(error!(ErrorCode::InvalidBidder));
+        }
+        let token_manager_key = auction.token_manager;
+        let auction_seeds = &[AUCTION_SEED.as_bytes(), token_manager_key.as_ref(), &[auction.bump]];
+        let destination = if auction.payment_mint == Pubkey::default() {
+            ctx.accounts.previous_bidder.to_account_info()
+        } else {
+            ctx.accounts.previous_bidder_payment_token_account.to_account_info()
+        };
+        transfer_from_escrow(
+            &auction.to_account_info(),
+            &[&auction_seeds[..]],
+            auction.payment_mint,
+            &ctx.accounts.auction_payment_token_account,
+            &destination,
+            previous_bidder,
+            &ctx.accounts.token_program,
+            auction.escrow_amount,
+        )?;
+    }
+
+    let auction = &mut ctx.accounts.auction;
+    auction.top_bidder = Some(ctx.accounts.bidder.key());
+    auction.top_bid = amount;
+    auction.escrow_amount = escrow_amount;
+    Ok(())
+}
@@ -2,19 +2,23 @@ use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::TokenAccount;
 use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
 use solana_nft_programs_token_manager::state::TokenManager;
-use solana_nft_programs_token_manager::state::TokenManagerKind;
 
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::*;
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::token::Token;
+use anchor_spl::token::{self};
 use solana_nft_programs_payment_manager::program::SolanaNftProgramsPaymentManager;
+use solana_nft_programs_token_manager::utils::assert_payment_token_account;
 
 use solana_program::sysvar::{self};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct AcceptListingIx {
     pub payment_amount: u64,
+    pub max_taker_fee_bps: u16,
 }
 
 #[derive(Accounts)]
@@ -28,8 +32,10 @@ pub struct AcceptListingCtx<'info> {
     // #[account(mut)]
     // transfer: UncheckedAccount<'info>,
 
-    #[account(mut, close = lister)]
+    #[account(mut, close = lister, constraint = listing.expires_at.is_none() || Clock::get().unwrap().unix_timestamp < listing.expires_at.unwrap() @ ErrorCode::ListingExpired)]
     listing: Box<Account<'info, Listing>>,
+    /// CHECK: This is not dangerous because it is checked to be the empty auction of the token manager in the handler
+    auction: UncheckedAccount<'info>,
     /// CHECK: This is not dangerous because account is checked below
     #[account(mut)]
     lister_payment_token_account: UncheckedAccount<'info>,
@@ -46,7 +52,7 @@ pub struct AcceptListingCtx<'info> {
         buyer_mint_token_account.mint == token_manager.mint &&
         buyer_mint_token_account.owner == buyer.key() @ ErrorCode::InvalidBuyerMintTokenAccount)]
     buyer_mint_token_account: Box<Account<'info, TokenAccount>>,
-    #[account(mut)]
+    #[account(mut, constraint = listing.allowed_buyer.is_none() || listing.allowed_buyer == Some(buyer.key()) @ ErrorCode::BuyerNotAllowed)]
     buyer: Signer<'info>,
 
     #[account(mut)]
@@ -90,19 +96,74 @@ pub struct AcceptListingCtx<'info> {
 }
 
 pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, AcceptListingCtx<'info>>, ix: AcceptListingIx) -> Result<()> {
-    let remaining_accs = &mut ctx.remaining_accounts.to_vec();
+    assert_no_auction(&ctx.accounts.auction, ctx.accounts.token_manager.key())?;
     if ix.payment_amount != ctx.accounts.listing.payment_amount {
         return Err(error!(ErrorCode::ListingChanged));
     }
+    if ctx.accounts.marketplace.taker_fee_bps.unwrap_or(0) > ix.max_taker_fee_bps {
+        return Err(error!(ErrorCode::MarketplaceFeeTooHigh));
+    }
+    assert_lister_fee_bps(&ctx.accounts.transfer_authority, &ctx.accounts.marketplace)?;
+    if ctx.accounts.listing.counted {
+        let marketplace = &mut ctx.accounts.marketplace;
+        marketplace.listing_count = marketplace.listing_count.checked_sub(1).expect("Sub error");
+    }
 
-    if remaining_accs.is_empty() {
+    // issuer share accounts come first in remaining accounts when the transfer authority takes a share
+    let issuer_share_amount = issuer_share_amount(&ctx.accounts.transfer_authority, ctx.accounts.listing.payment_amount)?;
+    let issuer_share_accounts_length = issuer_share_accounts_length(&ctx.accounts.token_manager, issuer_share_amount);
+    if ctx.remaining_accounts.len() < issuer_share_accounts_length {
         return Err(error!(ErrorCode::InvalidRemainingAccountsSize));
     }
-    let payment_remaining_accounts = if ctx.accounts.token_manager.kind == TokenManagerKind::Permissioned as u8 {
-        &remaining_accs[..remaining_accs.len() - 1]
-    } else {
-        &remaining_accs[..remaining_accs.len() - 2]
-    };
+    pay_issuer_share(
+        &ctx.accounts.token_manager,
+        ctx.accounts.listing.payment_mint,
+        &ctx.remaining_accounts[..issuer_share_accounts_length],
+        &ctx.accounts.payer,
+        &ctx.accounts.payer_payment_token_account,
+        &[],
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        issuer_share_amount,
+    )?;
+
+    // followed by the marketplace fee collector when the marketplace charges a fee
+    let (maker_fee, taker_fee) = marketplace_fee_amounts(&ctx.accounts.marketplace, ctx.accounts.listing.payment_amount)?;
+    let marketplace_fee = maker_fee.checked_add(taker_fee).expect("Add error");
+    let mut fee_accounts_length = issuer_share_accounts_length;
+    if marketplace_fee > 0 {
+        if ctx.remaining_accounts.len() <= fee_accounts_length {
+            return Err(error!(ErrorCode::InvalidRemainingAccountsSize));
+        }
+        pay_marketplace_fee(
+            &ctx.accounts.marketplace,
+            ctx.accounts.listing.payment_mint,
+            &ctx.remaining_accounts[fee_accounts_length],
+            &ctx.accounts.payer,
+            &ctx.accounts.payer_payment_token_account,
+            &[],
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+            marketplace_fee,
+        )?;
+        fee_accounts_length += 1;
+    }
+    let payment_amount = ctx
+        .accounts
+        .listing
+        .payment_amount
+        .checked_sub(issuer_share_amount)
+        .and_then(|amount| amount.checked_sub(maker_fee))
+        .ok_or_else(|| error!(ErrorCode::InvalidMarketplaceFee))?;
+
+    let remaining_accs = &mut ctx.remaining_accounts[fee_accounts_length..].to_vec();
+
+    // token manager transfer accounts for the token manager kind come last in remaining accounts
+    let transfer_accounts_length = transfer_accounts_length(&ctx.accounts.token_manager);
+    if remaining_accs.len() < transfer_accounts_length {
+        return Err(error!(ErrorCode::InvalidRemainingAccountsSize));
+    }
+    let (payment_remaining_accounts, transfer_accounts) = remaining_accs.split_at(remaining_accs.len() - transfer_accounts_length);
 
     // native SOL
     if ctx.accounts.payment_mint.key() == Pubkey::default() {
@@ -116,7 +177,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             system_program: ctx.accounts.system_program.to_account_info(),
         };
         let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts).with_remaining_accounts(payment_remaining_accounts.to_vec());
-        solana_nft_programs_payment_manager::cpi::handle_native_payment_with_royalties(cpi_ctx, ctx.accounts.listing.payment_amount)?;
+        solana_nft_programs_payment_manager::cpi::handle_native_payment_with_royalties(cpi_ctx, payment_amount)?;
     } else {
         // any SPL token
 
@@ -145,8 +206,8 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
             payer: ctx.accounts.payer.to_account_info(),
             token_program: ctx.accounts.token_program.to_account_info(),
         };
-        let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts).with_remaining_accounts(ctx.remaining_accounts.to_vec());
-        solana_nft_programs_payment_manager::cpi::handle_payment_with_royalties(cpi_ctx, ctx.accounts.listing.payment_amount)?;
+        let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_payment_manager.to_account_info(), cpi_accounts).with_remaining_accounts(payment_remaining_accounts.to_vec());
+        solana_nft_programs_payment_manager::cpi::handle_payment_with_royalties(cpi_ctx, payment_amount)?;
     }
 
     let transfer_authority_seeds = &[
@@ -167,18 +228,8 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
     let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts).with_signer(transfer_authority_signer);
     solana_nft_programs_token_manager::cpi::create_transfer_receipt(cpi_ctx, ctx.accounts.buyer.key())?;
 
-    let remaining_accounts_length = remaining_accs.len();
-    let mut transfer_remaining_accounts = Vec::new();
-    if ctx.accounts.token_manager.kind == TokenManagerKind::Permissioned as u8 {
-        // kind Managed
-        transfer_remaining_accounts.push(remaining_accs[remaining_accounts_length - 1].to_account_info());
-        transfer_remaining_accounts.push(ctx.accounts.transfer_receipt.to_account_info());
-    } else {
-        // kind Edition
-        transfer_remaining_accounts.push(remaining_accs[remaining_accounts_length - 2].to_account_info());
-        transfer_remaining_accounts.push(remaining_accs[remaining_accounts_length - 1].to_account_info());
-        transfer_remaining_accounts.push(ctx.accounts.transfer_receipt.to_account_info());
-    }
+    let mut transfer_remaining_accounts = transfer_accounts.to_vec();
+    transfer_remaining_accounts.push(ctx.accounts.transfer_receipt.to_account_info());
     let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::TransferCtx {
         token_manager: ctx.accounts.token_manager.to_account_info(),
         mint: ctx.accounts.mint.to_account_info(),
@@ -0,0 +1,87 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::decrement_listing_count;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerKind;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+pub struct CloseStaleListingCtx<'info> {
+    /// CHECK: This is not dangerous because we expect it to potentially be empty
+    #[account(mut, constraint = token_manager.key() == listing.token_manager @ ErrorCode::InvalidTokenManager)]
+    token_manager: UncheckedAccount<'info>,
+
+    #[account(mut, close = lister)]
+    listing: Box<Account<'info, Listing>>,
+    /// CHECK: This is not dangerous because of the listing.marketplace check and the marketplace may already be closed
+    #[account(mut, constraint = marketplace.key() == listing.marketplace @ ErrorCode::InvalidMarketplace)]
+    marketplace: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because of the listing.lister check
+    #[account(mut, constraint = lister.key() == listing.lister @ ErrorCode::InvalidLister)]
+    lister: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is only read when the token manager is still claimed
+    #[account(mut)]
+    lister_mint_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: This is not dangerous because this account is not read in this instruction
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because this account is not read in this instruction
+    #[account(mut)]
+    mint_manager: UncheckedAccount<'info>,
+
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    token_program: Program<'info, Token>,
+}
+
+/**
+ * permissionless close of a listing that has expired or whose token manager is no longer claimed
+ * permissioned tokens that are still claimed and delegated for the listing are undelegated, which needs the lister to sign
+ */
+pub fn handler(ctx: Context<CloseStaleListingCtx>) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    let expired = listing.expires_at.is_some() && Clock::get().unwrap().unix_timestamp >= listing.expires_at.unwrap();
+
+    let token_manager = if ctx.accounts.token_manager.data_is_empty() {
+        None
+    } else {
+        Some(Account::<TokenManager>::try_from(&ctx.accounts.token_manager)?)
+    };
+    let stale = match &token_manager {
+        Some(token_manager) => token_manager.state != TokenManagerState::Claimed as u8,
+        None => true,
+    };
+
+    if !expired && !stale {
+        return Err(error!(ErrorCode::ListingNotStale));
+    }
+    decrement_listing_count(&ctx.accounts.marketplace, &ctx.accounts.listing)?;
+
+    if let Some(token_manager) = token_manager {
+        if !stale && token_manager.kind == TokenManagerKind::Permissioned as u8 {
+            let lister_mint_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.lister_mint_token_account)?;
+            if lister_mint_token_account.key() == token_manager.recipient_token_account
+                && lister_mint_token_account.delegate.is_some()
+                && lister_mint_token_account.delegate.expect("Invalid delegate") == token_manager.key()
+            {
+                if !ctx.accounts.lister.is_signer {
+                    return Err(error!(ErrorCode::ListerSignatureRequired));
+                }
+                let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::UndelegateCtx {
+                    token_manager: ctx.accounts.token_manager.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    mint_manager: ctx.accounts.mint_manager.to_account_info(),
+                    recipient: ctx.accounts.lister.to_account_info(),
+                    recipient_token_account: ctx.accounts.lister_mint_token_account.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts);
+                solana_nft_programs_token_manager::cpi::undelegate(cpi_ctx)?;
+            }
+        }
+    }
+    Ok(())
+}
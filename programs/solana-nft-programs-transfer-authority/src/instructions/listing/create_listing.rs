@@ -3,8 +3,11 @@ use anchor_spl::token::TokenAccount;
 
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::assert_lister_fee_bps;
+use crate::utils::assert_no_auction;
 use anchor_lang::prelude::*;
 use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerKind;
 use solana_nft_programs_token_manager::state::TokenManagerState;
 
 use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
@@ -14,6 +17,8 @@ use solana_program::sysvar::{self};
 pub struct CreateListingIx {
     pub payment_amount: u64,
     pub payment_mint: Pubkey,
+    pub expires_at: Option<i64>,
+    pub allowed_buyer: Option<Pubkey>,
 }
 
 #[derive(Accounts)]
@@ -29,10 +34,13 @@ pub struct CreateListingCtx<'info> {
 
     #[account(constraint = transfer_authority.key() == token_manager.transfer_authority.expect("No transfer authority for token manager") @ ErrorCode::InvalidTransferAuthority)]
     transfer_authority: Box<Account<'info, TransferAuthority>>,
+    #[account(mut)]
     marketplace: Box<Account<'info, Marketplace>>,
 
     #[account(mut, constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager)]
     token_manager: Box<Account<'info, TokenManager>>,
+    /// CHECK: This is not dangerous because it is checked to be the empty auction of the token manager in the handler
+    auction: UncheckedAccount<'info>,
 
     /// CHECK: This is not dangerous because this account is not read in this instruction
     mint: UncheckedAccount<'info>,
@@ -56,6 +64,8 @@ pub struct CreateListingCtx<'info> {
 }
 
 pub fn handler(ctx: Context<CreateListingCtx>, ix: CreateListingIx) -> Result<()> {
+    assert_no_auction(&ctx.accounts.auction, ctx.accounts.token_manager.key())?;
+
     let listing = &mut ctx.accounts.listing;
     listing.bump = *ctx.bumps.get("listing").unwrap();
     listing.lister = ctx.accounts.lister.key();
@@ -64,6 +74,15 @@ pub fn handler(ctx: Context<CreateListingCtx>, ix: CreateListingIx) -> Result<()
     // payment
     listing.payment_amount = ix.payment_amount;
     listing.payment_mint = ix.payment_mint;
+    if ix.expires_at.is_some() && ix.expires_at.unwrap() <= Clock::get().unwrap().unix_timestamp {
+        return Err(error!(ErrorCode::InvalidListingExpiration));
+    }
+    listing.expires_at = ix.expires_at;
+    listing.allowed_buyer = ix.allowed_buyer;
+    listing.counted = true;
+
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.listing_count = marketplace.listing_count.checked_add(1).expect("Add error");
 
     if ctx.accounts.marketplace.payment_mints.is_some() && !ctx.accounts.marketplace.payment_mints.as_ref().unwrap().contains(&ix.payment_mint) {
         return Err(error!(ErrorCode::InvalidPaymentMint));
@@ -72,8 +91,10 @@ pub fn handler(ctx: Context<CreateListingCtx>, ix: CreateListingIx) -> Result<()
     if ctx.accounts.transfer_authority.allowed_marketplaces.is_some() && !ctx.accounts.transfer_authority.allowed_marketplaces.as_ref().unwrap().contains(&ctx.accounts.marketplace.key()) {
         return Err(error!(ErrorCode::MarketplaceNotAllowed));
     }
+    assert_lister_fee_bps(&ctx.accounts.transfer_authority, &ctx.accounts.marketplace)?;
 
-    if ctx.accounts.lister_token_account.delegate.is_none() {
+    // managed and programmable tokens are delegated to the token manager on claim, only permissioned tokens need delegating here
+    if ctx.accounts.lister_token_account.delegate.is_none() && ctx.accounts.token_manager.kind == TokenManagerKind::Permissioned as u8 {
         let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::DelegateCtx {
             token_manager: ctx.accounts.token_manager.to_account_info(),
             mint: ctx.accounts.mint.to_account_info(),
@@ -84,7 +105,8 @@ pub fn handler(ctx: Context<CreateListingCtx>, ix: CreateListingIx) -> Result<()
         };
         let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts);
         solana_nft_programs_token_manager::cpi::delegate(cpi_ctx)?;
-    } else if ctx.accounts.lister_token_account.delegate.expect("Invalid delegate") != ctx.accounts.token_manager.key()
+    } else if ctx.accounts.lister_token_account.delegate.is_none()
+        || ctx.accounts.lister_token_account.delegate.expect("Invalid delegate") != ctx.accounts.token_manager.key()
         || ctx.accounts.lister_token_account.delegated_amount != ctx.accounts.token_manager.amount
     {
         return Err(error!(ErrorCode::TokenNotDelegated));
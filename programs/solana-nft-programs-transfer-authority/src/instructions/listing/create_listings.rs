@@ -0,0 +1,149 @@
+use anchor_lang::system_program;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::instructions::CreateListingIx;
+use crate::state::*;
+use crate::utils::assert_lister_fee_bps;
+use crate::utils::assert_no_auction;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerKind;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+
+// listing, token manager, mint, mint manager, lister token account and auction for each listing
+pub const CREATE_LISTINGS_GROUP_SIZE: usize = 6;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateListingsIx {
+    pub listings: Vec<CreateListingIx>,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: CreateListingsIx)]
+pub struct CreateListingsCtx<'info> {
+    transfer_authority: Box<Account<'info, TransferAuthority>>,
+    #[account(mut)]
+    marketplace: Box<Account<'info, Marketplace>>,
+
+    #[account(mut)]
+    lister: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * create listings for many token managers under the same transfer authority in one marketplace
+ */
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, CreateListingsCtx<'info>>, ix: CreateListingsIx) -> Result<()> {
+    if ix.listings.is_empty() || ix.listings.len() > MAX_BULK_LISTINGS || ctx.remaining_accounts.len() != ix.listings.len() * CREATE_LISTINGS_GROUP_SIZE {
+        return Err(error!(ErrorCode::InvalidRemainingAccountsSize));
+    }
+    if ctx.accounts.transfer_authority.allowed_marketplaces.is_some() && !ctx.accounts.transfer_authority.allowed_marketplaces.as_ref().unwrap().contains(&ctx.accounts.marketplace.key()) {
+        return Err(error!(ErrorCode::MarketplaceNotAllowed));
+    }
+    assert_lister_fee_bps(&ctx.accounts.transfer_authority, &ctx.accounts.marketplace)?;
+
+    let rent = Rent::get()?;
+    for (item, group) in ix.listings.iter().zip(ctx.remaining_accounts.chunks(CREATE_LISTINGS_GROUP_SIZE)) {
+        let listing_info = &group[0];
+        let token_manager_info = &group[1];
+        let mint_info = &group[2];
+        let mint_manager_info = &group[3];
+        let lister_token_account_info = &group[4];
+        let auction_info = &group[5];
+
+        let token_manager = Account::<TokenManager>::try_from(token_manager_info)?;
+        if token_manager.state != TokenManagerState::Claimed as u8 {
+            return Err(error!(ErrorCode::InvalidTokenManager));
+        }
+        if token_manager.transfer_authority != Some(ctx.accounts.transfer_authority.key()) {
+            return Err(error!(ErrorCode::InvalidTransferAuthority));
+        }
+        assert_no_auction(auction_info, token_manager.key())?;
+        let lister_token_account = Account::<TokenAccount>::try_from(lister_token_account_info)?;
+        if lister_token_account.key() != token_manager.recipient_token_account {
+            return Err(error!(ErrorCode::InvalidListerMintTokenAccount));
+        }
+        if lister_token_account.owner != ctx.accounts.lister.key() {
+            return Err(error!(ErrorCode::InvalidLister));
+        }
+        if ctx.accounts.marketplace.payment_mints.is_some() && !ctx.accounts.marketplace.payment_mints.as_ref().unwrap().contains(&item.payment_mint) {
+            return Err(error!(ErrorCode::InvalidPaymentMint));
+        }
+        if item.expires_at.is_some() && item.expires_at.unwrap() <= Clock::get().unwrap().unix_timestamp {
+            return Err(error!(ErrorCode::InvalidListingExpiration));
+        }
+
+        // allocate listing, the address may already hold lamports so it is topped up, allocated and assigned instead of created
+        let token_manager_key = token_manager.key();
+        let (listing_key, listing_bump) = Pubkey::find_program_address(&[LISTING_SEED.as_bytes(), token_manager_key.as_ref()], ctx.program_id);
+        if listing_info.key() != listing_key {
+            return Err(error!(ErrorCode::InvalidDerivation));
+        }
+        let listing_seeds = &[LISTING_SEED.as_bytes(), token_manager_key.as_ref(), &[listing_bump]];
+        let listing_signer = &[&listing_seeds[..]];
+        let required_lamports = rent.minimum_balance(LISTING_SIZE).saturating_sub(listing_info.lamports());
+        if required_lamports > 0 {
+            let cpi_accounts = system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: listing_info.to_account_info(),
+            };
+            let cpi_context = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+            system_program::transfer(cpi_context, required_lamports)?;
+        }
+        let cpi_accounts = system_program::Allocate {
+            account_to_allocate: listing_info.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts).with_signer(listing_signer);
+        system_program::allocate(cpi_context, LISTING_SIZE as u64)?;
+        let cpi_accounts = system_program::Assign {
+            account_to_assign: listing_info.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts).with_signer(listing_signer);
+        system_program::assign(cpi_context, ctx.program_id)?;
+        let listing = Listing {
+            bump: listing_bump,
+            lister: ctx.accounts.lister.key(),
+            token_manager: token_manager_key,
+            marketplace: ctx.accounts.marketplace.key(),
+            payment_amount: item.payment_amount,
+            payment_mint: item.payment_mint,
+            expires_at: item.expires_at,
+            allowed_buyer: item.allowed_buyer,
+            counted: true,
+        };
+        let mut listing_data = listing_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut listing_data;
+        listing.try_serialize(&mut writer)?;
+        drop(listing_data);
+
+        if lister_token_account.delegate.is_none() && token_manager.kind == TokenManagerKind::Permissioned as u8 {
+            let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::DelegateCtx {
+                token_manager: token_manager_info.to_account_info(),
+                mint: mint_info.to_account_info(),
+                mint_manager: mint_manager_info.to_account_info(),
+                recipient: ctx.accounts.lister.to_account_info(),
+                recipient_token_account: lister_token_account_info.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts);
+            solana_nft_programs_token_manager::cpi::delegate(cpi_ctx)?;
+        } else if lister_token_account.delegate.is_none()
+            || lister_token_account.delegate.expect("Invalid delegate") != token_manager_key
+            || lister_token_account.delegated_amount != token_manager.amount
+        {
+            return Err(error!(ErrorCode::TokenNotDelegated));
+        }
+    }
+
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.listing_count = marketplace.listing_count.checked_add(ix.listings.len() as u64).expect("Add error");
+    Ok(())
+}
@@ -1,9 +1,17 @@
 pub mod accept_listing;
+pub mod close_stale_listing;
 pub mod create_listing;
+pub mod create_listings;
 pub mod remove_listing;
+pub mod remove_listings;
 pub mod update_listing;
+pub mod update_listings;
 
 pub use accept_listing::*;
+pub use close_stale_listing::*;
 pub use create_listing::*;
+pub use create_listings::*;
 pub use remove_listing::*;
+pub use remove_listings::*;
 pub use update_listing::*;
+pub use update_listings::*;
@@ -1,5 +1,6 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::decrement_listing_count;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Token;
 use anchor_spl::token::TokenAccount;
@@ -14,6 +15,9 @@ pub struct RemoveListingCtx<'info> {
 
     #[account(mut, close = lister)]
     listing: Box<Account<'info, Listing>>,
+    /// CHECK: This is not dangerous because of the listing.marketplace check and the marketplace may already be closed
+    #[account(mut, constraint = marketplace.key() == listing.marketplace @ ErrorCode::InvalidMarketplace)]
+    marketplace: UncheckedAccount<'info>,
     #[account(mut, constraint =
         lister_mint_token_account.amount == 1 &&
         lister_mint_token_account.mint == token_manager.mint &&
@@ -33,6 +37,8 @@ pub struct RemoveListingCtx<'info> {
 }
 
 pub fn handler(ctx: Context<RemoveListingCtx>) -> Result<()> {
+    decrement_listing_count(&ctx.accounts.marketplace, &ctx.accounts.listing)?;
+
     if ctx.accounts.lister_mint_token_account.delegate.is_some()
         && ctx.accounts.lister_mint_token_account.delegate.expect("Invalid delegate") == ctx.accounts.token_manager.key()
         && ctx.accounts.token_manager.kind == TokenManagerKind::Permissioned as u8
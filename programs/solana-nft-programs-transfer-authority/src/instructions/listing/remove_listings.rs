@@ -0,0 +1,77 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::decrement_listing_count;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerKind;
+
+// token manager, listing, marketplace, lister mint token account, mint and mint manager for each listing
+pub const REMOVE_LISTINGS_GROUP_SIZE: usize = 6;
+
+#[derive(Accounts)]
+pub struct RemoveListingsCtx<'info> {
+    #[account(mut)]
+    lister: Signer<'info>,
+
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    token_program: Program<'info, Token>,
+}
+
+/**
+ * remove many listings in one instruction, undelegating permissioned tokens like remove_listing
+ */
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, RemoveListingsCtx<'info>>) -> Result<()> {
+    let listings_length = ctx.remaining_accounts.len() / REMOVE_LISTINGS_GROUP_SIZE;
+    if listings_length == 0 || listings_length > MAX_BULK_LISTINGS || ctx.remaining_accounts.len() % REMOVE_LISTINGS_GROUP_SIZE != 0 {
+        return Err(error!(ErrorCode::InvalidRemainingAccountsSize));
+    }
+
+    for group in ctx.remaining_accounts.chunks(REMOVE_LISTINGS_GROUP_SIZE) {
+        let token_manager_info = &group[0];
+        let marketplace_info = &group[2];
+        let lister_mint_token_account_info = &group[3];
+        let mint_info = &group[4];
+        let mint_manager_info = &group[5];
+
+        let token_manager = Account::<TokenManager>::try_from(token_manager_info)?;
+        let listing = Account::<Listing>::try_from(&group[1])?;
+        let lister_mint_token_account = Account::<TokenAccount>::try_from(lister_mint_token_account_info)?;
+
+        if listing.token_manager != token_manager.key() {
+            return Err(error!(ErrorCode::InvalidTokenManager));
+        }
+        if listing.lister != ctx.accounts.lister.key() {
+            return Err(error!(ErrorCode::InvalidLister));
+        }
+        if listing.marketplace != marketplace_info.key() {
+            return Err(error!(ErrorCode::InvalidMarketplace));
+        }
+        if lister_mint_token_account.amount != 1 || lister_mint_token_account.mint != token_manager.mint || lister_mint_token_account.owner != ctx.accounts.lister.key() {
+            return Err(error!(ErrorCode::InvalidListerMintTokenAccount));
+        }
+
+        if lister_mint_token_account.delegate.is_some()
+            && lister_mint_token_account.delegate.expect("Invalid delegate") == token_manager.key()
+            && token_manager.kind == TokenManagerKind::Permissioned as u8
+        {
+            let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::UndelegateCtx {
+                token_manager: token_manager_info.to_account_info(),
+                mint: mint_info.to_account_info(),
+                mint_manager: mint_manager_info.to_account_info(),
+                recipient: ctx.accounts.lister.to_account_info(),
+                recipient_token_account: lister_mint_token_account_info.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts);
+            solana_nft_programs_token_manager::cpi::undelegate(cpi_ctx)?;
+        }
+
+        decrement_listing_count(marketplace_info, &listing)?;
+        listing.close(ctx.accounts.lister.to_account_info())?;
+    }
+    Ok(())
+}
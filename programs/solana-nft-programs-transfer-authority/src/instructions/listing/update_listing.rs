@@ -3,6 +3,7 @@ use solana_nft_programs_token_manager::state::TokenManager;
 
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::decrement_listing_count;
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -10,6 +11,8 @@ pub struct UpdateListingIx {
     pub marketplace: Pubkey,
     pub payment_amount: u64,
     pub payment_mint: Pubkey,
+    pub expires_at: Option<i64>,
+    pub allowed_buyer: Option<Pubkey>,
 }
 
 #[derive(Accounts)]
@@ -20,6 +23,11 @@ pub struct UpdateListingCtx<'info> {
 
     #[account(mut)]
     listing: Box<Account<'info, Listing>>,
+    /// CHECK: This is not dangerous because of the listing.marketplace check and the marketplace may already be closed
+    #[account(mut, constraint = marketplace.key() == listing.marketplace @ ErrorCode::InvalidMarketplace)]
+    marketplace: UncheckedAccount<'info>,
+    #[account(mut, constraint = new_marketplace.key() == ix.marketplace @ ErrorCode::InvalidMarketplace)]
+    new_marketplace: Box<Account<'info, Marketplace>>,
     #[account(mut, constraint =
         lister_mint_token_account.amount == 1 &&
         lister_mint_token_account.mint == token_manager.mint &&
@@ -30,11 +38,24 @@ pub struct UpdateListingCtx<'info> {
 }
 
 pub fn handler(ctx: Context<UpdateListingCtx>, ix: UpdateListingIx) -> Result<()> {
+    // uncounted listings start being counted once updated
+    if ctx.accounts.marketplace.key() != ctx.accounts.new_marketplace.key() || !ctx.accounts.listing.counted {
+        decrement_listing_count(&ctx.accounts.marketplace, &ctx.accounts.listing)?;
+        let new_marketplace = &mut ctx.accounts.new_marketplace;
+        new_marketplace.listing_count = new_marketplace.listing_count.checked_add(1).expect("Add error");
+    }
+
     let listing = &mut ctx.accounts.listing;
+    listing.counted = true;
     listing.lister = ctx.accounts.lister.key();
     listing.marketplace = ix.marketplace.key();
     listing.payment_amount = ix.payment_amount;
     listing.payment_mint = ix.payment_mint;
+    if ix.expires_at.is_some() && ix.expires_at.unwrap() <= Clock::get().unwrap().unix_timestamp {
+        return Err(error!(ErrorCode::InvalidListingExpiration));
+    }
+    listing.expires_at = ix.expires_at;
+    listing.allowed_buyer = ix.allowed_buyer;
 
     msg!("Mint: {}", ctx.accounts.token_manager.mint.key());
 
@@ -0,0 +1,62 @@
+use anchor_spl::token::TokenAccount;
+use solana_nft_programs_token_manager::state::TokenManager;
+
+use crate::errors::ErrorCode;
+use crate::instructions::UpdateListingIx;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+// token manager, listing and lister mint token account for each listing
+pub const UPDATE_LISTINGS_GROUP_SIZE: usize = 3;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateListingsIx {
+    pub listings: Vec<UpdateListingIx>,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: UpdateListingsIx)]
+pub struct UpdateListingsCtx<'info> {
+    marketplace: Box<Account<'info, Marketplace>>,
+    #[account(mut)]
+    lister: Signer<'info>,
+}
+
+/**
+ * reprice many listings of one marketplace in one instruction, listings keep their marketplace
+ */
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, UpdateListingsCtx<'info>>, ix: UpdateListingsIx) -> Result<()> {
+    if ix.listings.is_empty() || ix.listings.len() > MAX_BULK_LISTINGS || ctx.remaining_accounts.len() != ix.listings.len() * UPDATE_LISTINGS_GROUP_SIZE {
+        return Err(error!(ErrorCode::InvalidRemainingAccountsSize));
+    }
+
+    for (item, group) in ix.listings.iter().zip(ctx.remaining_accounts.chunks(UPDATE_LISTINGS_GROUP_SIZE)) {
+        let token_manager = Account::<TokenManager>::try_from(&group[0])?;
+        let mut listing = Account::<Listing>::try_from(&group[1])?;
+        let lister_mint_token_account = Account::<TokenAccount>::try_from(&group[2])?;
+
+        if listing.token_manager != token_manager.key() {
+            return Err(error!(ErrorCode::InvalidTokenManager));
+        }
+        if listing.marketplace != item.marketplace || listing.marketplace != ctx.accounts.marketplace.key() {
+            return Err(error!(ErrorCode::InvalidMarketplace));
+        }
+        if ctx.accounts.marketplace.payment_mints.is_some() && !ctx.accounts.marketplace.payment_mints.as_ref().unwrap().contains(&item.payment_mint) {
+            return Err(error!(ErrorCode::InvalidPaymentMint));
+        }
+        if lister_mint_token_account.amount != 1 || lister_mint_token_account.mint != token_manager.mint || lister_mint_token_account.owner != ctx.accounts.lister.key() {
+            return Err(error!(ErrorCode::InvalidListerMintTokenAccount));
+        }
+        if item.expires_at.is_some() && item.expires_at.unwrap() <= Clock::get().unwrap().unix_timestamp {
+            return Err(error!(ErrorCode::InvalidListingExpiration));
+        }
+
+        listing.lister = ctx.accounts.lister.key();
+        listing.payment_amount = item.payment_amount;
+        listing.payment_mint = item.payment_mint;
+        listing.expires_at = item.expires_at;
+        listing.allowed_buyer = item.allowed_buyer;
+        listing.exit(ctx.program_id)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptMarketplaceAuthorityCtx<'info> {
+    #[account(mut, constraint = marketplace.pending_authority == Some(pending_authority.key()) @ ErrorCode::InvalidPendingAuthority)]
+    marketplace: Box<Account<'info, Marketplace>>,
+    pending_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptMarketplaceAuthorityCtx>) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.authority = ctx.accounts.pending_authority.key();
+    marketplace.pending_authority = None;
+    Ok(())
+}
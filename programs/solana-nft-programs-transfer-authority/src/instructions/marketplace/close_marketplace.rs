@@ -0,0 +1,15 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseMarketplaceCtx<'info> {
+    #[account(mut, close = authority, constraint = marketplace.listing_count == 0 @ ErrorCode::MarketplaceHasListings)]
+    marketplace: Box<Account<'info, Marketplace>>,
+    #[account(mut, constraint = authority.key() == marketplace.authority @ ErrorCode::InvalidMarketplaceAuthority)]
+    authority: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<CloseMarketplaceCtx>) -> Result<()> {
+    Ok(())
+}
@@ -9,6 +9,9 @@ pub struct InitMarketplaceIx {
     pub name: String,
     pub authority: Pubkey,
     pub payment_mints: Option<Vec<Pubkey>>,
+    pub fee_collector: Option<Pubkey>,
+    pub maker_fee_bps: Option<u16>,
+    pub taker_fee_bps: Option<u16>,
 }
 
 #[derive(Accounts)]
@@ -17,7 +20,7 @@ pub struct InitMarketplaceCtx<'info> {
     #[account(
         init,
         payer = payer,
-        space = MARKETPLACE_SIZE,
+        space = marketplace_size(ix.payment_mints.as_ref()),
         seeds = [MARKETPLACE_SEED.as_bytes(), ix.name.as_bytes()], bump,
     )]
     marketplace: Box<Account<'info, Marketplace>>,
@@ -34,6 +37,15 @@ pub fn handler(ctx: Context<InitMarketplaceCtx>, ix: InitMarketplaceIx) -> Resul
     marketplace.payment_manager = ctx.accounts.payment_manager.key();
     marketplace.authority = ix.authority;
     marketplace.payment_mints = ix.payment_mints;
+    if ix.maker_fee_bps.unwrap_or(0) as u64 > BPS_DENOMINATOR || ix.taker_fee_bps.unwrap_or(0) as u64 > BPS_DENOMINATOR {
+        return Err(error!(ErrorCode::InvalidMarketplaceFee));
+    }
+    if ix.fee_collector.is_none() && (ix.maker_fee_bps.unwrap_or(0) > 0 || ix.taker_fee_bps.unwrap_or(0) > 0) {
+        return Err(error!(ErrorCode::InvalidMarketplaceFee));
+    }
+    marketplace.fee_collector = ix.fee_collector;
+    marketplace.maker_fee_bps = ix.maker_fee_bps;
+    marketplace.taker_fee_bps = ix.taker_fee_bps;
 
     if !ctx.accounts.payment_manager.include_seller_fee_basis_points {
         return Err(error!(ErrorCode::InvalidPaymentManager));
@@ -1,5 +1,11 @@
+pub mod accept_marketplace_authority;
+pub mod close_marketplace;
 pub mod init_marketplace;
+pub mod propose_marketplace_authority;
 pub mod update_marketplace;
 
+pub use accept_marketplace_authority::*;
+pub use close_marketplace::*;
 pub use init_marketplace::*;
+pub use propose_marketplace_authority::*;
 pub use update_marketplace::*;
@@ -0,0 +1,17 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeMarketplaceAuthorityCtx<'info> {
+    #[account(mut)]
+    marketplace: Box<Account<'info, Marketplace>>,
+    #[account(constraint = authority.key() == marketplace.authority @ ErrorCode::InvalidMarketplaceAuthority)]
+    authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ProposeMarketplaceAuthorityCtx>, new_authority: Pubkey) -> Result<()> {
+    let marketplace = &mut ctx.accounts.marketplace;
+    marketplace.pending_authority = Some(new_authority);
+    Ok(())
+}
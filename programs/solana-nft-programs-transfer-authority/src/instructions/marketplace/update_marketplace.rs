@@ -5,25 +5,41 @@ use anchor_lang::prelude::*;
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateMarketplaceIx {
     pub payment_manager: Pubkey,
-    pub authority: Pubkey,
     pub payment_mints: Option<Vec<Pubkey>>,
+    pub fee_collector: Option<Pubkey>,
+    pub maker_fee_bps: Option<u16>,
+    pub taker_fee_bps: Option<u16>,
 }
 
 #[derive(Accounts)]
 #[instruction(ix: UpdateMarketplaceIx)]
 pub struct UpdateMarketplaceCtx<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        realloc = marketplace_size(ix.payment_mints.as_ref()),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
     marketplace: Box<Account<'info, Marketplace>>,
 
     #[account(mut, constraint = authority.key() == marketplace.authority @ ErrorCode::InvalidMarketplaceAuthority)]
     authority: Signer<'info>,
+    system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<UpdateMarketplaceCtx>, ix: UpdateMarketplaceIx) -> Result<()> {
     let marketplace = &mut ctx.accounts.marketplace;
     marketplace.payment_manager = ix.payment_manager;
-    marketplace.authority = ix.authority;
     marketplace.payment_mints = ix.payment_mints;
+    if ix.maker_fee_bps.unwrap_or(0) as u64 > BPS_DENOMINATOR || ix.taker_fee_bps.unwrap_or(0) as u64 > BPS_DENOMINATOR {
+        return Err(error!(ErrorCode::InvalidMarketplaceFee));
+    }
+    if ix.fee_collector.is_none() && (ix.maker_fee_bps.unwrap_or(0) > 0 || ix.taker_fee_bps.unwrap_or(0) > 0) {
+        return Err(error!(ErrorCode::InvalidMarketplaceFee));
+    }
+    marketplace.fee_collector = ix.fee_collector;
+    marketplace.maker_fee_bps = ix.maker_fee_bps;
+    marketplace.taker_fee_bps = ix.taker_fee_bps;
 
     Ok(())
 }
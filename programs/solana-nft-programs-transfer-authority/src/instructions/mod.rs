@@ -1,19 +1,42 @@
 pub mod listing;
 pub use listing::accept_listing::*;
+pub use listing::close_stale_listing::*;
 pub use listing::create_listing::*;
+pub use listing::create_listings::*;
 pub use listing::remove_listing::*;
+pub use listing::remove_listings::*;
 pub use listing::update_listing::*;
+pub use listing::update_listings::*;
 
 pub mod transfer_authority;
+pub use transfer_authority::accept_transfer_authority_authority::*;
+pub use transfer_authority::close_transfer_authority::*;
 pub use transfer_authority::init_transfer_authority::*;
+pub use transfer_authority::propose_transfer_authority_authority::*;
 pub use transfer_authority::release::*;
 pub use transfer_authority::update_transfer_authority::*;
 pub use transfer_authority::whitelist_marketplaces::*;
 
 pub mod marketplace;
+pub use marketplace::accept_marketplace_authority::*;
+pub use marketplace::close_marketplace::*;
 pub use marketplace::init_marketplace::*;
+pub use marketplace::propose_marketplace_authority::*;
 pub use marketplace::update_marketplace::*;
 
+pub mod auction;
+pub use auction::cancel_auction::*;
+pub use auction::claim_auction::*;
+pub use auction::close_stale_auction::*;
+pub use auction::create_auction::*;
+pub use auction::place_bid::*;
+
+pub mod offer;
+pub use offer::accept_offer::*;
+pub use offer::cancel_offer::*;
+pub use offer::claim_offer::*;
+pub use offer::create_offer::*;
+
 pub mod transfer;
 pub use transfer::accept_transfer::*;
 pub use transfer::cancel_transfer::*;
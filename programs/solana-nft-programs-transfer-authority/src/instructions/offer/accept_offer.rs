@@ -0,0 +1,68 @@
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerKind;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+pub struct AcceptOfferCtx<'info> {
+    #[account(mut, constraint = offer.accepted_by.is_none() @ ErrorCode::OfferAlreadyAccepted)]
+    offer: Box<Account<'info, Offer>>,
+
+    #[account(mut, constraint =
+        token_manager.state == TokenManagerState::Claimed as u8
+        && token_manager.transfer_authority == Some(offer.transfer_authority)
+        && offer.token_manager.unwrap_or(token_manager.key()) == token_manager.key()
+        @ ErrorCode::InvalidTokenManager
+    )]
+    token_manager: Box<Account<'info, TokenManager>>,
+    /// CHECK: This is not dangerous because this account is not read in this instruction
+    mint: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because this account is not read in this instruction
+    #[account(mut)]
+    mint_manager: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = holder_token_account.key() == token_manager.recipient_token_account @ ErrorCode::InvalidHolderMintTokenAccount)]
+    holder_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = holder.key() == holder_token_account.owner @ ErrorCode::InvalidHolder)]
+    holder: Signer<'info>,
+
+    solana_nft_programs_token_manager: Program<'info, SolanaNftProgramsTokenManager>,
+    token_program: Program<'info, Token>,
+}
+
+/**
+ * records the holder's acceptance
+ * the token manager transfer needs the buyer's signature so the buyer completes the sale with claim_offer
+ */
+pub fn handler(ctx: Context<AcceptOfferCtx>) -> Result<()> {
+    let offer = &mut ctx.accounts.offer;
+    offer.accepted_by = Some(ctx.accounts.holder.key());
+    offer.accepted_token_manager = Some(ctx.accounts.token_manager.key());
+    offer.accepted_token_account = Some(ctx.accounts.holder_token_account.key());
+
+    if ctx.accounts.holder_token_account.delegate.is_none() && ctx.accounts.token_manager.kind == TokenManagerKind::Permissioned as u8 {
+        let cpi_accounts = solana_nft_programs_token_manager::cpi::accounts::DelegateCtx {
+            token_manager: ctx.accounts.token_manager.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            mint_manager: ctx.accounts.mint_manager.to_account_info(),
+            recipient: ctx.accounts.holder.to_account_info(),
+            recipient_token_account: ctx.accounts.holder_token_account.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.solana_nft_programs_token_manager.to_account_info(), cpi_accounts);
+        solana_nft_programs_token_manager::cpi::delegate(cpi_ctx)?;
+    } else if ctx.accounts.holder_token_account.delegate.is_none()
+        || ctx.accounts.holder_token_account.delegate.expect("Invalid delegate") != ctx.accounts.token_manager.key()
+        || ctx.accounts.holder_token_account.delegated_amount != ctx.accounts.token_manager.amount
+    {
+        return Err(error!(ErrorCode::TokenNotDelegated));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::CloseAccount;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::{self};
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+pub struct CancelOfferCtx<'info> {
+    #[account(mut, close = buyer)]
+    offer: Box<Account<'info, Offer>>,
+    /// CHECK: This is not dangerous because it is only read for accepted offers and checked in the handler
+    token_manager: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is only used for spl offers and checked in the handler
+    #[account(mut)]
+    offer_payment_token_account: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = buyer.key() == offer.buyer @ ErrorCode::InvalidOffer)]
+    buyer: Signer<'info>,
+    /// CHECK: This is not dangerous because it is only used for spl offers and checked in the handler
+    #[account(mut)]
+    buyer_payment_token_account: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+}
+
+/**
+ * returns the escrow to the buyer, native SOL is returned when the offer account is closed
+ * an accepted offer can only be cancelled once the holder no longer holds the accepted token
+ */
+pub fn handler(ctx: Context<CancelOfferCtx>) -> Result<()> {
+    let offer = &ctx.accounts.offer;
+    if let Some(accepted_token_manager) = offer.accepted_token_manager {
+        if ctx.accounts.token_manager.key() != accepted_token_manager {
+            return Err(error!(ErrorCode::InvalidTokenManager));
+        }
+        let stale = if ctx.accounts.token_manager.data_is_empty() {
+            true
+        } else {
+            let token_manager = Account::<TokenManager>::try_from(&ctx.accounts.token_manager)?;
+            token_manager.state != TokenManagerState::Claimed as u8 || Some(token_manager.recipient_token_account) != offer.accepted_token_account
+        };
+        if !stale {
+            return Err(error!(ErrorCode::OfferAlreadyAccepted));
+        }
+    }
+
+    if offer.payment_mint != Pubkey::default() {
+        let offer_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.offer_payment_token_account)?;
+        if offer_payment_token_account.owner != offer.key() || offer_payment_token_account.mint != offer.payment_mint {
+            return Err(error!(ErrorCode::InvalidOfferTokenAccount));
+        }
+        let buyer_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.buyer_payment_token_account)?;
+        if buyer_payment_token_account.mint != offer.payment_mint || buyer_payment_token_account.owner != offer.buyer {
+            return Err(error!(ErrorCode::InvalidBuyerPaymentTokenAccount));
+        }
+
+        let offer_target = offer.token_manager.unwrap_or(offer.transfer_authority);
+        let offer_seeds = &[OFFER_SEED.as_bytes(), offer_target.as_ref(), offer.buyer.as_ref(), &[offer.bump]];
+        let offer_signer = &[&offer_seeds[..]];
+
+        let cpi_accounts = token::Transfer {
+            from: ctx.accounts.offer_payment_token_account.to_account_info(),
+            to: ctx.accounts.buyer_payment_token_account.to_account_info(),
+            authority: offer.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(offer_signer);
+        token::transfer(cpi_context, offer_payment_token_account.amount)?;
+
+        let cpi_accounts = CloseAccount {
+            account: ctx.accounts.offer_payment_token_account.to_account_info(),
+            destination: ctx.accounts.buyer.to_account_info(),
+            authority: offer.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts).with_signer(offer_signer);
+        token::close_account(cpi_context)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::Token;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::{self};
+use solana_nft_programs_payment_manager::state::PaymentManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateOfferIx {
+    pub payment_amount: u64,
+    pub payment_mint: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: CreateOfferIx)]
+pub struct CreateOfferCtx<'info> {
+    #[account(
+        init,
+        payer = buyer,
+        space = OFFER_SIZE,
+        seeds = [OFFER_SEED.as_bytes(), target.key().as_ref(), buyer.key().as_ref()], bump,
+    )]
+    offer: Box<Account<'info, Offer>>,
+    /// CHECK: This is not dangerous because it is only used for spl offers and checked in the handler
+    #[account(mut)]
+    offer_payment_token_account: UncheckedAccount<'info>,
+
+    transfer_authority: Box<Account<'info, TransferAuthority>>,
+    /// CHECK: This is not dangerous because it is either the transfer authority or a token manager checked in the handler
+    target: UncheckedAccount<'info>,
+    marketplace: Box<Account<'info, Marketplace>>,
+    #[account(constraint = payment_manager.key() == marketplace.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: Box<Account<'info, PaymentManager>>,
+
+    #[account(mut)]
+    buyer: Signer<'info>,
+    /// CHECK: This is not dangerous because it is only used for spl offers and checked in the handler
+    #[account(mut)]
+    buyer_payment_token_account: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * escrows the offer amount for a specific token manager, or for any token manager under the transfer authority when the target is the transfer authority itself
+ * the escrow also holds the marketplace and payment manager taker fees the buyer owes on top of the offer amount
+ */
+pub fn handler(ctx: Context<CreateOfferCtx>, ix: CreateOfferIx) -> Result<()> {
+    let escrow_amount = escrow_amount(&ctx.accounts.marketplace, &ctx.accounts.payment_manager, ix.payment_amount)?;
+    let offer = &mut ctx.accounts.offer;
+    offer.bump = *ctx.bumps.get("offer").unwrap();
+    offer.buyer = ctx.accounts.buyer.key();
+    offer.transfer_authority = ctx.accounts.transfer_authority.key();
+    offer.marketplace = ctx.accounts.marketplace.key();
+    // payment
+    offer.payment_amount = ix.payment_amount;
+    offer.payment_mint = ix.payment_mint;
+    offer.escrow_amount = escrow_amount;
+    offer.accepted_by = None;
+    offer.accepted_token_manager = None;
+    offer.accepted_token_account = None;
+
+    if ctx.accounts.target.key() == ctx.accounts.transfer_authority.key() {
+        offer.token_manager = None;
+    } else {
+        let token_manager = Account::<TokenManager>::try_from(&ctx.accounts.target)?;
+        if token_manager.transfer_authority != Some(ctx.accounts.transfer_authority.key()) {
+            return Err(error!(ErrorCode::InvalidOfferTarget));
+        }
+        offer.token_manager = Some(token_manager.key());
+    }
+
+    if ctx.accounts.marketplace.payment_mints.is_some() && !ctx.accounts.marketplace.payment_mints.as_ref().unwrap().contains(&ix.payment_mint) {
+        return Err(error!(ErrorCode::InvalidPaymentMint));
+    }
+
+    if ctx.accounts.transfer_authority.allowed_marketplaces.is_some() && !ctx.accounts.transfer_authority.allowed_marketplaces.as_ref().unwrap().contains(&ctx.accounts.marketplace.key()) {
+        return Err(error!(ErrorCode::MarketplaceNotAllowed));
+    }
+
+    // native SOL
+    if ix.payment_mint == Pubkey::default() {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.buyer.to_account_info(),
+            to: offer.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_context, escrow_amount)?;
+    } else {
+        // any SPL token
+        let offer_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.offer_payment_token_account)?;
+        if offer_payment_token_account.owner != offer.key() || offer_payment_token_account.mint != ix.payment_mint {
+            return Err(error!(ErrorCode::InvalidOfferTokenAccount));
+        }
+        let buyer_payment_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.buyer_payment_token_account)?;
+        if buyer_payment_token_account.mint != ix.payment_mint || buyer_payment_token_account.amount < escrow_amount || buyer_payment_token_account.owner != ctx.accounts.buyer.key() {
+            return Err(error!(ErrorCode::InvalidBuyerPaymentTokenAccount));
+        }
+
+        let cpi_accounts = token::Transfer {
+            from: ctx.accounts.buyer_payment_token_account.to_account_info(),
+            to: ctx.accounts.offer_payment_token_account.to_account_info(),
+            authority: ctx.accounts.buyer.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_context, escrow_amount)?;
+    }
+
+    Ok(())
+}
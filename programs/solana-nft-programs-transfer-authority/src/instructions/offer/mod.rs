@@ -0,0 +1,9 @@
+pub mod accept_offer;
+pub mod cancel_offer;
+pub mod claim_offer;
+pub mod create_offer;
+
+pub use accept_offer::*;
+pub use cancel_offer::*;
+pub use claim_offer::*;
+pub use create_offer::*;
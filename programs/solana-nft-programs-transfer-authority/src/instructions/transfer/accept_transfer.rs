@@ -7,6 +7,8 @@ use anchor_spl::token::TokenAccount;
 
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::assert_no_auction;
+use crate::utils::decrement_listing_count;
 use anchor_lang::prelude::*;
 use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
 use solana_nft_programs_token_manager::state::TokenManager;
@@ -19,7 +21,12 @@ use spl_associated_token_account::get_associated_token_address;
 
 #[derive(Accounts)]
 pub struct AcceptTransferCtx<'info> {
-    #[account(mut, close = holder, constraint = transfer.token_manager == token_manager.key() @ ErrorCode::InvalidTransfer)]
+    #[account(
+        mut,
+        close = holder,
+        constraint = transfer.token_manager == token_manager.key() @ ErrorCode::InvalidTransfer,
+        constraint = transfer.expires_at.is_none() || Clock::get().unwrap().unix_timestamp < transfer.expires_at.unwrap() @ ErrorCode::TransferExpired
+    )]
     transfer: Box<Account<'info, Transfer>>,
     transfer_authority: Box<Account<'info, TransferAuthority>>,
     /// CHECK: This is not dangerous because this is just the pubkey that collects the closing account lamports
@@ -28,6 +35,11 @@ pub struct AcceptTransferCtx<'info> {
     /// CHECK: This is not dangerous because this is the receipt getting initialized
     #[account(mut)]
     listing: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is only used when the listing exists and checked against the listing marketplace in the handler
+    #[account(mut)]
+    marketplace: UncheckedAccount<'info>,
+    /// CHECK: This is not dangerous because it is checked to be the empty auction of the token manager in the handler
+    auction: UncheckedAccount<'info>,
 
     #[account(mut, constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager)]
     token_manager: Box<Account<'info, TokenManager>>,
@@ -58,6 +70,7 @@ pub struct AcceptTransferCtx<'info> {
 }
 
 pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, AcceptTransferCtx<'info>>) -> Result<()> {
+    assert_no_auction(&ctx.accounts.auction, ctx.accounts.token_manager.key())?;
     let instructions_account_info = ctx.accounts.instructions.to_account_info();
     let current_ix = load_current_index_checked(&instructions_account_info).expect("Error computing current index");
     if current_ix != 0_u16 {
@@ -129,7 +142,12 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
     )?;
     let listing_info = Account::<Listing>::try_from(&ctx.accounts.listing);
     if listing_info.is_ok() {
-        listing_info?.close(ctx.accounts.holder.to_account_info())?;
+        let listing = listing_info?;
+        if ctx.accounts.marketplace.key() != listing.marketplace {
+            return Err(error!(ErrorCode::InvalidMarketplace));
+        }
+        decrement_listing_count(&ctx.accounts.marketplace, &listing)?;
+        listing.close(ctx.accounts.holder.to_account_info())?;
     }
 
     Ok(())
@@ -0,0 +1,24 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseExpiredTransferCtx<'info> {
+    #[account(
+        mut,
+        close = holder,
+        constraint = transfer.expires_at.is_some() && Clock::get().unwrap().unix_timestamp >= transfer.expires_at.unwrap() @ ErrorCode::TransferNotExpired
+    )]
+    transfer: Box<Account<'info, Transfer>>,
+
+    /// CHECK: This is not dangerous because this is just the pubkey that collects the closing account lamports
+    #[account(mut, constraint = holder.key() == transfer.from @ ErrorCode::InvalidHolder)]
+    holder: UncheckedAccount<'info>,
+}
+
+/**
+ * permissionless close of a transfer that has expired
+ */
+pub fn handler(_ctx: Context<CloseExpiredTransferCtx>) -> Result<()> {
+    Ok(())
+}
@@ -9,6 +9,7 @@ use solana_nft_programs_token_manager::state::TokenManagerState;
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitTransferIx {
     pub to: Pubkey,
+    pub expires_at: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -41,6 +42,10 @@ pub fn handler(ctx: Context<InitTransferCtx>, ix: InitTransferIx) -> Result<()>
     transfer.token_manager = ctx.accounts.token_manager.key();
     transfer.from = ctx.accounts.holder.key();
     transfer.to = ix.to;
+    if ix.expires_at.is_some() && ix.expires_at.unwrap() <= Clock::get().unwrap().unix_timestamp {
+        return Err(error!(ErrorCode::InvalidTransferExpiration));
+    }
+    transfer.expires_at = ix.expires_at;
 
     if ctx.accounts.holder_token_account.delegate.expect("Invalid delegate").key() != ctx.accounts.token_manager.key()
         || ctx.accounts.holder_token_account.delegated_amount != ctx.accounts.token_manager.amount
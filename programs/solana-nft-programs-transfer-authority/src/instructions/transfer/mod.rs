@@ -1,7 +1,11 @@
 pub mod accept_transfer;
 pub mod cancel_transfer;
+pub mod close_expired_transfer;
 pub mod init_transfer;
+pub mod reject_transfer;
 
 pub use accept_transfer::*;
 pub use cancel_transfer::*;
+pub use close_expired_transfer::*;
 pub use init_transfer::*;
+pub use reject_transfer::*;
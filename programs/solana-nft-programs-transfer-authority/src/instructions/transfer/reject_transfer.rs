@@ -0,0 +1,19 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RejectTransferCtx<'info> {
+    #[account(mut, close = holder)]
+    transfer: Box<Account<'info, Transfer>>,
+
+    #[account(constraint = recipient.key() == transfer.to @ ErrorCode::InvalidRecipient)]
+    recipient: Signer<'info>,
+    /// CHECK: This is not dangerous because this is just the pubkey that collects the closing account lamports
+    #[account(mut, constraint = holder.key() == transfer.from @ ErrorCode::InvalidHolder)]
+    holder: UncheckedAccount<'info>,
+}
+
+pub fn handler(_ctx: Context<RejectTransferCtx>) -> Result<()> {
+    Ok(())
+}
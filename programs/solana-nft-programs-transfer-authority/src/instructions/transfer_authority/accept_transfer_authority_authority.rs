@@ -0,0 +1,17 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptTransferAuthorityAuthorityCtx<'info> {
+    #[account(mut, constraint = transfer_authority.pending_authority == Some(pending_authority.key()) @ ErrorCode::InvalidPendingAuthority)]
+    transfer_authority: Box<Account<'info, TransferAuthority>>,
+    pending_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptTransferAuthorityAuthorityCtx>) -> Result<()> {
+    let transfer_authority = &mut ctx.accounts.transfer_authority;
+    transfer_authority.authority = ctx.accounts.pending_authority.key();
+    transfer_authority.pending_authority = None;
+    Ok(())
+}
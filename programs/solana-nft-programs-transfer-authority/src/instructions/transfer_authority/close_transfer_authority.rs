@@ -0,0 +1,39 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseTransferAuthorityCtx<'info> {
+    /// CHECK: This is not dangerous because it is deserialized and checked in the handler
+    #[account(mut)]
+    transfer_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    authority: Signer<'info>,
+}
+
+/**
+ * refunds the transfer authority rent down to what its name and bump need
+ * token managers may still reference the transfer authority so the account stays owned by this program with its name and bump for release,
+ * this also keeps the name from being initialized again, otherwise anyone could recreate it and take over those token managers
+ * without an authority and with an empty marketplace allowlist nothing else can use it anymore
+ */
+pub fn handler(ctx: Context<CloseTransferAuthorityCtx>) -> Result<()> {
+    let transfer_authority_info = ctx.accounts.transfer_authority.to_account_info();
+    let mut transfer_authority = Account::<TransferAuthority>::try_from(&transfer_authority_info)?;
+    if transfer_authority.authority != ctx.accounts.authority.key() {
+        return Err(error!(ErrorCode::InvalidTransferAuthorityAuthority));
+    }
+    transfer_authority.authority = Pubkey::default();
+    transfer_authority.allowed_marketplaces = Some(Vec::new());
+    transfer_authority.issuer_share_bps = None;
+    transfer_authority.pending_authority = None;
+    let closed_size = 8 + transfer_authority.try_to_vec()?.len();
+    transfer_authority.exit(&crate::id())?;
+
+    let remaining_lamports = Rent::get()?.minimum_balance(closed_size);
+    let refund = transfer_authority_info.lamports().checked_sub(remaining_lamports).expect("Sub error");
+    **transfer_authority_info.try_borrow_mut_lamports()? = remaining_lamports;
+    **ctx.accounts.authority.try_borrow_mut_lamports()? = ctx.accounts.authority.lamports().checked_add(refund).expect("Add error");
+    transfer_authority_info.realloc(closed_size, false)?;
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use crate::errors::ErrorCode;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
@@ -6,6 +7,7 @@ pub struct InitTransferAuthorityIx {
     pub name: String,
     pub authority: Pubkey,
     pub allowed_marketplaces: Option<Vec<Pubkey>>,
+    pub issuer_share_bps: Option<u16>,
 }
 
 #[derive(Accounts)]
@@ -14,7 +16,7 @@ pub struct InitTransferAuthorityCtx<'info> {
     #[account(
         init,
         payer = payer,
-        space = TRANSFER_AUTHORITY_SIZE,
+        space = transfer_authority_size(ix.allowed_marketplaces.as_ref()),
         seeds = [TRANSFER_AUTHORITY_SEED.as_bytes(), ix.name.as_bytes()], bump,
     )]
     transfer_authority: Box<Account<'info, TransferAuthority>>,
@@ -29,6 +31,10 @@ pub fn handler(ctx: Context<InitTransferAuthorityCtx>, ix: InitTransferAuthority
     transfer_authority.name = ix.name;
     transfer_authority.authority = ix.authority;
     transfer_authority.allowed_marketplaces = ix.allowed_marketplaces;
+    if ix.issuer_share_bps.is_some() && ix.issuer_share_bps.unwrap() as u64 > BPS_DENOMINATOR {
+        return Err(error!(ErrorCode::InvalidIssuerShare));
+    }
+    transfer_authority.issuer_share_bps = ix.issuer_share_bps;
 
     Ok(())
 }
@@ -1,9 +1,15 @@
+pub mod accept_transfer_authority_authority;
+pub mod close_transfer_authority;
 pub mod init_transfer_authority;
+pub mod propose_transfer_authority_authority;
 pub mod release;
 pub mod update_transfer_authority;
 pub mod whitelist_marketplaces;
 
+pub use accept_transfer_authority_authority::*;
+pub use close_transfer_authority::*;
 pub use init_transfer_authority::*;
+pub use propose_transfer_authority_authority::*;
 pub use release::*;
 pub use update_transfer_authority::*;
 pub use whitelist_marketplaces::*;
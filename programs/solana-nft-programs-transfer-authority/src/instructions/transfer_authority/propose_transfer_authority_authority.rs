@@ -0,0 +1,17 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeTransferAuthorityAuthorityCtx<'info> {
+    #[account(mut)]
+    transfer_authority: Box<Account<'info, TransferAuthority>>,
+    #[account(constraint = transfer_authority.authority == authority.key() @ ErrorCode::InvalidTransferAuthorityAuthority)]
+    authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ProposeTransferAuthorityAuthorityCtx>, new_authority: Pubkey) -> Result<()> {
+    let transfer_authority = &mut ctx.accounts.transfer_authority;
+    transfer_authority.pending_authority = Some(new_authority);
+    Ok(())
+}
@@ -6,6 +6,7 @@ use solana_nft_programs_token_manager::state::TokenManager;
 
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::assert_no_auction;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -14,6 +15,8 @@ pub struct ReleaseCtx<'info> {
 
     #[account(mut, constraint = token_manager.invalidators.contains(&transfer_authority.key()) @ ErrorCode::InvalidTokenManager)]
     token_manager: Box<Account<'info, TokenManager>>,
+    /// CHECK: This is not dangerous because it is checked to be the empty auction of the token manager in the handler
+    auction: UncheckedAccount<'info>,
     #[account(mut, constraint = mint.key() == token_manager.mint @ ErrorCode::InvalidMint)]
     mint: Box<Account<'info, Mint>>,
     /// CHECK: This is not dangerous because we don't read or write from this account
@@ -37,6 +40,7 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
     if ctx.accounts.token_manager.transfer_authority.is_none() || ctx.accounts.token_manager.transfer_authority.unwrap() != ctx.accounts.transfer_authority.key() {
         return Err(error!(ErrorCode::InvalidTransferAuthority));
     }
+    assert_no_auction(&ctx.accounts.auction, ctx.accounts.token_manager.key())?;
 
     let transfer_authority_seeds = &[
         TRANSFER_AUTHORITY_SEED.as_bytes(),
@@ -4,23 +4,32 @@ use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateTransferAuthorityIx {
-    pub authority: Pubkey,
     pub allowed_marketplaces: Option<Vec<Pubkey>>,
+    pub issuer_share_bps: Option<u16>,
 }
 
 #[derive(Accounts)]
 #[instruction(ix: UpdateTransferAuthorityIx)]
 pub struct UpdateTransferAuthorityCtx<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        realloc = transfer_authority_size(ix.allowed_marketplaces.as_ref()),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
     transfer_authority: Box<Account<'info, TransferAuthority>>,
     #[account(mut, constraint = transfer_authority.authority == authority.key() @ ErrorCode::InvalidTransferAuthorityAuthority)]
     authority: Signer<'info>,
+    system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<UpdateTransferAuthorityCtx>, ix: UpdateTransferAuthorityIx) -> Result<()> {
     let transfer_authority = &mut ctx.accounts.transfer_authority;
-    transfer_authority.authority = ix.authority;
     transfer_authority.allowed_marketplaces = ix.allowed_marketplaces;
+    if ix.issuer_share_bps.is_some() && ix.issuer_share_bps.unwrap() as u64 > BPS_DENOMINATOR {
+        return Err(error!(ErrorCode::InvalidIssuerShare));
+    }
+    transfer_authority.issuer_share_bps = ix.issuer_share_bps;
 
     Ok(())
 }
@@ -10,11 +10,17 @@ pub struct WhitelistMarketplacesIx {
 #[derive(Accounts)]
 #[instruction(ix: WhitelistMarketplacesIx)]
 pub struct WhitelistMarketplacesCtx<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        realloc = transfer_authority_size(Some(&ix.allowed_marketplaces)),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
     transfer_authority: Box<Account<'info, TransferAuthority>>,
 
     #[account(mut, constraint = authority.key() == transfer_authority.authority @ ErrorCode::InvalidTransferAuthority)]
     authority: Signer<'info>,
+    system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<WhitelistMarketplacesCtx>, ix: WhitelistMarketplacesIx) -> Result<()> {
@@ -1,6 +1,7 @@
 pub mod errors;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
 use instructions::*;
@@ -29,6 +30,18 @@ pub mod solana_nft_programs_transfer_authority {
         transfer_authority::release::handler(ctx)
     }
 
+    pub fn propose_transfer_authority_authority(ctx: Context<ProposeTransferAuthorityAuthorityCtx>, new_authority: Pubkey) -> Result<()> {
+        transfer_authority::propose_transfer_authority_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_transfer_authority_authority(ctx: Context<AcceptTransferAuthorityAuthorityCtx>) -> Result<()> {
+        transfer_authority::accept_transfer_authority_authority::handler(ctx)
+    }
+
+    pub fn close_transfer_authority(ctx: Context<CloseTransferAuthorityCtx>) -> Result<()> {
+        transfer_authority::close_transfer_authority::handler(ctx)
+    }
+
     // listing
     pub fn create_listing(ctx: Context<CreateListingCtx>, ix: CreateListingIx) -> Result<()> {
         listing::create_listing::handler(ctx, ix)
@@ -46,6 +59,22 @@ pub mod solana_nft_programs_transfer_authority {
         listing::remove_listing::handler(ctx)
     }
 
+    pub fn close_stale_listing(ctx: Context<CloseStaleListingCtx>) -> Result<()> {
+        listing::close_stale_listing::handler(ctx)
+    }
+
+    pub fn create_listings<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, CreateListingsCtx<'info>>, ix: CreateListingsIx) -> Result<()> {
+        listing::create_listings::handler(ctx, ix)
+    }
+
+    pub fn update_listings<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, UpdateListingsCtx<'info>>, ix: UpdateListingsIx) -> Result<()> {
+        listing::update_listings::handler(ctx, ix)
+    }
+
+    pub fn remove_listings<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, RemoveListingsCtx<'info>>) -> Result<()> {
+        listing::remove_listings::handler(ctx)
+    }
+
     // marketplace
     pub fn init_marketplace(ctx: Context<InitMarketplaceCtx>, ix: InitMarketplaceIx) -> Result<()> {
         marketplace::init_marketplace::handler(ctx, ix)
@@ -55,6 +84,56 @@ pub mod solana_nft_programs_transfer_authority {
         marketplace::update_marketplace::handler(ctx, ix)
     }
 
+    pub fn propose_marketplace_authority(ctx: Context<ProposeMarketplaceAuthorityCtx>, new_authority: Pubkey) -> Result<()> {
+        marketplace::propose_marketplace_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_marketplace_authority(ctx: Context<AcceptMarketplaceAuthorityCtx>) -> Result<()> {
+        marketplace::accept_marketplace_authority::handler(ctx)
+    }
+
+    pub fn close_marketplace(ctx: Context<CloseMarketplaceCtx>) -> Result<()> {
+        marketplace::close_marketplace::handler(ctx)
+    }
+
+    // auction
+    pub fn create_auction(ctx: Context<CreateAuctionCtx>, ix: CreateAuctionIx) -> Result<()> {
+        auction::create_auction::handler(ctx, ix)
+    }
+
+    pub fn place_bid(ctx: Context<PlaceBidCtx>, amount: u64) -> Result<()> {
+        auction::place_bid::handler(ctx, amount)
+    }
+
+    pub fn claim_auction<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ClaimAuctionCtx<'info>>) -> Result<()> {
+        auction::claim_auction::handler(ctx)
+    }
+
+    pub fn cancel_auction(ctx: Context<CancelAuctionCtx>) -> Result<()> {
+        auction::cancel_auction::handler(ctx)
+    }
+
+    pub fn close_stale_auction(ctx: Context<CloseStaleAuctionCtx>) -> Result<()> {
+        auction::close_stale_auction::handler(ctx)
+    }
+
+    // offer
+    pub fn create_offer(ctx: Context<CreateOfferCtx>, ix: CreateOfferIx) -> Result<()> {
+        offer::create_offer::handler(ctx, ix)
+    }
+
+    pub fn accept_offer(ctx: Context<AcceptOfferCtx>) -> Result<()> {
+        offer::accept_offer::handler(ctx)
+    }
+
+    pub fn claim_offer<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ClaimOfferCtx<'info>>) -> Result<()> {
+        offer::claim_offer::handler(ctx)
+    }
+
+    pub fn cancel_offer(ctx: Context<CancelOfferCtx>) -> Result<()> {
+        offer::cancel_offer::handler(ctx)
+    }
+
     // transfer
     pub fn init_transfer(ctx: Context<InitTransferCtx>, ix: InitTransferIx) -> Result<()> {
         transfer::init_transfer::handler(ctx, ix)
@@ -67,4 +146,12 @@ pub mod solana_nft_programs_transfer_authority {
     pub fn accept_transfer<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, AcceptTransferCtx<'info>>) -> Result<()> {
         transfer::accept_transfer::handler(ctx)
     }
+
+    pub fn reject_transfer(ctx: Context<RejectTransferCtx>) -> Result<()> {
+        transfer::reject_transfer::handler(ctx)
+    }
+
+    pub fn close_expired_transfer(ctx: Context<CloseExpiredTransferCtx>) -> Result<()> {
+        transfer::close_expired_transfer::handler(ctx)
+    }
 }
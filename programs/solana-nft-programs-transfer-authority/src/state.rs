@@ -1,18 +1,33 @@
 use crate::errors::ErrorCode;
 use anchor_lang::prelude::*;
 
+// allowlists get room for this many entries up front and are reallocated when they grow beyond it
+pub const DEFAULT_ALLOWLIST_LENGTH: usize = 5;
+
 pub const TRANSFER_AUTHORITY_SEED: &str = "transfer-authority";
-pub const TRANSFER_AUTHORITY_SIZE: usize = 8 + 1 + 24 + 32 + 32 * 5 + 64;
+pub const TRANSFER_AUTHORITY_SIZE: usize = 8 + 1 + 24 + 32 + 32 * DEFAULT_ALLOWLIST_LENGTH + 3 + 33 + 64;
+pub fn transfer_authority_size(allowed_marketplaces: Option<&Vec<Pubkey>>) -> usize {
+    TRANSFER_AUTHORITY_SIZE + 32 * allowed_marketplaces.map_or(0, |m| m.len().saturating_sub(DEFAULT_ALLOWLIST_LENGTH))
+}
 #[account]
 pub struct TransferAuthority {
     pub bump: u8,
     pub name: String,
     pub authority: Pubkey,
     pub allowed_marketplaces: Option<Vec<Pubkey>>,
+    // share of each accepted listing paid to the token manager issuer or receipt holder
+    pub issuer_share_bps: Option<u16>,
+    // proposed authority that must accept before authority changes
+    pub pending_authority: Option<Pubkey>,
 }
 
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
 pub const MARKETPLACE_SEED: &str = "marketplace";
-pub const MARKETPLACE_SIZE: usize = 8 + 1 + 24 + 32 + 32 + 32 * 5 + 64;
+pub const MARKETPLACE_SIZE: usize = 8 + 1 + 24 + 32 + 32 + 32 * DEFAULT_ALLOWLIST_LENGTH + 33 + 3 + 3 + 33 + 8 + 64;
+pub fn marketplace_size(payment_mints: Option<&Vec<Pubkey>>) -> usize {
+    MARKETPLACE_SIZE + 32 * payment_mints.map_or(0, |m| m.len().saturating_sub(DEFAULT_ALLOWLIST_LENGTH))
+}
 #[account]
 pub struct Marketplace {
     pub bump: u8,
@@ -20,9 +35,17 @@ pub struct Marketplace {
     pub payment_manager: Pubkey,
     pub authority: Pubkey,
     pub payment_mints: Option<Vec<Pubkey>>,
+    // marketplace fees charged on accepted listings on top of any payment manager fees
+    pub fee_collector: Option<Pubkey>,
+    pub maker_fee_bps: Option<u16>,
+    pub taker_fee_bps: Option<u16>,
+    pub pending_authority: Option<Pubkey>,
+    // number of open listings, the marketplace can only be closed when there are none
+    pub listing_count: u64,
 }
 
 pub const LISTING_SEED: &str = "listing";
+pub const MAX_BULK_LISTINGS: usize = 8;
 pub const LISTING_SIZE: usize = 8 + std::mem::size_of::<Listing>() + 64;
 #[account]
 pub struct Listing {
@@ -33,6 +56,53 @@ pub struct Listing {
     // payment
     pub payment_amount: u64,
     pub payment_mint: Pubkey,
+    pub expires_at: Option<i64>,
+    // private listing that only this buyer can accept
+    pub allowed_buyer: Option<Pubkey>,
+    // whether this listing is included in the marketplace listing count
+    pub counted: bool,
+}
+
+pub const AUCTION_SEED: &str = "auction";
+pub const AUCTION_SIZE: usize = 8 + std::mem::size_of::<Auction>() + 64;
+#[account]
+pub struct Auction {
+    pub bump: u8,
+    pub lister: Pubkey,
+    pub lister_token_account: Pubkey,
+    pub token_manager: Pubkey,
+    pub marketplace: Pubkey,
+    // payment
+    pub payment_mint: Pubkey,
+    pub reserve_price: u64,
+    pub min_increment: u64,
+    pub end_time: i64,
+    // current top bid held in escrow
+    pub top_bidder: Option<Pubkey>,
+    pub top_bid: u64,
+    // top bid plus the taker fees at the time of the bid, whatever the fees do not use goes back to the winner
+    pub escrow_amount: u64,
+}
+
+pub const OFFER_SEED: &str = "offer";
+pub const OFFER_SIZE: usize = 8 + std::mem::size_of::<Offer>() + 64;
+#[account]
+pub struct Offer {
+    pub bump: u8,
+    pub buyer: Pubkey,
+    pub transfer_authority: Pubkey,
+    // none for a collection offer on any token manager under the transfer authority
+    pub token_manager: Option<Pubkey>,
+    pub marketplace: Pubkey,
+    // payment
+    pub payment_amount: u64,
+    pub payment_mint: Pubkey,
+    // payment amount plus the taker fees at creation, whatever the fees do not use goes back to the buyer
+    pub escrow_amount: u64,
+    // acceptance
+    pub accepted_by: Option<Pubkey>,
+    pub accepted_token_manager: Option<Pubkey>,
+    pub accepted_token_account: Option<Pubkey>,
 }
 
 pub const TRANSFER_SEED: &str = "transfer";
@@ -43,6 +113,7 @@ pub struct Transfer {
     pub token_manager: Pubkey,
     pub from: Pubkey,
     pub to: Pubkey,
+    pub expires_at: Option<i64>,
 }
 
 pub fn assert_derivation(program_id: &Pubkey, account: &AccountInfo, path: &[&[u8]]) -> Result<u8> {
@@ -0,0 +1,227 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::TokenAccount;
+use anchor_spl::token::{self};
+use solana_nft_programs_payment_manager::state::PaymentManager;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerKind;
+use solana_nft_programs_token_manager::utils::assert_payment_token_account;
+
+pub fn issuer_share_amount(transfer_authority: &TransferAuthority, payment_amount: u64) -> Result<u64> {
+    bps_amount(payment_amount, transfer_authority.issuer_share_bps)
+}
+
+// portion of the payment amount in basis points, payment amounts too large for the multiplication are rejected
+pub fn bps_amount(payment_amount: u64, bps: Option<u16>) -> Result<u64> {
+    payment_amount
+        .checked_mul(bps.unwrap_or(0) as u64)
+        .map(|amount| amount / BPS_DENOMINATOR)
+        .ok_or_else(|| error!(ErrorCode::PaymentAmountOverflow))
+}
+
+// issuer payment account, followed by the receipt token account when the token manager has a receipt mint
+pub fn issuer_share_accounts_length(token_manager: &TokenManager, issuer_share_amount: u64) -> usize {
+    if issuer_share_amount == 0 {
+        0
+    } else if token_manager.receipt_mint.is_some() {
+        2
+    } else {
+        1
+    }
+}
+
+// maker fee comes out of the lister proceeds and taker fee is charged to the payer on top of the payment amount
+pub fn marketplace_fee_amounts(marketplace: &Marketplace, payment_amount: u64) -> Result<(u64, u64)> {
+    Ok((bps_amount(payment_amount, marketplace.maker_fee_bps)?, bps_amount(payment_amount, marketplace.taker_fee_bps)?))
+}
+
+// taker fees of the marketplace and the payment manager, escrows hold them on top of the payment amount since the escrow itself pays them
+pub fn escrow_amount(marketplace: &Marketplace, payment_manager: &PaymentManager, payment_amount: u64) -> Result<u64> {
+    let (_, marketplace_taker_fee) = marketplace_fee_amounts(marketplace, payment_amount)?;
+    let payment_manager_taker_fee = bps_amount(payment_amount, Some(payment_manager.taker_fee_basis_points))?;
+    payment_amount
+        .checked_add(marketplace_taker_fee)
+        .and_then(|amount| amount.checked_add(payment_manager_taker_fee))
+        .ok_or_else(|| error!(ErrorCode::PaymentAmountOverflow))
+}
+
+// issuer share and maker fee both come out of the lister proceeds so together they cannot exceed the payment amount
+pub fn assert_lister_fee_bps(transfer_authority: &TransferAuthority, marketplace: &Marketplace) -> Result<()> {
+    if transfer_authority.issuer_share_bps.unwrap_or(0) as u64 + marketplace.maker_fee_bps.unwrap_or(0) as u64 > BPS_DENOMINATOR {
+        return Err(error!(ErrorCode::InvalidMarketplaceFee));
+    }
+    Ok(())
+}
+
+// pays marketplace fees from the payer to the marketplace fee collector, a wallet for native SOL or a token account for SPL payments
+#[allow(clippy::too_many_arguments)]
+pub fn pay_marketplace_fee<'info>(
+    marketplace: &Account<'info, Marketplace>,
+    payment_mint: Pubkey,
+    fee_collector_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    payer_payment_token_account: &AccountInfo<'info>,
+    payer_signer: &[&[&[u8]]],
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let fee_collector = marketplace.fee_collector.expect("No marketplace fee collector");
+    if payment_mint == Pubkey::default() {
+        if fee_collector_info.key() != fee_collector {
+            return Err(error!(ErrorCode::InvalidMarketplaceFeeCollector));
+        }
+        let cpi_accounts = system_program::Transfer {
+            from: payer.to_account_info(),
+            to: fee_collector_info.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_context, amount)?;
+    } else {
+        let fee_collector_token_account = Account::<TokenAccount>::try_from(fee_collector_info)?;
+        if fee_collector_token_account.mint != payment_mint || fee_collector_token_account.owner != fee_collector {
+            return Err(error!(ErrorCode::InvalidMarketplaceFeeCollector));
+        }
+        let cpi_accounts = token::Transfer {
+            from: payer_payment_token_account.to_account_info(),
+            to: fee_collector_info.to_account_info(),
+            authority: payer.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(token_program.to_account_info(), cpi_accounts).with_signer(payer_signer);
+        token::transfer(cpi_context, amount)?;
+    }
+    Ok(())
+}
+
+// listings created before the marketplace counted them were never counted and leave the count untouched
+pub fn decrement_listing_count(marketplace_info: &AccountInfo, listing: &Listing) -> Result<()> {
+    if !listing.counted || marketplace_info.data_is_empty() {
+        return Ok(());
+    }
+    let mut marketplace = Account::<Marketplace>::try_from(marketplace_info)?;
+    marketplace.listing_count = marketplace.listing_count.checked_sub(1).expect("Sub error");
+    marketplace.exit(&crate::id())
+}
+
+pub const PROGRAMMABLE_TRANSFER_ACCOUNTS_LENGTH: usize = 13;
+
+// mint manager for managed and permissioned, edition and metadata program for edition, and the token record and authorization rules set for programmable
+pub fn transfer_accounts_length(token_manager: &TokenManager) -> usize {
+    match token_manager.kind {
+        k if k == TokenManagerKind::Managed as u8 || k == TokenManagerKind::Permissioned as u8 => 1,
+        k if k == TokenManagerKind::Edition as u8 => 2,
+        k if k == TokenManagerKind::Programmable as u8 => PROGRAMMABLE_TRANSFER_ACCOUNTS_LENGTH,
+        _ => 0,
+    }
+}
+
+// pays the issuer share from the payer to the token manager issuer, or to the receipt holder when the token manager has a receipt mint
+// payer_signer holds the seeds of an escrow account paying spl tokens and is empty for a wallet payer
+#[allow(clippy::too_many_arguments)]
+pub fn pay_issuer_share<'info>(
+    token_manager: &Account<'info, TokenManager>,
+    payment_mint: Pubkey,
+    issuer_share_accounts: &[AccountInfo<'info>],
+    payer: &AccountInfo<'info>,
+    payer_payment_token_account: &AccountInfo<'info>,
+    payer_signer: &[&[&[u8]]],
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let issuer_share_accs = &mut issuer_share_accounts.iter();
+    let issuer_payment_account_info = next_account_info(issuer_share_accs)?;
+
+    if payment_mint == Pubkey::default() {
+        // native SOL goes directly to the issuer or receipt holder wallet
+        match token_manager.receipt_mint {
+            None => {
+                if issuer_payment_account_info.key() != token_manager.issuer {
+                    return Err(error!(ErrorCode::InvalidIssuerPaymentAccount));
+                }
+            }
+            Some(receipt_mint) => {
+                let receipt_token_account = Account::<TokenAccount>::try_from(next_account_info(issuer_share_accs)?)?;
+                if receipt_token_account.mint != receipt_mint || receipt_token_account.amount == 0 || receipt_token_account.owner != issuer_payment_account_info.key() {
+                    return Err(error!(ErrorCode::InvalidIssuerPaymentAccount));
+                }
+            }
+        }
+        let cpi_accounts = system_program::Transfer {
+            from: payer.to_account_info(),
+            to: issuer_payment_account_info.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_context, amount)?;
+    } else {
+        let issuer_payment_token_account = Account::<TokenAccount>::try_from(issuer_payment_account_info)?;
+        if issuer_payment_token_account.mint != payment_mint {
+            return Err(error!(ErrorCode::InvalidIssuerPaymentAccount));
+        }
+        assert_payment_token_account(&issuer_payment_token_account, token_manager, issuer_share_accs)?;
+
+        let cpi_accounts = token::Transfer {
+            from: payer_payment_token_account.to_account_info(),
+            to: issuer_payment_account_info.to_account_info(),
+            authority: payer.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(token_program.to_account_info(), cpi_accounts).with_signer(payer_signer);
+        token::transfer(cpi_context, amount)?;
+    }
+    Ok(())
+}
+
+// moves funds held in escrow by a program account, lamports for native SOL or tokens from an escrow token account it owns
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_from_escrow<'info>(
+    escrow: &AccountInfo<'info>,
+    escrow_signer: &[&[&[u8]]],
+    payment_mint: Pubkey,
+    escrow_payment_token_account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    recipient: Pubkey,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if payment_mint == Pubkey::default() {
+        if destination.key() != recipient {
+            return Err(error!(ErrorCode::InvalidRecipient));
+        }
+        **escrow.try_borrow_mut_lamports()? = escrow.lamports().checked_sub(amount).expect("Sub error");
+        **destination.try_borrow_mut_lamports()? = destination.lamports().checked_add(amount).expect("Add error");
+    } else {
+        let escrow_token_account = Account::<TokenAccount>::try_from(escrow_payment_token_account)?;
+        if escrow_token_account.owner != escrow.key() || escrow_token_account.mint != payment_mint {
+            return Err(error!(ErrorCode::InvalidEscrowTokenAccount));
+        }
+        let destination_token_account = Account::<TokenAccount>::try_from(destination)?;
+        if destination_token_account.owner != recipient || destination_token_account.mint != payment_mint {
+            return Err(error!(ErrorCode::InvalidEscrowTokenAccount));
+        }
+        let cpi_accounts = token::Transfer {
+            from: escrow_payment_token_account.to_account_info(),
+            to: destination.to_account_info(),
+            authority: escrow.to_account_info(),
+        };
+        let cpi_context = CpiContext::new(token_program.to_account_info(), cpi_accounts).with_signer(escrow_signer);
+        token::transfer(cpi_context, amount)?;
+    }
+    Ok(())
+}
+
+// tokens with an open auction can only leave the lister through claim_auction
+pub fn assert_no_auction(auction_info: &AccountInfo, token_manager: Pubkey) -> Result<()> {
+    assert_derivation(&crate::id(), auction_info, &[AUCTION_SEED.as_bytes(), token_manager.as_ref()])?;
+    if !auction_info.data_is_empty() {
+        return Err(error!(ErrorCode::AuctionInProgress));
+    }
+    Ok(())
+}
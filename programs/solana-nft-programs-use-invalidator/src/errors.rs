@@ -34,4 +34,34 @@ pub enum ErrorCode {
     InvalidMint,
     #[msg("Invalid issuer")]
     InvalidIssuer,
+    #[msg("Fee per use requires an extension payment mint")]
+    InvalidFeePerUse,
+    #[msg("Usage limit for the current period exceeded")]
+    UsageRateLimitExceeded,
+    #[msg("Invalid usage period")]
+    InvalidUsagePeriod,
+    #[msg("Refill period has not elapsed")]
+    RefillNotAvailable,
+    #[msg("Invalid refill schedule")]
+    InvalidRefillSchedule,
+    #[msg("Max use authorities reached")]
+    MaxUseAuthoritiesReached,
+    #[msg("Use authority already added")]
+    UseAuthorityAlreadyAdded,
+    #[msg("Invalid use authority")]
+    InvalidUseAuthority,
+    #[msg("Invalid usage attestation")]
+    InvalidUsageAttestation,
+    #[msg("Usage attestation nonce must be increasing")]
+    InvalidUsageNonce,
+    #[msg("Insufficient balance to pay for usages")]
+    InsufficientPaymentBalance,
+    #[msg("Usage attestations are not supported for pay per use")]
+    PayPerUseAttestationsDisallowed,
+    #[msg("Invalid payer")]
+    InvalidPayer,
+    #[msg("Missing accounts to pay the fee per use")]
+    MissingPaymentAccounts,
+    #[msg("Token manager is under dispute")]
+    TokenManagerDisputed,
 }
@@ -0,0 +1,40 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+
+#[derive(Accounts)]
+pub struct AddUseAuthorityCtx<'info> {
+    #[account(constraint = token_manager.state != TokenManagerState::Invalidated as u8 @ ErrorCode::InvalidTokenManager)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(
+        mut,
+        constraint = use_invalidator.token_manager == token_manager.key() @ ErrorCode::InvalidUseInvalidator,
+        realloc = use_invalidator_size(use_invalidator.usage_nonces.len() + 1),
+        realloc::payer = issuer,
+        realloc::zero = false,
+    )]
+    use_invalidator: Box<Account<'info, UseInvalidator>>,
+
+    #[account(mut, constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddUseAuthorityCtx>, use_authority: Pubkey) -> Result<()> {
+    let use_invalidator = &mut ctx.accounts.use_invalidator;
+    if use_invalidator.use_authorities.contains(&use_authority) {
+        return Err(error!(ErrorCode::UseAuthorityAlreadyAdded));
+    }
+    if use_invalidator.use_authorities.len() >= MAX_USE_AUTHORITIES {
+        return Err(error!(ErrorCode::MaxUseAuthoritiesReached));
+    }
+    use_invalidator.use_authorities.push(use_authority);
+    // a re-added authority keeps its last nonce so its old attestations cannot be replayed
+    if !use_invalidator.usage_nonces.iter().any(|n| n.authority == use_authority) {
+        use_invalidator.usage_nonces.push(UsageNonce { authority: use_authority, nonce: 0 });
+    }
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::record_usages;
+use anchor_lang::prelude::*;
+use arrayref::array_ref;
+use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::state::TokenManagerState;
+use solana_program::ed25519_program;
+use solana_program::sysvar::instructions::load_current_index_checked;
+use solana_program::sysvar::instructions::load_instruction_at_checked;
+use solana_program::sysvar::{self};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UsageAttestation {
+    pub token_manager: Pubkey,
+    pub use_invalidator: Pubkey,
+    // count of the token manager so attestations cannot be replayed after a reissue
+    pub mint_count: u64,
+    pub num_usages: u64,
+    pub nonce: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ApplyUsageAttestationsIx {
+    pub attestations: Vec<UsageAttestation>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyUsageAttestationsCtx<'info> {
+    #[account(constraint = token_manager.state == TokenManagerState::Claimed as u8 @ ErrorCode::InvalidTokenManager, constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed)]
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, constraint = use_invalidator.token_manager == token_manager.key() @ ErrorCode::InvalidUseInvalidator)]
+    use_invalidator: Box<Account<'info, UseInvalidator>>,
+
+    /// CHECK: This is not dangerous because the ID is checked with instructions sysvar
+    #[account(address = sysvar::instructions::id())]
+    instructions: UncheckedAccount<'info>,
+}
+
+// ed25519 program instruction layout, see solana_sdk::ed25519_instruction
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+const PUBKEY_SERIALIZED_SIZE: usize = 32;
+
+// collect (signer, message) pairs from every ed25519 verification preceding this instruction
+fn verified_messages(instructions_info: &AccountInfo) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+    let current_ix = load_current_index_checked(instructions_info)?;
+    let mut verified = Vec::new();
+    for i in 0..current_ix {
+        let ix = load_instruction_at_checked(i as usize, instructions_info)?;
+        if ix.program_id != ed25519_program::id() || ix.data.len() < SIGNATURE_OFFSETS_START {
+            continue;
+        }
+        let num_signatures = ix.data[0] as usize;
+        for j in 0..num_signatures {
+            let start = SIGNATURE_OFFSETS_START + j * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+            if ix.data.len() < start + SIGNATURE_OFFSETS_SERIALIZED_SIZE {
+                return Err(error!(ErrorCode::InvalidUsageAttestation));
+            }
+            let offsets = array_ref![ix.data, start, SIGNATURE_OFFSETS_SERIALIZED_SIZE];
+            let public_key_offset = u16::from_le_bytes(*array_ref![offsets, 4, 2]) as usize;
+            let public_key_instruction_index = u16::from_le_bytes(*array_ref![offsets, 6, 2]);
+            let message_data_offset = u16::from_le_bytes(*array_ref![offsets, 8, 2]) as usize;
+            let message_data_size = u16::from_le_bytes(*array_ref![offsets, 10, 2]) as usize;
+            let message_instruction_index = u16::from_le_bytes(*array_ref![offsets, 12, 2]);
+
+            // only accept verifications whose data lives in the ed25519 instruction itself
+            if public_key_instruction_index != u16::MAX || message_instruction_index != u16::MAX {
+                return Err(error!(ErrorCode::InvalidUsageAttestation));
+            }
+            if ix.data.len() < public_key_offset + PUBKEY_SERIALIZED_SIZE || ix.data.len() < message_data_offset + message_data_size {
+                return Err(error!(ErrorCode::InvalidUsageAttestation));
+            }
+            let public_key = Pubkey::new_from_array(*array_ref![ix.data, public_key_offset, PUBKEY_SERIALIZED_SIZE]);
+            let message = ix.data[message_data_offset..message_data_offset + message_data_size].to_vec();
+            verified.push((public_key, message));
+        }
+    }
+    Ok(verified)
+}
+
+fn is_use_authority(use_invalidator: &UseInvalidator, key: &Pubkey) -> bool {
+    (use_invalidator.use_authority.is_some() && *key == use_invalidator.use_authority.unwrap()) || use_invalidator.use_authorities.contains(key)
+}
+
+pub fn handler(ctx: Context<ApplyUsageAttestationsCtx>, ix: ApplyUsageAttestationsIx) -> Result<()> {
+    if ctx.accounts.use_invalidator.fee_per_use.is_some() {
+        return Err(error!(ErrorCode::PayPerUseAttestationsDisallowed));
+    }
+    let verified = verified_messages(&ctx.accounts.instructions.to_account_info())?;
+    let use_invalidator = &mut ctx.accounts.use_invalidator;
+
+    let mut num_usages: u64 = 0;
+    for attestation in ix.attestations {
+        if attestation.token_manager != ctx.accounts.token_manager.key() || attestation.use_invalidator != use_invalidator.key() || attestation.mint_count != ctx.accounts.token_manager.count {
+            return Err(error!(ErrorCode::InvalidUsageAttestation));
+        }
+        let message = attestation.try_to_vec()?;
+        let authority = match verified
+            .iter()
+            .find(|(signer, verified_message)| *verified_message == message && is_use_authority(use_invalidator, signer))
+        {
+            Some((signer, _)) => *signer,
+            None => return Err(error!(ErrorCode::InvalidUsageAttestation)),
+        };
+
+        // nonces are tracked per use authority so authorities do not need to coordinate
+        let usage_nonce = use_invalidator
+            .usage_nonces
+            .iter_mut()
+            .find(|n| n.authority == authority)
+            .ok_or_else(|| error!(ErrorCode::InvalidUsageAttestation))?;
+        if attestation.nonce <= usage_nonce.nonce {
+            return Err(error!(ErrorCode::InvalidUsageNonce));
+        }
+        usage_nonce.nonce = attestation.nonce;
+        num_usages = num_usages.checked_add(attestation.num_usages).expect("Add error");
+    }
+
+    record_usages(use_invalidator, ctx.accounts.token_manager.state_changed_at, num_usages, Clock::get().unwrap().unix_timestamp)
+}
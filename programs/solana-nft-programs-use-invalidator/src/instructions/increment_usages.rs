@@ -1,30 +1,117 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::record_usages;
 use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use anchor_spl::token::Token;
 use anchor_spl::token::TokenAccount;
+use anchor_spl::token::Transfer;
+use anchor_spl::token::{self};
+use solana_nft_programs_payment_manager::program::SolanaNftProgramsPaymentManager;
 use solana_nft_programs_token_manager::state::TokenManager;
+use solana_nft_programs_token_manager::utils::assert_payment_token_account;
 
 #[derive(Accounts)]
 #[instruction(num_usages: u64)]
 pub struct IncrementUsagesCtx<'info> {
-    #[account(constraint = token_manager.key() == use_invalidator.token_manager @ ErrorCode::InvalidUseInvalidator)]
+    #[account(constraint = token_manager.key() == use_invalidator.token_manager @ ErrorCode::InvalidUseInvalidator, constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed)]
     token_manager: Box<Account<'info, TokenManager>>,
 
-    #[account(mut, constraint = use_invalidator.total_usages.is_none() || use_invalidator.usages.checked_add(num_usages).expect("Add error") <= use_invalidator.total_usages.expect("No usage limit") @ ErrorCode::InsufficientUsages)]
+    #[account(mut)]
     use_invalidator: Box<Account<'info, UseInvalidator>>,
 
     #[account(constraint = token_manager.recipient_token_account == recipient_token_account.key() @ ErrorCode::InvalidTokenAccount)]
     recipient_token_account: Box<Account<'info, TokenAccount>>,
     #[account(
         constraint = (use_invalidator.use_authority.is_some() && user.key() == use_invalidator.use_authority.unwrap())
-        || (use_invalidator.use_authority.is_none() && user.key() == recipient_token_account.owner)
+        || use_invalidator.use_authorities.contains(&user.key())
+        || (use_invalidator.use_authority.is_none() && use_invalidator.use_authorities.is_empty() && user.key() == recipient_token_account.owner)
         @ ErrorCode::InvalidUser
     )]
     user: Signer<'info>,
+
+    // accounts below are only required to pay the fee per use, any signer can pay from their own token account
+    #[account(mut)]
+    payer: Option<Signer<'info>>,
+    #[account(mut)]
+    payer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut, constraint = payment_manager.key() == use_invalidator.payment_manager @ ErrorCode::InvalidPaymentManager)]
+    payment_manager: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    payment_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    #[account(mut)]
+    fee_collector_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    token_program: Option<Program<'info, Token>>,
+    solana_nft_programs_payment_manager: Option<Program<'info, SolanaNftProgramsPaymentManager>>,
 }
 
-pub fn handler(ctx: Context<IncrementUsagesCtx>, num_usages: u64) -> Result<()> {
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, IncrementUsagesCtx<'info>>, num_usages: u64) -> Result<()> {
     let use_invalidator = &mut ctx.accounts.use_invalidator;
-    use_invalidator.usages = use_invalidator.usages.checked_add(num_usages).expect("Add error");
+    record_usages(use_invalidator, ctx.accounts.token_manager.state_changed_at, num_usages, Clock::get().unwrap().unix_timestamp)?;
+
+    // pay per use
+    if let Some(fee_per_use) = use_invalidator.fee_per_use {
+        let price_to_pay = fee_per_use.checked_mul(num_usages).expect("Multiplication error");
+        let payment_mint_key = use_invalidator.extension_payment_mint.expect("No payment mint");
+        let payer = ctx.accounts.payer.as_ref().ok_or(error!(ErrorCode::MissingPaymentAccounts))?;
+        let payer_token_account = ctx.accounts.payer_token_account.as_ref().ok_or(error!(ErrorCode::MissingPaymentAccounts))?;
+        let payment_manager = ctx.accounts.payment_manager.as_ref().ok_or(error!(ErrorCode::MissingPaymentAccounts))?;
+        let payment_token_account = ctx.accounts.payment_token_account.as_ref().ok_or(error!(ErrorCode::MissingPaymentAccounts))?;
+        let fee_collector_token_account = ctx.accounts.fee_collector_token_account.as_ref().ok_or(error!(ErrorCode::MissingPaymentAccounts))?;
+        let token_program = ctx.accounts.token_program.as_ref().ok_or(error!(ErrorCode::MissingPaymentAccounts))?;
+        let payment_manager_program = ctx.accounts.solana_nft_programs_payment_manager.as_ref().ok_or(error!(ErrorCode::MissingPaymentAccounts))?;
+        if payment_token_account.mint != payment_mint_key {
+            return Err(error!(ErrorCode::InvalidPaymentTokenAccount));
+        }
+        if fee_collector_token_account.mint != payment_mint_key {
+            return Err(error!(ErrorCode::InvalidPaymentMint));
+        }
+        if payer_token_account.owner != payer.key() || payer_token_account.mint != payment_mint_key {
+            return Err(error!(ErrorCode::InvalidPayerTokenAccount));
+        }
+        if payer_token_account.amount < price_to_pay {
+            return Err(error!(ErrorCode::InsufficientPaymentBalance));
+        }
+        let remaining_accs = &mut ctx.remaining_accounts.iter();
+        assert_payment_token_account(payment_token_account, &ctx.accounts.token_manager, remaining_accs)?;
+
+        if payment_manager.owner.key() == payment_manager_program.key() {
+            let payment_mint_info = next_account_info(remaining_accs)?;
+            let payment_mint = Account::<Mint>::try_from(payment_mint_info)?;
+            if payment_mint_key != payment_mint.key() {
+                return Err(error!(ErrorCode::InvalidPaymentMint));
+            }
+
+            let mint_info = next_account_info(remaining_accs)?;
+            let mint = Account::<Mint>::try_from(mint_info)?;
+            if ctx.accounts.token_manager.mint != mint.key() {
+                return Err(error!(ErrorCode::InvalidMint));
+            }
+            let mint_metadata_info = next_account_info(remaining_accs)?;
+
+            let cpi_accounts = solana_nft_programs_payment_manager::cpi::accounts::HandlePaymentWithRoyaltiesCtx {
+                payment_manager: payment_manager.to_account_info(),
+                payer_token_account: payer_token_account.to_account_info(),
+                fee_collector_token_account: fee_collector_token_account.to_account_info(),
+                payment_token_account: payment_token_account.to_account_info(),
+                payment_mint: payment_mint.to_account_info(),
+                mint: mint.to_account_info(),
+                mint_metadata: mint_metadata_info.to_account_info(),
+                payer: payer.to_account_info(),
+                token_program: token_program.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(payment_manager_program.to_account_info(), cpi_accounts).with_remaining_accounts(remaining_accs.cloned().collect::<Vec<AccountInfo<'info>>>());
+            solana_nft_programs_payment_manager::cpi::handle_payment_with_royalties(cpi_ctx, price_to_pay)?;
+        } else {
+            let cpi_accounts = Transfer {
+                from: payer_token_account.to_account_info(),
+                to: payment_token_account.to_account_info(),
+                authority: payer.to_account_info(),
+            };
+            let cpi_context = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+            token::transfer(cpi_context, price_to_pay)?;
+        }
+    }
     Ok(())
 }
@@ -14,6 +14,13 @@ pub struct InitIx {
     pub extension_payment_amount: Option<u64>,
     pub extension_payment_mint: Option<Pubkey>,
     pub extension_usages: Option<u64>,
+    pub max_usages_per_period: Option<u64>,
+    pub usage_period_seconds: Option<i64>,
+    pub refill_period_seconds: Option<i64>,
+    pub refill_usages: Option<u64>,
+    pub refill_reset: Option<bool>,
+    pub refill_payment_amount: Option<u64>,
+    pub fee_per_use: Option<u64>,
 }
 
 #[derive(Accounts)]
@@ -37,6 +44,22 @@ pub struct InitCtx<'info> {
 }
 
 pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
+    if ix.max_usages_per_period.is_some() != ix.usage_period_seconds.is_some() {
+        return Err(error!(ErrorCode::InvalidUsagePeriod));
+    } else if ix.usage_period_seconds.is_some() && ix.usage_period_seconds.unwrap() <= 0 {
+        return Err(error!(ErrorCode::InvalidUsagePeriod));
+    } else if ix.refill_period_seconds.is_some() != ix.refill_usages.is_some() {
+        return Err(error!(ErrorCode::InvalidRefillSchedule));
+    } else if ix.refill_period_seconds.is_some() && (ix.refill_period_seconds.unwrap() <= 0 || ix.total_usages.is_none()) {
+        return Err(error!(ErrorCode::InvalidRefillSchedule));
+    } else if ix.refill_payment_amount.is_some() && (ix.refill_period_seconds.is_none() || ix.extension_payment_mint.is_none()) {
+        return Err(error!(ErrorCode::InvalidRefillSchedule));
+    } else if ix.refill_period_seconds.is_some() && ix.refill_payment_amount.is_none() && ix.max_usages.is_none() {
+        // unpaid refills never lapse, so max usages is their only invalidation path
+        return Err(error!(ErrorCode::InvalidRefillSchedule));
+    } else if ix.fee_per_use.is_some() && ix.extension_payment_mint.is_none() {
+        return Err(error!(ErrorCode::InvalidFeePerUse));
+    }
     let use_invalidator = &mut ctx.accounts.use_invalidator;
     use_invalidator.bump = *ctx.bumps.get("use_invalidator").unwrap();
     use_invalidator.token_manager = ctx.accounts.token_manager.key();
@@ -49,5 +72,20 @@ pub fn handler(ctx: Context<InitCtx>, ix: InitIx) -> Result<()> {
     use_invalidator.extension_payment_amount = ix.extension_payment_amount;
     use_invalidator.extension_payment_mint = ix.extension_payment_mint;
     use_invalidator.extension_usages = ix.extension_usages;
+    use_invalidator.max_usages_per_period = ix.max_usages_per_period;
+    use_invalidator.usage_period_seconds = ix.usage_period_seconds;
+    use_invalidator.period_started_at = None;
+    use_invalidator.period_usages = None;
+    use_invalidator.refill_period_seconds = ix.refill_period_seconds;
+    use_invalidator.refill_usages = ix.refill_usages;
+    use_invalidator.refill_reset = ix.refill_reset;
+    use_invalidator.refill_payment_amount = ix.refill_payment_amount;
+    use_invalidator.last_refilled_at = None;
+    use_invalidator.use_authorities = Vec::new();
+    use_invalidator.usage_nonces = match ix.use_authority {
+        Some(use_authority) => vec![UsageNonce { authority: use_authority, nonce: 0 }],
+        None => Vec::new(),
+    };
+    use_invalidator.fee_per_use = ix.fee_per_use;
     Ok(())
 }
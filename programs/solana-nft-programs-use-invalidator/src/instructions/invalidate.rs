@@ -1,15 +1,16 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::usages_exhausted;
 use anchor_lang::prelude::*;
 use solana_nft_programs_token_manager::program::SolanaNftProgramsTokenManager;
 use solana_nft_programs_token_manager::state::TokenManager;
 
 #[derive(Accounts)]
 pub struct InvalidateCtx<'info> {
-    #[account(mut)]
+    #[account(mut, constraint = token_manager.disputed != Some(true) @ ErrorCode::TokenManagerDisputed)]
     token_manager: Box<Account<'info, TokenManager>>,
 
-    #[account(mut, constraint = use_invalidator.usages >= use_invalidator.total_usages.expect("No usage limit") @ ErrorCode::InsufficientUsages)]
+    #[account(mut, constraint = usages_exhausted(&use_invalidator, token_manager.state_changed_at, Clock::get().unwrap().unix_timestamp) @ ErrorCode::InsufficientUsages)]
     use_invalidator: Box<Account<'info, UseInvalidator>>,
 
     /// CHECK: This is not dangerous because we don't read or write from this account
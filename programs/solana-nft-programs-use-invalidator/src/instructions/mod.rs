@@ -1,11 +1,19 @@
+pub mod add_use_authority;
+pub mod apply_usage_attestations;
 pub mod close;
 pub mod extend_usages;
 pub mod increment_usages;
 pub mod init;
 pub mod invalidate;
+pub mod refill_usages;
+pub mod remove_use_authority;
 
+pub use add_use_authority::*;
+pub use apply_usage_attestations::*;
 pub use close::*;
 pub use extend_usages::*;
 pub use increment_usages::*;
 pub use init::*;
 pub use invalidate::*;
+pub use refill_usages::*;
+pub use remove_use_authority::*;
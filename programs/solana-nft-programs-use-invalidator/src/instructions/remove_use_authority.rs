@@ -0,0 +1,25 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_nft_programs_token_manager::state::TokenManager;
+
+#[derive(Accounts)]
+pub struct RemoveUseAuthorityCtx<'info> {
+    token_manager: Box<Account<'info, TokenManager>>,
+
+    #[account(mut, constraint = use_invalidator.token_manager == token_manager.key() @ ErrorCode::InvalidUseInvalidator)]
+    use_invalidator: Box<Account<'info, UseInvalidator>>,
+
+    #[account(constraint = issuer.key() == token_manager.issuer @ ErrorCode::InvalidIssuer)]
+    issuer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveUseAuthorityCtx>, use_authority: Pubkey) -> Result<()> {
+    let use_invalidator = &mut ctx.accounts.use_invalidator;
+    let index = use_invalidator.use_authorities.iter().position(|k| *k == use_authority);
+    if index.is_none() {
+        return Err(error!(ErrorCode::InvalidUseAuthority));
+    }
+    use_invalidator.use_authorities.remove(index.unwrap());
+    Ok(())
+}
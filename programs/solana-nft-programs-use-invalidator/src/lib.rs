@@ -1,6 +1,7 @@
 pub mod errors;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
 use instructions::*;
@@ -15,14 +16,30 @@ pub mod solana_nft_programs_use_invalidator {
         init::handler(ctx, ix)
     }
 
-    pub fn increment_usages(ctx: Context<IncrementUsagesCtx>, num_usages: u64) -> Result<()> {
+    pub fn increment_usages<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, IncrementUsagesCtx<'info>>, num_usages: u64) -> Result<()> {
         increment_usages::handler(ctx, num_usages)
     }
 
+    pub fn apply_usage_attestations(ctx: Context<ApplyUsageAttestationsCtx>, ix: ApplyUsageAttestationsIx) -> Result<()> {
+        apply_usage_attestations::handler(ctx, ix)
+    }
+
     pub fn extend_usages<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ExtendUsagesCtx<'info>>, payment_amount: u64) -> Result<()> {
         extend_usages::handler(ctx, payment_amount)
     }
 
+    pub fn refill_usages<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, RefillUsagesCtx<'info>>) -> Result<()> {
+        refill_usages::handler(ctx)
+    }
+
+    pub fn add_use_authority(ctx: Context<AddUseAuthorityCtx>, use_authority: Pubkey) -> Result<()> {
+        add_use_authority::handler(ctx, use_authority)
+    }
+
+    pub fn remove_use_authority(ctx: Context<RemoveUseAuthorityCtx>, use_authority: Pubkey) -> Result<()> {
+        remove_use_authority::handler(ctx, use_authority)
+    }
+
     pub fn invalidate<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, InvalidateCtx<'info>>) -> Result<()> {
         invalidate::handler(ctx)
     }
@@ -1,7 +1,21 @@
 use anchor_lang::prelude::*;
 
 pub const USE_INVALIDATOR_SEED: &str = "use-invalidator";
-pub const USE_INVALIDATOR_SIZE: usize = 8 + std::mem::size_of::<UseInvalidator>() + 8;
+pub const MAX_USE_AUTHORITIES: usize = 5;
+pub const MAX_USAGE_NONCES: usize = MAX_USE_AUTHORITIES + 1;
+pub const USE_INVALIDATOR_SIZE: usize = 8 + std::mem::size_of::<UseInvalidator>() + MAX_USE_AUTHORITIES * 32 + MAX_USAGE_NONCES * std::mem::size_of::<UsageNonce>() + 8;
+
+// usage nonces are never evicted so the use invalidator grows with every new use authority
+pub fn use_invalidator_size(num_usage_nonces: usize) -> usize {
+    USE_INVALIDATOR_SIZE + num_usage_nonces.saturating_sub(MAX_USAGE_NONCES) * std::mem::size_of::<UsageNonce>()
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UsageNonce {
+    pub authority: Pubkey,
+    pub nonce: u64,
+}
+
 #[account]
 pub struct UseInvalidator {
     pub bump: u8,
@@ -15,4 +29,21 @@ pub struct UseInvalidator {
     pub extension_payment_mint: Option<Pubkey>,
     pub extension_usages: Option<u64>,
     pub max_usages: Option<u64>,
+    // rate limiting
+    pub max_usages_per_period: Option<u64>,
+    pub usage_period_seconds: Option<i64>,
+    pub period_started_at: Option<i64>,
+    pub period_usages: Option<u64>,
+    // refills
+    pub refill_period_seconds: Option<i64>,
+    pub refill_usages: Option<u64>,
+    pub refill_reset: Option<bool>,
+    pub refill_payment_amount: Option<u64>,
+    pub last_refilled_at: Option<i64>,
+    // additional authorities allowed to increment usages
+    pub use_authorities: Vec<Pubkey>,
+    // last nonce applied from signed usage attestations of every use authority ever added
+    pub usage_nonces: Vec<UsageNonce>,
+    // optional fee charged in the extension payment mint on every increment
+    pub fee_per_use: Option<u64>,
 }
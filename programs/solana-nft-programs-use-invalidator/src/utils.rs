@@ -0,0 +1,86 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use std::cmp::max;
+use std::cmp::min;
+
+// apply refills for every elapsed period since the last refill, returning the number of periods applied
+// paid refills only grant the current period so missed periods cannot be bought afterwards
+pub fn apply_refills(use_invalidator: &mut UseInvalidator, period_start: i64, now: i64, paid: bool) -> u64 {
+    let (refill_period_seconds, refill_usages, total_usages) = match (use_invalidator.refill_period_seconds, use_invalidator.refill_usages, use_invalidator.total_usages) {
+        (Some(refill_period_seconds), Some(refill_usages), Some(total_usages)) => (refill_period_seconds, refill_usages, total_usages),
+        _ => return 0,
+    };
+    let last_refilled_at = use_invalidator.last_refilled_at.unwrap_or(period_start);
+    if now < last_refilled_at {
+        return 0;
+    }
+    let periods = (now.checked_sub(last_refilled_at).expect("Sub error") / refill_period_seconds) as u64;
+    if periods == 0 {
+        return 0;
+    }
+    let granted_periods = if paid { 1 } else { periods };
+
+    let mut new_total_usages = if use_invalidator.refill_reset.unwrap_or(false) {
+        // reset available usages without clawing back any paid extensions
+        max(total_usages, use_invalidator.usages.checked_add(refill_usages).expect("Add error"))
+    } else {
+        total_usages.checked_add(refill_usages.checked_mul(granted_periods).expect("Multiplication error")).expect("Add error")
+    };
+    if let Some(max_usages) = use_invalidator.max_usages {
+        new_total_usages = min(new_total_usages, max_usages);
+    }
+    use_invalidator.total_usages = Some(new_total_usages);
+    use_invalidator.last_refilled_at = Some(
+        last_refilled_at
+            .checked_add(refill_period_seconds.checked_mul(periods as i64).expect("Multiplication error"))
+            .expect("Add error"),
+    );
+    granted_periods
+}
+
+// whether the use invalidator has run out of usages and can invalidate its token manager
+pub fn usages_exhausted(use_invalidator: &UseInvalidator, period_start: i64, now: i64) -> bool {
+    if use_invalidator.max_usages.is_some() && use_invalidator.usages >= use_invalidator.max_usages.unwrap() {
+        return true;
+    }
+    let total_usages_reached = use_invalidator.total_usages.is_some() && use_invalidator.usages >= use_invalidator.total_usages.unwrap();
+    match (use_invalidator.refill_period_seconds, use_invalidator.refill_payment_amount) {
+        (None, _) => total_usages_reached,
+        // a paid subscription lapses once a refill is due and has not been paid for
+        (Some(refill_period_seconds), Some(_)) => {
+            let last_refilled_at = use_invalidator.last_refilled_at.unwrap_or(period_start);
+            total_usages_reached && now >= last_refilled_at.checked_add(refill_period_seconds).expect("Add error")
+        }
+        // unpaid refills rely on max usages
+        (Some(_), None) => false,
+    }
+}
+
+// apply unpaid refills, check usage and rate limits and record the given usages
+pub fn record_usages(use_invalidator: &mut UseInvalidator, period_start: i64, num_usages: u64, now: i64) -> Result<()> {
+    // unpaid refills are applied lazily before checking available usages
+    if use_invalidator.refill_payment_amount.is_none() {
+        apply_refills(use_invalidator, period_start, now, false);
+    }
+    if use_invalidator.total_usages.is_some() && use_invalidator.usages.checked_add(num_usages).expect("Add error") > use_invalidator.total_usages.unwrap() {
+        return Err(error!(ErrorCode::InsufficientUsages));
+    }
+
+    if let (Some(max_usages_per_period), Some(usage_period_seconds)) = (use_invalidator.max_usages_per_period, use_invalidator.usage_period_seconds) {
+        // fixed window that starts with the first usage after the previous window elapsed
+        let mut period_usages = use_invalidator.period_usages.unwrap_or(0);
+        if use_invalidator.period_started_at.is_none() || now >= use_invalidator.period_started_at.unwrap().checked_add(usage_period_seconds).expect("Add error") {
+            use_invalidator.period_started_at = Some(now);
+            period_usages = 0;
+        }
+        period_usages = period_usages.checked_add(num_usages).expect("Add error");
+        if period_usages > max_usages_per_period {
+            return Err(error!(ErrorCode::UsageRateLimitExceeded));
+        }
+        use_invalidator.period_usages = Some(period_usages);
+    }
+
+    use_invalidator.usages = use_invalidator.usages.checked_add(num_usages).expect("Add error");
+    Ok(())
+}
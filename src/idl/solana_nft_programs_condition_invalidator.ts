@@ -0,0 +1,755 @@
+export type SolanaNftProgramsConditionInvalidator = {
+  version: "1.0.0";
+  name: "solana_nft_programs_condition_invalidator";
+  instructions: [
+    {
+      name: "init";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "conditionInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "incrementUsages";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "conditionInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "numUsages";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "invalidate";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "conditionInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "invalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManagerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "close";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "conditionInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "closer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "conditionInvalidator";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "collector";
+            type: "publicKey";
+          },
+          {
+            name: "usages";
+            type: "u64";
+          },
+          {
+            name: "useAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "conditions";
+            type: {
+              vec: {
+                defined: "Condition";
+              };
+            };
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "InitIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "collector";
+            type: "publicKey";
+          },
+          {
+            name: "useAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "conditions";
+            type: {
+              vec: {
+                defined: "Condition";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "Condition";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "TimeAfter";
+            fields: [
+              {
+                name: "timestamp";
+                type: "i64";
+              }
+            ];
+          },
+          {
+            name: "DurationElapsed";
+            fields: [
+              {
+                name: "seconds";
+                type: "i64";
+              }
+            ];
+          },
+          {
+            name: "UsagesReached";
+            fields: [
+              {
+                name: "usages";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "TokenBalanceBelow";
+            fields: [
+              {
+                name: "token_account";
+                type: "publicKey";
+              },
+              {
+                name: "amount";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "TokenBalanceAtLeast";
+            fields: [
+              {
+                name: "token_account";
+                type: "publicKey";
+              },
+              {
+                name: "amount";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "AccountExists";
+            fields: [
+              {
+                name: "account";
+                type: "publicKey";
+              }
+            ];
+          },
+          {
+            name: "And";
+            fields: [
+              {
+                name: "left";
+                type: "u8";
+              },
+              {
+                name: "right";
+                type: "u8";
+              }
+            ];
+          },
+          {
+            name: "Or";
+            fields: [
+              {
+                name: "left";
+                type: "u8";
+              },
+              {
+                name: "right";
+                type: "u8";
+              }
+            ];
+          },
+          {
+            name: "Not";
+            fields: [
+              {
+                name: "child";
+                type: "u8";
+              }
+            ];
+          }
+        ];
+      };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidTokenAccount";
+      msg: "Invalid token account";
+    },
+    {
+      code: 6001;
+      name: "InvalidIssuer";
+      msg: "Invalid issuer";
+    },
+    {
+      code: 6002;
+      name: "InvalidUser";
+      msg: "Invalid user";
+    },
+    {
+      code: 6003;
+      name: "InvalidTokenManager";
+      msg: "Invalid token manager for this invalidator";
+    },
+    {
+      code: 6004;
+      name: "InvalidConditionInvalidator";
+      msg: "Invalid condition invalidator";
+    },
+    {
+      code: 6005;
+      name: "InvalidCollector";
+      msg: "Invalid collector";
+    },
+    {
+      code: 6006;
+      name: "TooManyConditions";
+      msg: "Too many conditions";
+    },
+    {
+      code: 6007;
+      name: "InvalidConditions";
+      msg: "Invalid conditions";
+    },
+    {
+      code: 6008;
+      name: "MissingConditionAccount";
+      msg: "Account referenced by a condition not found";
+    },
+    {
+      code: 6009;
+      name: "ConditionsNotMet";
+      msg: "Conditions are not met";
+    }
+  ];
+};
+
+export const IDL: SolanaNftProgramsConditionInvalidator = {
+  version: "1.0.0",
+  name: "solana_nft_programs_condition_invalidator",
+  instructions: [
+    {
+      name: "init",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "conditionInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "incrementUsages",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "conditionInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "numUsages",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "invalidate",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "conditionInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "close",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "conditionInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "closer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: "conditionInvalidator",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "collector",
+            type: "publicKey",
+          },
+          {
+            name: "usages",
+            type: "u64",
+          },
+          {
+            name: "useAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "conditions",
+            type: {
+              vec: {
+                defined: "Condition",
+              },
+            },
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "InitIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "collector",
+            type: "publicKey",
+          },
+          {
+            name: "useAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "conditions",
+            type: {
+              vec: {
+                defined: "Condition",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "Condition",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "TimeAfter",
+            fields: [
+              {
+                name: "timestamp",
+                type: "i64",
+              },
+            ],
+          },
+          {
+            name: "DurationElapsed",
+            fields: [
+              {
+                name: "seconds",
+                type: "i64",
+              },
+            ],
+          },
+          {
+            name: "UsagesReached",
+            fields: [
+              {
+                name: "usages",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "TokenBalanceBelow",
+            fields: [
+              {
+                name: "token_account",
+                type: "publicKey",
+              },
+              {
+                name: "amount",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "TokenBalanceAtLeast",
+            fields: [
+              {
+                name: "token_account",
+                type: "publicKey",
+              },
+              {
+                name: "amount",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "AccountExists",
+            fields: [
+              {
+                name: "account",
+                type: "publicKey",
+              },
+            ],
+          },
+          {
+            name: "And",
+            fields: [
+              {
+                name: "left",
+                type: "u8",
+              },
+              {
+                name: "right",
+                type: "u8",
+              },
+            ],
+          },
+          {
+            name: "Or",
+            fields: [
+              {
+                name: "left",
+                type: "u8",
+              },
+              {
+                name: "right",
+                type: "u8",
+              },
+            ],
+          },
+          {
+            name: "Not",
+            fields: [
+              {
+                name: "child",
+                type: "u8",
+              },
+            ],
+          },
+        ],
+      },
+    },
+  ],
+  errors: [
+    {
+      code: 6000,
+      name: "InvalidTokenAccount",
+      msg: "Invalid token account",
+    },
+    {
+      code: 6001,
+      name: "InvalidIssuer",
+      msg: "Invalid issuer",
+    },
+    {
+      code: 6002,
+      name: "InvalidUser",
+      msg: "Invalid user",
+    },
+    {
+      code: 6003,
+      name: "InvalidTokenManager",
+      msg: "Invalid token manager for this invalidator",
+    },
+    {
+      code: 6004,
+      name: "InvalidConditionInvalidator",
+      msg: "Invalid condition invalidator",
+    },
+    {
+      code: 6005,
+      name: "InvalidCollector",
+      msg: "Invalid collector",
+    },
+    {
+      code: 6006,
+      name: "TooManyConditions",
+      msg: "Too many conditions",
+    },
+    {
+      code: 6007,
+      name: "InvalidConditions",
+      msg: "Invalid conditions",
+    },
+    {
+      code: 6008,
+      name: "MissingConditionAccount",
+      msg: "Account referenced by a condition not found",
+    },
+    {
+      code: 6009,
+      name: "ConditionsNotMet",
+      msg: "Conditions are not met",
+    },
+  ],
+};
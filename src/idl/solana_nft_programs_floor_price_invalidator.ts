@@ -0,0 +1,547 @@
+export type SolanaNftProgramsFloorPriceInvalidator = {
+  version: "1.0.0";
+  name: "solana_nft_programs_floor_price_invalidator";
+  instructions: [
+    {
+      name: "init";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "floorPriceInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "invalidate";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "floorPriceInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "priceFeed";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "invalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManagerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "close";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "floorPriceInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "closer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "floorPriceInvalidator";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "collector";
+            type: "publicKey";
+          },
+          {
+            name: "priceFeed";
+            type: "publicKey";
+          },
+          {
+            name: "oracleProgram";
+            type: "publicKey";
+          },
+          {
+            name: "thresholdPrice";
+            type: "i64";
+          },
+          {
+            name: "thresholdExponent";
+            type: "i32";
+          },
+          {
+            name: "maxStalenessSeconds";
+            type: "i64";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "InitIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "collector";
+            type: "publicKey";
+          },
+          {
+            name: "priceFeed";
+            type: "publicKey";
+          },
+          {
+            name: "oracleProgram";
+            type: "publicKey";
+          },
+          {
+            name: "thresholdPrice";
+            type: "i64";
+          },
+          {
+            name: "thresholdExponent";
+            type: "i32";
+          },
+          {
+            name: "maxStalenessSeconds";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "PriceFeedData";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "owner";
+            type: "publicKey";
+          },
+          {
+            name: "price";
+            type: "i64";
+          },
+          {
+            name: "exponent";
+            type: "i32";
+          },
+          {
+            name: "publishTime";
+            type: "i64";
+          }
+        ];
+      };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidIssuer";
+      msg: "Invalid issuer";
+    },
+    {
+      code: 6001;
+      name: "InvalidTokenManager";
+      msg: "Invalid token manager for this invalidator";
+    },
+    {
+      code: 6002;
+      name: "InvalidFloorPriceInvalidator";
+      msg: "Invalid floor price invalidator";
+    },
+    {
+      code: 6003;
+      name: "InvalidCollector";
+      msg: "Invalid collector";
+    },
+    {
+      code: 6004;
+      name: "InvalidInstruction";
+      msg: "Invalid instruction";
+    },
+    {
+      code: 6005;
+      name: "InvalidPriceFeed";
+      msg: "Invalid price feed";
+    },
+    {
+      code: 6006;
+      name: "StalePriceFeed";
+      msg: "Price feed is stale";
+    },
+    {
+      code: 6007;
+      name: "FloorPriceAboveThreshold";
+      msg: "Floor price is above the threshold";
+    },
+    {
+      code: 6008;
+      name: "InvalidExponent";
+      msg: "Invalid price exponent";
+    }
+  ];
+};
+
+export const IDL: SolanaNftProgramsFloorPriceInvalidator = {
+  version: "1.0.0",
+  name: "solana_nft_programs_floor_price_invalidator",
+  instructions: [
+    {
+      name: "init",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "floorPriceInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "invalidate",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "floorPriceInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "priceFeed",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "close",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "floorPriceInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "closer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: "floorPriceInvalidator",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "collector",
+            type: "publicKey",
+          },
+          {
+            name: "priceFeed",
+            type: "publicKey",
+          },
+          {
+            name: "oracleProgram",
+            type: "publicKey",
+          },
+          {
+            name: "thresholdPrice",
+            type: "i64",
+          },
+          {
+            name: "thresholdExponent",
+            type: "i32",
+          },
+          {
+            name: "maxStalenessSeconds",
+            type: "i64",
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "InitIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "collector",
+            type: "publicKey",
+          },
+          {
+            name: "priceFeed",
+            type: "publicKey",
+          },
+          {
+            name: "oracleProgram",
+            type: "publicKey",
+          },
+          {
+            name: "thresholdPrice",
+            type: "i64",
+          },
+          {
+            name: "thresholdExponent",
+            type: "i32",
+          },
+          {
+            name: "maxStalenessSeconds",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "PriceFeedData",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "owner",
+            type: "publicKey",
+          },
+          {
+            name: "price",
+            type: "i64",
+          },
+          {
+            name: "exponent",
+            type: "i32",
+          },
+          {
+            name: "publishTime",
+            type: "i64",
+          },
+        ],
+      },
+    },
+  ],
+  errors: [
+    {
+      code: 6000,
+      name: "InvalidIssuer",
+      msg: "Invalid issuer",
+    },
+    {
+      code: 6001,
+      name: "InvalidTokenManager",
+      msg: "Invalid token manager for this invalidator",
+    },
+    {
+      code: 6002,
+      name: "InvalidFloorPriceInvalidator",
+      msg: "Invalid floor price invalidator",
+    },
+    {
+      code: 6003,
+      name: "InvalidCollector",
+      msg: "Invalid collector",
+    },
+    {
+      code: 6004,
+      name: "InvalidInstruction",
+      msg: "Invalid instruction",
+    },
+    {
+      code: 6005,
+      name: "InvalidPriceFeed",
+      msg: "Invalid price feed",
+    },
+    {
+      code: 6006,
+      name: "StalePriceFeed",
+      msg: "Price feed is stale",
+    },
+    {
+      code: 6007,
+      name: "FloorPriceAboveThreshold",
+      msg: "Floor price is above the threshold",
+    },
+    {
+      code: 6008,
+      name: "InvalidExponent",
+      msg: "Invalid price exponent",
+    },
+  ],
+};
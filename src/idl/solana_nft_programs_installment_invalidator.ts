@@ -0,0 +1,659 @@
+export type SolanaNftProgramsInstallmentInvalidator = {
+  version: "1.0.0";
+  name: "solana_nft_programs_installment_invalidator";
+  instructions: [
+    {
+      name: "init";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "installmentInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "payInstallment";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "installmentInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeCollectorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsPaymentManager";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "invalidate";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "installmentInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "invalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManagerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "close";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "installmentInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "closer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "installmentInvalidator";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "paymentManager";
+            type: "publicKey";
+          },
+          {
+            name: "collector";
+            type: "publicKey";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "installmentAmount";
+            type: "u64";
+          },
+          {
+            name: "installmentPeriodSeconds";
+            type: "i64";
+          },
+          {
+            name: "numInstallments";
+            type: "u64";
+          },
+          {
+            name: "installmentsPaid";
+            type: "u64";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "InitIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "collector";
+            type: "publicKey";
+          },
+          {
+            name: "paymentManager";
+            type: "publicKey";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "installmentAmount";
+            type: "u64";
+          },
+          {
+            name: "installmentPeriodSeconds";
+            type: "i64";
+          },
+          {
+            name: "numInstallments";
+            type: "u64";
+          }
+        ];
+      };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidPaymentTokenAccount";
+      msg: "Token account not owned by the claim approver";
+    },
+    {
+      code: 6001;
+      name: "InvalidPayerTokenAccount";
+      msg: "Token account not owned by the payer";
+    },
+    {
+      code: 6002;
+      name: "InvalidIssuer";
+      msg: "Invalid issuer";
+    },
+    {
+      code: 6003;
+      name: "InvalidTokenManager";
+      msg: "Invalid token manager for this invalidator";
+    },
+    {
+      code: 6004;
+      name: "InvalidInstallmentInvalidator";
+      msg: "Invalid installment invalidator";
+    },
+    {
+      code: 6005;
+      name: "InvalidInstruction";
+      msg: "Invalid instruction";
+    },
+    {
+      code: 6006;
+      name: "InvalidPaymentMint";
+      msg: "Invalid payment mint on installment invalidator";
+    },
+    {
+      code: 6007;
+      name: "InvalidCollector";
+      msg: "Invalid collector";
+    },
+    {
+      code: 6008;
+      name: "InvalidPaymentManagerProgram";
+      msg: "Invalid payment manager program";
+    },
+    {
+      code: 6009;
+      name: "InvalidPaymentManager";
+      msg: "Invalid payment manager";
+    },
+    {
+      code: 6010;
+      name: "InvalidMint";
+      msg: "Invalid mint";
+    },
+    {
+      code: 6011;
+      name: "InstallmentsPaidOff";
+      msg: "All installments have been paid";
+    },
+    {
+      code: 6012;
+      name: "InstallmentOverdue";
+      msg: "Installment is overdue";
+    },
+    {
+      code: 6013;
+      name: "InstallmentsInProgress";
+      msg: "Installments are neither missed nor paid off";
+    }
+  ];
+};
+
+export const IDL: SolanaNftProgramsInstallmentInvalidator = {
+  version: "1.0.0",
+  name: "solana_nft_programs_installment_invalidator",
+  instructions: [
+    {
+      name: "init",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "installmentInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "payInstallment",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "installmentInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsPaymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "invalidate",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "installmentInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "close",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "installmentInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "closer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: "installmentInvalidator",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "paymentManager",
+            type: "publicKey",
+          },
+          {
+            name: "collector",
+            type: "publicKey",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "installmentAmount",
+            type: "u64",
+          },
+          {
+            name: "installmentPeriodSeconds",
+            type: "i64",
+          },
+          {
+            name: "numInstallments",
+            type: "u64",
+          },
+          {
+            name: "installmentsPaid",
+            type: "u64",
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "InitIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "collector",
+            type: "publicKey",
+          },
+          {
+            name: "paymentManager",
+            type: "publicKey",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "installmentAmount",
+            type: "u64",
+          },
+          {
+            name: "installmentPeriodSeconds",
+            type: "i64",
+          },
+          {
+            name: "numInstallments",
+            type: "u64",
+          },
+        ],
+      },
+    },
+  ],
+  errors: [
+    {
+      code: 6000,
+      name: "InvalidPaymentTokenAccount",
+      msg: "Token account not owned by the claim approver",
+    },
+    {
+      code: 6001,
+      name: "InvalidPayerTokenAccount",
+      msg: "Token account not owned by the payer",
+    },
+    {
+      code: 6002,
+      name: "InvalidIssuer",
+      msg: "Invalid issuer",
+    },
+    {
+      code: 6003,
+      name: "InvalidTokenManager",
+      msg: "Invalid token manager for this invalidator",
+    },
+    {
+      code: 6004,
+      name: "InvalidInstallmentInvalidator",
+      msg: "Invalid installment invalidator",
+    },
+    {
+      code: 6005,
+      name: "InvalidInstruction",
+      msg: "Invalid instruction",
+    },
+    {
+      code: 6006,
+      name: "InvalidPaymentMint",
+      msg: "Invalid payment mint on installment invalidator",
+    },
+    {
+      code: 6007,
+      name: "InvalidCollector",
+      msg: "Invalid collector",
+    },
+    {
+      code: 6008,
+      name: "InvalidPaymentManagerProgram",
+      msg: "Invalid payment manager program",
+    },
+    {
+      code: 6009,
+      name: "InvalidPaymentManager",
+      msg: "Invalid payment manager",
+    },
+    {
+      code: 6010,
+      name: "InvalidMint",
+      msg: "Invalid mint",
+    },
+    {
+      code: 6011,
+      name: "InstallmentsPaidOff",
+      msg: "All installments have been paid",
+    },
+    {
+      code: 6012,
+      name: "InstallmentOverdue",
+      msg: "Installment is overdue",
+    },
+    {
+      code: 6013,
+      name: "InstallmentsInProgress",
+      msg: "Installments are neither missed nor paid off",
+    },
+  ],
+};
@@ -0,0 +1,889 @@
+export type SolanaNftProgramsLoan = {
+  version: "1.0.0";
+  name: "solana_nft_programs_loan";
+  instructions: [
+    {
+      name: "init";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "loan";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "borrowerReceiptTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "loanReceiptTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "borrower";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "fund";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "loan";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "loanReceiptTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lenderReceiptTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lenderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "borrowerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lender";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "repay";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "loan";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "borrower";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lenderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManagerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "cancel";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "loan";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "loanReceiptTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "borrowerReceiptTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "borrower";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManagerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "invalidate";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "loan";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "borrower";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lender";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManagerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "loan";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "borrower";
+            type: "publicKey";
+          },
+          {
+            name: "lender";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "state";
+            type: "u8";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "principal";
+            type: "u64";
+          },
+          {
+            name: "interestBps";
+            type: "u64";
+          },
+          {
+            name: "durationSeconds";
+            type: "i64";
+          },
+          {
+            name: "dueAt";
+            type: {
+              option: "i64";
+            };
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "InitIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "principal";
+            type: "u64";
+          },
+          {
+            name: "interestBps";
+            type: "u64";
+          },
+          {
+            name: "durationSeconds";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "LoanState";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Requested";
+          },
+          {
+            name: "Active";
+          }
+        ];
+      };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidTokenManager";
+      msg: "Invalid token manager for this loan";
+    },
+    {
+      code: 6001;
+      name: "InvalidBorrower";
+      msg: "Invalid borrower";
+    },
+    {
+      code: 6002;
+      name: "InvalidLender";
+      msg: "Invalid lender";
+    },
+    {
+      code: 6003;
+      name: "InvalidLoanState";
+      msg: "Invalid loan state";
+    },
+    {
+      code: 6004;
+      name: "InvalidInstruction";
+      msg: "Invalid instruction";
+    },
+    {
+      code: 6005;
+      name: "InvalidReceiptTokenAccount";
+      msg: "Invalid receipt token account";
+    },
+    {
+      code: 6006;
+      name: "InvalidPaymentTokenAccount";
+      msg: "Invalid payment token account";
+    },
+    {
+      code: 6007;
+      name: "InvalidRecipientTokenAccount";
+      msg: "Invalid recipient token account";
+    },
+    {
+      code: 6008;
+      name: "LoanNotDue";
+      msg: "Loan is not yet due";
+    },
+    {
+      code: 6009;
+      name: "LoanOverdue";
+      msg: "Loan is overdue";
+    },
+    {
+      code: 6010;
+      name: "RepaymentAmountOverflow";
+      msg: "Repayment amount overflows";
+    }
+  ];
+};
+
+export const IDL: SolanaNftProgramsLoan = {
+  version: "1.0.0",
+  name: "solana_nft_programs_loan",
+  instructions: [
+    {
+      name: "init",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "loan",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "borrowerReceiptTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "loanReceiptTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "borrower",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "fund",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "loan",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "loanReceiptTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lenderReceiptTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lenderPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "borrowerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lender",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "repay",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "loan",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "borrower",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lenderPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "cancel",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "loan",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "loanReceiptTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "borrowerReceiptTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "borrower",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "invalidate",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "loan",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "borrower",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lender",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: "loan",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "borrower",
+            type: "publicKey",
+          },
+          {
+            name: "lender",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "state",
+            type: "u8",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "principal",
+            type: "u64",
+          },
+          {
+            name: "interestBps",
+            type: "u64",
+          },
+          {
+            name: "durationSeconds",
+            type: "i64",
+          },
+          {
+            name: "dueAt",
+            type: {
+              option: "i64",
+            },
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "InitIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "principal",
+            type: "u64",
+          },
+          {
+            name: "interestBps",
+            type: "u64",
+          },
+          {
+            name: "durationSeconds",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "LoanState",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Requested",
+          },
+          {
+            name: "Active",
+          },
+        ],
+      },
+    },
+  ],
+  errors: [
+    {
+      code: 6000,
+      name: "InvalidTokenManager",
+      msg: "Invalid token manager for this loan",
+    },
+    {
+      code: 6001,
+      name: "InvalidBorrower",
+      msg: "Invalid borrower",
+    },
+    {
+      code: 6002,
+      name: "InvalidLender",
+      msg: "Invalid lender",
+    },
+    {
+      code: 6003,
+      name: "InvalidLoanState",
+      msg: "Invalid loan state",
+    },
+    {
+      code: 6004,
+      name: "InvalidInstruction",
+      msg: "Invalid instruction",
+    },
+    {
+      code: 6005,
+      name: "InvalidReceiptTokenAccount",
+      msg: "Invalid receipt token account",
+    },
+    {
+      code: 6006,
+      name: "InvalidPaymentTokenAccount",
+      msg: "Invalid payment token account",
+    },
+    {
+      code: 6007,
+      name: "InvalidRecipientTokenAccount",
+      msg: "Invalid recipient token account",
+    },
+    {
+      code: 6008,
+      name: "LoanNotDue",
+      msg: "Loan is not yet due",
+    },
+    {
+      code: 6009,
+      name: "LoanOverdue",
+      msg: "Loan is overdue",
+    },
+    {
+      code: 6010,
+      name: "RepaymentAmountOverflow",
+      msg: "Repayment amount overflows",
+    },
+  ],
+};
@@ -0,0 +1,271 @@
+export type SolanaNftProgramsMockOracle = {
+  version: "1.0.0";
+  name: "solana_nft_programs_mock_oracle";
+  instructions: [
+    {
+      name: "initPriceFeed";
+      accounts: [
+        {
+          name: "priceFeed";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "owner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitPriceFeedIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "setPrice";
+      accounts: [
+        {
+          name: "priceFeed";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "owner";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "SetPriceIx";
+          };
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: "priceFeed";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "owner";
+            type: "publicKey";
+          },
+          {
+            name: "price";
+            type: "i64";
+          },
+          {
+            name: "exponent";
+            type: "i32";
+          },
+          {
+            name: "publishTime";
+            type: "i64";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "InitPriceFeedIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "price";
+            type: "i64";
+          },
+          {
+            name: "exponent";
+            type: "i32";
+          }
+        ];
+      };
+    },
+    {
+      name: "SetPriceIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "price";
+            type: "i64";
+          },
+          {
+            name: "exponent";
+            type: "i32";
+          },
+          {
+            name: "publishTime";
+            type: {
+              option: "i64";
+            };
+          }
+        ];
+      };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidOwner";
+      msg: "Invalid price feed owner";
+    }
+  ];
+};
+
+export const IDL: SolanaNftProgramsMockOracle = {
+  version: "1.0.0",
+  name: "solana_nft_programs_mock_oracle",
+  instructions: [
+    {
+      name: "initPriceFeed",
+      accounts: [
+        {
+          name: "priceFeed",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitPriceFeedIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "setPrice",
+      accounts: [
+        {
+          name: "priceFeed",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "SetPriceIx",
+          },
+        },
+      ],
+    },
+  ],
+  accounts: [
+    {
+      name: "priceFeed",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "owner",
+            type: "publicKey",
+          },
+          {
+            name: "price",
+            type: "i64",
+          },
+          {
+            name: "exponent",
+            type: "i32",
+          },
+          {
+            name: "publishTime",
+            type: "i64",
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "InitPriceFeedIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "price",
+            type: "i64",
+          },
+          {
+            name: "exponent",
+            type: "i32",
+          },
+        ],
+      },
+    },
+    {
+      name: "SetPriceIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "price",
+            type: "i64",
+          },
+          {
+            name: "exponent",
+            type: "i32",
+          },
+          {
+            name: "publishTime",
+            type: {
+              option: "i64",
+            },
+          },
+        ],
+      },
+    },
+  ],
+  errors: [
+    {
+      code: 6000,
+      name: "InvalidOwner",
+      msg: "Invalid price feed owner",
+    },
+  ],
+};
@@ -106,6 +106,274 @@ export type SolanaNftProgramsPaidClaimApprover = {
       ];
       args: [];
     },
+    {
+      name: "initSecurityDeposit";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claimApprover";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "securityDeposit";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitSecurityDepositIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "payWithDeposit";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeCollectorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "claimApprover";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "claimReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "securityDeposit";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payerDepositTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "depositTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsPaymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "claimDeposit";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "securityDeposit";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "depositTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destination";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "observeRentalEnd";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "securityDeposit";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "refundDeposit";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "securityDeposit";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "depositTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destination";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "splitDeposit";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "securityDeposit";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "depositTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuerDestination";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "depositorDestination";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManagerArbiter";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "arbiter";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "issuerAmount";
+          type: "u64";
+        }
+      ];
+    },
     {
       name: "close";
       accounts: [
@@ -162,12 +430,130 @@ export type SolanaNftProgramsPaidClaimApprover = {
           {
             name: "collector";
             type: "publicKey";
+          },
+          {
+            name: "requiresDeposit";
+            type: {
+              option: "bool";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "securityDeposit";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "mintCount";
+            type: "u64";
+          },
+          {
+            name: "issuer";
+            type: "publicKey";
+          },
+          {
+            name: "arbiter";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "paymentMint";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "disputeWindowSeconds";
+            type: "i64";
+          },
+          {
+            name: "maxExpiration";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "depositor";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "depositedAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "remainingAmount";
+            type: "u64";
+          },
+          {
+            name: "endedAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "tokenManagerClosed";
+            type: {
+              option: "bool";
+            };
           }
         ];
       };
     }
   ];
   types: [
+    {
+      name: "InitSecurityDepositIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "paymentMint";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "arbiter";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "disputeWindowSeconds";
+            type: "i64";
+          },
+          {
+            name: "maxExpiration";
+            type: {
+              option: "i64";
+            };
+          }
+        ];
+      };
+    },
     {
       name: "InitIx";
       type: {
@@ -245,19 +631,183 @@ export type SolanaNftProgramsPaidClaimApprover = {
       msg: "Invalid payment mint";
     },
     {
-      code: 6010;
-      name: "InvalidMint";
-      msg: "Invalid mint";
-    }
-  ];
-};
-
-export const IDL: SolanaNftProgramsPaidClaimApprover = {
-  version: "4.6.0",
-  name: "solana_nft_programs_paid_claim_approver",
-  instructions: [
-    {
-      name: "init",
+      code: 6010;
+      name: "InvalidMint";
+      msg: "Invalid mint";
+    },
+    {
+      code: 6011;
+      name: "SecurityDepositRequired";
+      msg: "Claim requires a security deposit";
+    },
+    {
+      code: 6012;
+      name: "InvalidSecurityDeposit";
+      msg: "Invalid security deposit";
+    },
+    {
+      code: 6013;
+      name: "SecurityDepositAlreadyFunded";
+      msg: "Security deposit already funded";
+    },
+    {
+      code: 6014;
+      name: "InvalidDepositTokenAccount";
+      msg: "Invalid deposit token account";
+    },
+    {
+      code: 6015;
+      name: "InvalidDepositAuthority";
+      msg: "Invalid security deposit authority";
+    },
+    {
+      code: 6016;
+      name: "RentalNotEnded";
+      msg: "Rental has not ended";
+    },
+    {
+      code: 6017;
+      name: "DisputeWindowClosed";
+      msg: "Dispute window has closed";
+    },
+    {
+      code: 6018;
+      name: "DisputeWindowOpen";
+      msg: "Dispute window is still open";
+    },
+    {
+      code: 6019;
+      name: "InvalidDepositAmount";
+      msg: "Invalid deposit amount";
+    },
+    {
+      code: 6020;
+      name: "InvalidDepositor";
+      msg: "Invalid depositor";
+    },
+    {
+      code: 6021;
+      name: "InvalidInstruction";
+      msg: "Invalid instruction";
+    },
+    {
+      code: 6022;
+      name: "InvalidTokenManagerArbiter";
+      msg: "Invalid token manager arbiter";
+    }
+  ];
+};
+
+export const IDL: SolanaNftProgramsPaidClaimApprover = {
+  version: "4.6.0",
+  name: "solana_nft_programs_paid_claim_approver",
+  instructions: [
+    {
+      name: "init",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claimApprover",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "pay",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "claimApprover",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "claimReceipt",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsPaymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initSecurityDeposit",
       accounts: [
         {
           name: "tokenManager",
@@ -270,12 +820,12 @@ export const IDL: SolanaNftProgramsPaidClaimApprover = {
           isSigner: false,
         },
         {
-          name: "issuer",
+          name: "securityDeposit",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "payer",
+          name: "issuer",
           isMut: true,
           isSigner: true,
         },
@@ -289,13 +839,13 @@ export const IDL: SolanaNftProgramsPaidClaimApprover = {
         {
           name: "ix",
           type: {
-            defined: "InitIx",
+            defined: "InitSecurityDepositIx",
           },
         },
       ],
     },
     {
-      name: "pay",
+      name: "payWithDeposit",
       accounts: [
         {
           name: "tokenManager",
@@ -337,6 +887,21 @@ export const IDL: SolanaNftProgramsPaidClaimApprover = {
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "securityDeposit",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payerDepositTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "depositTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "solanaNftProgramsTokenManager",
           isMut: false,
@@ -360,6 +925,155 @@ export const IDL: SolanaNftProgramsPaidClaimApprover = {
       ],
       args: [],
     },
+    {
+      name: "claimDeposit",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "securityDeposit",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "depositTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destination",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "observeRentalEnd",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "securityDeposit",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "refundDeposit",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "securityDeposit",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "depositTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destination",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "splitDeposit",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "securityDeposit",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "depositTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuerDestination",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "depositorDestination",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerArbiter",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "arbiter",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "issuerAmount",
+          type: "u64",
+        },
+      ],
+    },
     {
       name: "close",
       accounts: [
@@ -417,11 +1131,129 @@ export const IDL: SolanaNftProgramsPaidClaimApprover = {
             name: "collector",
             type: "publicKey",
           },
+          {
+            name: "requiresDeposit",
+            type: {
+              option: "bool",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "securityDeposit",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "mintCount",
+            type: "u64",
+          },
+          {
+            name: "issuer",
+            type: "publicKey",
+          },
+          {
+            name: "arbiter",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "paymentMint",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "disputeWindowSeconds",
+            type: "i64",
+          },
+          {
+            name: "maxExpiration",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "depositor",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "depositedAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "remainingAmount",
+            type: "u64",
+          },
+          {
+            name: "endedAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "tokenManagerClosed",
+            type: {
+              option: "bool",
+            },
+          },
         ],
       },
     },
   ],
   types: [
+    {
+      name: "InitSecurityDepositIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "paymentMint",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "arbiter",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "disputeWindowSeconds",
+            type: "i64",
+          },
+          {
+            name: "maxExpiration",
+            type: {
+              option: "i64",
+            },
+          },
+        ],
+      },
+    },
     {
       name: "InitIx",
       type: {
@@ -503,5 +1335,65 @@ export const IDL: SolanaNftProgramsPaidClaimApprover = {
       name: "InvalidMint",
       msg: "Invalid mint",
     },
+    {
+      code: 6011,
+      name: "SecurityDepositRequired",
+      msg: "Claim requires a security deposit",
+    },
+    {
+      code: 6012,
+      name: "InvalidSecurityDeposit",
+      msg: "Invalid security deposit",
+    },
+    {
+      code: 6013,
+      name: "SecurityDepositAlreadyFunded",
+      msg: "Security deposit already funded",
+    },
+    {
+      code: 6014,
+      name: "InvalidDepositTokenAccount",
+      msg: "Invalid deposit token account",
+    },
+    {
+      code: 6015,
+      name: "InvalidDepositAuthority",
+      msg: "Invalid security deposit authority",
+    },
+    {
+      code: 6016,
+      name: "RentalNotEnded",
+      msg: "Rental has not ended",
+    },
+    {
+      code: 6017,
+      name: "DisputeWindowClosed",
+      msg: "Dispute window has closed",
+    },
+    {
+      code: 6018,
+      name: "DisputeWindowOpen",
+      msg: "Dispute window is still open",
+    },
+    {
+      code: 6019,
+      name: "InvalidDepositAmount",
+      msg: "Invalid deposit amount",
+    },
+    {
+      code: 6020,
+      name: "InvalidDepositor",
+      msg: "Invalid depositor",
+    },
+    {
+      code: 6021,
+      name: "InvalidInstruction",
+      msg: "Invalid instruction",
+    },
+    {
+      code: 6022,
+      name: "InvalidTokenManagerArbiter",
+      msg: "Invalid token manager arbiter",
+    },
   ],
 };
@@ -191,6 +191,79 @@ export type SolanaNftProgramsTimeInvalidator = {
       ];
       args: [];
     },
+    {
+      name: "createSublease";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "timeInvalidator";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "subleaseTokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lessorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lessor";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "lesseeTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lessee";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "CreateSubleaseIx";
+          };
+        }
+      ];
+    },
     {
       name: "close";
       accounts: [
@@ -287,6 +360,18 @@ export type SolanaNftProgramsTimeInvalidator = {
     }
   ];
   types: [
+    {
+      name: "CreateSubleaseIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "expiration";
+            type: "i64";
+          }
+        ];
+      };
+    },
     {
       name: "InitIx";
       type: {
@@ -447,6 +532,16 @@ export type SolanaNftProgramsTimeInvalidator = {
       code: 6018;
       name: "InvalidNewMaxExpiration";
       msg: "Invalid new max expiration";
+    },
+    {
+      code: 6019;
+      name: "SubleaseExceedsExpiration";
+      msg: "Sublease cannot outlast the rental expiration";
+    },
+    {
+      code: 6020;
+      name: "TokenManagerDisputed";
+      msg: "Token manager is under dispute";
     }
   ];
 };
@@ -644,6 +739,79 @@ export const IDL: SolanaNftProgramsTimeInvalidator = {
       ],
       args: [],
     },
+    {
+      name: "createSublease",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "timeInvalidator",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "subleaseTokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lessorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lessor",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "lesseeTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lessee",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "CreateSubleaseIx",
+          },
+        },
+      ],
+    },
     {
       name: "close",
       accounts: [
@@ -740,6 +908,18 @@ export const IDL: SolanaNftProgramsTimeInvalidator = {
     },
   ],
   types: [
+    {
+      name: "CreateSubleaseIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "expiration",
+            type: "i64",
+          },
+        ],
+      },
+    },
     {
       name: "InitIx",
       type: {
@@ -901,5 +1081,15 @@ export const IDL: SolanaNftProgramsTimeInvalidator = {
       name: "InvalidNewMaxExpiration",
       msg: "Invalid new max expiration",
     },
+    {
+      code: 6019,
+      name: "SubleaseExceedsExpiration",
+      msg: "Sublease cannot outlast the rental expiration",
+    },
+    {
+      code: 6020,
+      name: "TokenManagerDisputed",
+      msg: "Token manager is under dispute",
+    },
   ],
 };
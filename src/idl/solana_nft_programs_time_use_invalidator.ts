@@ -0,0 +1,969 @@
+export type SolanaNftProgramsTimeUseInvalidator = {
+  version: "1.0.0";
+  name: "solana_nft_programs_time_use_invalidator";
+  instructions: [
+    {
+      name: "init";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "timeUseInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "incrementUsages";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "timeUseInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "numUsages";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "extend";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "timeUseInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeCollectorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsPaymentManager";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "numExtensions";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "invalidate";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "timeUseInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "invalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManagerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "close";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "timeUseInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "closer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "timeUseInvalidator";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "paymentManager";
+            type: "publicKey";
+          },
+          {
+            name: "collector";
+            type: "publicKey";
+          },
+          {
+            name: "mode";
+            type: "u8";
+          },
+          {
+            name: "expiration";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "durationSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "maxExpiration";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "usages";
+            type: "u64";
+          },
+          {
+            name: "totalUsages";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "maxUsages";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "useAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "extensionPaymentAmount";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "extensionPaymentMint";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "extensionDurationSeconds";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "extensionUsages";
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "InitIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "collector";
+            type: "publicKey";
+          },
+          {
+            name: "paymentManager";
+            type: "publicKey";
+          },
+          {
+            name: "mode";
+            type: "u8";
+          },
+          {
+            name: "durationSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "maxExpiration";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "totalUsages";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "maxUsages";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "useAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "extensionPaymentAmount";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "extensionPaymentMint";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "extensionDurationSeconds";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "extensionUsages";
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "ConditionMode";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Or";
+          },
+          {
+            name: "And";
+          }
+        ];
+      };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidPaymentTokenAccount";
+      msg: "Token account not owned by the claim approver";
+    },
+    {
+      code: 6001;
+      name: "InvalidPayerTokenAccount";
+      msg: "Token account not owned by the issuer";
+    },
+    {
+      code: 6002;
+      name: "InvalidTokenAccount";
+      msg: "Invalid token account";
+    },
+    {
+      code: 6003;
+      name: "InvalidIssuer";
+      msg: "Invalid issuer";
+    },
+    {
+      code: 6004;
+      name: "InvalidUser";
+      msg: "Invalid user";
+    },
+    {
+      code: 6005;
+      name: "InvalidTokenManager";
+      msg: "Invalid token manager for this invalidator";
+    },
+    {
+      code: 6006;
+      name: "InvalidTimeUseInvalidator";
+      msg: "Invalid time use invalidator";
+    },
+    {
+      code: 6007;
+      name: "InvalidInstruction";
+      msg: "Invalid instruction";
+    },
+    {
+      code: 6008;
+      name: "InsufficientUsages";
+      msg: "Usages at the maximum";
+    },
+    {
+      code: 6009;
+      name: "InvalidExtendExpiration";
+      msg: "Max expiration exceeded";
+    },
+    {
+      code: 6010;
+      name: "MaxUsagesReached";
+      msg: "Max usages reached";
+    },
+    {
+      code: 6011;
+      name: "InvalidExtensionAmount";
+      msg: "Invalid extension amount";
+    },
+    {
+      code: 6012;
+      name: "InvalidPaymentMint";
+      msg: "Invalid payment mint on time use invalidator";
+    },
+    {
+      code: 6013;
+      name: "InvalidCollector";
+      msg: "Invalid collector";
+    },
+    {
+      code: 6014;
+      name: "InvalidPaymentManagerProgram";
+      msg: "Invalid payment manager program";
+    },
+    {
+      code: 6015;
+      name: "InvalidPaymentManager";
+      msg: "Invalid payment manager";
+    },
+    {
+      code: 6016;
+      name: "InvalidMint";
+      msg: "Invalid mint";
+    },
+    {
+      code: 6017;
+      name: "TokenManagerDisputed";
+      msg: "Token manager is under dispute";
+    }
+  ];
+};
+
+export const IDL: SolanaNftProgramsTimeUseInvalidator = {
+  version: "1.0.0",
+  name: "solana_nft_programs_time_use_invalidator",
+  instructions: [
+    {
+      name: "init",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeUseInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "incrementUsages",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeUseInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "numUsages",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "extend",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeUseInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsPaymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "numExtensions",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "invalidate",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "timeUseInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "close",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "timeUseInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "closer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: "timeUseInvalidator",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "paymentManager",
+            type: "publicKey",
+          },
+          {
+            name: "collector",
+            type: "publicKey",
+          },
+          {
+            name: "mode",
+            type: "u8",
+          },
+          {
+            name: "expiration",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "durationSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "maxExpiration",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "usages",
+            type: "u64",
+          },
+          {
+            name: "totalUsages",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "maxUsages",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "useAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "extensionPaymentAmount",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "extensionPaymentMint",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "extensionDurationSeconds",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "extensionUsages",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "InitIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "collector",
+            type: "publicKey",
+          },
+          {
+            name: "paymentManager",
+            type: "publicKey",
+          },
+          {
+            name: "mode",
+            type: "u8",
+          },
+          {
+            name: "durationSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "maxExpiration",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "totalUsages",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "maxUsages",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "useAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "extensionPaymentAmount",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "extensionPaymentMint",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "extensionDurationSeconds",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "extensionUsages",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "ConditionMode",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Or",
+          },
+          {
+            name: "And",
+          },
+        ],
+      },
+    },
+  ],
+  errors: [
+    {
+      code: 6000,
+      name: "InvalidPaymentTokenAccount",
+      msg: "Token account not owned by the claim approver",
+    },
+    {
+      code: 6001,
+      name: "InvalidPayerTokenAccount",
+      msg: "Token account not owned by the issuer",
+    },
+    {
+      code: 6002,
+      name: "InvalidTokenAccount",
+      msg: "Invalid token account",
+    },
+    {
+      code: 6003,
+      name: "InvalidIssuer",
+      msg: "Invalid issuer",
+    },
+    {
+      code: 6004,
+      name: "InvalidUser",
+      msg: "Invalid user",
+    },
+    {
+      code: 6005,
+      name: "InvalidTokenManager",
+      msg: "Invalid token manager for this invalidator",
+    },
+    {
+      code: 6006,
+      name: "InvalidTimeUseInvalidator",
+      msg: "Invalid time use invalidator",
+    },
+    {
+      code: 6007,
+      name: "InvalidInstruction",
+      msg: "Invalid instruction",
+    },
+    {
+      code: 6008,
+      name: "InsufficientUsages",
+      msg: "Usages at the maximum",
+    },
+    {
+      code: 6009,
+      name: "InvalidExtendExpiration",
+      msg: "Max expiration exceeded",
+    },
+    {
+      code: 6010,
+      name: "MaxUsagesReached",
+      msg: "Max usages reached",
+    },
+    {
+      code: 6011,
+      name: "InvalidExtensionAmount",
+      msg: "Invalid extension amount",
+    },
+    {
+      code: 6012,
+      name: "InvalidPaymentMint",
+      msg: "Invalid payment mint on time use invalidator",
+    },
+    {
+      code: 6013,
+      name: "InvalidCollector",
+      msg: "Invalid collector",
+    },
+    {
+      code: 6014,
+      name: "InvalidPaymentManagerProgram",
+      msg: "Invalid payment manager program",
+    },
+    {
+      code: 6015,
+      name: "InvalidPaymentManager",
+      msg: "Invalid payment manager",
+    },
+    {
+      code: 6016,
+      name: "InvalidMint",
+      msg: "Invalid mint",
+    },
+    {
+      code: 6017,
+      name: "TokenManagerDisputed",
+      msg: "Token manager is under dispute",
+    },
+  ],
+};
@@ -222,6 +222,65 @@ export type SolanaNftProgramsTokenManager = {
         }
       ];
     },
+    {
+      name: "createClaimReceiptWithExpiration";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claimApprover";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "claimReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "CreateClaimReceiptWithExpirationIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "closeClaimReceipt";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "claimReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
     {
       name: "claimReceiptMint";
       accounts: [
@@ -474,74 +533,49 @@ export type SolanaNftProgramsTokenManager = {
       ];
     },
     {
-      name: "createMintManager";
+      name: "updateInvalidationTypeByInvalidator";
       accounts: [
         {
-          name: "mintManager";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "mint";
+          name: "tokenManager";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "freezeAuthority";
+          name: "invalidator";
           isMut: false;
           isSigner: true;
-        },
-        {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
-        },
+        }
+      ];
+      args: [
         {
-          name: "systemProgram";
-          isMut: false;
-          isSigner: false;
+          name: "invalidationType";
+          type: "u8";
         }
       ];
-      args: [];
     },
     {
-      name: "closeMintManager";
+      name: "allowRelease";
       accounts: [
         {
-          name: "mintManager";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "mint";
+          name: "tokenManager";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "freezeAuthority";
+          name: "invalidator";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "payer";
+          name: "issuer";
           isMut: false;
           isSigner: true;
-        },
-        {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
         }
       ];
       args: [];
     },
     {
-      name: "createTransferReceipt";
+      name: "setArbiter";
       accounts: [
         {
           name: "tokenManager";
@@ -549,14 +583,14 @@ export type SolanaNftProgramsTokenManager = {
           isSigner: false;
         },
         {
-          name: "transferAuthority";
-          isMut: false;
-          isSigner: true;
+          name: "tokenManagerArbiter";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "transferReceipt";
+          name: "issuer";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
           name: "payer";
@@ -571,13 +605,13 @@ export type SolanaNftProgramsTokenManager = {
       ];
       args: [
         {
-          name: "target";
+          name: "arbiter";
           type: "publicKey";
         }
       ];
     },
     {
-      name: "updateTransferReceipt";
+      name: "openDispute";
       accounts: [
         {
           name: "tokenManager";
@@ -585,25 +619,20 @@ export type SolanaNftProgramsTokenManager = {
           isSigner: false;
         },
         {
-          name: "transferAuthority";
-          isMut: false;
-          isSigner: true;
-        },
-        {
-          name: "transferReceipt";
+          name: "tokenManagerArbiter";
           isMut: true;
           isSigner: false;
-        }
-      ];
-      args: [
+        },
         {
-          name: "target";
-          type: "publicKey";
+          name: "arbiter";
+          isMut: false;
+          isSigner: true;
         }
       ];
+      args: [];
     },
     {
-      name: "closeTransferReceipt";
+      name: "resolveDispute";
       accounts: [
         {
           name: "tokenManager";
@@ -611,61 +640,53 @@ export type SolanaNftProgramsTokenManager = {
           isSigner: false;
         },
         {
-          name: "transferAuthority";
+          name: "tokenManagerArbiter";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "arbiter";
           isMut: false;
           isSigner: true;
         },
         {
-          name: "transferReceipt";
-          isMut: true;
+          name: "instructions";
+          isMut: false;
           isSigner: false;
-        },
+        }
+      ];
+      args: [
         {
-          name: "recipient";
-          isMut: true;
-          isSigner: false;
+          name: "ix";
+          type: {
+            defined: "ResolveDisputeIx";
+          };
         }
       ];
-      args: [];
     },
     {
-      name: "transfer";
+      name: "closeTokenManagerArbiter";
       accounts: [
         {
           name: "tokenManager";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "mint";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "currentHolderTokenAccount";
+          name: "tokenManagerArbiter";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "recipient";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "recipientTokenAccount";
+          name: "payer";
           isMut: true;
           isSigner: false;
-        },
-        {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
         }
       ];
       args: [];
     },
     {
-      name: "send";
+      name: "createSublease";
       accounts: [
         {
           name: "tokenManager";
@@ -674,43 +695,43 @@ export type SolanaNftProgramsTokenManager = {
         },
         {
           name: "mint";
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "mintManager";
+          name: "subleaseTokenManager";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "recipient";
-          isMut: true;
+          name: "approver";
+          isMut: false;
           isSigner: true;
         },
         {
-          name: "recipientTokenAccount";
+          name: "lessorTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "target";
+          name: "lessor";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "targetTokenAccount";
+          name: "lesseeTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "payer";
-          isMut: true;
+          name: "lessee";
+          isMut: false;
           isSigner: true;
         },
         {
-          name: "associatedTokenProgram";
-          isMut: false;
-          isSigner: false;
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
         {
           name: "tokenProgram";
@@ -721,22 +742,19 @@ export type SolanaNftProgramsTokenManager = {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
-        },
-        {
-          name: "rent";
-          isMut: false;
-          isSigner: false;
-        },
+        }
+      ];
+      args: [
         {
-          name: "instructions";
-          isMut: false;
-          isSigner: false;
+          name: "ix";
+          type: {
+            defined: "CreateSubleaseIx";
+          };
         }
       ];
-      args: [];
     },
     {
-      name: "delegate";
+      name: "endSublease";
       accounts: [
         {
           name: "tokenManager";
@@ -749,17 +767,22 @@ export type SolanaNftProgramsTokenManager = {
           isSigner: false;
         },
         {
-          name: "mintManager";
+          name: "subleaseTokenManager";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "recipient";
+          name: "lesseeTokenAccount";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "recipientTokenAccount";
+          name: "lessorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lessor";
           isMut: true;
           isSigner: false;
         },
@@ -772,35 +795,35 @@ export type SolanaNftProgramsTokenManager = {
       args: [];
     },
     {
-      name: "undelegate";
+      name: "createMintManager";
       accounts: [
         {
-          name: "tokenManager";
+          name: "mintManager";
           isMut: true;
           isSigner: false;
         },
         {
           name: "mint";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "mintManager";
-          isMut: true;
-          isSigner: false;
+          name: "freezeAuthority";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "recipient";
+          name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "recipientTokenAccount";
-          isMut: true;
+          name: "tokenProgram";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "tokenProgram";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         }
@@ -808,7 +831,7 @@ export type SolanaNftProgramsTokenManager = {
       args: [];
     },
     {
-      name: "migrate";
+      name: "closeMintManager";
       accounts: [
         {
           name: "mintManager";
@@ -816,24 +839,322 @@ export type SolanaNftProgramsTokenManager = {
           isSigner: false;
         },
         {
-          name: "tokenManager";
+          name: "mint";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenManagerTokenAccount";
-          isMut: true;
-          isSigner: false;
+          name: "freezeAuthority";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "mint";
-          isMut: true;
-          isSigner: false;
+          name: "payer";
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: "mintMetadata";
-          isMut: true;
-          isSigner: false;
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createTransferReceipt";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "transferReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "target";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "updateTransferReceipt";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "transferReceipt";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "target";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "closeTransferReceipt";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "transferReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "transfer";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "currentHolderTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "send";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "target";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "targetTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "delegate";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "undelegate";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrate";
+      accounts: [
+        {
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManagerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
         },
         {
           name: "mintEdition";
@@ -956,6 +1277,70 @@ export type SolanaNftProgramsTokenManager = {
             type: {
               vec: "publicKey";
             };
+          },
+          {
+            name: "disputed";
+            type: {
+              option: "bool";
+            };
+          },
+          {
+            name: "subleased";
+            type: {
+              option: "bool";
+            };
+          },
+          {
+            name: "releasable";
+            type: {
+              option: "bool";
+            };
+          },
+          {
+            name: "parent";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "expiration";
+            type: {
+              option: "i64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "tokenManagerArbiter";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "mintCount";
+            type: "u64";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "arbiter";
+            type: "publicKey";
+          },
+          {
+            name: "payer";
+            type: "publicKey";
+          },
+          {
+            name: "disputedAt";
+            type: {
+              option: "i64";
+            };
           }
         ];
       };
@@ -1016,6 +1401,18 @@ export type SolanaNftProgramsTokenManager = {
           {
             name: "target";
             type: "publicKey";
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "payer";
+            type: {
+              option: "publicKey";
+            };
           }
         ];
       };
@@ -1054,6 +1451,34 @@ export type SolanaNftProgramsTokenManager = {
     }
   ];
   types: [
+    {
+      name: "CreateClaimReceiptWithExpirationIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "target";
+            type: "publicKey";
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "CreateSubleaseIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "expiration";
+            type: "i64";
+          }
+        ];
+      };
+    },
     {
       name: "InitIx";
       type: {
@@ -1078,6 +1503,20 @@ export type SolanaNftProgramsTokenManager = {
         ];
       };
     },
+    {
+      name: "ResolveDisputeIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "invalidationType";
+            type: {
+              option: "u8";
+            };
+          }
+        ];
+      };
+    },
     {
       name: "TokenManagerState";
       type: {
@@ -1345,6 +1784,81 @@ export type SolanaNftProgramsTokenManager = {
       code: 6039;
       name: "InvalidReturnTarget";
       msg: "Invalid return target";
+    },
+    {
+      code: 6040;
+      name: "ClaimReceiptExpired";
+      msg: "Claim receipt has expired";
+    },
+    {
+      code: 6041;
+      name: "ClaimReceiptStillValid";
+      msg: "Claim receipt is neither expired nor stale";
+    },
+    {
+      code: 6042;
+      name: "InvalidClaimReceiptPayer";
+      msg: "Invalid claim receipt payer";
+    },
+    {
+      code: 6043;
+      name: "TokenManagerDisputed";
+      msg: "Token manager is under dispute";
+    },
+    {
+      code: 6044;
+      name: "TokenManagerNotDisputed";
+      msg: "Token manager is not under dispute";
+    },
+    {
+      code: 6045;
+      name: "InvalidArbiter";
+      msg: "Invalid arbiter";
+    },
+    {
+      code: 6046;
+      name: "InvalidDisputeOutcome";
+      msg: "Dispute outcome must be return or release";
+    },
+    {
+      code: 6047;
+      name: "InvalidTokenManagerArbiter";
+      msg: "Invalid token manager arbiter";
+    },
+    {
+      code: 6048;
+      name: "TokenManagerArbiterStillValid";
+      msg: "Token manager arbiter is still in use";
+    },
+    {
+      code: 6049;
+      name: "TokenManagerSubleased";
+      msg: "Token manager is subleased";
+    },
+    {
+      code: 6050;
+      name: "InvalidSublease";
+      msg: "Invalid sublease";
+    },
+    {
+      code: 6051;
+      name: "InvalidSubleaseExpiration";
+      msg: "Invalid sublease expiration";
+    },
+    {
+      code: 6052;
+      name: "SubleaseNotExpired";
+      msg: "Sublease has not expired";
+    },
+    {
+      code: 6053;
+      name: "SubleaseStillValid";
+      msg: "Sublease is still active";
+    },
+    {
+      code: 6054;
+      name: "DisputeOutcomeNotEnforced";
+      msg: "Dispute outcome must be enforced by an invalidation in the same transaction";
     }
   ];
 };
@@ -1362,30 +1876,240 @@ export const IDL: SolanaNftProgramsTokenManager = {
           isSigner: false,
         },
         {
-          name: "mintCounter",
-          isMut: true,
+          name: "mintCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "issuerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "uninit",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "issuerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initMintCounter",
+      accounts: [
+        {
+          name: "mintCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "mint",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "setClaimApprover",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "claimApprover",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "setTransferAuthority",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "transferAuthority",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "addInvalidator",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "invalidator",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "replaceInvalidator",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "newInvalidator",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "createClaimReceipt",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claimApprover",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "claimReceipt",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "target",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "createClaimReceiptWithExpiration",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "mint",
+          name: "claimApprover",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
         },
         {
-          name: "issuer",
+          name: "claimReceipt",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
           name: "payer",
           isMut: true,
           isSigner: true,
         },
-        {
-          name: "issuerTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
         {
           name: "systemProgram",
           isMut: false,
@@ -1396,13 +2120,34 @@ export const IDL: SolanaNftProgramsTokenManager = {
         {
           name: "ix",
           type: {
-            defined: "InitIx",
+            defined: "CreateClaimReceiptWithExpirationIx",
           },
         },
       ],
     },
     {
-      name: "uninit",
+      name: "closeClaimReceipt",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "claimReceipt",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimReceiptMint",
       accounts: [
         {
           name: "tokenManager",
@@ -1415,23 +2160,22 @@ export const IDL: SolanaNftProgramsTokenManager = {
           isSigner: true,
         },
         {
-          name: "issuerTokenAccount",
+          name: "receiptMint",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "receiptMintMetadata",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
+          name: "recipientTokenAccount",
+          isMut: true,
           isSigner: false,
         },
-      ],
-      args: [],
-    },
-    {
-      name: "initMintCounter",
-      accounts: [
         {
-          name: "mintCounter",
+          name: "receiptMintManager",
           isMut: true,
           isSigner: false,
         },
@@ -1440,21 +2184,41 @@ export const IDL: SolanaNftProgramsTokenManager = {
           isMut: true,
           isSigner: true,
         },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedToken",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
       ],
       args: [
         {
-          name: "mint",
-          type: "publicKey",
+          name: "name",
+          type: "string",
         },
       ],
     },
     {
-      name: "setClaimApprover",
+      name: "issue",
       accounts: [
         {
           name: "tokenManager",
@@ -1462,62 +2226,71 @@ export const IDL: SolanaNftProgramsTokenManager = {
           isSigner: false,
         },
         {
-          name: "issuer",
+          name: "tokenManagerTokenAccount",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "claimApprover",
-          type: "publicKey",
+          name: "issuer",
+          isMut: false,
+          isSigner: true,
         },
-      ],
-    },
-    {
-      name: "setTransferAuthority",
-      accounts: [
         {
-          name: "tokenManager",
+          name: "issuerTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "issuer",
+          name: "payer",
           isMut: true,
           isSigner: true,
         },
-      ],
-      args: [
         {
-          name: "transferAuthority",
-          type: "publicKey",
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "addInvalidator",
+      name: "unissue",
       accounts: [
         {
           name: "tokenManager",
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "issuer",
           isMut: true,
           isSigner: true,
         },
-      ],
-      args: [
         {
-          name: "invalidator",
-          type: "publicKey",
+          name: "issuerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "replaceInvalidator",
+      name: "claim",
       accounts: [
         {
           name: "tokenManager",
@@ -1525,56 +2298,86 @@ export const IDL: SolanaNftProgramsTokenManager = {
           isSigner: false,
         },
         {
-          name: "invalidator",
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
           isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "recipient",
+          isMut: true,
           isSigner: true,
         },
-      ],
-      args: [
         {
-          name: "newInvalidator",
-          type: "publicKey",
+          name: "recipientTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "createClaimReceipt",
+      name: "invalidate",
       accounts: [
         {
           name: "tokenManager",
-          isMut: false,
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "claimApprover",
-          isMut: false,
-          isSigner: true,
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "claimReceipt",
+          name: "mint",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "payer",
+          name: "recipientTokenAccount",
           isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "invalidator",
+          isMut: false,
           isSigner: true,
         },
         {
-          name: "systemProgram",
+          name: "collector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "target",
-          type: "publicKey",
+          name: "rent",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "claimReceiptMint",
+      name: "updateInvalidationType",
       accounts: [
         {
           name: "tokenManager",
@@ -1586,66 +2389,94 @@ export const IDL: SolanaNftProgramsTokenManager = {
           isMut: true,
           isSigner: true,
         },
+      ],
+      args: [
         {
-          name: "receiptMint",
-          isMut: true,
-          isSigner: true,
+          name: "invalidationType",
+          type: "u8",
         },
+      ],
+    },
+    {
+      name: "updateInvalidationTypeByInvalidator",
+      accounts: [
         {
-          name: "receiptMintMetadata",
+          name: "tokenManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "recipientTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "invalidator",
+          isMut: false,
+          isSigner: true,
         },
+      ],
+      args: [
         {
-          name: "receiptMintManager",
+          name: "invalidationType",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "allowRelease",
+      accounts: [
+        {
+          name: "tokenManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "payer",
-          isMut: true,
+          name: "invalidator",
+          isMut: false,
           isSigner: true,
         },
         {
-          name: "tokenProgram",
+          name: "issuer",
           isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setArbiter",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "associatedToken",
-          isMut: false,
+          name: "tokenManagerArbiter",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
+          name: "payer",
+          isMut: true,
+          isSigner: true,
         },
         {
-          name: "rent",
+          name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
       ],
       args: [
         {
-          name: "name",
-          type: "string",
+          name: "arbiter",
+          type: "publicKey",
         },
       ],
     },
     {
-      name: "issue",
+      name: "openDispute",
       accounts: [
         {
           name: "tokenManager",
@@ -1653,71 +2484,74 @@ export const IDL: SolanaNftProgramsTokenManager = {
           isSigner: false,
         },
         {
-          name: "tokenManagerTokenAccount",
+          name: "tokenManagerArbiter",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "issuer",
+          name: "arbiter",
           isMut: false,
           isSigner: true,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "resolveDispute",
+      accounts: [
         {
-          name: "issuerTokenAccount",
+          name: "tokenManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "payer",
+          name: "tokenManagerArbiter",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "tokenProgram",
+          name: "arbiter",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
         },
         {
-          name: "systemProgram",
+          name: "instructions",
           isMut: false,
           isSigner: false,
         },
       ],
-      args: [],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "ResolveDisputeIx",
+          },
+        },
+      ],
     },
     {
-      name: "unissue",
+      name: "closeTokenManagerArbiter",
       accounts: [
         {
           name: "tokenManager",
-          isMut: true,
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "tokenManagerTokenAccount",
+          name: "tokenManagerArbiter",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "issuer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "issuerTokenAccount",
+          name: "payer",
           isMut: true,
           isSigner: false,
         },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
       ],
       args: [],
     },
     {
-      name: "claim",
+      name: "createSublease",
       accounts: [
         {
           name: "tokenManager",
@@ -1725,25 +2559,45 @@ export const IDL: SolanaNftProgramsTokenManager = {
           isSigner: false,
         },
         {
-          name: "tokenManagerTokenAccount",
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "subleaseTokenManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "mint",
+          name: "approver",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
         },
         {
-          name: "recipient",
+          name: "lessorTokenAccount",
           isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lessor",
+          isMut: false,
           isSigner: true,
         },
         {
-          name: "recipientTokenAccount",
+          name: "lesseeTokenAccount",
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "lessee",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
         {
           name: "tokenProgram",
           isMut: false,
@@ -1755,10 +2609,17 @@ export const IDL: SolanaNftProgramsTokenManager = {
           isSigner: false,
         },
       ],
-      args: [],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "CreateSubleaseIx",
+          },
+        },
+      ],
     },
     {
-      name: "invalidate",
+      name: "endSublease",
       accounts: [
         {
           name: "tokenManager",
@@ -1766,64 +2627,38 @@ export const IDL: SolanaNftProgramsTokenManager = {
           isSigner: false,
         },
         {
-          name: "tokenManagerTokenAccount",
-          isMut: true,
+          name: "mint",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "mint",
+          name: "subleaseTokenManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "recipientTokenAccount",
+          name: "lesseeTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "invalidator",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "collector",
+          name: "lessorTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
+          name: "lessor",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "rent",
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
       ],
       args: [],
     },
-    {
-      name: "updateInvalidationType",
-      accounts: [
-        {
-          name: "tokenManager",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "issuer",
-          isMut: true,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "invalidationType",
-          type: "u8",
-        },
-      ],
-    },
     {
       name: "createMintManager",
       accounts: [
@@ -2308,6 +3143,70 @@ export const IDL: SolanaNftProgramsTokenManager = {
               vec: "publicKey",
             },
           },
+          {
+            name: "disputed",
+            type: {
+              option: "bool",
+            },
+          },
+          {
+            name: "subleased",
+            type: {
+              option: "bool",
+            },
+          },
+          {
+            name: "releasable",
+            type: {
+              option: "bool",
+            },
+          },
+          {
+            name: "parent",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "expiration",
+            type: {
+              option: "i64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "tokenManagerArbiter",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "mintCount",
+            type: "u64",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "arbiter",
+            type: "publicKey",
+          },
+          {
+            name: "payer",
+            type: "publicKey",
+          },
+          {
+            name: "disputedAt",
+            type: {
+              option: "i64",
+            },
+          },
         ],
       },
     },
@@ -2368,6 +3267,18 @@ export const IDL: SolanaNftProgramsTokenManager = {
             name: "target",
             type: "publicKey",
           },
+          {
+            name: "expiresAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "payer",
+            type: {
+              option: "publicKey",
+            },
+          },
         ],
       },
     },
@@ -2405,6 +3316,34 @@ export const IDL: SolanaNftProgramsTokenManager = {
     },
   ],
   types: [
+    {
+      name: "CreateClaimReceiptWithExpirationIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "target",
+            type: "publicKey",
+          },
+          {
+            name: "expiresAt",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "CreateSubleaseIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "expiration",
+            type: "i64",
+          },
+        ],
+      },
+    },
     {
       name: "InitIx",
       type: {
@@ -2429,6 +3368,20 @@ export const IDL: SolanaNftProgramsTokenManager = {
         ],
       },
     },
+    {
+      name: "ResolveDisputeIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "invalidationType",
+            type: {
+              option: "u8",
+            },
+          },
+        ],
+      },
+    },
     {
       name: "TokenManagerState",
       type: {
@@ -2697,5 +3650,80 @@ export const IDL: SolanaNftProgramsTokenManager = {
       name: "InvalidReturnTarget",
       msg: "Invalid return target",
     },
+    {
+      code: 6040,
+      name: "ClaimReceiptExpired",
+      msg: "Claim receipt has expired",
+    },
+    {
+      code: 6041,
+      name: "ClaimReceiptStillValid",
+      msg: "Claim receipt is neither expired nor stale",
+    },
+    {
+      code: 6042,
+      name: "InvalidClaimReceiptPayer",
+      msg: "Invalid claim receipt payer",
+    },
+    {
+      code: 6043,
+      name: "TokenManagerDisputed",
+      msg: "Token manager is under dispute",
+    },
+    {
+      code: 6044,
+      name: "TokenManagerNotDisputed",
+      msg: "Token manager is not under dispute",
+    },
+    {
+      code: 6045,
+      name: "InvalidArbiter",
+      msg: "Invalid arbiter",
+    },
+    {
+      code: 6046,
+      name: "InvalidDisputeOutcome",
+      msg: "Dispute outcome must be return or release",
+    },
+    {
+      code: 6047,
+      name: "InvalidTokenManagerArbiter",
+      msg: "Invalid token manager arbiter",
+    },
+    {
+      code: 6048,
+      name: "TokenManagerArbiterStillValid",
+      msg: "Token manager arbiter is still in use",
+    },
+    {
+      code: 6049,
+      name: "TokenManagerSubleased",
+      msg: "Token manager is subleased",
+    },
+    {
+      code: 6050,
+      name: "InvalidSublease",
+      msg: "Invalid sublease",
+    },
+    {
+      code: 6051,
+      name: "InvalidSubleaseExpiration",
+      msg: "Invalid sublease expiration",
+    },
+    {
+      code: 6052,
+      name: "SubleaseNotExpired",
+      msg: "Sublease has not expired",
+    },
+    {
+      code: 6053,
+      name: "SubleaseStillValid",
+      msg: "Sublease is still active",
+    },
+    {
+      code: 6054,
+      name: "DisputeOutcomeNotEnforced",
+      msg: "Dispute outcome must be enforced by an invalidation in the same transaction",
+    },
   ],
 };
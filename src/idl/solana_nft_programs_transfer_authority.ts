@@ -42,6 +42,11 @@ export type SolanaNftProgramsTransferAuthority = {
           name: "authority";
           isMut: true;
           isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
@@ -65,6 +70,11 @@ export type SolanaNftProgramsTransferAuthority = {
           name: "authority";
           isMut: true;
           isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
@@ -89,6 +99,11 @@ export type SolanaNftProgramsTransferAuthority = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "auction";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "mint";
           isMut: true;
@@ -132,6 +147,59 @@ export type SolanaNftProgramsTransferAuthority = {
       ];
       args: [];
     },
+    {
+      name: "proposeTransferAuthorityAuthority";
+      accounts: [
+        {
+          name: "transferAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "newAuthority";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "acceptTransferAuthorityAuthority";
+      accounts: [
+        {
+          name: "transferAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "pendingAuthority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeTransferAuthority";
+      accounts: [
+        {
+          name: "transferAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
     {
       name: "createListing";
       accounts: [
@@ -147,7 +215,7 @@ export type SolanaNftProgramsTransferAuthority = {
         },
         {
           name: "marketplace";
-          isMut: false;
+          isMut: true;
           isSigner: false;
         },
         {
@@ -155,6 +223,11 @@ export type SolanaNftProgramsTransferAuthority = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "auction";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "mint";
           isMut: false;
@@ -223,6 +296,16 @@ export type SolanaNftProgramsTransferAuthority = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "marketplace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "newMarketplace";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "listerMintTokenAccount";
           isMut: true;
@@ -261,6 +344,11 @@ export type SolanaNftProgramsTransferAuthority = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "auction";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "listerPaymentTokenAccount";
           isMut: true;
@@ -389,6 +477,11 @@ export type SolanaNftProgramsTransferAuthority = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "marketplace";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "listerMintTokenAccount";
           isMut: true;
@@ -423,23 +516,89 @@ export type SolanaNftProgramsTransferAuthority = {
       args: [];
     },
     {
-      name: "initMarketplace";
+      name: "closeStaleListing";
       accounts: [
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listing";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "marketplace";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "paymentManager";
+          name: "lister";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listerMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createListings";
+      accounts: [
+        {
+          name: "transferAuthority";
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "marketplace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
+          isMut: true;
+          isSigner: true;
+        },
         {
           name: "payer";
           isMut: true;
           isSigner: true;
         },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "systemProgram";
           isMut: false;
@@ -450,21 +609,21 @@ export type SolanaNftProgramsTransferAuthority = {
         {
           name: "ix";
           type: {
-            defined: "InitMarketplaceIx";
+            defined: "CreateListingsIx";
           };
         }
       ];
     },
     {
-      name: "updateMarketplace";
+      name: "updateListings";
       accounts: [
         {
           name: "marketplace";
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "authority";
+          name: "lister";
           isMut: true;
           isSigner: true;
         }
@@ -473,36 +632,75 @@ export type SolanaNftProgramsTransferAuthority = {
         {
           name: "ix";
           type: {
-            defined: "UpdateMarketplaceIx";
+            defined: "UpdateListingsIx";
           };
         }
       ];
     },
     {
-      name: "initTransfer";
+      name: "removeListings";
       accounts: [
         {
-          name: "transfer";
+          name: "lister";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "tokenManager";
+          name: "solanaNftProgramsTokenManager";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "holderTokenAccount";
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initMarketplace";
+      accounts: [
+        {
+          name: "marketplace";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "holder";
+          name: "paymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "payer";
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitMarketplaceIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "updateMarketplace";
+      accounts: [
+        {
+          name: "marketplace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
           isMut: true;
           isSigner: true;
         },
@@ -516,31 +714,58 @@ export type SolanaNftProgramsTransferAuthority = {
         {
           name: "ix";
           type: {
-            defined: "InitTransferIx";
+            defined: "UpdateMarketplaceIx";
           };
         }
       ];
     },
     {
-      name: "cancelTransfer";
+      name: "proposeMarketplaceAuthority";
       accounts: [
         {
-          name: "transfer";
+          name: "marketplace";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenManager";
+          name: "authority";
           isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "newAuthority";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "acceptMarketplaceAuthority";
+      accounts: [
+        {
+          name: "marketplace";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "holderTokenAccount";
+          name: "pendingAuthority";
           isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeMarketplace";
+      accounts: [
+        {
+          name: "marketplace";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "holder";
+          name: "authority";
           isMut: true;
           isSigner: true;
         }
@@ -548,10 +773,10 @@ export type SolanaNftProgramsTransferAuthority = {
       args: [];
     },
     {
-      name: "acceptTransfer";
+      name: "createAuction";
       accounts: [
         {
-          name: "transfer";
+          name: "auction";
           isMut: true;
           isSigner: false;
         },
@@ -561,13 +786,8 @@ export type SolanaNftProgramsTransferAuthority = {
           isSigner: false;
         },
         {
-          name: "transferReceipt";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "listing";
-          isMut: true;
+          name: "marketplace";
+          isMut: false;
           isSigner: false;
         },
         {
@@ -581,12 +801,17 @@ export type SolanaNftProgramsTransferAuthority = {
           isSigner: false;
         },
         {
-          name: "recipientTokenAccount";
+          name: "mintManager";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "recipient";
+          name: "listerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
           isMut: true;
           isSigner: true;
         },
@@ -596,22 +821,70 @@ export type SolanaNftProgramsTransferAuthority = {
           isSigner: true;
         },
         {
-          name: "holderTokenAccount";
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "CreateAuctionIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "placeBid";
+      accounts: [
+        {
+          name: "auction";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "holder";
+          name: "auctionPaymentTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "solanaNftProgramsTokenManager";
+          name: "bidder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "bidderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "previousBidder";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "previousBidderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "associatedTokenProgram";
+          name: "paymentManager";
           isMut: false;
           isSigner: false;
         },
@@ -624,101 +897,899 @@ export type SolanaNftProgramsTransferAuthority = {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "claimAuction";
+      accounts: [
+        {
+          name: "transferAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "auction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "auctionPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listerMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyerMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "buyerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "rent";
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "instructions";
+          name: "mintMetadataInfo";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "feeCollectorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeCollector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsPaymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         }
       ];
       args: [];
-    }
-  ];
-  accounts: [
-    {
-      name: "transferAuthority";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "name";
-            type: "string";
-          },
-          {
-            name: "authority";
-            type: "publicKey";
-          },
-          {
-            name: "allowedMarketplaces";
-            type: {
-              option: {
-                vec: "publicKey";
-              };
-            };
-          }
-        ];
-      };
     },
     {
-      name: "marketplace";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "name";
-            type: "string";
-          },
-          {
-            name: "paymentManager";
-            type: "publicKey";
-          },
-          {
-            name: "authority";
-            type: "publicKey";
-          },
-          {
-            name: "paymentMints";
-            type: {
-              option: {
-                vec: "publicKey";
-              };
-            };
-          }
-        ];
-      };
+      name: "cancelAuction";
+      accounts: [
+        {
+          name: "auction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
     },
     {
-      name: "listing";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "lister";
-            type: "publicKey";
-          },
-          {
-            name: "tokenManager";
-            type: "publicKey";
-          },
-          {
-            name: "marketplace";
+      name: "closeStaleAuction";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "auction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "auctionPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lister";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "topBidder";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "topBidderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createOffer";
+      accounts: [
+        {
+          name: "offer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "offerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "target";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "buyerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "CreateOfferIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "acceptOffer";
+      accounts: [
+        {
+          name: "offer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "holderTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "holder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "claimOffer";
+      accounts: [
+        {
+          name: "transferAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "offer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "offerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "auction";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "holderPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "holderMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "holder";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyerMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "buyerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadataInfo";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "feeCollectorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeCollector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsPaymentManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "cancelOffer";
+      accounts: [
+        {
+          name: "offer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "offerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "buyerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initTransfer";
+      accounts: [
+        {
+          name: "transfer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "holderTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "holder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitTransferIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "cancelTransfer";
+      accounts: [
+        {
+          name: "transfer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "holderTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "holder";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "acceptTransfer";
+      accounts: [
+        {
+          name: "transfer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "transferAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "transferReceipt";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "listing";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "marketplace";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "auction";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "recipientTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "holderTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "holder";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsTokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "rejectTransfer";
+      accounts: [
+        {
+          name: "transfer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "holder";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeExpiredTransfer";
+      accounts: [
+        {
+          name: "transfer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "holder";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "transferAuthority";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "allowedMarketplaces";
+            type: {
+              option: {
+                vec: "publicKey";
+              };
+            };
+          },
+          {
+            name: "issuerShareBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "pendingAuthority";
+            type: {
+              option: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "marketplace";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "paymentManager";
+            type: "publicKey";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "paymentMints";
+            type: {
+              option: {
+                vec: "publicKey";
+              };
+            };
+          },
+          {
+            name: "feeCollector";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "makerFeeBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "takerFeeBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "pendingAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "listingCount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "listing";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "lister";
+            type: "publicKey";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "marketplace";
+            type: "publicKey";
+          },
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "allowedBuyer";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "counted";
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "auction";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "lister";
+            type: "publicKey";
+          },
+          {
+            name: "listerTokenAccount";
+            type: "publicKey";
+          },
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "marketplace";
+            type: "publicKey";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "reservePrice";
+            type: "u64";
+          },
+          {
+            name: "minIncrement";
+            type: "u64";
+          },
+          {
+            name: "endTime";
+            type: "i64";
+          },
+          {
+            name: "topBidder";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "topBid";
+            type: "u64";
+          },
+          {
+            name: "escrowAmount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "offer";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "buyer";
+            type: "publicKey";
+          },
+          {
+            name: "transferAuthority";
+            type: "publicKey";
+          },
+          {
+            name: "tokenManager";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "marketplace";
             type: "publicKey";
           },
           {
@@ -728,6 +1799,28 @@ export type SolanaNftProgramsTransferAuthority = {
           {
             name: "paymentMint";
             type: "publicKey";
+          },
+          {
+            name: "escrowAmount";
+            type: "u64";
+          },
+          {
+            name: "acceptedBy";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "acceptedTokenManager";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "acceptedTokenAccount";
+            type: {
+              option: "publicKey";
+            };
           }
         ];
       };
@@ -752,12 +1845,42 @@ export type SolanaNftProgramsTransferAuthority = {
           {
             name: "to";
             type: "publicKey";
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
           }
         ];
       };
     }
   ];
   types: [
+    {
+      name: "CreateAuctionIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "reservePrice";
+            type: "u64";
+          },
+          {
+            name: "minIncrement";
+            type: "u64";
+          },
+          {
+            name: "endTime";
+            type: "i64";
+          }
+        ];
+      };
+    },
     {
       name: "AcceptListingIx";
       type: {
@@ -766,6 +1889,10 @@ export type SolanaNftProgramsTransferAuthority = {
           {
             name: "paymentAmount";
             type: "u64";
+          },
+          {
+            name: "maxTakerFeeBps";
+            type: "u16";
           }
         ];
       };
@@ -780,8 +1907,52 @@ export type SolanaNftProgramsTransferAuthority = {
             type: "u64";
           },
           {
-            name: "paymentMint";
-            type: "publicKey";
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "allowedBuyer";
+            type: {
+              option: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "CreateListingsIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "listings";
+            type: {
+              vec: {
+                defined: "CreateListingIx";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "UpdateListingsIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "listings";
+            type: {
+              vec: {
+                defined: "UpdateListingIx";
+              };
+            };
           }
         ];
       };
@@ -802,6 +1973,18 @@ export type SolanaNftProgramsTransferAuthority = {
           {
             name: "paymentMint";
             type: "publicKey";
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "allowedBuyer";
+            type: {
+              option: "publicKey";
+            };
           }
         ];
       };
@@ -826,6 +2009,24 @@ export type SolanaNftProgramsTransferAuthority = {
                 vec: "publicKey";
               };
             };
+          },
+          {
+            name: "feeCollector";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "makerFeeBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "takerFeeBps";
+            type: {
+              option: "u16";
+            };
           }
         ];
       };
@@ -839,10 +2040,6 @@ export type SolanaNftProgramsTransferAuthority = {
             name: "paymentManager";
             type: "publicKey";
           },
-          {
-            name: "authority";
-            type: "publicKey";
-          },
           {
             name: "paymentMints";
             type: {
@@ -850,6 +2047,40 @@ export type SolanaNftProgramsTransferAuthority = {
                 vec: "publicKey";
               };
             };
+          },
+          {
+            name: "feeCollector";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "makerFeeBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "takerFeeBps";
+            type: {
+              option: "u16";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "CreateOfferIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
           }
         ];
       };
@@ -862,6 +2093,12 @@ export type SolanaNftProgramsTransferAuthority = {
           {
             name: "to";
             type: "publicKey";
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
           }
         ];
       };
@@ -886,6 +2123,12 @@ export type SolanaNftProgramsTransferAuthority = {
                 vec: "publicKey";
               };
             };
+          },
+          {
+            name: "issuerShareBps";
+            type: {
+              option: "u16";
+            };
           }
         ];
       };
@@ -895,10 +2138,6 @@ export type SolanaNftProgramsTransferAuthority = {
       type: {
         kind: "struct";
         fields: [
-          {
-            name: "authority";
-            type: "publicKey";
-          },
           {
             name: "allowedMarketplaces";
             type: {
@@ -906,6 +2145,12 @@ export type SolanaNftProgramsTransferAuthority = {
                 vec: "publicKey";
               };
             };
+          },
+          {
+            name: "issuerShareBps";
+            type: {
+              option: "u16";
+            };
           }
         ];
       };
@@ -962,125 +2207,1095 @@ export type SolanaNftProgramsTransferAuthority = {
       msg: "Invalid offer token account";
     },
     {
-      code: 6007;
-      name: "InvalidPaymentManager";
-      msg: "Invalid payment manager";
+      code: 6007;
+      name: "InvalidPaymentManager";
+      msg: "Invalid payment manager";
+    },
+    {
+      code: 6008;
+      name: "InvalidMint";
+      msg: "Invalid mint";
+    },
+    {
+      code: 6009;
+      name: "InvalidFeeCollector";
+      msg: "Invalid fee collector";
+    },
+    {
+      code: 6010;
+      name: "InvalidListerPaymentTokenAccount";
+      msg: "Invalid lister payment token account";
+    },
+    {
+      code: 6011;
+      name: "InvalidListerMintTokenAccount";
+      msg: "Invalid lister mint token account";
+    },
+    {
+      code: 6012;
+      name: "InvalidMarketplaceAuthority";
+      msg: "Invalid marketplace authority";
+    },
+    {
+      code: 6013;
+      name: "InvalidTransferAuthorityAuthority";
+      msg: "Invalid transfer authority authority";
+    },
+    {
+      code: 6014;
+      name: "InvalidTransferAuthority";
+      msg: "Invalid transfer authority";
+    },
+    {
+      code: 6015;
+      name: "MarketplaceNotAllowed";
+      msg: "Marketplace place not allowed by transfer authority";
+    },
+    {
+      code: 6016;
+      name: "InvalidHolder";
+      msg: "Invalid token holder";
+    },
+    {
+      code: 6017;
+      name: "InvalidHolderMintTokenAccount";
+      msg: "Invalid holder token account";
+    },
+    {
+      code: 6018;
+      name: "InvalidTransfer";
+      msg: "Invalid transfer account";
+    },
+    {
+      code: 6019;
+      name: "InvalidRecipient";
+      msg: "Invalid recipient";
+    },
+    {
+      code: 6020;
+      name: "InvalidRecipientMintTokenAccount";
+      msg: "Invalid recipient mint token account";
+    },
+    {
+      code: 6021;
+      name: "InvalidDerivation";
+      msg: "Invalid derivation";
+    },
+    {
+      code: 6022;
+      name: "InstructionsDisallowed";
+      msg: "Transaction included disallowed";
+    },
+    {
+      code: 6023;
+      name: "TokenNotDelegated";
+      msg: "Token must be delegated";
+    },
+    {
+      code: 6024;
+      name: "ListingChanged";
+      msg: "Listing payment amount or mint has changed";
+    },
+    {
+      code: 6025;
+      name: "InvalidRemainingAccountsSize";
+      msg: "Invalid remaining accounts size";
+    },
+    {
+      code: 6026;
+      name: "InvalidPayerPaymentTokenAccount";
+      msg: "Invalid payer payment token account";
+    },
+    {
+      code: 6027;
+      name: "InvalidIssuerShare";
+      msg: "Issuer share must be at most 10000 basis points";
+    },
+    {
+      code: 6028;
+      name: "InvalidIssuerPaymentAccount";
+      msg: "Invalid issuer payment account";
+    },
+    {
+      code: 6029;
+      name: "ListingExpired";
+      msg: "Listing has expired";
+    },
+    {
+      code: 6030;
+      name: "InvalidListingExpiration";
+      msg: "Invalid listing expiration";
+    },
+    {
+      code: 6031;
+      name: "ListingNotStale";
+      msg: "Listing is still active";
+    },
+    {
+      code: 6032;
+      name: "InvalidOffer";
+      msg: "Invalid offer";
+    },
+    {
+      code: 6033;
+      name: "InvalidOfferTarget";
+      msg: "Invalid offer target";
+    },
+    {
+      code: 6034;
+      name: "OfferAlreadyAccepted";
+      msg: "Offer has already been accepted";
+    },
+    {
+      code: 6035;
+      name: "OfferNotAccepted";
+      msg: "Offer has not been accepted";
+    },
+    {
+      code: 6036;
+      name: "InvalidAuctionEndTime";
+      msg: "Invalid auction end time";
+    },
+    {
+      code: 6037;
+      name: "AuctionEnded";
+      msg: "Auction has ended";
+    },
+    {
+      code: 6038;
+      name: "AuctionNotEnded";
+      msg: "Auction has not ended";
+    },
+    {
+      code: 6039;
+      name: "BidTooLow";
+      msg: "Bid is below the reserve price or minimum increment";
+    },
+    {
+      code: 6040;
+      name: "InvalidBidder";
+      msg: "Invalid bidder";
+    },
+    {
+      code: 6041;
+      name: "AuctionHasBids";
+      msg: "Auction already has bids";
+    },
+    {
+      code: 6042;
+      name: "AuctionNotStale";
+      msg: "Auction is still active";
+    },
+    {
+      code: 6043;
+      name: "InvalidEscrowTokenAccount";
+      msg: "Invalid escrow token account";
+    },
+    {
+      code: 6044;
+      name: "BuyerNotAllowed";
+      msg: "Buyer is not allowed to accept this listing";
+    },
+    {
+      code: 6045;
+      name: "InvalidMarketplaceFee";
+      msg: "Invalid marketplace fee";
+    },
+    {
+      code: 6046;
+      name: "InvalidMarketplaceFeeCollector";
+      msg: "Invalid marketplace fee collector";
+    },
+    {
+      code: 6047;
+      name: "InvalidPendingAuthority";
+      msg: "Invalid pending authority";
+    },
+    {
+      code: 6048;
+      name: "MarketplaceHasListings";
+      msg: "Marketplace still has open listings";
+    },
+    {
+      code: 6049;
+      name: "TransferExpired";
+      msg: "Transfer has expired";
+    },
+    {
+      code: 6050;
+      name: "InvalidTransferExpiration";
+      msg: "Invalid transfer expiration";
+    },
+    {
+      code: 6051;
+      name: "TransferNotExpired";
+      msg: "Transfer has not expired";
+    },
+    {
+      code: 6052;
+      name: "ListerSignatureRequired";
+      msg: "Lister must sign to undelegate a listed permissioned token";
+    },
+    {
+      code: 6053;
+      name: "AuctionInProgress";
+      msg: "Token has an open auction";
+    },
+    {
+      code: 6054;
+      name: "AuctionSettled";
+      msg: "Auction has already been settled";
+    },
+    {
+      code: 6055;
+      name: "AuctionNotSettled";
+      msg: "Auction has not been settled";
+    },
+    {
+      code: 6056;
+      name: "MarketplaceFeeTooHigh";
+      msg: "Marketplace taker fee is above the accepted maximum";
+    },
+    {
+      code: 6057;
+      name: "PaymentAmountOverflow";
+      msg: "Payment amount overflows the fee calculation";
+    }
+  ];
+};
+
+export const IDL: SolanaNftProgramsTransferAuthority = {
+  version: "1.0.0",
+  name: "solana_nft_programs_transfer_authority",
+  instructions: [
+    {
+      name: "initTransferAuthority",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitTransferAuthorityIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateTransferAuthority",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdateTransferAuthorityIx",
+          },
+        },
+      ],
     },
     {
-      code: 6008;
-      name: "InvalidMint";
-      msg: "Invalid mint";
+      name: "whitelistMarketplaces",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "WhitelistMarketplacesIx",
+          },
+        },
+      ],
     },
     {
-      code: 6009;
-      name: "InvalidFeeCollector";
-      msg: "Invalid fee collector";
+      name: "release",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "auction",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManagerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holderTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "collector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6010;
-      name: "InvalidListerPaymentTokenAccount";
-      msg: "Invalid lister payment token account";
+      name: "proposeTransferAuthorityAuthority",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "newAuthority",
+          type: "publicKey",
+        },
+      ],
     },
     {
-      code: 6011;
-      name: "InvalidListerMintTokenAccount";
-      msg: "Invalid lister mint token account";
+      name: "acceptTransferAuthorityAuthority",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "pendingAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6012;
-      name: "InvalidMarketplaceAuthority";
-      msg: "Invalid marketplace authority";
+      name: "closeTransferAuthority",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6013;
-      name: "InvalidTransferAuthorityAuthority";
-      msg: "Invalid transfer authority authority";
+      name: "createListing",
+      accounts: [
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transferAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "auction",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "CreateListingIx",
+          },
+        },
+      ],
     },
     {
-      code: 6014;
-      name: "InvalidTransferAuthority";
-      msg: "Invalid transfer authority";
+      name: "updateListing",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newMarketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdateListingIx",
+          },
+        },
+      ],
     },
     {
-      code: 6015;
-      name: "MarketplaceNotAllowed";
-      msg: "Marketplace place not allowed by transfer authority";
+      name: "acceptListing",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "transferReceipt",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "auction",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "listerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadataInfo",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeCollector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsPaymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "AcceptListingIx",
+          },
+        },
+      ],
     },
     {
-      code: 6016;
-      name: "InvalidHolder";
-      msg: "Invalid token holder";
+      name: "removeListing",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6017;
-      name: "InvalidHolderMintTokenAccount";
-      msg: "Invalid holder token account";
+      name: "closeStaleListing",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listing",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6018;
-      name: "InvalidTransfer";
-      msg: "Invalid transfer account";
+      name: "createListings",
+      accounts: [
+        {
+          name: "transferAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "CreateListingsIx",
+          },
+        },
+      ],
     },
     {
-      code: 6019;
-      name: "InvalidRecipient";
-      msg: "Invalid recipient";
+      name: "updateListings",
+      accounts: [
+        {
+          name: "marketplace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdateListingsIx",
+          },
+        },
+      ],
     },
     {
-      code: 6020;
-      name: "InvalidRecipientMintTokenAccount";
-      msg: "Invalid recipient mint token account";
+      name: "removeListings",
+      accounts: [
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6021;
-      name: "InvalidDerivation";
-      msg: "Invalid derivation";
+      name: "initMarketplace",
+      accounts: [
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitMarketplaceIx",
+          },
+        },
+      ],
     },
     {
-      code: 6022;
-      name: "InstructionsDisallowed";
-      msg: "Transaction included disallowed";
+      name: "updateMarketplace",
+      accounts: [
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdateMarketplaceIx",
+          },
+        },
+      ],
     },
     {
-      code: 6023;
-      name: "TokenNotDelegated";
-      msg: "Token must be delegated";
+      name: "proposeMarketplaceAuthority",
+      accounts: [
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "newAuthority",
+          type: "publicKey",
+        },
+      ],
     },
     {
-      code: 6024;
-      name: "ListingChanged";
-      msg: "Listing payment amount or mint has changed";
+      name: "acceptMarketplaceAuthority",
+      accounts: [
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "pendingAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6025;
-      name: "InvalidRemainingAccountsSize";
-      msg: "Invalid remaining accounts size";
+      name: "closeMarketplace",
+      accounts: [
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6026;
-      name: "InvalidPayerPaymentTokenAccount";
-      msg: "Invalid payer payment token account";
-    }
-  ];
-};
-
-export const IDL: SolanaNftProgramsTransferAuthority = {
-  version: "1.0.0",
-  name: "solana_nft_programs_transfer_authority",
-  instructions: [
-    {
-      name: "initTransferAuthority",
+      name: "createAuction",
       accounts: [
+        {
+          name: "auction",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "transferAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerTokenAccount",
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
         {
           name: "payer",
           isMut: true,
           isSigner: true,
         },
+        {
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "systemProgram",
           isMut: false,
@@ -1091,36 +3306,74 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
         {
           name: "ix",
           type: {
-            defined: "InitTransferAuthorityIx",
+            defined: "CreateAuctionIx",
           },
         },
       ],
     },
     {
-      name: "updateTransferAuthority",
+      name: "placeBid",
       accounts: [
         {
-          name: "transferAuthority",
+          name: "auction",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "authority",
+          name: "auctionPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "bidder",
           isMut: true,
           isSigner: true,
         },
+        {
+          name: "bidderPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "previousBidder",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "previousBidderPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "marketplace",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
       ],
       args: [
         {
-          name: "ix",
-          type: {
-            defined: "UpdateTransferAuthorityIx",
-          },
+          name: "amount",
+          type: "u64",
         },
       ],
     },
     {
-      name: "whitelistMarketplaces",
+      name: "claimAuction",
       accounts: [
         {
           name: "transferAuthority",
@@ -1128,25 +3381,52 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
           isSigner: false,
         },
         {
-          name: "authority",
+          name: "transferReceipt",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "auction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "auctionPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "listerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lister",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
           isMut: true,
           isSigner: true,
         },
-      ],
-      args: [
         {
-          name: "ix",
-          type: {
-            defined: "WhitelistMarketplacesIx",
-          },
+          name: "buyerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
         },
-      ],
-    },
-    {
-      name: "release",
-      accounts: [
         {
-          name: "transferAuthority",
+          name: "marketplace",
           isMut: false,
           isSigner: false,
         },
@@ -1157,29 +3437,39 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
         },
         {
           name: "mint",
-          isMut: true,
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "tokenManagerTokenAccount",
-          isMut: true,
+          name: "mintMetadataInfo",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "holderTokenAccount",
+          name: "paymentManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "holder",
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "collector",
+          name: "feeCollector",
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "solanaNftProgramsPaymentManager",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "solanaNftProgramsTokenManager",
           isMut: false,
@@ -1191,7 +3481,7 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
           isSigner: false,
         },
         {
-          name: "rent",
+          name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
@@ -1199,70 +3489,112 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
       args: [],
     },
     {
-      name: "createListing",
+      name: "cancelAuction",
       accounts: [
         {
-          name: "listing",
+          name: "auction",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "transferAuthority",
+          name: "lister",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeStaleAuction",
+      accounts: [
+        {
+          name: "tokenManager",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "marketplace",
-          isMut: false,
+          name: "auction",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenManager",
+          name: "auctionPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "mint",
-          isMut: false,
+          name: "lister",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "mintManager",
+          name: "topBidder",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "listerTokenAccount",
+          name: "topBidderPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "lister",
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createOffer",
+      accounts: [
+        {
+          name: "offer",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "payer",
+          name: "offerPaymentTokenAccount",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "solanaNftProgramsTokenManager",
+          name: "transferAuthority",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
+          name: "target",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "marketplace",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "instructions",
+          name: "paymentManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
@@ -1271,46 +3603,59 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
         {
           name: "ix",
           type: {
-            defined: "CreateListingIx",
+            defined: "CreateOfferIx",
           },
         },
       ],
     },
     {
-      name: "updateListing",
+      name: "acceptOffer",
       accounts: [
+        {
+          name: "offer",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "tokenManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "listing",
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "listerMintTokenAccount",
+          name: "holderTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "lister",
+          name: "holder",
           isMut: true,
           isSigner: true,
         },
-      ],
-      args: [
         {
-          name: "ix",
-          type: {
-            defined: "UpdateListingIx",
-          },
+          name: "solanaNftProgramsTokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "acceptListing",
+      name: "claimOffer",
       accounts: [
         {
           name: "transferAuthority",
@@ -1323,42 +3668,47 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
           isSigner: false,
         },
         {
-          name: "listing",
+          name: "offer",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "listerPaymentTokenAccount",
+          name: "offerPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "listerMintTokenAccount",
+          name: "auction",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "holderPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "lister",
+          name: "holderMintTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "buyerMintTokenAccount",
+          name: "holder",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "buyer",
+          name: "buyerMintTokenAccount",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "payer",
+          name: "buyer",
           isMut: true,
           isSigner: true,
         },
         {
-          name: "payerPaymentTokenAccount",
+          name: "buyerPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
@@ -1427,59 +3777,37 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
           isMut: false,
           isSigner: false,
         },
-        {
-          name: "instructions",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "AcceptListingIx",
-          },
-        },
       ],
+      args: [],
     },
     {
-      name: "removeListing",
+      name: "cancelOffer",
       accounts: [
         {
-          name: "tokenManager",
+          name: "offer",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "listing",
-          isMut: true,
+          name: "tokenManager",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "listerMintTokenAccount",
+          name: "offerPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "lister",
+          name: "buyer",
           isMut: true,
           isSigner: true,
         },
         {
-          name: "mint",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "mintManager",
+          name: "buyerPaymentTokenAccount",
           isMut: true,
           isSigner: false,
         },
-        {
-          name: "solanaNftProgramsTokenManager",
-          isMut: false,
-          isSigner: false,
-        },
         {
           name: "tokenProgram",
           isMut: false,
@@ -1488,62 +3816,6 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
       ],
       args: [],
     },
-    {
-      name: "initMarketplace",
-      accounts: [
-        {
-          name: "marketplace",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "paymentManager",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "InitMarketplaceIx",
-          },
-        },
-      ],
-    },
-    {
-      name: "updateMarketplace",
-      accounts: [
-        {
-          name: "marketplace",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: true,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "UpdateMarketplaceIx",
-          },
-        },
-      ],
-    },
     {
       name: "initTransfer",
       accounts: [
@@ -1636,6 +3908,16 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "marketplace",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "auction",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "tokenManager",
           isMut: true,
@@ -1704,6 +3986,43 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
       ],
       args: [],
     },
+    {
+      name: "rejectTransfer",
+      accounts: [
+        {
+          name: "transfer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipient",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "holder",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeExpiredTransfer",
+      accounts: [
+        {
+          name: "transfer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "holder",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
   ],
   accounts: [
     {
@@ -1720,22 +4039,142 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             type: "string",
           },
           {
-            name: "authority",
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "allowedMarketplaces",
+            type: {
+              option: {
+                vec: "publicKey",
+              },
+            },
+          },
+          {
+            name: "issuerShareBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "pendingAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "marketplace",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "paymentManager",
+            type: "publicKey",
+          },
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "paymentMints",
+            type: {
+              option: {
+                vec: "publicKey",
+              },
+            },
+          },
+          {
+            name: "feeCollector",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "makerFeeBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "takerFeeBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "pendingAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "listingCount",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "listing",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "lister",
+            type: "publicKey",
+          },
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "marketplace",
+            type: "publicKey",
+          },
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
             type: "publicKey",
           },
           {
-            name: "allowedMarketplaces",
+            name: "expiresAt",
             type: {
-              option: {
-                vec: "publicKey",
-              },
+              option: "i64",
+            },
+          },
+          {
+            name: "allowedBuyer",
+            type: {
+              option: "publicKey",
             },
           },
+          {
+            name: "counted",
+            type: "bool",
+          },
         ],
       },
     },
     {
-      name: "marketplace",
+      name: "auction",
       type: {
         kind: "struct",
         fields: [
@@ -1744,30 +4183,56 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             type: "u8",
           },
           {
-            name: "name",
-            type: "string",
+            name: "lister",
+            type: "publicKey",
           },
           {
-            name: "paymentManager",
+            name: "listerTokenAccount",
             type: "publicKey",
           },
           {
-            name: "authority",
+            name: "tokenManager",
             type: "publicKey",
           },
           {
-            name: "paymentMints",
+            name: "marketplace",
+            type: "publicKey",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "reservePrice",
+            type: "u64",
+          },
+          {
+            name: "minIncrement",
+            type: "u64",
+          },
+          {
+            name: "endTime",
+            type: "i64",
+          },
+          {
+            name: "topBidder",
             type: {
-              option: {
-                vec: "publicKey",
-              },
+              option: "publicKey",
             },
           },
+          {
+            name: "topBid",
+            type: "u64",
+          },
+          {
+            name: "escrowAmount",
+            type: "u64",
+          },
         ],
       },
     },
     {
-      name: "listing",
+      name: "offer",
       type: {
         kind: "struct",
         fields: [
@@ -1776,13 +4241,19 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             type: "u8",
           },
           {
-            name: "lister",
+            name: "buyer",
             type: "publicKey",
           },
           {
-            name: "tokenManager",
+            name: "transferAuthority",
             type: "publicKey",
           },
+          {
+            name: "tokenManager",
+            type: {
+              option: "publicKey",
+            },
+          },
           {
             name: "marketplace",
             type: "publicKey",
@@ -1795,6 +4266,28 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             name: "paymentMint",
             type: "publicKey",
           },
+          {
+            name: "escrowAmount",
+            type: "u64",
+          },
+          {
+            name: "acceptedBy",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "acceptedTokenManager",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "acceptedTokenAccount",
+            type: {
+              option: "publicKey",
+            },
+          },
         ],
       },
     },
@@ -1819,11 +4312,41 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             name: "to",
             type: "publicKey",
           },
+          {
+            name: "expiresAt",
+            type: {
+              option: "i64",
+            },
+          },
         ],
       },
     },
   ],
   types: [
+    {
+      name: "CreateAuctionIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "reservePrice",
+            type: "u64",
+          },
+          {
+            name: "minIncrement",
+            type: "u64",
+          },
+          {
+            name: "endTime",
+            type: "i64",
+          },
+        ],
+      },
+    },
     {
       name: "AcceptListingIx",
       type: {
@@ -1833,6 +4356,10 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             name: "paymentAmount",
             type: "u64",
           },
+          {
+            name: "maxTakerFeeBps",
+            type: "u16",
+          },
         ],
       },
     },
@@ -1849,6 +4376,50 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             name: "paymentMint",
             type: "publicKey",
           },
+          {
+            name: "expiresAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "allowedBuyer",
+            type: {
+              option: "publicKey",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "CreateListingsIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "listings",
+            type: {
+              vec: {
+                defined: "CreateListingIx",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "UpdateListingsIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "listings",
+            type: {
+              vec: {
+                defined: "UpdateListingIx",
+              },
+            },
+          },
         ],
       },
     },
@@ -1869,6 +4440,18 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             name: "paymentMint",
             type: "publicKey",
           },
+          {
+            name: "expiresAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "allowedBuyer",
+            type: {
+              option: "publicKey",
+            },
+          },
         ],
       },
     },
@@ -1893,6 +4476,24 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
               },
             },
           },
+          {
+            name: "feeCollector",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "makerFeeBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "takerFeeBps",
+            type: {
+              option: "u16",
+            },
+          },
         ],
       },
     },
@@ -1905,10 +4506,6 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             name: "paymentManager",
             type: "publicKey",
           },
-          {
-            name: "authority",
-            type: "publicKey",
-          },
           {
             name: "paymentMints",
             type: {
@@ -1917,6 +4514,40 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
               },
             },
           },
+          {
+            name: "feeCollector",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "makerFeeBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "takerFeeBps",
+            type: {
+              option: "u16",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "CreateOfferIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
         ],
       },
     },
@@ -1929,6 +4560,12 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
             name: "to",
             type: "publicKey",
           },
+          {
+            name: "expiresAt",
+            type: {
+              option: "i64",
+            },
+          },
         ],
       },
     },
@@ -1953,6 +4590,12 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
               },
             },
           },
+          {
+            name: "issuerShareBps",
+            type: {
+              option: "u16",
+            },
+          },
         ],
       },
     },
@@ -1961,10 +4604,6 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
       type: {
         kind: "struct",
         fields: [
-          {
-            name: "authority",
-            type: "publicKey",
-          },
           {
             name: "allowedMarketplaces",
             type: {
@@ -1973,6 +4612,12 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
               },
             },
           },
+          {
+            name: "issuerShareBps",
+            type: {
+              option: "u16",
+            },
+          },
         ],
       },
     },
@@ -2127,5 +4772,160 @@ export const IDL: SolanaNftProgramsTransferAuthority = {
       name: "InvalidPayerPaymentTokenAccount",
       msg: "Invalid payer payment token account",
     },
+    {
+      code: 6027,
+      name: "InvalidIssuerShare",
+      msg: "Issuer share must be at most 10000 basis points",
+    },
+    {
+      code: 6028,
+      name: "InvalidIssuerPaymentAccount",
+      msg: "Invalid issuer payment account",
+    },
+    {
+      code: 6029,
+      name: "ListingExpired",
+      msg: "Listing has expired",
+    },
+    {
+      code: 6030,
+      name: "InvalidListingExpiration",
+      msg: "Invalid listing expiration",
+    },
+    {
+      code: 6031,
+      name: "ListingNotStale",
+      msg: "Listing is still active",
+    },
+    {
+      code: 6032,
+      name: "InvalidOffer",
+      msg: "Invalid offer",
+    },
+    {
+      code: 6033,
+      name: "InvalidOfferTarget",
+      msg: "Invalid offer target",
+    },
+    {
+      code: 6034,
+      name: "OfferAlreadyAccepted",
+      msg: "Offer has already been accepted",
+    },
+    {
+      code: 6035,
+      name: "OfferNotAccepted",
+      msg: "Offer has not been accepted",
+    },
+    {
+      code: 6036,
+      name: "InvalidAuctionEndTime",
+      msg: "Invalid auction end time",
+    },
+    {
+      code: 6037,
+      name: "AuctionEnded",
+      msg: "Auction has ended",
+    },
+    {
+      code: 6038,
+      name: "AuctionNotEnded",
+      msg: "Auction has not ended",
+    },
+    {
+      code: 6039,
+      name: "BidTooLow",
+      msg: "Bid is below the reserve price or minimum increment",
+    },
+    {
+      code: 6040,
+      name: "InvalidBidder",
+      msg: "Invalid bidder",
+    },
+    {
+      code: 6041,
+      name: "AuctionHasBids",
+      msg: "Auction already has bids",
+    },
+    {
+      code: 6042,
+      name: "AuctionNotStale",
+      msg: "Auction is still active",
+    },
+    {
+      code: 6043,
+      name: "InvalidEscrowTokenAccount",
+      msg: "Invalid escrow token account",
+    },
+    {
+      code: 6044,
+      name: "BuyerNotAllowed",
+      msg: "Buyer is not allowed to accept this listing",
+    },
+    {
+      code: 6045,
+      name: "InvalidMarketplaceFee",
+      msg: "Invalid marketplace fee",
+    },
+    {
+      code: 6046,
+      name: "InvalidMarketplaceFeeCollector",
+      msg: "Invalid marketplace fee collector",
+    },
+    {
+      code: 6047,
+      name: "InvalidPendingAuthority",
+      msg: "Invalid pending authority",
+    },
+    {
+      code: 6048,
+      name: "MarketplaceHasListings",
+      msg: "Marketplace still has open listings",
+    },
+    {
+      code: 6049,
+      name: "TransferExpired",
+      msg: "Transfer has expired",
+    },
+    {
+      code: 6050,
+      name: "InvalidTransferExpiration",
+      msg: "Invalid transfer expiration",
+    },
+    {
+      code: 6051,
+      name: "TransferNotExpired",
+      msg: "Transfer has not expired",
+    },
+    {
+      code: 6052,
+      name: "ListerSignatureRequired",
+      msg: "Lister must sign to undelegate a listed permissioned token",
+    },
+    {
+      code: 6053,
+      name: "AuctionInProgress",
+      msg: "Token has an open auction",
+    },
+    {
+      code: 6054,
+      name: "AuctionSettled",
+      msg: "Auction has already been settled",
+    },
+    {
+      code: 6055,
+      name: "AuctionNotSettled",
+      msg: "Auction has not been settled",
+    },
+    {
+      code: 6056,
+      name: "MarketplaceFeeTooHigh",
+      msg: "Marketplace taker fee is above the accepted maximum",
+    },
+    {
+      code: 6057,
+      name: "PaymentAmountOverflow",
+      msg: "Payment amount overflows the fee calculation",
+    },
   ],
 };
@@ -62,6 +62,48 @@ export type SolanaNftProgramsUseInvalidator = {
           name: "user";
           isMut: false;
           isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+          isOptional: true;
+        },
+        {
+          name: "payerTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "paymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "feeCollectorTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "solanaNftProgramsPaymentManager";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
         }
       ];
       args: [
@@ -71,6 +113,34 @@ export type SolanaNftProgramsUseInvalidator = {
         }
       ];
     },
+    {
+      name: "applyUsageAttestations";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "useInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "ApplyUsageAttestationsIx";
+          };
+        }
+      ];
+    },
     {
       name: "extendUsages";
       accounts: [
@@ -127,6 +197,114 @@ export type SolanaNftProgramsUseInvalidator = {
         }
       ];
     },
+    {
+      name: "refillUsages";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "useInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentManager";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeCollectorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "solanaNftProgramsPaymentManager";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "addUseAuthority";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "useInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "useAuthority";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "removeUseAuthority";
+      accounts: [
+        {
+          name: "tokenManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "useInvalidator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "issuer";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "useAuthority";
+          type: "publicKey";
+        }
+      ];
+    },
     {
       name: "invalidate";
       accounts: [
@@ -266,12 +444,130 @@ export type SolanaNftProgramsUseInvalidator = {
             type: {
               option: "u64";
             };
+          },
+          {
+            name: "maxUsagesPerPeriod";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "usagePeriodSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "periodStartedAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "periodUsages";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "refillPeriodSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "refillUsages";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "refillReset";
+            type: {
+              option: "bool";
+            };
+          },
+          {
+            name: "refillPaymentAmount";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "lastRefilledAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "useAuthorities";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "usageNonces";
+            type: {
+              vec: {
+                defined: "UsageNonce";
+              };
+            };
+          },
+          {
+            name: "feePerUse";
+            type: {
+              option: "u64";
+            };
           }
         ];
       };
     }
   ];
   types: [
+    {
+      name: "UsageAttestation";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "tokenManager";
+            type: "publicKey";
+          },
+          {
+            name: "useInvalidator";
+            type: "publicKey";
+          },
+          {
+            name: "mintCount";
+            type: "u64";
+          },
+          {
+            name: "numUsages";
+            type: "u64";
+          },
+          {
+            name: "nonce";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "ApplyUsageAttestationsIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "attestations";
+            type: {
+              vec: {
+                defined: "UsageAttestation";
+              };
+            };
+          }
+        ];
+      };
+    },
     {
       name: "InitIx";
       type: {
@@ -320,6 +616,64 @@ export type SolanaNftProgramsUseInvalidator = {
             type: {
               option: "u64";
             };
+          },
+          {
+            name: "maxUsagesPerPeriod";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "usagePeriodSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "refillPeriodSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "refillUsages";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "refillReset";
+            type: {
+              option: "bool";
+            };
+          },
+          {
+            name: "refillPaymentAmount";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "feePerUse";
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "UsageNonce";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "nonce";
+            type: "u64";
           }
         ];
       };
@@ -405,6 +759,81 @@ export type SolanaNftProgramsUseInvalidator = {
       code: 6015;
       name: "InvalidIssuer";
       msg: "Invalid issuer";
+    },
+    {
+      code: 6016;
+      name: "InvalidFeePerUse";
+      msg: "Fee per use requires an extension payment mint";
+    },
+    {
+      code: 6017;
+      name: "UsageRateLimitExceeded";
+      msg: "Usage limit for the current period exceeded";
+    },
+    {
+      code: 6018;
+      name: "InvalidUsagePeriod";
+      msg: "Invalid usage period";
+    },
+    {
+      code: 6019;
+      name: "RefillNotAvailable";
+      msg: "Refill period has not elapsed";
+    },
+    {
+      code: 6020;
+      name: "InvalidRefillSchedule";
+      msg: "Invalid refill schedule";
+    },
+    {
+      code: 6021;
+      name: "MaxUseAuthoritiesReached";
+      msg: "Max use authorities reached";
+    },
+    {
+      code: 6022;
+      name: "UseAuthorityAlreadyAdded";
+      msg: "Use authority already added";
+    },
+    {
+      code: 6023;
+      name: "InvalidUseAuthority";
+      msg: "Invalid use authority";
+    },
+    {
+      code: 6024;
+      name: "InvalidUsageAttestation";
+      msg: "Invalid usage attestation";
+    },
+    {
+      code: 6025;
+      name: "InvalidUsageNonce";
+      msg: "Usage attestation nonce must be increasing";
+    },
+    {
+      code: 6026;
+      name: "InsufficientPaymentBalance";
+      msg: "Insufficient balance to pay for usages";
+    },
+    {
+      code: 6027;
+      name: "PayPerUseAttestationsDisallowed";
+      msg: "Usage attestations are not supported for pay per use";
+    },
+    {
+      code: 6028;
+      name: "InvalidPayer";
+      msg: "Invalid payer";
+    },
+    {
+      code: 6029;
+      name: "MissingPaymentAccounts";
+      msg: "Missing accounts to pay the fee per use";
+    },
+    {
+      code: 6030;
+      name: "TokenManagerDisputed";
+      msg: "Token manager is under dispute";
     }
   ];
 };
@@ -432,12 +861,113 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
           isSigner: true,
         },
         {
-          name: "payer",
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "incrementUsages",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "useInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+          isOptional: true,
+        },
+        {
+          name: "payerTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "paymentManager",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "paymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "feeCollectorTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "solanaNftProgramsPaymentManager",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: "numUsages",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "applyUsageAttestations",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "useInvalidator",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "instructions",
           isMut: false,
           isSigner: false,
         },
@@ -446,13 +976,13 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
         {
           name: "ix",
           type: {
-            defined: "InitIx",
+            defined: "ApplyUsageAttestationsIx",
           },
         },
       ],
     },
     {
-      name: "incrementUsages",
+      name: "extendUsages",
       accounts: [
         {
           name: "tokenManager",
@@ -465,25 +995,50 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
           isSigner: false,
         },
         {
-          name: "recipientTokenAccount",
+          name: "paymentManager",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeCollectorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "user",
+          name: "solanaNftProgramsPaymentManager",
           isMut: false,
-          isSigner: true,
+          isSigner: false,
         },
       ],
       args: [
         {
-          name: "numUsages",
+          name: "paymentAmount",
           type: "u64",
         },
       ],
     },
     {
-      name: "extendUsages",
+      name: "refillUsages",
       accounts: [
         {
           name: "tokenManager",
@@ -531,10 +1086,62 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
           isSigner: false,
         },
       ],
+      args: [],
+    },
+    {
+      name: "addUseAuthority",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "useInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
       args: [
         {
-          name: "paymentAmount",
-          type: "u64",
+          name: "useAuthority",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "removeUseAuthority",
+      accounts: [
+        {
+          name: "tokenManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "useInvalidator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "issuer",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "useAuthority",
+          type: "publicKey",
         },
       ],
     },
@@ -678,11 +1285,129 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
               option: "u64",
             },
           },
+          {
+            name: "maxUsagesPerPeriod",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "usagePeriodSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "periodStartedAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "periodUsages",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "refillPeriodSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "refillUsages",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "refillReset",
+            type: {
+              option: "bool",
+            },
+          },
+          {
+            name: "refillPaymentAmount",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "lastRefilledAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "useAuthorities",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "usageNonces",
+            type: {
+              vec: {
+                defined: "UsageNonce",
+              },
+            },
+          },
+          {
+            name: "feePerUse",
+            type: {
+              option: "u64",
+            },
+          },
         ],
       },
     },
   ],
   types: [
+    {
+      name: "UsageAttestation",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "tokenManager",
+            type: "publicKey",
+          },
+          {
+            name: "useInvalidator",
+            type: "publicKey",
+          },
+          {
+            name: "mintCount",
+            type: "u64",
+          },
+          {
+            name: "numUsages",
+            type: "u64",
+          },
+          {
+            name: "nonce",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "ApplyUsageAttestationsIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "attestations",
+            type: {
+              vec: {
+                defined: "UsageAttestation",
+              },
+            },
+          },
+        ],
+      },
+    },
     {
       name: "InitIx",
       type: {
@@ -732,6 +1457,64 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
               option: "u64",
             },
           },
+          {
+            name: "maxUsagesPerPeriod",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "usagePeriodSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "refillPeriodSeconds",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "refillUsages",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "refillReset",
+            type: {
+              option: "bool",
+            },
+          },
+          {
+            name: "refillPaymentAmount",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "feePerUse",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "UsageNonce",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "nonce",
+            type: "u64",
+          },
         ],
       },
     },
@@ -817,5 +1600,80 @@ export const IDL: SolanaNftProgramsUseInvalidator = {
       name: "InvalidIssuer",
       msg: "Invalid issuer",
     },
+    {
+      code: 6016,
+      name: "InvalidFeePerUse",
+      msg: "Fee per use requires an extension payment mint",
+    },
+    {
+      code: 6017,
+      name: "UsageRateLimitExceeded",
+      msg: "Usage limit for the current period exceeded",
+    },
+    {
+      code: 6018,
+      name: "InvalidUsagePeriod",
+      msg: "Invalid usage period",
+    },
+    {
+      code: 6019,
+      name: "RefillNotAvailable",
+      msg: "Refill period has not elapsed",
+    },
+    {
+      code: 6020,
+      name: "InvalidRefillSchedule",
+      msg: "Invalid refill schedule",
+    },
+    {
+      code: 6021,
+      name: "MaxUseAuthoritiesReached",
+      msg: "Max use authorities reached",
+    },
+    {
+      code: 6022,
+      name: "UseAuthorityAlreadyAdded",
+      msg: "Use authority already added",
+    },
+    {
+      code: 6023,
+      name: "InvalidUseAuthority",
+      msg: "Invalid use authority",
+    },
+    {
+      code: 6024,
+      name: "InvalidUsageAttestation",
+      msg: "Invalid usage attestation",
+    },
+    {
+      code: 6025,
+      name: "InvalidUsageNonce",
+      msg: "Usage attestation nonce must be increasing",
+    },
+    {
+      code: 6026,
+      name: "InsufficientPaymentBalance",
+      msg: "Insufficient balance to pay for usages",
+    },
+    {
+      code: 6027,
+      name: "PayPerUseAttestationsDisallowed",
+      msg: "Usage attestations are not supported for pay per use",
+    },
+    {
+      code: 6028,
+      name: "InvalidPayer",
+      msg: "Invalid payer",
+    },
+    {
+      code: 6029,
+      name: "MissingPaymentAccounts",
+      msg: "Missing accounts to pay the fee per use",
+    },
+    {
+      code: 6030,
+      name: "TokenManagerDisputed",
+      msg: "Token manager is under dispute",
+    },
   ],
 };
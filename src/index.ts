@@ -1,5 +1,6 @@
 export * from "./api";
 export * as claimLinks from "./claimLinks";
+export * from "./invalidators";
 export * from "./marketplace";
 export * as programs from "./programs";
 export * as rentals from "./rentals";
@@ -0,0 +1,616 @@
+import { BN } from "@coral-xyz/anchor";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import {
+  Metadata,
+  TokenStandard,
+} from "@metaplex-foundation/mpl-token-metadata";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import type {
+  AccountMeta,
+  Connection,
+  PublicKey,
+  Transaction,
+} from "@solana/web3.js";
+import {
+  ComputeBudgetProgram,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import type { AccountData } from "@solana-nft-programs/common";
+import {
+  findMintMetadataId,
+  tryNull,
+  withFindOrInitAssociatedTokenAccount,
+} from "@solana-nft-programs/common";
+import { PAYMENT_MANAGER_ADDRESS } from "@solana-nft-programs/payment-manager";
+import { withRemainingAccountsForPayment } from "@solana-nft-programs/payment-manager/dist/cjs/utils";
+
+import { defaultPaymentManagerId } from "./programs/claimApprover";
+import type {
+  Condition,
+  ConditionInvalidationParams,
+} from "./programs/conditionInvalidator";
+import { conditionInvalidatorProgram } from "./programs/conditionInvalidator";
+import { getConditionInvalidator } from "./programs/conditionInvalidator/accounts";
+import { findConditionInvalidatorAddress } from "./programs/conditionInvalidator/pda";
+import { conditionAccountIds } from "./programs/conditionInvalidator/utils";
+import type { InstallmentInvalidationParams } from "./programs/installmentInvalidator";
+import { installmentInvalidatorProgram } from "./programs/installmentInvalidator";
+import { getInstallmentInvalidator } from "./programs/installmentInvalidator/accounts";
+import { findInstallmentInvalidatorAddress } from "./programs/installmentInvalidator/pda";
+import type { TimeUseInvalidationParams } from "./programs/timeUseInvalidator";
+import {
+  ConditionMode,
+  timeUseInvalidatorProgram,
+} from "./programs/timeUseInvalidator";
+import { getTimeUseInvalidator } from "./programs/timeUseInvalidator/accounts";
+import { findTimeUseInvalidatorAddress } from "./programs/timeUseInvalidator/pda";
+import type { TokenManagerData } from "./programs/tokenManager";
+import {
+  CRANK_KEY,
+  InvalidationType,
+  TOKEN_MANAGER_ADDRESS,
+  TokenManagerKind,
+} from "./programs/tokenManager";
+import { getTokenManager } from "./programs/tokenManager/accounts";
+import { tokenManagerAddressFromMint } from "./programs/tokenManager/pda";
+import { withRemainingAccountsForInvalidate } from "./programs/tokenManager/utils";
+
+/**
+ * Accounts shared by the invalidate instruction of every invalidator plugin
+ * @param transaction
+ * @param connection
+ * @param wallet
+ * @param mintId
+ * @param invalidationType invalidation type the token manager will have once the invalidator invalidates it
+ * @returns Token manager, its token account and the remaining accounts for the token manager invalidate
+ */
+const withInvalidatorInvalidateAccounts = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  invalidationType?: InvalidationType
+): Promise<{
+  tokenManagerData: AccountData<TokenManagerData>;
+  tokenManagerTokenAccountId: PublicKey;
+  remainingAccounts: AccountMeta[];
+}> => {
+  const tokenManagerId = tokenManagerAddressFromMint(mintId);
+  const [tokenManagerData, metadata] = await Promise.all([
+    getTokenManager(connection, tokenManagerId),
+    tryNull(
+      Metadata.fromAccountAddress(connection, findMintMetadataId(mintId))
+    ),
+  ]);
+  if (invalidationType !== undefined) {
+    tokenManagerData.parsed.invalidationType = invalidationType;
+  }
+  if (
+    tokenManagerData.parsed.kind === TokenManagerKind.Programmable ||
+    metadata?.tokenStandard === TokenStandard.ProgrammableNonFungible
+  ) {
+    transaction.add(
+      ComputeBudgetProgram.setComputeUnitLimit({
+        units: 1000000,
+      })
+    );
+  }
+
+  const recipientTokenAccount = await getAccount(
+    connection,
+    tokenManagerData.parsed.recipientTokenAccount
+  );
+  const tokenManagerTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    mintId,
+    tokenManagerId,
+    wallet.publicKey,
+    true
+  );
+  const remainingAccounts = await withRemainingAccountsForInvalidate(
+    transaction,
+    connection,
+    wallet,
+    mintId,
+    tokenManagerData,
+    recipientTokenAccount.owner,
+    metadata
+  );
+  return { tokenManagerData, tokenManagerTokenAccountId, remainingAccounts };
+};
+
+/**
+ * Initializes a time use invalidator, the token manager must still be initialized
+ * and list the time use invalidator in its invalidators
+ * @param transaction
+ * @param connection
+ * @param wallet
+ * @param tokenManagerId
+ * @param params
+ * @param payer
+ * @returns Transaction and the time use invalidator id
+ */
+export const withInitTimeUseInvalidator = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  params: TimeUseInvalidationParams,
+  payer = wallet.publicKey
+): Promise<[Transaction, PublicKey]> => {
+  const timeUseInvalidatorId = findTimeUseInvalidatorAddress(tokenManagerId);
+  const initIx = await timeUseInvalidatorProgram(connection, wallet)
+    .methods.init({
+      collector: params.collector ?? CRANK_KEY,
+      paymentManager: params.paymentManager ?? defaultPaymentManagerId,
+      mode: params.mode ?? ConditionMode.Or,
+      durationSeconds:
+        params.durationSeconds !== undefined
+          ? new BN(params.durationSeconds)
+          : null,
+      maxExpiration:
+        params.maxExpiration !== undefined
+          ? new BN(params.maxExpiration)
+          : null,
+      totalUsages:
+        params.totalUsages !== undefined ? new BN(params.totalUsages) : null,
+      maxUsages:
+        params.maxUsages !== undefined ? new BN(params.maxUsages) : null,
+      useAuthority: params.useAuthority ?? null,
+      extensionPaymentAmount: params.extension
+        ? new BN(params.extension.extensionPaymentAmount)
+        : null,
+      extensionPaymentMint: params.extension?.extensionPaymentMint ?? null,
+      extensionDurationSeconds:
+        params.extension?.extensionDurationSeconds !== undefined
+          ? new BN(params.extension.extensionDurationSeconds)
+          : null,
+      extensionUsages:
+        params.extension?.extensionUsages !== undefined
+          ? new BN(params.extension.extensionUsages)
+          : null,
+    })
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      timeUseInvalidator: timeUseInvalidatorId,
+      issuer: wallet.publicKey,
+      payer,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(initIx);
+  return [transaction, timeUseInvalidatorId];
+};
+
+export const withTimeUseIncrementUsages = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  numUsages: number
+): Promise<Transaction> => {
+  const tokenManagerData = await getTokenManager(connection, tokenManagerId);
+  const incrementUsagesIx = await timeUseInvalidatorProgram(connection, wallet)
+    .methods.incrementUsages(new BN(numUsages))
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      timeUseInvalidator: findTimeUseInvalidatorAddress(tokenManagerId),
+      recipientTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      user: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(incrementUsagesIx);
+  return transaction;
+};
+
+export const withTimeUseExtend = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  numExtensions: number,
+  buySideTokenAccountId?: PublicKey
+): Promise<Transaction> => {
+  const timeUseInvalidatorId = findTimeUseInvalidatorAddress(tokenManagerId);
+  const [timeUseInvalidatorData, tokenManagerData] = await Promise.all([
+    getTimeUseInvalidator(connection, timeUseInvalidatorId),
+    getTokenManager(connection, tokenManagerId),
+  ]);
+  const extensionPaymentMint =
+    timeUseInvalidatorData.parsed.extensionPaymentMint;
+  if (!extensionPaymentMint) throw "Time use invalidator has no extension";
+
+  const payerTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    extensionPaymentMint,
+    wallet.publicKey,
+    wallet.publicKey
+  );
+  const [paymentTokenAccountId, feeCollectorTokenAccountId, remainingAccounts] =
+    await withRemainingAccountsForPayment(
+      transaction,
+      connection,
+      wallet,
+      tokenManagerData.parsed.mint,
+      extensionPaymentMint,
+      tokenManagerData.parsed.issuer,
+      timeUseInvalidatorData.parsed.paymentManager,
+      buySideTokenAccountId,
+      {
+        receiptMint: tokenManagerData.parsed.receiptMint,
+      }
+    );
+
+  const extendIx = await timeUseInvalidatorProgram(connection, wallet)
+    .methods.extend(new BN(numExtensions))
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      timeUseInvalidator: timeUseInvalidatorId,
+      paymentManager: timeUseInvalidatorData.parsed.paymentManager,
+      paymentTokenAccount: paymentTokenAccountId,
+      feeCollectorTokenAccount: feeCollectorTokenAccountId,
+      payer: wallet.publicKey,
+      payerTokenAccount: payerTokenAccountId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  transaction.add(extendIx);
+  return transaction;
+};
+
+/**
+ * Invalidates a token manager through its time use invalidator and closes the invalidator
+ * @param transaction
+ * @param connection
+ * @param wallet
+ * @param mintId
+ * @returns Transaction
+ */
+export const withTimeUseInvalidate = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const program = timeUseInvalidatorProgram(connection, wallet);
+  const tokenManagerId = tokenManagerAddressFromMint(mintId);
+  const timeUseInvalidatorId = findTimeUseInvalidatorAddress(tokenManagerId);
+  const timeUseInvalidatorData = await getTimeUseInvalidator(
+    connection,
+    timeUseInvalidatorId
+  );
+  const { tokenManagerData, tokenManagerTokenAccountId, remainingAccounts } =
+    await withInvalidatorInvalidateAccounts(
+      transaction,
+      connection,
+      wallet,
+      mintId
+    );
+
+  const invalidateIx = await program.methods
+    .invalidate()
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      timeUseInvalidator: timeUseInvalidatorId,
+      invalidator: wallet.publicKey,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenManagerTokenAccount: tokenManagerTokenAccountId,
+      mint: mintId,
+      recipientTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  transaction.add(invalidateIx);
+
+  const closeIx = await program.methods
+    .close()
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      timeUseInvalidator: timeUseInvalidatorId,
+      collector: timeUseInvalidatorData.parsed.collector,
+      closer: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(closeIx);
+  return transaction;
+};
+
+/**
+ * Initializes a condition invalidator, the token manager must still be initialized
+ * and list the condition invalidator in its invalidators
+ * @param transaction
+ * @param connection
+ * @param wallet
+ * @param tokenManagerId
+ * @param params
+ * @param payer
+ * @returns Transaction and the condition invalidator id
+ */
+export const withInitConditionInvalidator = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  params: ConditionInvalidationParams,
+  payer = wallet.publicKey
+): Promise<[Transaction, PublicKey]> => {
+  const conditionInvalidatorId =
+    findConditionInvalidatorAddress(tokenManagerId);
+  const initIx = await conditionInvalidatorProgram(connection, wallet)
+    .methods.init({
+      collector: params.collector ?? CRANK_KEY,
+      useAuthority: params.useAuthority ?? null,
+      conditions: params.conditions,
+    })
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      conditionInvalidator: conditionInvalidatorId,
+      issuer: wallet.publicKey,
+      payer,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(initIx);
+  return [transaction, conditionInvalidatorId];
+};
+
+export const withConditionIncrementUsages = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  numUsages: number
+): Promise<Transaction> => {
+  const tokenManagerData = await getTokenManager(connection, tokenManagerId);
+  const incrementUsagesIx = await conditionInvalidatorProgram(
+    connection,
+    wallet
+  )
+    .methods.incrementUsages(new BN(numUsages))
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      conditionInvalidator: findConditionInvalidatorAddress(tokenManagerId),
+      recipientTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      user: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(incrementUsagesIx);
+  return transaction;
+};
+
+/**
+ * Invalidates a token manager through its condition invalidator and closes the invalidator
+ * @param transaction
+ * @param connection
+ * @param wallet
+ * @param mintId
+ * @returns Transaction
+ */
+export const withConditionInvalidate = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const program = conditionInvalidatorProgram(connection, wallet);
+  const tokenManagerId = tokenManagerAddressFromMint(mintId);
+  const conditionInvalidatorId =
+    findConditionInvalidatorAddress(tokenManagerId);
+  const conditionInvalidatorData = await getConditionInvalidator(
+    connection,
+    conditionInvalidatorId
+  );
+  const { tokenManagerData, tokenManagerTokenAccountId, remainingAccounts } =
+    await withInvalidatorInvalidateAccounts(
+      transaction,
+      connection,
+      wallet,
+      mintId
+    );
+
+  const conditionAccounts = conditionAccountIds(
+    conditionInvalidatorData.parsed.conditions as Condition[]
+  ).map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }));
+  const invalidateIx = await program.methods
+    .invalidate()
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      conditionInvalidator: conditionInvalidatorId,
+      invalidator: wallet.publicKey,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenManagerTokenAccount: tokenManagerTokenAccountId,
+      mint: mintId,
+      recipientTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .remainingAccounts([...conditionAccounts, ...remainingAccounts])
+    .instruction();
+  transaction.add(invalidateIx);
+
+  const closeIx = await program.methods
+    .close()
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      conditionInvalidator: conditionInvalidatorId,
+      collector: conditionInvalidatorData.parsed.collector,
+      closer: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(closeIx);
+  return transaction;
+};
+
+/**
+ * Initializes an installment invalidator, the token manager must still be initialized
+ * with return invalidation and list the installment invalidator as its only invalidator
+ * @param transaction
+ * @param connection
+ * @param wallet
+ * @param tokenManagerId
+ * @param params
+ * @param payer
+ * @returns Transaction and the installment invalidator id
+ */
+export const withInitInstallmentInvalidator = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  params: InstallmentInvalidationParams,
+  payer = wallet.publicKey
+): Promise<[Transaction, PublicKey]> => {
+  const installmentInvalidatorId =
+    findInstallmentInvalidatorAddress(tokenManagerId);
+  const initIx = await installmentInvalidatorProgram(connection, wallet)
+    .methods.init({
+      collector: params.collector ?? CRANK_KEY,
+      paymentManager: params.paymentManager ?? defaultPaymentManagerId,
+      paymentMint: params.paymentMint,
+      installmentAmount: new BN(params.installmentAmount),
+      installmentPeriodSeconds: new BN(params.installmentPeriodSeconds),
+      numInstallments: new BN(params.numInstallments),
+    })
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      installmentInvalidator: installmentInvalidatorId,
+      issuer: wallet.publicKey,
+      payer,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(initIx);
+  return [transaction, installmentInvalidatorId];
+};
+
+export const withPayInstallment = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  buySideTokenAccountId?: PublicKey
+): Promise<Transaction> => {
+  const installmentInvalidatorId =
+    findInstallmentInvalidatorAddress(tokenManagerId);
+  const [installmentInvalidatorData, tokenManagerData] = await Promise.all([
+    getInstallmentInvalidator(connection, installmentInvalidatorId),
+    getTokenManager(connection, tokenManagerId),
+  ]);
+  const paymentMint = installmentInvalidatorData.parsed.paymentMint;
+
+  const payerTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    paymentMint,
+    wallet.publicKey,
+    wallet.publicKey
+  );
+  const [paymentTokenAccountId, feeCollectorTokenAccountId, remainingAccounts] =
+    await withRemainingAccountsForPayment(
+      transaction,
+      connection,
+      wallet,
+      tokenManagerData.parsed.mint,
+      paymentMint,
+      tokenManagerData.parsed.issuer,
+      installmentInvalidatorData.parsed.paymentManager,
+      buySideTokenAccountId,
+      {
+        receiptMint: tokenManagerData.parsed.receiptMint,
+      }
+    );
+
+  const payInstallmentIx = await installmentInvalidatorProgram(
+    connection,
+    wallet
+  )
+    .methods.payInstallment()
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      installmentInvalidator: installmentInvalidatorId,
+      paymentManager: installmentInvalidatorData.parsed.paymentManager,
+      paymentTokenAccount: paymentTokenAccountId,
+      feeCollectorTokenAccount: feeCollectorTokenAccountId,
+      payer: wallet.publicKey,
+      payerTokenAccount: payerTokenAccountId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  transaction.add(payInstallmentIx);
+  return transaction;
+};
+
+/**
+ * Invalidates a token manager through its installment invalidator and closes the invalidator,
+ * releasing the token to the renter once every installment is paid and returning it otherwise
+ * @param transaction
+ * @param connection
+ * @param wallet
+ * @param mintId
+ * @returns Transaction
+ */
+export const withInstallmentInvalidate = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const program = installmentInvalidatorProgram(connection, wallet);
+  const tokenManagerId = tokenManagerAddressFromMint(mintId);
+  const installmentInvalidatorId =
+    findInstallmentInvalidatorAddress(tokenManagerId);
+  const installmentInvalidatorData = await getInstallmentInvalidator(
+    connection,
+    installmentInvalidatorId
+  );
+  const paidOff = installmentInvalidatorData.parsed.installmentsPaid.gte(
+    installmentInvalidatorData.parsed.numInstallments
+  );
+  const { tokenManagerData, tokenManagerTokenAccountId, remainingAccounts } =
+    await withInvalidatorInvalidateAccounts(
+      transaction,
+      connection,
+      wallet,
+      mintId,
+      paidOff ? InvalidationType.Release : undefined
+    );
+
+  const invalidateIx = await program.methods
+    .invalidate()
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      installmentInvalidator: installmentInvalidatorId,
+      invalidator: wallet.publicKey,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      tokenManagerTokenAccount: tokenManagerTokenAccountId,
+      mint: mintId,
+      recipientTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  transaction.add(invalidateIx);
+
+  const closeIx = await program.methods
+    .close()
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      installmentInvalidator: installmentInvalidatorId,
+      collector: installmentInvalidatorData.parsed.collector,
+      closer: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(closeIx);
+  return transaction;
+};
@@ -1,22 +1,27 @@
 import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
 import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import { Metadata } from "@metaplex-foundation/mpl-token-metadata";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAccount,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import type { AccountMeta, Connection, Transaction } from "@solana/web3.js";
 import {
+  ComputeBudgetProgram,
   PublicKey,
   SystemProgram,
   SYSVAR_INSTRUCTIONS_PUBKEY,
   SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
+import type { AccountData } from "@solana-nft-programs/common";
 import {
   emptyWallet,
   findAta,
   findMintEditionId,
   findMintMetadataId,
   tryGetAccount,
+  tryNull,
   withFindOrInitAssociatedTokenAccount,
   withWrapSol,
 } from "@solana-nft-programs/common";
@@ -26,9 +31,11 @@ import { findPaymentManagerAddress } from "@solana-nft-programs/payment-manager/
 import { withRemainingAccountsForHandlePaymentWithRoyalties } from "@solana-nft-programs/payment-manager/dist/cjs/utils";
 import type BN from "bn.js";
 
+import type { TokenManagerData } from "./programs/tokenManager";
 import {
   getRemainingAccountsForKind,
   InvalidationType,
+  remainingAccountForProgrammableTransfer,
   TOKEN_MANAGER_ADDRESS,
   TokenManagerKind,
   tokenManagerProgram,
@@ -40,17 +47,24 @@ import {
   findTokenManagerAddress,
   findTransferReceiptId,
 } from "./programs/tokenManager/pda";
+import type { MarketplaceData } from "./programs/transferAuthority";
 import {
   transferAuthorityProgram,
   WSOL_MINT,
 } from "./programs/transferAuthority";
 import {
+  getAuction,
   getListing,
   getMarketplace,
+  getOffer,
+  getTransfer,
+  getTransferAuthority,
 } from "./programs/transferAuthority/accounts";
 import {
+  findAuctionAddress,
   findListingAddress,
   findMarketplaceAddress,
+  findOfferAddress,
   findTransferAddress,
   findTransferAuthorityAddress,
 } from "./programs/transferAuthority/pda";
@@ -140,7 +154,8 @@ export const withInitTransferAuthority = async (
   name: string,
   authority = wallet.publicKey,
   payer = wallet.publicKey,
-  allowedMarketplaces?: PublicKey[]
+  allowedMarketplaces?: PublicKey[],
+  issuerShareBps?: number
 ): Promise<[Transaction, PublicKey]> => {
   const transferAuthProgram = transferAuthorityProgram(connection, wallet);
   const transferAuthorityId = findTransferAuthorityAddress(name);
@@ -150,6 +165,7 @@ export const withInitTransferAuthority = async (
       name: name,
       authority: authority,
       allowedMarketplaces: allowedMarketplaces ?? null,
+      issuerShareBps: issuerShareBps ?? null,
     })
     .accounts({
       transferAuthority: transferAuthorityId,
@@ -166,26 +182,94 @@ export const withUpdateTransferAuthority = async (
   connection: Connection,
   wallet: Wallet,
   name: string,
-  authority: PublicKey,
-  allowedMarketplaces?: PublicKey[]
+  allowedMarketplaces?: PublicKey[],
+  issuerShareBps?: number
 ): Promise<Transaction> => {
   const transferAuthProgram = transferAuthorityProgram(connection, wallet);
   const transferAuthorityId = findTransferAuthorityAddress(name);
 
   const updateTransferAuthorityIx = await transferAuthProgram.methods
     .updateTransferAuthority({
-      authority: authority,
-      allowedMarketplaces: allowedMarketplaces,
+      allowedMarketplaces: allowedMarketplaces ?? null,
+      issuerShareBps: issuerShareBps ?? null,
     })
     .accounts({
       transferAuthority: transferAuthorityId,
-      authority: authority,
+      authority: wallet.publicKey,
+      systemProgram: SystemProgram.programId,
     })
     .instruction();
   transaction.add(updateTransferAuthorityIx);
   return transaction;
 };
 
+export const withProposeTransferAuthorityAuthority = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  name: string,
+  newAuthority: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const transferAuthorityId = findTransferAuthorityAddress(name);
+
+  const proposeTransferAuthorityAuthorityIx = await transferAuthProgram.methods
+    .proposeTransferAuthorityAuthority(newAuthority)
+    .accounts({
+      transferAuthority: transferAuthorityId,
+      authority: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(proposeTransferAuthorityAuthorityIx);
+  return transaction;
+};
+
+export const withAcceptTransferAuthorityAuthority = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  name: string
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const transferAuthorityId = findTransferAuthorityAddress(name);
+
+  const acceptTransferAuthorityAuthorityIx = await transferAuthProgram.methods
+    .acceptTransferAuthorityAuthority()
+    .accounts({
+      transferAuthority: transferAuthorityId,
+      pendingAuthority: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(acceptTransferAuthorityAuthorityIx);
+  return transaction;
+};
+
+export const withCloseTransferAuthority = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  name: string
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const transferAuthorityId = findTransferAuthorityAddress(name);
+
+  const closeTransferAuthorityIx = await transferAuthProgram.methods
+    .closeTransferAuthority()
+    .accounts({
+      transferAuthority: transferAuthorityId,
+      authority: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(closeTransferAuthorityIx);
+  return transaction;
+};
+
+export type MarketplaceFees = {
+  feeCollector: PublicKey;
+  makerFeeBps: number;
+  takerFeeBps: number;
+};
+
 export const withInitMarketplace = async (
   transaction: Transaction,
   connection: Connection,
@@ -193,7 +277,8 @@ export const withInitMarketplace = async (
   name: string,
   paymentManagerName: string,
   paymentMints?: PublicKey[],
-  payer = wallet.publicKey
+  payer = wallet.publicKey,
+  fees?: MarketplaceFees
 ): Promise<[Transaction, PublicKey]> => {
   const transferAuthProgram = transferAuthorityProgram(connection, wallet);
   const marketplaceId = findMarketplaceAddress(name);
@@ -204,6 +289,9 @@ export const withInitMarketplace = async (
       name: name,
       authority: wallet.publicKey,
       paymentMints: paymentMints ?? null,
+      feeCollector: fees?.feeCollector ?? null,
+      makerFeeBps: fees?.makerFeeBps ?? null,
+      takerFeeBps: fees?.takerFeeBps ?? null,
     })
     .accounts({
       marketplace: marketplaceId,
@@ -222,8 +310,8 @@ export const withUpdateMarketplace = async (
   wallet: Wallet,
   name: string,
   paymentManagerName: string,
-  authority: PublicKey,
-  paymentMints: PublicKey[]
+  paymentMints: PublicKey[],
+  fees?: MarketplaceFees
 ): Promise<Transaction> => {
   const transferAuthProgram = transferAuthorityProgram(connection, wallet);
   const marketplaceId = findMarketplaceAddress(name);
@@ -232,18 +320,82 @@ export const withUpdateMarketplace = async (
   const updateMarketplaceIx = await transferAuthProgram.methods
     .updateMarketplace({
       paymentManager: paymentManagerId,
-      authority: authority,
       paymentMints: paymentMints,
+      feeCollector: fees?.feeCollector ?? null,
+      makerFeeBps: fees?.makerFeeBps ?? null,
+      takerFeeBps: fees?.takerFeeBps ?? null,
     })
     .accounts({
       marketplace: marketplaceId,
-      authority: authority,
+      authority: wallet.publicKey,
+      systemProgram: SystemProgram.programId,
     })
     .instruction();
   transaction.add(updateMarketplaceIx);
   return transaction;
 };
 
+export const withProposeMarketplaceAuthority = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  name: string,
+  newAuthority: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const marketplaceId = findMarketplaceAddress(name);
+
+  const proposeMarketplaceAuthorityIx = await transferAuthProgram.methods
+    .proposeMarketplaceAuthority(newAuthority)
+    .accounts({
+      marketplace: marketplaceId,
+      authority: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(proposeMarketplaceAuthorityIx);
+  return transaction;
+};
+
+export const withAcceptMarketplaceAuthority = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  name: string
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const marketplaceId = findMarketplaceAddress(name);
+
+  const acceptMarketplaceAuthorityIx = await transferAuthProgram.methods
+    .acceptMarketplaceAuthority()
+    .accounts({
+      marketplace: marketplaceId,
+      pendingAuthority: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(acceptMarketplaceAuthorityIx);
+  return transaction;
+};
+
+export const withCloseMarketplace = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  name: string
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const marketplaceId = findMarketplaceAddress(name);
+
+  const closeMarketplaceIx = await transferAuthProgram.methods
+    .closeMarketplace()
+    .accounts({
+      marketplace: marketplaceId,
+      authority: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(closeMarketplaceIx);
+  return transaction;
+};
+
 export const withCreateListing = async (
   transaction: Transaction,
   connection: Connection,
@@ -252,7 +404,9 @@ export const withCreateListing = async (
   markeptlaceName: string,
   paymentAmount: BN,
   paymentMint = PublicKey.default,
-  payer = wallet.publicKey
+  payer = wallet.publicKey,
+  expiresAt?: BN,
+  allowedBuyer?: PublicKey
 ): Promise<[Transaction, PublicKey]> => {
   const transferAuthProgram = transferAuthorityProgram(connection, wallet);
   const listingId = findListingAddress(mintId);
@@ -280,7 +434,9 @@ export const withCreateListing = async (
         mintId,
         marketplaceId,
         paymentAmount,
-        paymentMint
+        paymentMint,
+        expiresAt,
+        allowedBuyer
       )
     );
   } else {
@@ -289,12 +445,15 @@ export const withCreateListing = async (
       .createListing({
         paymentAmount: paymentAmount,
         paymentMint: paymentMint,
+        expiresAt: expiresAt ?? null,
+        allowedBuyer: allowedBuyer ?? null,
       })
       .accounts({
         listing: listingId,
         transferAuthority: tokenManagerData.parsed.transferAuthority,
         marketplace: marketplaceId,
         tokenManager: tokenManagerId,
+        auction: findAuctionAddress(mintId),
         mint: mintId,
         mintManager: mintManagerId,
         listerTokenAccount: listerTokenAccountId,
@@ -319,7 +478,9 @@ export const withUpdateListing = async (
   mintId: PublicKey,
   marketplaceId: PublicKey,
   paymentAmount: BN,
-  paymentMint: PublicKey
+  paymentMint: PublicKey,
+  expiresAt?: BN,
+  allowedBuyer?: PublicKey
 ): Promise<Transaction> => {
   const transferAuthProgram = transferAuthorityProgram(connection, wallet);
   const listingData = await tryGetAccount(() => getListing(connection, mintId));
@@ -338,10 +499,14 @@ export const withUpdateListing = async (
       marketplace: marketplaceId,
       paymentAmount: paymentAmount,
       paymentMint: paymentMint,
+      expiresAt: expiresAt ?? null,
+      allowedBuyer: allowedBuyer ?? null,
     })
     .accounts({
       tokenManager: listingData.parsed.tokenManager,
       listing: listingId,
+      marketplace: listingData.parsed.marketplace,
+      newMarketplace: marketplaceId,
       listerMintTokenAccount: listerMintTokenAccountId,
       lister: wallet.publicKey,
     })
@@ -361,12 +526,17 @@ export const withRemoveListing = async (
   const listingId = findListingAddress(mintId);
   const tokenManagerId = findTokenManagerAddress(mintId);
   const mintManagerId = findMintManagerId(mintId);
+  const listingData = await tryGetAccount(() => getListing(connection, mintId));
+  if (!listingData?.parsed) {
+    throw `No listing found for mint address ${mintId.toString()}`;
+  }
 
   const removeListingIx = await transferAuthProgram.methods
     .removeListing()
     .accounts({
       tokenManager: tokenManagerId,
       listing: listingId,
+      marketplace: listingData.parsed.marketplace,
       listerMintTokenAccount: listerMintTokenAccountId,
       lister: wallet.publicKey,
       mint: mintId,
@@ -379,6 +549,245 @@ export const withRemoveListing = async (
   return transaction;
 };
 
+export type BulkListing = {
+  mintId: PublicKey;
+  paymentAmount: BN;
+  paymentMint?: PublicKey;
+  expiresAt?: BN;
+  allowedBuyer?: PublicKey;
+};
+
+export const withCreateListings = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  markeptlaceName: string,
+  listings: BulkListing[],
+  payer = wallet.publicKey
+): Promise<[Transaction, PublicKey]> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const marketplaceId = findMarketplaceAddress(markeptlaceName);
+  const firstListing = listings[0];
+  if (!firstListing) {
+    throw `No listings to create`;
+  }
+  const tokenManagerData = await tryGetAccount(() =>
+    getTokenManager(connection, findTokenManagerAddress(firstListing.mintId))
+  );
+  if (!tokenManagerData?.parsed.transferAuthority) {
+    throw `No transfer authority for token manager`;
+  }
+
+  const remainingAccounts: AccountMeta[] = [];
+  for (const { mintId } of listings) {
+    remainingAccounts.push(
+      {
+        pubkey: findListingAddress(mintId),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: findTokenManagerAddress(mintId),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: mintId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: findMintManagerId(mintId),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: await findAta(mintId, wallet.publicKey, true),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: findAuctionAddress(mintId),
+        isSigner: false,
+        isWritable: false,
+      }
+    );
+  }
+
+  const createListingsIx = await transferAuthProgram.methods
+    .createListings({
+      listings: listings.map((listing) => ({
+        paymentAmount: listing.paymentAmount,
+        paymentMint: listing.paymentMint ?? PublicKey.default,
+        expiresAt: listing.expiresAt ?? null,
+        allowedBuyer: listing.allowedBuyer ?? null,
+      })),
+    })
+    .accounts({
+      transferAuthority: tokenManagerData.parsed.transferAuthority,
+      marketplace: marketplaceId,
+      lister: wallet.publicKey,
+      payer: payer ?? wallet.publicKey,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  transaction.add(createListingsIx);
+  return [transaction, marketplaceId];
+};
+
+export const withUpdateListings = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  marketplaceId: PublicKey,
+  listings: BulkListing[]
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+
+  const remainingAccounts: AccountMeta[] = [];
+  for (const { mintId } of listings) {
+    remainingAccounts.push(
+      {
+        pubkey: findTokenManagerAddress(mintId),
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: findListingAddress(mintId),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: await findAta(mintId, wallet.publicKey, true),
+        isSigner: false,
+        isWritable: false,
+      }
+    );
+  }
+
+  const updateListingsIx = await transferAuthProgram.methods
+    .updateListings({
+      listings: listings.map((listing) => ({
+        marketplace: marketplaceId,
+        paymentAmount: listing.paymentAmount,
+        paymentMint: listing.paymentMint ?? PublicKey.default,
+        expiresAt: listing.expiresAt ?? null,
+        allowedBuyer: listing.allowedBuyer ?? null,
+      })),
+    })
+    .accounts({
+      marketplace: marketplaceId,
+      lister: wallet.publicKey,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  transaction.add(updateListingsIx);
+  return transaction;
+};
+
+export const withRemoveListings = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintIds: PublicKey[]
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+
+  const remainingAccounts: AccountMeta[] = [];
+  for (const mintId of mintIds) {
+    const listingData = await tryGetAccount(() =>
+      getListing(connection, mintId)
+    );
+    if (!listingData?.parsed) {
+      throw `No listing found for mint address ${mintId.toString()}`;
+    }
+    remainingAccounts.push(
+      {
+        pubkey: findTokenManagerAddress(mintId),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: findListingAddress(mintId),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: listingData.parsed.marketplace,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: await findAta(mintId, wallet.publicKey, true),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: mintId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: findMintManagerId(mintId),
+        isSigner: false,
+        isWritable: true,
+      }
+    );
+  }
+
+  const removeListingsIx = await transferAuthProgram.methods
+    .removeListings()
+    .accounts({
+      lister: wallet.publicKey,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  transaction.add(removeListingsIx);
+  return transaction;
+};
+
+export const withCloseStaleListing = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const listingId = findListingAddress(mintId);
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const listingData = await tryGetAccount(() => getListing(connection, mintId));
+  if (!listingData?.parsed) {
+    throw `No listing found for mint address ${mintId.toString()}`;
+  }
+  const listerMintTokenAccountId = await findAta(
+    mintId,
+    listingData.parsed.lister,
+    true
+  );
+
+  const closeStaleListingIx = await transferAuthProgram.methods
+    .closeStaleListing()
+    .accounts({
+      tokenManager: tokenManagerId,
+      listing: listingId,
+      marketplace: listingData.parsed.marketplace,
+      lister: listingData.parsed.lister,
+      listerMintTokenAccount: listerMintTokenAccountId,
+      mint: mintId,
+      mintManager: findMintManagerId(mintId),
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  transaction.add(closeStaleListingIx);
+  return transaction;
+};
+
 export const withAcceptListing = async (
   transaction: Transaction,
   connection: Connection,
@@ -388,7 +797,8 @@ export const withAcceptListing = async (
   paymentAmount: BN,
   paymentMint: PublicKey,
   buySideReceiver?: PublicKey,
-  payer = buyer
+  payer = buyer,
+  maxTakerFeeBps?: number
 ): Promise<Transaction> => {
   const transferAuthProgram = transferAuthorityProgram(connection, wallet);
   const listingData = await tryGetAccount(() => getListing(connection, mintId));
@@ -492,11 +902,50 @@ export const withAcceptListing = async (
   if (!tokenManagerData.parsed.transferAuthority) {
     throw `No transfer authority for token manager`;
   }
-  const remainingAccountsForKind = getRemainingAccountsForKind(
-    mintId,
-    tokenManagerData.parsed.kind
+  let remainingAccountsForKind: AccountMeta[];
+  if (tokenManagerData.parsed.kind === TokenManagerKind.Programmable) {
+    const metadata = await tryNull(
+      Metadata.fromAccountAddress(connection, mintMetadataId)
+    );
+    remainingAccountsForKind = remainingAccountForProgrammableTransfer(
+      listingData.parsed.lister,
+      mintId,
+      listerMintTokenAccountId,
+      buyerMintTokenAccountId,
+      metadata?.programmableConfig?.ruleSet
+    );
+    transaction.add(
+      ComputeBudgetProgram.setComputeUnitLimit({
+        units: 1000000,
+      })
+    );
+  } else {
+    remainingAccountsForKind = getRemainingAccountsForKind(
+      mintId,
+      tokenManagerData.parsed.kind
+    );
+  }
+  // issuer share accounts come first, followed by the marketplace fee collector
+  const issuerShareAccounts = await withRemainingAccountsForIssuerShare(
+    transaction,
+    connection,
+    wallet,
+    tokenManagerData,
+    tokenManagerData.parsed.transferAuthority,
+    listingData.parsed.paymentMint,
+    listingData.parsed.paymentAmount
+  );
+  const marketplaceFeeAccounts = await withRemainingAccountsForMarketplaceFee(
+    transaction,
+    connection,
+    wallet,
+    marketplaceData,
+    listingData.parsed.paymentMint,
+    listingData.parsed.paymentAmount
   );
   const remainingAccounts: AccountMeta[] = [
+    ...issuerShareAccounts,
+    ...marketplaceFeeAccounts,
     ...remainingAccountsForHandlePaymentWithRoyalties,
     ...remainingAccountsForKind,
   ];
@@ -511,11 +960,13 @@ export const withAcceptListing = async (
   const acceptListingIx = await transferAuthProgram.methods
     .acceptListing({
       paymentAmount: paymentAmount,
+      maxTakerFeeBps: maxTakerFeeBps ?? marketplaceData.parsed.takerFeeBps ?? 0,
     })
     .accounts({
       transferAuthority: tokenManagerData.parsed.transferAuthority,
       transferReceipt: transferReceiptId,
       listing: listingData.pubkey,
+      auction: findAuctionAddress(mintId),
       listerPaymentTokenAccount: listerPaymentTokenAccountId,
       listerMintTokenAccount: listerMintTokenAccountId,
       lister: listingData.parsed.lister,
@@ -566,6 +1017,7 @@ export const withWhitelistMarektplaces = async (
     .accounts({
       transferAuthority: transferAuthority,
       authority: wallet.publicKey,
+      systemProgram: SystemProgram.programId,
     })
     .instruction();
   transaction.add(whitelistMarketplaceIx);
@@ -579,7 +1031,8 @@ export const withInitTransfer = async (
   to: PublicKey,
   mintId: PublicKey,
   holderTokenAccountId: PublicKey,
-  payer = wallet.publicKey
+  payer = wallet.publicKey,
+  expiresAt?: BN
 ): Promise<Transaction> => {
   const transferAuthProgram = transferAuthorityProgram(connection, wallet);
   const transferId = findTransferAddress(mintId);
@@ -588,6 +1041,7 @@ export const withInitTransfer = async (
   const initTransferIx = await transferAuthProgram.methods
     .initTransfer({
       to: to,
+      expiresAt: expiresAt ?? null,
     })
     .accounts({
       transfer: transferId,
@@ -631,29 +1085,83 @@ export const withCancelTransfer = async (
   return transaction;
 };
 
-export const withAcceptTransfer = async (
+export const withRejectTransfer = async (
   transaction: Transaction,
   connection: Connection,
   wallet: Wallet,
-  mintId: PublicKey,
-  recipient: PublicKey,
-  holder: PublicKey
+  mintId: PublicKey
 ): Promise<Transaction> => {
   const transferAuthProgram = transferAuthorityProgram(connection, wallet);
-  const transferId = findTransferAddress(mintId);
-  const tokenManagerId = findTokenManagerAddress(mintId);
-  const transferReceiptId = findTransferReceiptId(tokenManagerId);
-  const listingId = findListingAddress(mintId);
-  const tokenManagerData = await tryGetAccount(() =>
-    getTokenManager(connection, tokenManagerId)
+  const transferData = await tryGetAccount(() =>
+    getTransfer(connection, mintId)
   );
-  if (!tokenManagerData) {
-    throw `No token manager found for mint ${mintId.toString()}`;
+  if (!transferData) {
+    throw `No transfer found for mint id ${mintId.toString()}`;
   }
-  if (!tokenManagerData.parsed.transferAuthority) {
-    throw `No transfer autority found for mint id ${mintId.toString()}`;
-  }
-  const recipientTokenAccountId = await findAta(mintId, recipient, true);
+
+  const rejectTransferIx = await transferAuthProgram.methods
+    .rejectTransfer()
+    .accounts({
+      transfer: transferData.pubkey,
+      recipient: wallet.publicKey,
+      holder: transferData.parsed.from,
+    })
+    .instruction();
+  transaction.add(rejectTransferIx);
+  return transaction;
+};
+
+export const withCloseExpiredTransfer = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const transferData = await tryGetAccount(() =>
+    getTransfer(connection, mintId)
+  );
+  if (!transferData) {
+    throw `No transfer found for mint id ${mintId.toString()}`;
+  }
+
+  const closeExpiredTransferIx = await transferAuthProgram.methods
+    .closeExpiredTransfer()
+    .accounts({
+      transfer: transferData.pubkey,
+      holder: transferData.parsed.from,
+    })
+    .instruction();
+  transaction.add(closeExpiredTransferIx);
+  return transaction;
+};
+
+export const withAcceptTransfer = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  recipient: PublicKey,
+  holder: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const transferId = findTransferAddress(mintId);
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const transferReceiptId = findTransferReceiptId(tokenManagerId);
+  const listingId = findListingAddress(mintId);
+  const tokenManagerData = await tryGetAccount(() =>
+    getTokenManager(connection, tokenManagerId)
+  );
+  if (!tokenManagerData) {
+    throw `No token manager found for mint ${mintId.toString()}`;
+  }
+  if (!tokenManagerData.parsed.transferAuthority) {
+    throw `No transfer autority found for mint id ${mintId.toString()}`;
+  }
+  const listingData = await tryGetAccount(() =>
+    getListing(connection, listingId)
+  );
+  const recipientTokenAccountId = await findAta(mintId, recipient, true);
   const remainingAccountsForTransfer = [
     ...getRemainingAccountsForKind(mintId, tokenManagerData.parsed.kind),
     {
@@ -670,6 +1178,8 @@ export const withAcceptTransfer = async (
       transferAuthority: tokenManagerData.parsed.transferAuthority,
       transferReceipt: transferReceiptId,
       listing: listingId,
+      marketplace: listingData?.parsed.marketplace ?? listingId,
+      auction: findAuctionAddress(mintId),
       tokenManager: tokenManagerId,
       mint: mintId,
       recipientTokenAccount: recipientTokenAccountId,
@@ -732,6 +1242,7 @@ export const withRelease = async (
     .accounts({
       transferAuthority: transferAuthorityId,
       tokenManager: tokenManagerId,
+      auction: findAuctionAddress(mintId),
       mint: mintId,
       tokenManagerTokenAccount: tokenManagerTokenAccountId,
       holderTokenAccount: holderTokenAccountId,
@@ -749,3 +1260,712 @@ export const withRelease = async (
   transaction.add(releaseIx);
   return transaction;
 };
+
+const withRemainingAccountsForTransfer = async (
+  transaction: Transaction,
+  connection: Connection,
+  tokenManagerData: AccountData<TokenManagerData>,
+  holder: PublicKey,
+  recipientTokenAccountId: PublicKey
+): Promise<AccountMeta[]> => {
+  const mintId = tokenManagerData.parsed.mint;
+  if (tokenManagerData.parsed.kind === TokenManagerKind.Programmable) {
+    const metadata = await tryNull(
+      Metadata.fromAccountAddress(connection, findMintMetadataId(mintId))
+    );
+    transaction.add(
+      ComputeBudgetProgram.setComputeUnitLimit({
+        units: 1000000,
+      })
+    );
+    return remainingAccountForProgrammableTransfer(
+      holder,
+      mintId,
+      tokenManagerData.parsed.recipientTokenAccount,
+      recipientTokenAccountId,
+      metadata?.programmableConfig?.ruleSet
+    );
+  }
+  return getRemainingAccountsForKind(mintId, tokenManagerData.parsed.kind);
+};
+
+/**
+ * Issuer share accounts, the issuer payment account followed by the receipt
+ * token account when the token manager has a receipt mint
+ */
+export const withRemainingAccountsForIssuerShare = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerData: AccountData<TokenManagerData>,
+  transferAuthorityId: PublicKey,
+  paymentMint: PublicKey,
+  paymentAmount: BN
+): Promise<AccountMeta[]> => {
+  const transferAuthorityData = await getTransferAuthority(
+    connection,
+    transferAuthorityId
+  );
+  const issuerShareBps = transferAuthorityData.parsed.issuerShareBps ?? 0;
+  if (paymentAmount.muln(issuerShareBps).divn(10000).isZero()) {
+    return [];
+  }
+  const nativePayment = paymentMint.equals(PublicKey.default);
+  const { issuer, receiptMint } = tokenManagerData.parsed;
+
+  let issuerShareOwner = issuer;
+  let receiptTokenAccountId: PublicKey | undefined;
+  if (receiptMint) {
+    const receiptMintLargestAccount =
+      await connection.getTokenLargestAccounts(receiptMint);
+    receiptTokenAccountId = receiptMintLargestAccount.value[0]?.address;
+    if (!receiptTokenAccountId) throw "No receipt mint holder found";
+    const receiptTokenAccount = await getAccount(
+      connection,
+      receiptTokenAccountId
+    );
+    issuerShareOwner = receiptTokenAccount.owner;
+  }
+
+  const remainingAccounts: AccountMeta[] = [
+    {
+      pubkey: nativePayment
+        ? issuerShareOwner
+        : await withFindOrInitAssociatedTokenAccount(
+            transaction,
+            connection,
+            paymentMint,
+            issuerShareOwner,
+            wallet.publicKey,
+            true
+          ),
+      isSigner: false,
+      isWritable: true,
+    },
+  ];
+  if (receiptTokenAccountId) {
+    remainingAccounts.push({
+      pubkey: receiptTokenAccountId,
+      isSigner: false,
+      isWritable: false,
+    });
+  }
+  return remainingAccounts;
+};
+
+/**
+ * Marketplace fee collector, a wallet for native SOL or a token account for
+ * SPL payments, only passed when the marketplace charges a fee
+ */
+export const withRemainingAccountsForMarketplaceFee = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  marketplaceData: AccountData<MarketplaceData>,
+  paymentMint: PublicKey,
+  paymentAmount: BN
+): Promise<AccountMeta[]> => {
+  const { feeCollector, makerFeeBps, takerFeeBps } = marketplaceData.parsed;
+  const marketplaceFee = paymentAmount
+    .muln(makerFeeBps ?? 0)
+    .divn(10000)
+    .add(paymentAmount.muln(takerFeeBps ?? 0).divn(10000));
+  if (!feeCollector || marketplaceFee.isZero()) {
+    return [];
+  }
+  return [
+    {
+      pubkey: paymentMint.equals(PublicKey.default)
+        ? feeCollector
+        : await withFindOrInitAssociatedTokenAccount(
+            transaction,
+            connection,
+            paymentMint,
+            feeCollector,
+            wallet.publicKey,
+            true
+          ),
+      isSigner: false,
+      isWritable: true,
+    },
+  ];
+};
+
+export const withCreateOffer = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  target: PublicKey,
+  transferAuthorityId: PublicKey,
+  marketplaceId: PublicKey,
+  paymentAmount: BN,
+  paymentMint = PublicKey.default
+): Promise<[Transaction, PublicKey]> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const offerId = findOfferAddress(target, wallet.publicKey);
+  const nativePayment = paymentMint.equals(PublicKey.default);
+  const marketplaceData = await getMarketplace(connection, marketplaceId);
+
+  const offerPaymentTokenAccountId = nativePayment
+    ? offerId
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        offerId,
+        wallet.publicKey,
+        true
+      );
+  const buyerPaymentTokenAccountId = nativePayment
+    ? wallet.publicKey
+    : await findAta(paymentMint, wallet.publicKey, true);
+
+  const createOfferIx = await transferAuthProgram.methods
+    .createOffer({
+      paymentAmount: paymentAmount,
+      paymentMint: paymentMint,
+    })
+    .accounts({
+      offer: offerId,
+      offerPaymentTokenAccount: offerPaymentTokenAccountId,
+      transferAuthority: transferAuthorityId,
+      target: target,
+      marketplace: marketplaceId,
+      paymentManager: marketplaceData.parsed.paymentManager,
+      buyer: wallet.publicKey,
+      buyerPaymentTokenAccount: buyerPaymentTokenAccountId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(createOfferIx);
+  return [transaction, offerId];
+};
+
+export const withAcceptOffer = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  offerId: PublicKey,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const tokenManagerData = await tryGetAccount(() =>
+    getTokenManager(connection, tokenManagerId)
+  );
+  if (!tokenManagerData) {
+    throw `No token manager found for mint id ${mintId.toString()}`;
+  }
+
+  const acceptOfferIx = await transferAuthProgram.methods
+    .acceptOffer()
+    .accounts({
+      offer: offerId,
+      tokenManager: tokenManagerId,
+      mint: mintId,
+      mintManager: findMintManagerId(mintId),
+      holderTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      holder: wallet.publicKey,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  transaction.add(acceptOfferIx);
+  return transaction;
+};
+
+export const withClaimOffer = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  offerId: PublicKey,
+  buySideReceiver?: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const offerData = await tryGetAccount(() => getOffer(connection, offerId));
+  if (!offerData?.parsed) {
+    throw `No offer found with id ${offerId.toString()}`;
+  }
+  const holder = offerData.parsed.acceptedBy;
+  const tokenManagerId = offerData.parsed.acceptedTokenManager;
+  if (!holder || !tokenManagerId) {
+    throw `Offer ${offerId.toString()} has not been accepted`;
+  }
+  const tokenManagerData = await getTokenManager(connection, tokenManagerId);
+  const mintId = tokenManagerData.parsed.mint;
+  const marketplaceData = await getMarketplace(
+    connection,
+    offerData.parsed.marketplace
+  );
+  const paymentManagerData = await getPaymentManager(
+    connection,
+    marketplaceData.parsed.paymentManager
+  );
+  const paymentMint = offerData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+
+  const holderPaymentTokenAccountId = nativePayment
+    ? holder
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        holder,
+        wallet.publicKey
+      );
+  const buyerPaymentTokenAccountId = nativePayment
+    ? wallet.publicKey
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        wallet.publicKey,
+        wallet.publicKey
+      );
+  const buyerMintTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    mintId,
+    wallet.publicKey,
+    wallet.publicKey,
+    true
+  );
+  const feeCollectorTokenAccountId = nativePayment
+    ? paymentManagerData.parsed.feeCollector
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        paymentManagerData.parsed.feeCollector,
+        wallet.publicKey,
+        true
+      );
+
+  const remainingAccountsForHandlePaymentWithRoyalties =
+    await withRemainingAccountsForHandlePaymentWithRoyalties(
+      transaction,
+      connection,
+      wallet,
+      mintId,
+      paymentMint,
+      buySideReceiver,
+      [holder.toString(), wallet.publicKey.toString()]
+    );
+  const remainingAccountsForTransfer = await withRemainingAccountsForTransfer(
+    transaction,
+    connection,
+    tokenManagerData,
+    holder,
+    buyerMintTokenAccountId
+  );
+  const remainingAccountsForIssuerShare =
+    await withRemainingAccountsForIssuerShare(
+      transaction,
+      connection,
+      wallet,
+      tokenManagerData,
+      offerData.parsed.transferAuthority,
+      paymentMint,
+      offerData.parsed.paymentAmount
+    );
+  const remainingAccountsForMarketplaceFee =
+    await withRemainingAccountsForMarketplaceFee(
+      transaction,
+      connection,
+      wallet,
+      marketplaceData,
+      paymentMint,
+      offerData.parsed.paymentAmount
+    );
+
+  const claimOfferIx = await transferAuthProgram.methods
+    .claimOffer()
+    .accounts({
+      transferAuthority: offerData.parsed.transferAuthority,
+      transferReceipt: findTransferReceiptId(tokenManagerId),
+      offer: offerId,
+      offerPaymentTokenAccount: nativePayment
+        ? offerId
+        : await findAta(paymentMint, offerId, true),
+      auction: findAuctionAddress(mintId),
+      holderPaymentTokenAccount: holderPaymentTokenAccountId,
+      holderMintTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      holder: holder,
+      buyerMintTokenAccount: buyerMintTokenAccountId,
+      buyer: wallet.publicKey,
+      buyerPaymentTokenAccount: buyerPaymentTokenAccountId,
+      marketplace: offerData.parsed.marketplace,
+      tokenManager: tokenManagerId,
+      mint: mintId,
+      mintMetadataInfo: findMintMetadataId(mintId),
+      paymentManager: marketplaceData.parsed.paymentManager,
+      paymentMint: paymentMint,
+      feeCollectorTokenAccount: feeCollectorTokenAccountId,
+      feeCollector: paymentManagerData.parsed.feeCollector,
+      solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      associatedTokenProgram: ASSOCIATED_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts([
+      ...remainingAccountsForIssuerShare,
+      ...remainingAccountsForMarketplaceFee,
+      ...remainingAccountsForHandlePaymentWithRoyalties,
+      ...remainingAccountsForTransfer,
+    ])
+    .instruction();
+  transaction.add(claimOfferIx);
+  return transaction;
+};
+
+export const withCancelOffer = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  offerId: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const offerData = await tryGetAccount(() => getOffer(connection, offerId));
+  if (!offerData?.parsed) {
+    throw `No offer found with id ${offerId.toString()}`;
+  }
+  const paymentMint = offerData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+
+  const cancelOfferIx = await transferAuthProgram.methods
+    .cancelOffer()
+    .accounts({
+      offer: offerId,
+      tokenManager:
+        offerData.parsed.acceptedTokenManager ??
+        offerData.parsed.tokenManager ??
+        offerData.parsed.transferAuthority,
+      offerPaymentTokenAccount: nativePayment
+        ? offerId
+        : await findAta(paymentMint, offerId, true),
+      buyer: wallet.publicKey,
+      buyerPaymentTokenAccount: nativePayment
+        ? wallet.publicKey
+        : await findAta(paymentMint, wallet.publicKey, true),
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  transaction.add(cancelOfferIx);
+  return transaction;
+};
+
+export const withCreateAuction = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  marketplaceName: string,
+  reservePrice: BN,
+  minIncrement: BN,
+  endTime: BN,
+  paymentMint = PublicKey.default,
+  payer = wallet.publicKey
+): Promise<[Transaction, PublicKey]> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const auctionId = findAuctionAddress(mintId);
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  const tokenManagerData = await tryGetAccount(() =>
+    getTokenManager(connection, tokenManagerId)
+  );
+  if (!tokenManagerData?.parsed) {
+    throw `No token manager found for mint id ${mintId.toString()}`;
+  }
+  if (!tokenManagerData.parsed.transferAuthority) {
+    throw `No transfer authority for token manager`;
+  }
+
+  const createAuctionIx = await transferAuthProgram.methods
+    .createAuction({
+      paymentMint: paymentMint,
+      reservePrice: reservePrice,
+      minIncrement: minIncrement,
+      endTime: endTime,
+    })
+    .accounts({
+      auction: auctionId,
+      transferAuthority: tokenManagerData.parsed.transferAuthority,
+      marketplace: findMarketplaceAddress(marketplaceName),
+      tokenManager: tokenManagerId,
+      mint: mintId,
+      mintManager: findMintManagerId(mintId),
+      listerTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+      lister: wallet.publicKey,
+      payer: payer,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(createAuctionIx);
+
+  // spl bids are escrowed in a token account owned by the auction
+  if (!paymentMint.equals(PublicKey.default)) {
+    await withFindOrInitAssociatedTokenAccount(
+      transaction,
+      connection,
+      paymentMint,
+      auctionId,
+      payer,
+      true
+    );
+  }
+  return [transaction, auctionId];
+};
+
+export const withPlaceBid = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  amount: BN
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const auctionId = findAuctionAddress(mintId);
+  const auctionData = await tryGetAccount(() =>
+    getAuction(connection, auctionId)
+  );
+  if (!auctionData?.parsed) {
+    throw `No auction found for mint id ${mintId.toString()}`;
+  }
+  const paymentMint = auctionData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+  const previousBidder = auctionData.parsed.topBidder ?? wallet.publicKey;
+  const marketplaceData = await getMarketplace(
+    connection,
+    auctionData.parsed.marketplace
+  );
+
+  const placeBidIx = await transferAuthProgram.methods
+    .placeBid(amount)
+    .accounts({
+      auction: auctionId,
+      auctionPaymentTokenAccount: nativePayment
+        ? auctionId
+        : await findAta(paymentMint, auctionId, true),
+      bidder: wallet.publicKey,
+      bidderPaymentTokenAccount: nativePayment
+        ? wallet.publicKey
+        : await findAta(paymentMint, wallet.publicKey, true),
+      previousBidder: previousBidder,
+      previousBidderPaymentTokenAccount: nativePayment
+        ? previousBidder
+        : await findAta(paymentMint, previousBidder, true),
+      marketplace: auctionData.parsed.marketplace,
+      paymentManager: marketplaceData.parsed.paymentManager,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(placeBidIx);
+  return transaction;
+};
+
+export const withClaimAuction = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey,
+  buySideReceiver?: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const auctionId = findAuctionAddress(mintId);
+  const auctionData = await tryGetAccount(() =>
+    getAuction(connection, auctionId)
+  );
+  if (!auctionData?.parsed) {
+    throw `No auction found for mint id ${mintId.toString()}`;
+  }
+  const tokenManagerData = await getTokenManager(
+    connection,
+    auctionData.parsed.tokenManager
+  );
+  if (!tokenManagerData.parsed.transferAuthority) {
+    throw `No transfer authority for token manager`;
+  }
+  const marketplaceData = await getMarketplace(
+    connection,
+    auctionData.parsed.marketplace
+  );
+  const paymentManagerData = await getPaymentManager(
+    connection,
+    marketplaceData.parsed.paymentManager
+  );
+  const lister = auctionData.parsed.lister;
+  const paymentMint = auctionData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+
+  const listerPaymentTokenAccountId = nativePayment
+    ? lister
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        lister,
+        wallet.publicKey
+      );
+  const buyerPaymentTokenAccountId = nativePayment
+    ? wallet.publicKey
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        wallet.publicKey,
+        wallet.publicKey
+      );
+  const buyerMintTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    mintId,
+    wallet.publicKey,
+    wallet.publicKey,
+    true
+  );
+  const feeCollectorTokenAccountId = nativePayment
+    ? paymentManagerData.parsed.feeCollector
+    : await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        connection,
+        paymentMint,
+        paymentManagerData.parsed.feeCollector,
+        wallet.publicKey,
+        true
+      );
+
+  const remainingAccountsForIssuerShare =
+    await withRemainingAccountsForIssuerShare(
+      transaction,
+      connection,
+      wallet,
+      tokenManagerData,
+      tokenManagerData.parsed.transferAuthority,
+      paymentMint,
+      auctionData.parsed.topBid
+    );
+  const remainingAccountsForMarketplaceFee =
+    await withRemainingAccountsForMarketplaceFee(
+      transaction,
+      connection,
+      wallet,
+      marketplaceData,
+      paymentMint,
+      auctionData.parsed.topBid
+    );
+  const remainingAccountsForHandlePaymentWithRoyalties =
+    await withRemainingAccountsForHandlePaymentWithRoyalties(
+      transaction,
+      connection,
+      wallet,
+      mintId,
+      paymentMint,
+      buySideReceiver,
+      [lister.toString(), wallet.publicKey.toString()]
+    );
+  const remainingAccountsForTransfer = await withRemainingAccountsForTransfer(
+    transaction,
+    connection,
+    tokenManagerData,
+    lister,
+    buyerMintTokenAccountId
+  );
+
+  const claimAuctionIx = await transferAuthProgram.methods
+    .claimAuction()
+    .accounts({
+      transferAuthority: tokenManagerData.parsed.transferAuthority,
+      transferReceipt: findTransferReceiptId(tokenManagerData.pubkey),
+      auction: auctionId,
+      auctionPaymentTokenAccount: nativePayment
+        ? auctionId
+        : await findAta(paymentMint, auctionId, true),
+      listerPaymentTokenAccount: listerPaymentTokenAccountId,
+      listerMintTokenAccount: auctionData.parsed.listerTokenAccount,
+      lister: lister,
+      buyerMintTokenAccount: buyerMintTokenAccountId,
+      buyer: wallet.publicKey,
+      buyerPaymentTokenAccount: buyerPaymentTokenAccountId,
+      marketplace: auctionData.parsed.marketplace,
+      tokenManager: tokenManagerData.pubkey,
+      mint: mintId,
+      mintMetadataInfo: findMintMetadataId(mintId),
+      paymentManager: marketplaceData.parsed.paymentManager,
+      paymentMint: paymentMint,
+      feeCollectorTokenAccount: feeCollectorTokenAccountId,
+      feeCollector: paymentManagerData.parsed.feeCollector,
+      solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+      solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts([
+      ...remainingAccountsForIssuerShare,
+      ...remainingAccountsForMarketplaceFee,
+      ...remainingAccountsForHandlePaymentWithRoyalties,
+      ...remainingAccountsForTransfer,
+    ])
+    .instruction();
+  transaction.add(claimAuctionIx);
+  return transaction;
+};
+
+export const withCancelAuction = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const cancelAuctionIx = await transferAuthProgram.methods
+    .cancelAuction()
+    .accounts({
+      auction: findAuctionAddress(mintId),
+      lister: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(cancelAuctionIx);
+  return transaction;
+};
+
+export const withCloseStaleAuction = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  mintId: PublicKey
+): Promise<Transaction> => {
+  const transferAuthProgram = transferAuthorityProgram(connection, wallet);
+  const auctionId = findAuctionAddress(mintId);
+  const auctionData = await tryGetAccount(() =>
+    getAuction(connection, auctionId)
+  );
+  if (!auctionData?.parsed) {
+    throw `No auction found for mint id ${mintId.toString()}`;
+  }
+  const paymentMint = auctionData.parsed.paymentMint;
+  const nativePayment = paymentMint.equals(PublicKey.default);
+  const topBidder = auctionData.parsed.topBidder ?? auctionData.parsed.lister;
+
+  const closeStaleAuctionIx = await transferAuthProgram.methods
+    .closeStaleAuction()
+    .accounts({
+      tokenManager: auctionData.parsed.tokenManager,
+      auction: auctionId,
+      auctionPaymentTokenAccount: nativePayment
+        ? auctionId
+        : await findAta(paymentMint, auctionId, true),
+      lister: auctionData.parsed.lister,
+      topBidder: topBidder,
+      topBidderPaymentTokenAccount: nativePayment
+        ? topBidder
+        : await findAta(paymentMint, topBidder, true),
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+  transaction.add(closeStaleAuctionIx);
+  return transaction;
+};
@@ -2,13 +2,19 @@ import { BorshAccountsCoder } from "@coral-xyz/anchor";
 import type { Connection, PublicKey } from "@solana/web3.js";
 import type { AccountData } from "@solana-nft-programs/common";
 
-import type { PaidClaimApproverData } from "./constants";
+import type {
+  PaidClaimApproverData,
+  SecurityDepositData,
+} from "./constants";
 import {
   CLAIM_APPROVER_ADDRESS,
   CLAIM_APPROVER_IDL,
   claimApproverProgram,
 } from "./constants";
-import { findClaimApproverAddress } from "./pda";
+import {
+  findClaimApproverAddress,
+  findSecurityDepositAddress,
+} from "./pda";
 
 export const getClaimApprover = async (
   connection: Connection,
@@ -68,3 +74,17 @@ export const getAllClaimApprovers = async (
   });
   return claimApprovers;
 };
+
+export const getSecurityDeposit = async (
+  connection: Connection,
+  tokenManagerId: PublicKey
+): Promise<AccountData<SecurityDepositData>> => {
+  const program = claimApproverProgram(connection);
+  const securityDepositId = findSecurityDepositAddress(tokenManagerId);
+
+  const parsed = await program.account.securityDeposit.fetch(securityDepositId);
+  return {
+    parsed,
+    pubkey: securityDepositId,
+  };
+};
@@ -14,6 +14,7 @@ export const CLAIM_APPROVER_ADDRESS = new PublicKey(
 );
 
 export const CLAIM_APPROVER_SEED = "paid-claim-approver";
+export const SECURITY_DEPOSIT_SEED = "security-deposit";
 
 export const CLAIM_APPROVER_IDL = CLAIM_APPROVER_TYPES.IDL;
 
@@ -25,6 +26,11 @@ export type PaidClaimApproverData = ParsedIdlAccountData<
   CLAIM_APPROVER_PROGRAM
 >;
 
+export type SecurityDepositData = ParsedIdlAccountData<
+  "securityDeposit",
+  CLAIM_APPROVER_PROGRAM
+>;
+
 export const defaultPaymentManagerId = findPaymentManagerAddress(
   DEFAULT_PAYMENT_MANAGER_NAME
 );
@@ -1,7 +1,11 @@
 import { utils } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 
-import { CLAIM_APPROVER_ADDRESS, CLAIM_APPROVER_SEED } from "./constants";
+import {
+  CLAIM_APPROVER_ADDRESS,
+  CLAIM_APPROVER_SEED,
+  SECURITY_DEPOSIT_SEED,
+} from "./constants";
 
 /**
  * Finds the address of the paid claim approver.
@@ -15,3 +19,16 @@ export const findClaimApproverAddress = (
     CLAIM_APPROVER_ADDRESS
   )[0];
 };
+
+/**
+ * Finds the address of the security deposit for a token manager.
+ * @returns
+ */
+export const findSecurityDepositAddress = (
+  tokenManagerId: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(SECURITY_DEPOSIT_SEED), tokenManagerId.toBuffer()],
+    CLAIM_APPROVER_ADDRESS
+  )[0];
+};
@@ -0,0 +1,20 @@
+import type { Connection, PublicKey } from "@solana/web3.js";
+import type { AccountData } from "@solana-nft-programs/common";
+
+import type { ConditionInvalidatorData } from "./constants";
+import { conditionInvalidatorProgram } from "./constants";
+
+export const getConditionInvalidator = async (
+  connection: Connection,
+  conditionInvalidatorId: PublicKey
+): Promise<AccountData<ConditionInvalidatorData>> => {
+  const program = conditionInvalidatorProgram(connection);
+
+  const parsed = await program.account.conditionInvalidator.fetch(
+    conditionInvalidatorId
+  );
+  return {
+    parsed,
+    pubkey: conditionInvalidatorId,
+  };
+};
@@ -0,0 +1,60 @@
+import type { BN } from "@coral-xyz/anchor";
+import { AnchorProvider, Program } from "@coral-xyz/anchor";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import type { ConfirmOptions, Connection } from "@solana/web3.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import type { ParsedIdlAccountData } from "@solana-nft-programs/common";
+import { emptyWallet } from "@solana-nft-programs/common";
+
+import * as CONDITION_INVALIDATOR_TYPES from "../../idl/solana_nft_programs_condition_invalidator";
+
+export const CONDITION_INVALIDATOR_ADDRESS = new PublicKey(
+  "cndaCNu31VN7mMj5Wxrb3KnNL8VAC6MJBbF3ZJ6JFxz"
+);
+
+export const CONDITION_INVALIDATOR_SEED = "condition-invalidator";
+
+export const CONDITION_INVALIDATOR_IDL = CONDITION_INVALIDATOR_TYPES.IDL;
+
+export type CONDITION_INVALIDATOR_PROGRAM =
+  CONDITION_INVALIDATOR_TYPES.SolanaNftProgramsConditionInvalidator;
+
+export type ConditionInvalidatorData = ParsedIdlAccountData<
+  "conditionInvalidator",
+  CONDITION_INVALIDATOR_PROGRAM
+>;
+
+// conditions form a flattened expression tree where the last condition is the root
+// and combinators reference earlier conditions by index
+export type Condition =
+  | { timeAfter: { timestamp: BN } }
+  | { durationElapsed: { seconds: BN } }
+  | { usagesReached: { usages: BN } }
+  | { tokenBalanceBelow: { tokenAccount: PublicKey; amount: BN } }
+  | { tokenBalanceAtLeast: { tokenAccount: PublicKey; amount: BN } }
+  | { accountExists: { account: PublicKey } }
+  | { and: { left: number; right: number } }
+  | { or: { left: number; right: number } }
+  | { not: { child: number } };
+
+export type ConditionInvalidationParams = {
+  collector?: PublicKey;
+  useAuthority?: PublicKey;
+  conditions: Condition[];
+};
+
+export const conditionInvalidatorProgram = (
+  connection: Connection,
+  wallet?: Wallet,
+  confirmOptions?: ConfirmOptions
+) => {
+  return new Program<CONDITION_INVALIDATOR_PROGRAM>(
+    CONDITION_INVALIDATOR_IDL,
+    CONDITION_INVALIDATOR_ADDRESS,
+    new AnchorProvider(
+      connection,
+      wallet ?? emptyWallet(Keypair.generate().publicKey),
+      confirmOptions ?? {}
+    )
+  );
+};
@@ -0,0 +1,23 @@
+import { utils } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+import {
+  CONDITION_INVALIDATOR_ADDRESS,
+  CONDITION_INVALIDATOR_SEED,
+} from "./constants";
+
+/**
+ * Finds the condition invalidator for this token manager.
+ * @returns
+ */
+export const findConditionInvalidatorAddress = (
+  tokenManagerId: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(CONDITION_INVALIDATOR_SEED),
+      tokenManagerId.toBuffer(),
+    ],
+    CONDITION_INVALIDATOR_ADDRESS
+  )[0];
+};
@@ -0,0 +1,31 @@
+import type { PublicKey } from "@solana/web3.js";
+
+import type { Condition } from "./constants";
+
+const conditionAccountId = (condition: Condition): PublicKey | undefined => {
+  if ("tokenBalanceBelow" in condition) {
+    return condition.tokenBalanceBelow.tokenAccount;
+  } else if ("tokenBalanceAtLeast" in condition) {
+    return condition.tokenBalanceAtLeast.tokenAccount;
+  } else if ("accountExists" in condition) {
+    return condition.accountExists.account;
+  }
+  return undefined;
+};
+
+/**
+ * Distinct accounts referenced by the conditions, invalidate expects them
+ * ahead of the token manager invalidate accounts
+ * @param conditions
+ * @returns Condition account ids
+ */
+export const conditionAccountIds = (conditions: Condition[]): PublicKey[] => {
+  const accountIds: PublicKey[] = [];
+  for (const condition of conditions) {
+    const accountId = conditionAccountId(condition);
+    if (accountId && !accountIds.some((id) => id.equals(accountId))) {
+      accountIds.push(accountId);
+    }
+  }
+  return accountIds;
+};
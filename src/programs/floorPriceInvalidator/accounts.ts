@@ -0,0 +1,20 @@
+import type { Connection, PublicKey } from "@solana/web3.js";
+import type { AccountData } from "@solana-nft-programs/common";
+
+import type { FloorPriceInvalidatorData } from "./constants";
+import { floorPriceInvalidatorProgram } from "./constants";
+
+export const getFloorPriceInvalidator = async (
+  connection: Connection,
+  floorPriceInvalidatorId: PublicKey
+): Promise<AccountData<FloorPriceInvalidatorData>> => {
+  const program = floorPriceInvalidatorProgram(connection);
+
+  const parsed = await program.account.floorPriceInvalidator.fetch(
+    floorPriceInvalidatorId
+  );
+  return {
+    parsed,
+    pubkey: floorPriceInvalidatorId,
+  };
+};
@@ -0,0 +1,40 @@
+import { AnchorProvider, Program } from "@coral-xyz/anchor";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import type { ConfirmOptions, Connection } from "@solana/web3.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import type { ParsedIdlAccountData } from "@solana-nft-programs/common";
+import { emptyWallet } from "@solana-nft-programs/common";
+
+import * as FLOOR_PRICE_INVALIDATOR_TYPES from "../../idl/solana_nft_programs_floor_price_invalidator";
+
+export const FLOOR_PRICE_INVALIDATOR_ADDRESS = new PublicKey(
+  "fpr4GCNqSLchrzyES2ZnMKAkjHMKkP6YpqkRhKyHNrZ"
+);
+
+export const FLOOR_PRICE_INVALIDATOR_SEED = "floor-price-invalidator";
+
+export const FLOOR_PRICE_INVALIDATOR_IDL = FLOOR_PRICE_INVALIDATOR_TYPES.IDL;
+
+export type FLOOR_PRICE_INVALIDATOR_PROGRAM =
+  FLOOR_PRICE_INVALIDATOR_TYPES.SolanaNftProgramsFloorPriceInvalidator;
+
+export type FloorPriceInvalidatorData = ParsedIdlAccountData<
+  "floorPriceInvalidator",
+  FLOOR_PRICE_INVALIDATOR_PROGRAM
+>;
+
+export const floorPriceInvalidatorProgram = (
+  connection: Connection,
+  wallet?: Wallet,
+  confirmOptions?: ConfirmOptions
+) => {
+  return new Program<FLOOR_PRICE_INVALIDATOR_PROGRAM>(
+    FLOOR_PRICE_INVALIDATOR_IDL,
+    FLOOR_PRICE_INVALIDATOR_ADDRESS,
+    new AnchorProvider(
+      connection,
+      wallet ?? emptyWallet(Keypair.generate().publicKey),
+      confirmOptions ?? {}
+    )
+  );
+};
@@ -0,0 +1,23 @@
+import { utils } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+import {
+  FLOOR_PRICE_INVALIDATOR_ADDRESS,
+  FLOOR_PRICE_INVALIDATOR_SEED,
+} from "./constants";
+
+/**
+ * Finds the floor price invalidator for this token manager.
+ * @returns
+ */
+export const findFloorPriceInvalidatorAddress = (
+  tokenManagerId: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(FLOOR_PRICE_INVALIDATOR_SEED),
+      tokenManagerId.toBuffer(),
+    ],
+    FLOOR_PRICE_INVALIDATOR_ADDRESS
+  )[0];
+};
@@ -1,5 +1,10 @@
 export * as claimApprover from "./claimApprover";
+export * as conditionInvalidator from "./conditionInvalidator";
+export * as floorPriceInvalidator from "./floorPriceInvalidator";
+export * as installmentInvalidator from "./installmentInvalidator";
+export * as loan from "./loan";
 export * as timeInvalidator from "./timeInvalidator";
+export * as timeUseInvalidator from "./timeUseInvalidator";
 export * as tokenManager from "./tokenManager";
 export * as transferAuthority from "./transferAuthority";
 export * as useInvalidator from "./useInvalidator";
@@ -0,0 +1,20 @@
+import type { Connection, PublicKey } from "@solana/web3.js";
+import type { AccountData } from "@solana-nft-programs/common";
+
+import type { InstallmentInvalidatorData } from "./constants";
+import { installmentInvalidatorProgram } from "./constants";
+
+export const getInstallmentInvalidator = async (
+  connection: Connection,
+  installmentInvalidatorId: PublicKey
+): Promise<AccountData<InstallmentInvalidatorData>> => {
+  const program = installmentInvalidatorProgram(connection);
+
+  const parsed = await program.account.installmentInvalidator.fetch(
+    installmentInvalidatorId
+  );
+  return {
+    parsed,
+    pubkey: installmentInvalidatorId,
+  };
+};
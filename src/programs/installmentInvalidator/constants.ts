@@ -0,0 +1,49 @@
+import { AnchorProvider, Program } from "@coral-xyz/anchor";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import type { ConfirmOptions, Connection } from "@solana/web3.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import type { ParsedIdlAccountData } from "@solana-nft-programs/common";
+import { emptyWallet } from "@solana-nft-programs/common";
+
+import * as INSTALLMENT_INVALIDATOR_TYPES from "../../idl/solana_nft_programs_installment_invalidator";
+
+export const INSTALLMENT_INVALIDATOR_ADDRESS = new PublicKey(
+  "instRN6K1bvL2WLtfUevmTQb7Ntm7quj4NXt6Bsi4y3"
+);
+
+export const INSTALLMENT_INVALIDATOR_SEED = "installment-invalidator";
+
+export const INSTALLMENT_INVALIDATOR_IDL = INSTALLMENT_INVALIDATOR_TYPES.IDL;
+
+export type INSTALLMENT_INVALIDATOR_PROGRAM =
+  INSTALLMENT_INVALIDATOR_TYPES.SolanaNftProgramsInstallmentInvalidator;
+
+export type InstallmentInvalidatorData = ParsedIdlAccountData<
+  "installmentInvalidator",
+  INSTALLMENT_INVALIDATOR_PROGRAM
+>;
+
+export type InstallmentInvalidationParams = {
+  collector?: PublicKey;
+  paymentManager?: PublicKey;
+  paymentMint: PublicKey;
+  installmentAmount: number;
+  installmentPeriodSeconds: number;
+  numInstallments: number;
+};
+
+export const installmentInvalidatorProgram = (
+  connection: Connection,
+  wallet?: Wallet,
+  confirmOptions?: ConfirmOptions
+) => {
+  return new Program<INSTALLMENT_INVALIDATOR_PROGRAM>(
+    INSTALLMENT_INVALIDATOR_IDL,
+    INSTALLMENT_INVALIDATOR_ADDRESS,
+    new AnchorProvider(
+      connection,
+      wallet ?? emptyWallet(Keypair.generate().publicKey),
+      confirmOptions ?? {}
+    )
+  );
+};
@@ -0,0 +1,3 @@
+export * as accounts from "./accounts";
+export * from "./constants";
+export * as pda from "./pda";
@@ -0,0 +1,23 @@
+import { utils } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+import {
+  INSTALLMENT_INVALIDATOR_ADDRESS,
+  INSTALLMENT_INVALIDATOR_SEED,
+} from "./constants";
+
+/**
+ * Finds the installment invalidator for this token manager.
+ * @returns
+ */
+export const findInstallmentInvalidatorAddress = (
+  tokenManagerId: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(INSTALLMENT_INVALIDATOR_SEED),
+      tokenManagerId.toBuffer(),
+    ],
+    INSTALLMENT_INVALIDATOR_ADDRESS
+  )[0];
+};
@@ -0,0 +1,18 @@
+import type { Connection, PublicKey } from "@solana/web3.js";
+import type { AccountData } from "@solana-nft-programs/common";
+
+import type { LoanData } from "./constants";
+import { loanProgram } from "./constants";
+
+export const getLoan = async (
+  connection: Connection,
+  loanId: PublicKey
+): Promise<AccountData<LoanData>> => {
+  const program = loanProgram(connection);
+
+  const parsed = await program.account.loan.fetch(loanId);
+  return {
+    parsed,
+    pubkey: loanId,
+  };
+};
@@ -0,0 +1,36 @@
+import { AnchorProvider, Program } from "@coral-xyz/anchor";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import type { ConfirmOptions, Connection } from "@solana/web3.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import type { ParsedIdlAccountData } from "@solana-nft-programs/common";
+import { emptyWallet } from "@solana-nft-programs/common";
+
+import * as LOAN_TYPES from "../../idl/solana_nft_programs_loan";
+
+export const LOAN_ADDRESS = new PublicKey(
+  "Loanfn4Vpk1yJvACWVJiCo5r5tEFRyVYp5fEz3KEH8v"
+);
+
+export const LOAN_SEED = "loan";
+
+export const LOAN_IDL = LOAN_TYPES.IDL;
+
+export type LOAN_PROGRAM = LOAN_TYPES.SolanaNftProgramsLoan;
+
+export type LoanData = ParsedIdlAccountData<"loan", LOAN_PROGRAM>;
+
+export const loanProgram = (
+  connection: Connection,
+  wallet?: Wallet,
+  confirmOptions?: ConfirmOptions
+) => {
+  return new Program<LOAN_PROGRAM>(
+    LOAN_IDL,
+    LOAN_ADDRESS,
+    new AnchorProvider(
+      connection,
+      wallet ?? emptyWallet(Keypair.generate().publicKey),
+      confirmOptions ?? {}
+    )
+  );
+};
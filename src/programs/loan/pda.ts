@@ -0,0 +1,15 @@
+import { utils } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+import { LOAN_ADDRESS, LOAN_SEED } from "./constants";
+
+/**
+ * Finds the loan for this token manager.
+ * @returns
+ */
+export const findLoanAddress = (tokenManagerId: PublicKey): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(LOAN_SEED), tokenManagerId.toBuffer()],
+    LOAN_ADDRESS
+  )[0];
+};
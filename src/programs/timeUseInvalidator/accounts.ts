@@ -0,0 +1,20 @@
+import type { Connection, PublicKey } from "@solana/web3.js";
+import type { AccountData } from "@solana-nft-programs/common";
+
+import type { TimeUseInvalidatorData } from "./constants";
+import { timeUseInvalidatorProgram } from "./constants";
+
+export const getTimeUseInvalidator = async (
+  connection: Connection,
+  timeUseInvalidatorId: PublicKey
+): Promise<AccountData<TimeUseInvalidatorData>> => {
+  const program = timeUseInvalidatorProgram(connection);
+
+  const parsed = await program.account.timeUseInvalidator.fetch(
+    timeUseInvalidatorId
+  );
+  return {
+    parsed,
+    pubkey: timeUseInvalidatorId,
+  };
+};
@@ -0,0 +1,62 @@
+import { AnchorProvider, Program } from "@coral-xyz/anchor";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import type { ConfirmOptions, Connection } from "@solana/web3.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import type { ParsedIdlAccountData } from "@solana-nft-programs/common";
+import { emptyWallet } from "@solana-nft-programs/common";
+
+import * as TIME_USE_INVALIDATOR_TYPES from "../../idl/solana_nft_programs_time_use_invalidator";
+
+export const TIME_USE_INVALIDATOR_ADDRESS = new PublicKey(
+  "tuseXXvAtJB9wR1Q2VepvnKHK9Mdfho2ufLgvj5d6nA"
+);
+
+export const TIME_USE_INVALIDATOR_SEED = "time-use-invalidator";
+
+export const TIME_USE_INVALIDATOR_IDL = TIME_USE_INVALIDATOR_TYPES.IDL;
+
+export type TIME_USE_INVALIDATOR_PROGRAM =
+  TIME_USE_INVALIDATOR_TYPES.SolanaNftProgramsTimeUseInvalidator;
+
+export type TimeUseInvalidatorData = ParsedIdlAccountData<
+  "timeUseInvalidator",
+  TIME_USE_INVALIDATOR_PROGRAM
+>;
+
+export enum ConditionMode {
+  Or = 0,
+  And = 1,
+}
+
+export type TimeUseInvalidationParams = {
+  collector?: PublicKey;
+  paymentManager?: PublicKey;
+  mode?: ConditionMode;
+  durationSeconds?: number;
+  maxExpiration?: number;
+  totalUsages?: number;
+  maxUsages?: number;
+  useAuthority?: PublicKey;
+  extension?: {
+    extensionPaymentAmount: number;
+    extensionPaymentMint: PublicKey;
+    extensionDurationSeconds?: number;
+    extensionUsages?: number;
+  };
+};
+
+export const timeUseInvalidatorProgram = (
+  connection: Connection,
+  wallet?: Wallet,
+  confirmOptions?: ConfirmOptions
+) => {
+  return new Program<TIME_USE_INVALIDATOR_PROGRAM>(
+    TIME_USE_INVALIDATOR_IDL,
+    TIME_USE_INVALIDATOR_ADDRESS,
+    new AnchorProvider(
+      connection,
+      wallet ?? emptyWallet(Keypair.generate().publicKey),
+      confirmOptions ?? {}
+    )
+  );
+};
@@ -0,0 +1,23 @@
+import { utils } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+import {
+  TIME_USE_INVALIDATOR_ADDRESS,
+  TIME_USE_INVALIDATOR_SEED,
+} from "./constants";
+
+/**
+ * Finds the time use invalidator for this token manager.
+ * @returns
+ */
+export const findTimeUseInvalidatorAddress = (
+  tokenManagerId: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(TIME_USE_INVALIDATOR_SEED),
+      tokenManagerId.toBuffer(),
+    ],
+    TIME_USE_INVALIDATOR_ADDRESS
+  )[0];
+};
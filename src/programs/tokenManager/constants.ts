@@ -23,6 +23,10 @@ export const TOKEN_MANAGER_SEED = "token-manager";
 
 export const RECEIPT_MINT_MANAGER_SEED = "receipt-mint-manager";
 
+export const TOKEN_MANAGER_ARBITER_SEED = "token-manager-arbiter";
+
+export const SUBLEASE_SEED = "sublease";
+
 export const TOKEN_MANAGER_IDL = TOKEN_MANAGER_TYPES.IDL;
 
 export type TOKEN_MANAGER_PROGRAM =
@@ -9,7 +9,9 @@ import {
 } from ".";
 import {
   CLAIM_RECEIPT_SEED,
+  SUBLEASE_SEED,
   TOKEN_MANAGER_ADDRESS,
+  TOKEN_MANAGER_ARBITER_SEED,
   TOKEN_MANAGER_SEED,
 } from "./constants";
 
@@ -109,3 +111,32 @@ export const findReceiptMintManagerId = (): PublicKey => {
     TOKEN_MANAGER_ADDRESS
   )[0];
 };
+
+/**
+ * Finds the token manager arbiter id.
+ * @returns
+ */
+export const findTokenManagerArbiterId = (
+  tokenManagerId: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(TOKEN_MANAGER_ARBITER_SEED),
+      tokenManagerId.toBuffer(),
+    ],
+    TOKEN_MANAGER_ADDRESS
+  )[0];
+};
+
+/**
+ * Finds the child token manager of a sublease, derived from its parent.
+ * @returns
+ */
+export const findSubleaseTokenManagerAddress = (
+  tokenManagerId: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(SUBLEASE_SEED), tokenManagerId.toBuffer()],
+    TOKEN_MANAGER_ADDRESS
+  )[0];
+};
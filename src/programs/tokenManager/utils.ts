@@ -40,8 +40,10 @@ import {
   TokenManagerKind,
   TokenManagerState,
 } from ".";
+import { getTokenManager } from "./accounts";
 import {
   findMintManagerId,
+  findSubleaseTokenManagerAddress,
   findTokenManagerAddress,
   findTransferReceiptId,
 } from "./pda";
@@ -189,6 +191,37 @@ export const withRemainingAccountsForInvalidate = async (
     );
   }
 
+  // invalidation cascades to the child token manager of a sublease
+  if (
+    tokenManagerData.parsed.state === TokenManagerState.Claimed &&
+    tokenManagerData.parsed.subleased
+  ) {
+    const subleaseTokenManagerId = findSubleaseTokenManagerAddress(
+      tokenManagerData.pubkey
+    );
+    const subleaseTokenManagerData = await getTokenManager(
+      connection,
+      subleaseTokenManagerId
+    );
+    remainingAccounts.push(
+      {
+        pubkey: subleaseTokenManagerId,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: subleaseTokenManagerData.parsed.recipientTokenAccount,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: subleaseTokenManagerData.parsed.issuer,
+        isSigner: false,
+        isWritable: true,
+      }
+    );
+  }
+
   if (
     tokenManagerData.parsed.kind === TokenManagerKind.Programmable &&
     (tokenManagerData.parsed.invalidationType === InvalidationType.Release ||
@@ -576,6 +609,87 @@ export const remainingAccountForProgrammableUnlockAndTransfer = (
   ];
 };
 
+export const remainingAccountForProgrammableTransfer = (
+  currentHolder: PublicKey,
+  mintId: PublicKey,
+  currentHolderTokenAccountId: PublicKey,
+  recipientTokenAccountId: PublicKey,
+  rulesetId?: PublicKey | null
+): AccountMeta[] => {
+  const tokenManagerTokenAccountId = getAssociatedTokenAddressSync(
+    mintId,
+    findTokenManagerAddress(mintId),
+    true
+  );
+  return [
+    {
+      pubkey: currentHolder,
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: tokenManagerTokenAccountId,
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: SystemProgram.programId,
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: findMintMetadataId(mintId),
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: findMintEditionId(mintId),
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: findTokenRecordId(mintId, currentHolderTokenAccountId),
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: findTokenRecordId(mintId, tokenManagerTokenAccountId),
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: findTokenRecordId(mintId, recipientTokenAccountId),
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: SYSVAR_INSTRUCTIONS_PUBKEY,
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: ASSOCIATED_TOKEN_PROGRAM_ID,
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: TOKEN_AUTH_RULES_ID,
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: rulesetId ?? METADATA_PROGRAM_ID,
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: METADATA_PROGRAM_ID,
+      isSigner: false,
+      isWritable: false,
+    },
+  ];
+};
+
 export const getRemainingAccountsForIssue = (
   tokenManagerKind: TokenManagerKind,
   mintId: PublicKey,
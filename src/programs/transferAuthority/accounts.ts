@@ -3,8 +3,10 @@ import type { Connection, PublicKey } from "@solana/web3.js";
 import type { AccountData } from "@solana-nft-programs/common";
 
 import type {
+  AuctionData,
   ListingData,
   MarketplaceData,
+  OfferData,
   TransferAuthorityData,
   TransferData,
 } from "./constants";
@@ -296,6 +298,36 @@ export const getTransfersToUser = async (
   );
 };
 
+//////// Offer ////////
+
+export const getOffer = async (
+  connection: Connection,
+  offerId: PublicKey
+): Promise<AccountData<OfferData>> => {
+  const program = transferAuthorityProgram(connection);
+
+  const parsed = await program.account.offer.fetch(offerId);
+  return {
+    parsed,
+    pubkey: offerId,
+  };
+};
+
+//////// Auction ////////
+
+export const getAuction = async (
+  connection: Connection,
+  auctionId: PublicKey
+): Promise<AccountData<AuctionData>> => {
+  const program = transferAuthorityProgram(connection);
+
+  const parsed = await program.account.auction.fetch(auctionId);
+  return {
+    parsed,
+    pubkey: auctionId,
+  };
+};
+
 //////// utils ////////
 export const getAllOfType = async <T>(
   connection: Connection,
@@ -15,6 +15,8 @@ export const TRANSFER_AUTHORITY_SEED = "transfer-authority";
 export const MARKETPLACE_SEED = "marketplace";
 export const LISTING_SEED = "listing";
 export const TRANSFER_SEED = "transfer";
+export const OFFER_SEED = "offer";
+export const AUCTION_SEED = "auction";
 
 export const TRANSFER_AUTHORITY_IDL = TRANSFER_AUTHORITY_TYPES.IDL;
 
@@ -43,6 +45,14 @@ export type TransferData = ParsedIdlAccountData<
   "transfer",
   TRANSFER_AUTHORITY_PROGRAM
 >;
+export type OfferData = ParsedIdlAccountData<
+  "offer",
+  TRANSFER_AUTHORITY_PROGRAM
+>;
+export type AuctionData = ParsedIdlAccountData<
+  "auction",
+  TRANSFER_AUTHORITY_PROGRAM
+>;
 
 export const transferAuthorityProgram = (
   connection: Connection,
@@ -3,8 +3,10 @@ import { PublicKey } from "@solana/web3.js";
 
 import { findTokenManagerAddress } from "../tokenManager/pda";
 import {
+  AUCTION_SEED,
   LISTING_SEED,
   MARKETPLACE_SEED,
+  OFFER_SEED,
   TRANSFER_AUTHORITY_ADDRESS,
   TRANSFER_AUTHORITY_SEED,
   TRANSFER_SEED,
@@ -58,3 +60,29 @@ export const findTransferAddress = (mintId: PublicKey): PublicKey => {
     TRANSFER_AUTHORITY_ADDRESS
   )[0];
 };
+
+/**
+ * Finds the address of the offer.
+ * @returns
+ */
+export const findOfferAddress = (
+  target: PublicKey,
+  buyer: PublicKey
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(OFFER_SEED), target.toBytes(), buyer.toBytes()],
+    TRANSFER_AUTHORITY_ADDRESS
+  )[0];
+};
+
+/**
+ * Finds the address of the auction.
+ * @returns
+ */
+export const findAuctionAddress = (mintId: PublicKey): PublicKey => {
+  const tokenManagerId = findTokenManagerAddress(mintId);
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode(AUCTION_SEED), tokenManagerId.toBytes()],
+    TRANSFER_AUTHORITY_ADDRESS
+  )[0];
+};
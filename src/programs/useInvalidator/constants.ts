@@ -34,6 +34,18 @@ export type UseInvalidationParams = {
     extensionPaymentAmount: number;
     maxUsages?: number;
   };
+  rateLimit?: {
+    maxUsagesPerPeriod: number;
+    usagePeriodSeconds: number;
+  };
+  refill?: {
+    refillPeriodSeconds: number;
+    refillUsages: number;
+    refillReset?: boolean;
+    refillPaymentAmount?: number;
+    maxUsages?: number;
+  };
+  feePerUse?: number;
 };
 
 export const useInvalidatorProgram = (
@@ -0,0 +1,26 @@
+import type { BN } from "@coral-xyz/anchor";
+import { BorshCoder } from "@coral-xyz/anchor";
+import type { PublicKey } from "@solana/web3.js";
+
+import { USE_INVALIDATOR_IDL } from "./constants";
+
+export type UsageAttestation = {
+  tokenManager: PublicKey;
+  useInvalidator: PublicKey;
+  mintCount: BN;
+  numUsages: BN;
+  nonce: BN;
+};
+
+/**
+ * Serializes a usage attestation into the message a use authority signs
+ * @param attestation
+ * @returns Message bytes verified by apply_usage_attestations
+ */
+export const usageAttestationMessage = (
+  attestation: UsageAttestation
+): Buffer =>
+  new BorshCoder(USE_INVALIDATOR_IDL).types.encode(
+    "UsageAttestation",
+    attestation
+  );
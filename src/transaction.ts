@@ -13,9 +13,15 @@ import {
   TOKEN_PROGRAM_ID,
   unpackAccount,
 } from "@solana/spl-token";
-import type { Connection, PublicKey, Transaction } from "@solana/web3.js";
+import type {
+  AccountMeta,
+  Connection,
+  PublicKey,
+  Transaction,
+} from "@solana/web3.js";
 import {
   ComputeBudgetProgram,
+  Ed25519Program,
   Keypair,
   SystemProgram,
   SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -48,7 +54,11 @@ import {
   claimApproverProgram,
   defaultPaymentManagerId,
 } from "./programs/claimApprover";
-import { findClaimApproverAddress } from "./programs/claimApprover/pda";
+import { getSecurityDeposit } from "./programs/claimApprover/accounts";
+import {
+  findClaimApproverAddress,
+  findSecurityDepositAddress,
+} from "./programs/claimApprover/pda";
 import type { TimeInvalidationParams } from "./programs/timeInvalidator";
 import { timeInvalidatorProgram } from "./programs/timeInvalidator";
 import { findTimeInvalidatorAddress } from "./programs/timeInvalidator/pda";
@@ -89,8 +99,12 @@ import {
 } from "./programs/transferAuthority/accounts";
 import { findListingAddress } from "./programs/transferAuthority/pda";
 import type { UseInvalidationParams } from "./programs/useInvalidator";
-import { useInvalidatorProgram } from "./programs/useInvalidator";
+import {
+  USE_INVALIDATOR_ADDRESS,
+  useInvalidatorProgram,
+} from "./programs/useInvalidator";
 import { findUseInvalidatorAddress } from "./programs/useInvalidator/pda";
+import { usageAttestationMessage } from "./programs/useInvalidator/utils";
 
 export type IssueParameters = {
   claimPayment?: ClaimApproverParams;
@@ -364,6 +378,8 @@ export const withIssueToken = async (
   //////////////////////////////
   if (useInvalidation) {
     const useInvalidatorId = findUseInvalidatorAddress(tokenManagerId);
+    const maxUsages =
+      useInvalidation.extension?.maxUsages ?? useInvalidation.refill?.maxUsages;
     const useInvalidatorIx = await usgInvalidatorProgram.methods
       .init({
         collector: useInvalidation.collector || CRANK_KEY,
@@ -372,9 +388,7 @@ export const withIssueToken = async (
         totalUsages: useInvalidation.totalUsages
           ? new BN(useInvalidation.totalUsages)
           : null,
-        maxUsages: useInvalidation.extension?.maxUsages
-          ? new BN(useInvalidation.extension?.maxUsages)
-          : null,
+        maxUsages: maxUsages ? new BN(maxUsages) : null,
         useAuthority: useInvalidation.useAuthority || null,
         extensionPaymentAmount: useInvalidation.extension
           ?.extensionPaymentAmount
@@ -385,6 +399,25 @@ export const withIssueToken = async (
         extensionUsages: useInvalidation.extension?.extensionUsages
           ? new BN(useInvalidation.extension.extensionUsages)
           : null,
+        maxUsagesPerPeriod: useInvalidation.rateLimit
+          ? new BN(useInvalidation.rateLimit.maxUsagesPerPeriod)
+          : null,
+        usagePeriodSeconds: useInvalidation.rateLimit
+          ? new BN(useInvalidation.rateLimit.usagePeriodSeconds)
+          : null,
+        refillPeriodSeconds: useInvalidation.refill
+          ? new BN(useInvalidation.refill.refillPeriodSeconds)
+          : null,
+        refillUsages: useInvalidation.refill
+          ? new BN(useInvalidation.refill.refillUsages)
+          : null,
+        refillReset: useInvalidation.refill?.refillReset ?? null,
+        refillPaymentAmount: useInvalidation.refill?.refillPaymentAmount
+          ? new BN(useInvalidation.refill.refillPaymentAmount)
+          : null,
+        feePerUse: useInvalidation.feePerUse
+          ? new BN(useInvalidation.feePerUse)
+          : null,
       })
       .accounts({
         tokenManager: tokenManagerId,
@@ -528,9 +561,11 @@ export const withClaimToken = async (
   buySideTokenAccountId?: PublicKey
 ): Promise<Transaction> => {
   const claimApproverId = findClaimApproverAddress(tokenManagerId);
+  const securityDepositId = findSecurityDepositAddress(tokenManagerId);
   const accountData = await fetchAccountDataById(connection, [
     tokenManagerId,
     claimApproverId,
+    securityDepositId,
   ]);
   const tokenManagerInfo = accountData[tokenManagerId.toString()];
   if (!tokenManagerInfo?.data) throw "Token manager not found";
@@ -547,6 +582,14 @@ export const withClaimToken = async (
         CLAIM_APPROVER_IDL
       )
     : null;
+  const securityDepositInfo = accountData[securityDepositId.toString()];
+  const securityDepositData = securityDepositInfo
+    ? tryDecodeIdlAccount<"securityDeposit", CLAIM_APPROVER_PROGRAM>(
+        securityDepositInfo,
+        "securityDeposit",
+        CLAIM_APPROVER_IDL
+      )
+    : null;
 
   const metadataId = findMintMetadataId(tokenManagerData.parsed.mint);
   const metadata = await tryNull(
@@ -600,25 +643,68 @@ export const withClaimToken = async (
       }
     );
 
-    const payIx = await claimApproverProgram(connection, wallet)
-      .methods.pay()
-      .accounts({
-        tokenManager: tokenManagerId,
-        paymentTokenAccount: issuerTokenAccountId,
-        feeCollectorTokenAccount: feeCollectorTokenAccountId,
-        paymentManager: claimApproverData.parsed.paymentManager,
-        claimApprover: claimApproverId,
-        payer: wallet.publicKey,
-        payerTokenAccount: payerTokenAccountId,
-        claimReceipt: claimReceiptId,
-        solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
-        solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-      })
-      .remainingAccounts(remainingAccounts)
-      .instruction();
-    transaction.add(payIx);
+    if (
+      claimApproverData.parsed.requiresDeposit &&
+      securityDepositData?.parsed
+    ) {
+      // sol deposits are held by the security deposit account itself
+      const depositMint = securityDepositData.parsed.paymentMint;
+      const payerDepositTokenAccountId = depositMint
+        ? getAssociatedTokenAddressSync(depositMint, wallet.publicKey)
+        : securityDepositId;
+      const depositTokenAccountId = depositMint
+        ? await withFindOrInitAssociatedTokenAccount(
+            transaction,
+            connection,
+            depositMint,
+            securityDepositId,
+            wallet.publicKey,
+            true
+          )
+        : securityDepositId;
+      const payWithDepositIx = await claimApproverProgram(connection, wallet)
+        .methods.payWithDeposit()
+        .accounts({
+          tokenManager: tokenManagerId,
+          paymentTokenAccount: issuerTokenAccountId,
+          feeCollectorTokenAccount: feeCollectorTokenAccountId,
+          paymentManager: claimApproverData.parsed.paymentManager,
+          claimApprover: claimApproverId,
+          payer: wallet.publicKey,
+          payerTokenAccount: payerTokenAccountId,
+          claimReceipt: claimReceiptId,
+          securityDeposit: securityDepositId,
+          payerDepositTokenAccount: payerDepositTokenAccountId,
+          depositTokenAccount: depositTokenAccountId,
+          solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+          solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .instruction();
+      transaction.add(payWithDepositIx);
+    } else {
+      const payIx = await claimApproverProgram(connection, wallet)
+        .methods.pay()
+        .accounts({
+          tokenManager: tokenManagerId,
+          paymentTokenAccount: issuerTokenAccountId,
+          feeCollectorTokenAccount: feeCollectorTokenAccountId,
+          paymentManager: claimApproverData.parsed.paymentManager,
+          claimApprover: claimApproverId,
+          payer: wallet.publicKey,
+          payerTokenAccount: payerTokenAccountId,
+          claimReceipt: claimReceiptId,
+          solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+          solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .instruction();
+      transaction.add(payIx);
+    }
   } else if (tokenManagerData.parsed.claimApprover) {
     const createClaimReceiptIx = await tokenManagerProgram(connection, wallet)
       .methods.createClaimReceipt(wallet.publicKey)
@@ -750,24 +836,27 @@ export const withInvalidate = async (
   const timeInvalidatorId =
     timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId);
 
-  const [useInvalidatorData, timeInvalidatorData, tokenManagerData, metadata] =
-    await Promise.all([
-      tryGetAccount(() =>
-        useInvalidator.accounts.getUseInvalidator(connection, useInvalidatorId)
-      ),
-      tryGetAccount(() =>
-        timeInvalidator.accounts.getTimeInvalidator(
-          connection,
-          timeInvalidatorId
-        )
-      ),
-      tryGetAccount(() =>
-        tokenManager.accounts.getTokenManager(connection, tokenManagerId)
-      ),
-      tryNull(
-        Metadata.fromAccountAddress(connection, findMintMetadataId(mintId))
-      ),
-    ]);
+  const [
+    useInvalidatorData,
+    timeInvalidatorData,
+    tokenManagerData,
+    metadata,
+    securityDepositData,
+  ] = await Promise.all([
+    tryGetAccount(() =>
+      useInvalidator.accounts.getUseInvalidator(connection, useInvalidatorId)
+    ),
+    tryGetAccount(() =>
+      timeInvalidator.accounts.getTimeInvalidator(connection, timeInvalidatorId)
+    ),
+    tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(connection, tokenManagerId)
+    ),
+    tryNull(
+      Metadata.fromAccountAddress(connection, findMintMetadataId(mintId))
+    ),
+    tryGetAccount(() => getSecurityDeposit(connection, tokenManagerId)),
+  ]);
 
   if (!tokenManagerData) return transaction;
   if (
@@ -888,6 +977,18 @@ export const withInvalidate = async (
       .instruction();
     transaction.add(invalidateIx);
   }
+
+  if (securityDepositData?.parsed.depositor) {
+    // save the rental end so the dispute window starts at the invalidation
+    const observeRentalEndIx = await claimApproverProgram(connection, wallet)
+      .methods.observeRentalEnd()
+      .accountsStrict({
+        tokenManager: tokenManagerId,
+        securityDeposit: securityDepositData.pubkey,
+      })
+      .instruction();
+    transaction.add(observeRentalEndIx);
+  }
   return transaction;
 };
 
@@ -964,7 +1065,11 @@ export const withUse = async (
   wallet: Wallet,
   mintId: PublicKey,
   usages: number,
-  collector?: PublicKey
+  collector?: PublicKey,
+  options?: {
+    payer?: PublicKey;
+  },
+  buySideTokenAccountId?: PublicKey
 ): Promise<Transaction> => {
   const tokenManagerId = tokenManagerAddressFromMint(mintId);
   const usgInvalidatorProgram = useInvalidatorProgram(connection, wallet);
@@ -993,6 +1098,13 @@ export const withUse = async (
         extensionPaymentAmount: null,
         extensionPaymentMint: null,
         extensionUsages: null,
+        maxUsagesPerPeriod: null,
+        usagePeriodSeconds: null,
+        refillPeriodSeconds: null,
+        refillUsages: null,
+        refillReset: null,
+        refillPaymentAmount: null,
+        feePerUse: null,
       })
       .accounts({
         tokenManager: tokenManagerId,
@@ -1008,6 +1120,59 @@ export const withUse = async (
   if (!tokenManagerData?.parsed.recipientTokenAccount)
     throw new Error("Token manager has not been claimed");
 
+  // pay per use accounts default to the program id when unused
+  const payer = options?.payer ?? wallet.publicKey;
+  let paymentAccounts = {
+    payer: USE_INVALIDATOR_ADDRESS,
+    payerTokenAccount: USE_INVALIDATOR_ADDRESS,
+    paymentManager: USE_INVALIDATOR_ADDRESS,
+    paymentTokenAccount: USE_INVALIDATOR_ADDRESS,
+    feeCollectorTokenAccount: USE_INVALIDATOR_ADDRESS,
+    tokenProgram: USE_INVALIDATOR_ADDRESS,
+    solanaNftProgramsPaymentManager: USE_INVALIDATOR_ADDRESS,
+  };
+  let paymentRemainingAccounts: AccountMeta[] = [];
+  if (
+    useInvalidatorData?.parsed.feePerUse &&
+    useInvalidatorData.parsed.extensionPaymentMint
+  ) {
+    const payerTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+      transaction,
+      connection,
+      useInvalidatorData.parsed.extensionPaymentMint,
+      payer,
+      wallet.publicKey
+    );
+    const [
+      paymentTokenAccountId,
+      feeCollectorTokenAccountId,
+      remainingAccounts,
+    ] = await withRemainingAccountsForPayment(
+      transaction,
+      connection,
+      wallet,
+      tokenManagerData.parsed.mint,
+      useInvalidatorData.parsed.extensionPaymentMint,
+      tokenManagerData.parsed.issuer,
+      useInvalidatorData.parsed.paymentManager,
+      buySideTokenAccountId,
+      {
+        receiptMint: tokenManagerData.parsed.receiptMint,
+        payer,
+      }
+    );
+    paymentAccounts = {
+      payer,
+      payerTokenAccount: payerTokenAccountId,
+      paymentManager: useInvalidatorData.parsed.paymentManager,
+      paymentTokenAccount: paymentTokenAccountId,
+      feeCollectorTokenAccount: feeCollectorTokenAccountId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+    };
+    paymentRemainingAccounts = remainingAccounts;
+  }
+
   // use
   const incrementUsagesIx = await usgInvalidatorProgram.methods
     .incrementUsages(new BN(usages))
@@ -1016,15 +1181,19 @@ export const withUse = async (
       useInvalidator: useInvalidatorId,
       recipientTokenAccount: tokenManagerData?.parsed.recipientTokenAccount,
       user: wallet.publicKey,
+      ...paymentAccounts,
     })
+    .remainingAccounts(paymentRemainingAccounts)
     .instruction();
   transaction.add(incrementUsagesIx);
 
+  // refilled usages only run out at max usages
+  const usageLimit = useInvalidatorData?.parsed.refillPeriodSeconds
+    ? useInvalidatorData?.parsed.maxUsages
+    : useInvalidatorData?.parsed.totalUsages;
   if (
-    useInvalidatorData?.parsed.totalUsages &&
-    useInvalidatorData?.parsed.usages
-      .add(new BN(usages))
-      .gte(useInvalidatorData?.parsed.totalUsages)
+    usageLimit &&
+    useInvalidatorData?.parsed.usages.add(new BN(usages)).gte(usageLimit)
   ) {
     const tokenManagerTokenAccountId =
       await withFindOrInitAssociatedTokenAccount(
@@ -1216,6 +1385,186 @@ export const withExtendUsages = async (
   return transaction;
 };
 
+export const withRefillUsages = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  options?: {
+    payer?: PublicKey;
+  },
+  buySideTokenAccountId?: PublicKey
+): Promise<Transaction> => {
+  const usgInvalidatorProgram = useInvalidatorProgram(connection, wallet);
+  const useInvalidatorId =
+    useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId);
+  const [useInvalidatorData, tokenManagerData] = await Promise.all([
+    useInvalidator.accounts.getUseInvalidator(connection, useInvalidatorId),
+    tokenManager.accounts.getTokenManager(connection, tokenManagerId),
+  ]);
+  if (!useInvalidatorData.parsed.extensionPaymentMint) {
+    throw "Use invalidator has no payment mint";
+  }
+
+  const payerTokenAccountId = await withFindOrInitAssociatedTokenAccount(
+    transaction,
+    connection,
+    useInvalidatorData.parsed.extensionPaymentMint,
+    wallet.publicKey,
+    wallet.publicKey
+  );
+
+  const [
+    paymentTokenAccountId,
+    feeCollectorTokenAccountId,
+    remainingAccounts,
+  ] = await withRemainingAccountsForPayment(
+    transaction,
+    connection,
+    wallet,
+    tokenManagerData.parsed.mint,
+    useInvalidatorData.parsed.extensionPaymentMint,
+    tokenManagerData.parsed.issuer,
+    useInvalidatorData.parsed.paymentManager,
+    buySideTokenAccountId,
+    {
+      receiptMint: tokenManagerData.parsed.receiptMint,
+      payer: options?.payer,
+    }
+  );
+
+  const refillUsagesIx = await usgInvalidatorProgram.methods
+    .refillUsages()
+    .accounts({
+      tokenManager: tokenManagerId,
+      useInvalidator: useInvalidatorId,
+      paymentManager: useInvalidatorData.parsed.paymentManager,
+      paymentTokenAccount: paymentTokenAccountId,
+      feeCollectorTokenAccount: feeCollectorTokenAccountId,
+      payer: wallet.publicKey,
+      payerTokenAccount: payerTokenAccountId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+  transaction.add(refillUsagesIx);
+
+  return transaction;
+};
+
+export const withAddUseAuthority = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  useAuthority: PublicKey
+): Promise<Transaction> => {
+  const usgInvalidatorProgram = useInvalidatorProgram(connection, wallet);
+  const useInvalidatorId =
+    useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId);
+
+  const addUseAuthorityIx = await usgInvalidatorProgram.methods
+    .addUseAuthority(useAuthority)
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      useInvalidator: useInvalidatorId,
+      issuer: wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  transaction.add(addUseAuthorityIx);
+
+  return transaction;
+};
+
+export const withRemoveUseAuthority = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  useAuthority: PublicKey
+): Promise<Transaction> => {
+  const usgInvalidatorProgram = useInvalidatorProgram(connection, wallet);
+  const useInvalidatorId =
+    useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId);
+
+  const removeUseAuthorityIx = await usgInvalidatorProgram.methods
+    .removeUseAuthority(useAuthority)
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      useInvalidator: useInvalidatorId,
+      issuer: wallet.publicKey,
+    })
+    .instruction();
+  transaction.add(removeUseAuthorityIx);
+
+  return transaction;
+};
+
+/**
+ * Applies usages signed off chain by use authorities
+ * Each signature must cover the message built with usageAttestationMessage
+ * @param transaction
+ * @param connection
+ * @param wallet
+ * @param tokenManagerId
+ * @param attestations
+ * @returns Transaction with an ed25519 verification per attestation
+ */
+export const withApplyUsageAttestations = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  attestations: {
+    authority: PublicKey;
+    numUsages: BN;
+    nonce: BN;
+    signature: Uint8Array;
+  }[]
+): Promise<Transaction> => {
+  const usgInvalidatorProgram = useInvalidatorProgram(connection, wallet);
+  const useInvalidatorId =
+    useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId);
+  const tokenManagerData = await tokenManager.accounts.getTokenManager(
+    connection,
+    tokenManagerId
+  );
+
+  const usageAttestations = attestations.map(
+    ({ authority, numUsages, nonce, signature }) => {
+      const usageAttestation = {
+        tokenManager: tokenManagerId,
+        useInvalidator: useInvalidatorId,
+        mintCount: tokenManagerData.parsed.count,
+        numUsages,
+        nonce,
+      };
+      transaction.add(
+        Ed25519Program.createInstructionWithPublicKey({
+          publicKey: authority.toBytes(),
+          message: usageAttestationMessage(usageAttestation),
+          signature,
+        })
+      );
+      return usageAttestation;
+    }
+  );
+
+  const applyUsageAttestationsIx = await usgInvalidatorProgram.methods
+    .applyUsageAttestations({ attestations: usageAttestations })
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      useInvalidator: useInvalidatorId,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    })
+    .instruction();
+  transaction.add(applyUsageAttestationsIx);
+
+  return transaction;
+};
+
 export const withResetExpiration = async (
   transaction: Transaction,
   connection: Connection,
@@ -1473,3 +1822,62 @@ export const withReplaceInvalidator = async (
 
   return transaction;
 };
+
+export const withCreateClaimReceiptWithExpiration = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  tokenManagerId: PublicKey,
+  target: PublicKey,
+  expiresAt: number,
+  payer = wallet.publicKey
+): Promise<Transaction> => {
+  const tmManagerProgram = tokenManagerProgram(connection, wallet);
+
+  const createClaimReceiptIx = await tmManagerProgram.methods
+    .createClaimReceiptWithExpiration({
+      target,
+      expiresAt: new BN(expiresAt),
+    })
+    .accountsStrict({
+      tokenManager: tokenManagerId,
+      claimApprover: wallet.publicKey,
+      claimReceipt: tokenManager.pda.findClaimReceiptId(
+        tokenManagerId,
+        target
+      ),
+      payer,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+
+  transaction.add(createClaimReceiptIx);
+
+  return transaction;
+};
+
+export const withCloseClaimReceipt = async (
+  transaction: Transaction,
+  connection: Connection,
+  wallet: Wallet,
+  claimReceiptId: PublicKey
+): Promise<Transaction> => {
+  const tmManagerProgram = tokenManagerProgram(connection, wallet);
+  const claimReceiptData = await tmManagerProgram.account.claimReceipt.fetch(
+    claimReceiptId
+  );
+  if (!claimReceiptData.payer) throw "Claim receipt payer not found";
+
+  const closeClaimReceiptIx = await tmManagerProgram.methods
+    .closeClaimReceipt()
+    .accountsStrict({
+      tokenManager: claimReceiptData.tokenManager,
+      claimReceipt: claimReceiptId,
+      payer: claimReceiptData.payer,
+    })
+    .instruction();
+
+  transaction.add(closeClaimReceiptIx);
+
+  return transaction;
+};
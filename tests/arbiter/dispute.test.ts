@@ -0,0 +1,386 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SYSVAR_RENT_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+
+import { claimToken, invalidate, issueToken, withUse } from "../../src";
+import { claimApprover, tokenManager } from "../../src/programs";
+import {
+  InvalidationType,
+  TOKEN_MANAGER_ADDRESS,
+  TokenManagerState,
+} from "../../src/programs/tokenManager";
+import {
+  getRemainingAccountsForKind,
+  withRemainingAccountsForReturn,
+} from "../../src/programs/tokenManager/utils";
+
+describe("Token manager arbiter", () => {
+  let provider: SolanaProvider;
+  const RECIPIENT_START_PAYMENT_AMOUNT = 1000;
+  const RENTAL_PAYMENT_AMOUNT = 10;
+  const DEPOSIT_AMOUNT = 0.1 * LAMPORTS_PER_SOL;
+  const ISSUER_SHARE = 0.04 * LAMPORTS_PER_SOL;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  const arbiter = Keypair.generate();
+  const otherArbiter = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient, arbiter, otherArbiter]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create payment mint
+    [, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  const invalidateTransaction = async (invalidator: Keypair) => {
+    const transaction = new Transaction();
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    const remainingAccountsForReturn = await withRemainingAccountsForReturn(
+      transaction,
+      provider.connection,
+      new Wallet(invalidator),
+      tokenManagerData
+    );
+    transaction.add(
+      await tokenManager
+        .tokenManagerProgram(provider.connection, new Wallet(invalidator))
+        .methods.invalidate()
+        .accounts({
+          tokenManager: tokenManagerId,
+          tokenManagerTokenAccount: await findAta(
+            rentalMint,
+            tokenManagerId,
+            true
+          ),
+          mint: rentalMint,
+          recipientTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+          invalidator: invalidator.publicKey,
+          collector: invalidator.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .remainingAccounts([
+          ...getRemainingAccountsForKind(
+            rentalMint,
+            tokenManagerData.parsed.kind
+          ),
+          ...remainingAccountsForReturn,
+        ])
+        .instruction()
+    );
+    return transaction;
+  };
+
+  const splitDepositTransaction = async (splitter: Keypair) => {
+    const transaction = new Transaction();
+    transaction.add(
+      await claimApprover
+        .claimApproverProgram(provider.connection, new Wallet(splitter))
+        .methods.splitDeposit(new BN(ISSUER_SHARE))
+        .accounts({
+          tokenManager: tokenManagerId,
+          securityDeposit:
+            claimApprover.pda.findSecurityDepositAddress(tokenManagerId),
+          depositTokenAccount:
+            claimApprover.pda.findSecurityDepositAddress(tokenManagerId),
+          issuerDestination: user.publicKey,
+          depositorDestination: recipient.publicKey,
+          issuer: user.publicKey,
+          tokenManagerArbiter:
+            tokenManager.pda.findTokenManagerArbiterId(tokenManagerId),
+          arbiter: splitter.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction()
+    );
+    return transaction;
+  };
+
+  it("Create rental with arbiter", async () => {
+    tokenManagerId = tokenManager.pda.tokenManagerAddressFromMint(rentalMint);
+    const tokenManagerArbiterId =
+      tokenManager.pda.findTokenManagerArbiterId(tokenManagerId);
+    const [transaction] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        claimPayment: {
+          paymentAmount: RENTAL_PAYMENT_AMOUNT,
+          paymentMint: paymentMint,
+        },
+        timeInvalidation: { durationSeconds: 1 },
+        useInvalidation: { totalUsages: 5 },
+        // reserves the invalidator slot that set arbiter takes
+        customInvalidators: [tokenManagerArbiterId],
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    const addInvalidatorIndex = transaction.instructions.findIndex(
+      (ix) =>
+        ix.programId.equals(TOKEN_MANAGER_ADDRESS) &&
+        ix.data.includes(tokenManagerArbiterId.toBuffer())
+    );
+    const setArbiterIx = await tokenManager
+      .tokenManagerProgram(provider.connection, new Wallet(user))
+      .methods.setArbiter(arbiter.publicKey)
+      .accounts({
+        tokenManager: tokenManagerId,
+        tokenManagerArbiter: tokenManagerArbiterId,
+        issuer: user.publicKey,
+        payer: user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+    const initSecurityDepositIx = await claimApprover
+      .claimApproverProgram(provider.connection, new Wallet(user))
+      .methods.initSecurityDeposit({
+        paymentMint: null,
+        amount: new BN(DEPOSIT_AMOUNT),
+        arbiter: null,
+        disputeWindowSeconds: new BN(60),
+        maxExpiration: null,
+      })
+      .accounts({
+        tokenManager: tokenManagerId,
+        claimApprover: claimApprover.pda.findClaimApproverAddress(
+          tokenManagerId
+        ),
+        securityDeposit:
+          claimApprover.pda.findSecurityDepositAddress(tokenManagerId),
+        issuer: user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+    transaction.instructions.splice(
+      addInvalidatorIndex,
+      1,
+      setArbiterIx,
+      initSecurityDepositIx
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(
+      tokenManagerData.parsed.invalidators.map((i) => i.toString())
+    ).toContain(tokenManagerArbiterId.toString());
+    expect(
+      tokenManagerData.parsed.invalidators.map((i) => i.toString())
+    ).not.toContain(arbiter.publicKey.toString());
+  });
+
+  it("Claim", async () => {
+    const transaction = await claimToken(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Claimed);
+  });
+
+  it("Arbiter cannot invalidate without a dispute", async () => {
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await invalidateTransaction(arbiter),
+        new Wallet(arbiter)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Open dispute", async () => {
+    const transaction = new Transaction();
+    transaction.add(
+      await tokenManager
+        .tokenManagerProgram(provider.connection, new Wallet(arbiter))
+        .methods.openDispute()
+        .accounts({
+          tokenManager: tokenManagerId,
+          tokenManagerArbiter:
+            tokenManager.pda.findTokenManagerArbiterId(tokenManagerId),
+          arbiter: arbiter.publicKey,
+        })
+        .instruction()
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(arbiter)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.disputed).toEqual(true);
+  });
+
+  it("Use is blocked during a dispute", async () => {
+    const transaction = new Transaction();
+    await withUse(
+      transaction,
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint,
+      1
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Time invalidation is blocked during a dispute", async () => {
+    await new Promise((r) => setTimeout(r, 2000));
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await invalidate(provider.connection, new Wallet(user), rentalMint),
+        new Wallet(user)
+      )
+    ).rejects.toThrow();
+  });
+
+  const resolveDisputeInstruction = async (
+    invalidationType: InvalidationType
+  ) =>
+    tokenManager
+      .tokenManagerProgram(provider.connection, new Wallet(arbiter))
+      .methods.resolveDispute({ invalidationType })
+      .accounts({
+        tokenManager: tokenManagerId,
+        tokenManagerArbiter:
+          tokenManager.pda.findTokenManagerArbiterId(tokenManagerId),
+        arbiter: arbiter.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .instruction();
+
+  it("Resolve dispute without enforcing the outcome fails", async () => {
+    const transaction = new Transaction();
+    transaction.add(await resolveDisputeInstruction(InvalidationType.Return));
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(arbiter))
+    ).rejects.toThrow();
+  });
+
+  it("Resolve dispute and return the token", async () => {
+    const transaction = new Transaction();
+    transaction.add(await resolveDisputeInstruction(InvalidationType.Return));
+    transaction.add(...(await invalidateTransaction(arbiter)).instructions);
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(arbiter)
+    );
+
+    const tokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(provider.connection, tokenManagerId)
+    );
+    expect(tokenManagerData).toEqual(null);
+
+    const checkIssuerTokenAccount = await getAccount(
+      provider.connection,
+      issuerTokenAccountId
+    );
+    expect(checkIssuerTokenAccount.amount.toString()).toEqual("1");
+  });
+
+  it("Split deposit by another signer fails", async () => {
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await splitDepositTransaction(otherArbiter),
+        new Wallet(otherArbiter)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Arbiter splits the deposit", async () => {
+    const recipientLamportsBefore = await provider.connection.getBalance(
+      recipient.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      await splitDepositTransaction(arbiter),
+      new Wallet(arbiter)
+    );
+
+    const recipientLamportsAfter = await provider.connection.getBalance(
+      recipient.publicKey
+    );
+    expect(recipientLamportsAfter - recipientLamportsBefore).toEqual(
+      DEPOSIT_AMOUNT - ISSUER_SHARE
+    );
+
+    const securityDepositData = await tryGetAccount(() =>
+      claimApprover.accounts.getSecurityDeposit(
+        provider.connection,
+        tokenManagerId
+      )
+    );
+    expect(securityDepositData).toEqual(null);
+  });
+});
@@ -0,0 +1,200 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+
+import {
+  claimToken,
+  issueToken,
+  withConditionIncrementUsages,
+  withConditionInvalidate,
+  withInitConditionInvalidator,
+} from "../../src";
+import { conditionInvalidator, tokenManager } from "../../src/programs";
+import type { Condition } from "../../src/programs/conditionInvalidator";
+import {
+  InvalidationType,
+  TokenManagerKind,
+  TokenManagerState,
+} from "../../src/programs/tokenManager";
+
+describe("Condition invalidator", () => {
+  let provider: SolanaProvider;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let balanceTokenAccountId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // token account whose balance is referenced by the conditions
+    [balanceTokenAccountId] = await createMint(
+      provider.connection,
+      new Wallet(user),
+      { amount: 10 }
+    );
+  });
+
+  const issueAndClaim = async (
+    conditions: Condition[],
+    invalidationType: InvalidationType
+  ) => {
+    const [issuerTokenAccountId, mintId] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+    const tokenManagerId = tokenManager.pda.tokenManagerAddressFromMint(mintId);
+    const [transaction] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        mint: mintId,
+        issuerTokenAccountId,
+        kind: TokenManagerKind.Managed,
+        invalidationType,
+        customInvalidators: [
+          conditionInvalidator.pda.findConditionInvalidatorAddress(
+            tokenManagerId
+          ),
+        ],
+      }
+    );
+    // the invalidator must be initialized before the token is issued
+    const [initTransaction] = await withInitConditionInvalidator(
+      new Transaction(),
+      provider.connection,
+      new Wallet(user),
+      tokenManagerId,
+      { conditions }
+    );
+    transaction.instructions.splice(
+      transaction.instructions.length - 1,
+      0,
+      ...initTransaction.instructions
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+    return [issuerTokenAccountId, mintId, tokenManagerId] as const;
+  };
+
+  const invalidate = async (mintId: PublicKey) => {
+    const transaction = new Transaction();
+    await withConditionInvalidate(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      mintId
+    );
+    return executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet
+    );
+  };
+
+  it("Invalidate once usages or a low balance are reached", async () => {
+    const [issuerTokenAccountId, mintId, tokenManagerId] = await issueAndClaim(
+      [
+        { usagesReached: { usages: new BN(2) } },
+        {
+          tokenBalanceBelow: {
+            tokenAccount: balanceTokenAccountId,
+            amount: new BN(1),
+          },
+        },
+        { or: { left: 0, right: 1 } },
+      ],
+      InvalidationType.Return
+    );
+
+    await expect(invalidate(mintId)).rejects.toThrow();
+
+    const transaction = new Transaction();
+    await withConditionIncrementUsages(
+      transaction,
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      2
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    // the balance account is passed ahead of the return accounts
+    await invalidate(mintId);
+
+    const tokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(provider.connection, tokenManagerId)
+    );
+    expect(tokenManagerData).toEqual(null);
+    const checkIssuerTokenAccount = await getAccount(
+      provider.connection,
+      issuerTokenAccountId
+    );
+    expect(checkIssuerTokenAccount.amount.toString()).toEqual("1");
+    const conditionInvalidatorInfo = await provider.connection.getAccountInfo(
+      conditionInvalidator.pda.findConditionInvalidatorAddress(tokenManagerId)
+    );
+    expect(conditionInvalidatorInfo).toBeNull();
+  });
+
+  it("Invalidate on time and account conditions", async () => {
+    const [, mintId, tokenManagerId] = await issueAndClaim(
+      [
+        {
+          timeAfter: { timestamp: new BN(Math.floor(Date.now() / 1000) - 10) },
+        },
+        { accountExists: { account: balanceTokenAccountId } },
+        { and: { left: 0, right: 1 } },
+      ],
+      InvalidationType.Invalidate
+    );
+
+    await invalidate(mintId);
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(
+      TokenManagerState.Invalidated
+    );
+    // the invalidator is only closed once the token manager no longer needs it
+    const conditionInvalidatorInfo = await provider.connection.getAccountInfo(
+      conditionInvalidator.pda.findConditionInvalidatorAddress(tokenManagerId)
+    );
+    expect(conditionInvalidatorInfo).not.toBeNull();
+  });
+});
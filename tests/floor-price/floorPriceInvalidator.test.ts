@@ -0,0 +1,265 @@
+import { AnchorProvider, BN, Program, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+
+import { claimToken, issueToken } from "../../src";
+import type { SolanaNftProgramsMockOracle } from "../../src/idl/solana_nft_programs_mock_oracle";
+import { IDL as MOCK_ORACLE_IDL } from "../../src/idl/solana_nft_programs_mock_oracle";
+import { floorPriceInvalidator, tokenManager } from "../../src/programs";
+import {
+  TOKEN_MANAGER_ADDRESS,
+  TokenManagerKind,
+} from "../../src/programs/tokenManager";
+import {
+  getRemainingAccountsForKind,
+  withRemainingAccountsForReturn,
+} from "../../src/programs/tokenManager/utils";
+
+const MOCK_ORACLE_ADDRESS = new PublicKey(
+  "orcvQGVAjzAQcXpeiSX1NpmWBVoHSaddcXjG16syVHm"
+);
+
+describe("Floor price invalidator", () => {
+  let provider: SolanaProvider;
+  let mockOracle: Program<SolanaNftProgramsMockOracle>;
+  const THRESHOLD_PRICE = 50;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  const priceFeed = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    mockOracle = new Program<SolanaNftProgramsMockOracle>(
+      MOCK_ORACLE_IDL,
+      MOCK_ORACLE_ADDRESS,
+      new AnchorProvider(provider.connection, provider.wallet, {})
+    );
+    for (const keypair of [user, recipient]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  const setPrice = async (price: number, exponent: number) => {
+    const transaction = new Transaction();
+    transaction.add(
+      await mockOracle.methods
+        .setPrice({ price: new BN(price), exponent, publishTime: null })
+        .accounts({
+          priceFeed: priceFeed.publicKey,
+          owner: provider.wallet.publicKey,
+        })
+        .instruction()
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  };
+
+  const issueWithFloorPrice = async (thresholdExponent: number) => {
+    const [transaction, issuedTokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        kind: TokenManagerKind.Managed,
+        customInvalidators: [
+          floorPriceInvalidator.pda.findFloorPriceInvalidatorAddress(
+            tokenManager.pda.tokenManagerAddressFromMint(rentalMint)
+          ),
+        ],
+      }
+    );
+    const initIx = await floorPriceInvalidator
+      .floorPriceInvalidatorProgram(provider.connection, new Wallet(user))
+      .methods.init({
+        collector: user.publicKey,
+        priceFeed: priceFeed.publicKey,
+        oracleProgram: MOCK_ORACLE_ADDRESS,
+        thresholdPrice: new BN(THRESHOLD_PRICE),
+        thresholdExponent,
+        maxStalenessSeconds: new BN(60),
+      })
+      .accounts({
+        tokenManager: issuedTokenManagerId,
+        floorPriceInvalidator:
+          floorPriceInvalidator.pda.findFloorPriceInvalidatorAddress(
+            issuedTokenManagerId
+          ),
+        issuer: user.publicKey,
+        payer: user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+    // the invalidator must be initialized before the token is issued
+    transaction.instructions.splice(
+      transaction.instructions.length - 1,
+      0,
+      initIx
+    );
+    return [transaction, issuedTokenManagerId] as const;
+  };
+
+  const invalidateTransaction = async () => {
+    const transaction = new Transaction();
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    const remainingAccountsForReturn = await withRemainingAccountsForReturn(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      tokenManagerData
+    );
+    transaction.add(
+      await floorPriceInvalidator
+        .floorPriceInvalidatorProgram(provider.connection, provider.wallet)
+        .methods.invalidate()
+        .accounts({
+          tokenManager: tokenManagerId,
+          floorPriceInvalidator:
+            floorPriceInvalidator.pda.findFloorPriceInvalidatorAddress(
+              tokenManagerId
+            ),
+          priceFeed: priceFeed.publicKey,
+          invalidator: provider.wallet.publicKey,
+          solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenManagerTokenAccount: await findAta(
+            rentalMint,
+            tokenManagerId,
+            true
+          ),
+          mint: rentalMint,
+          recipientTokenAccount: tokenManagerData.parsed.recipientTokenAccount,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .remainingAccounts([
+          ...getRemainingAccountsForKind(
+            rentalMint,
+            tokenManagerData.parsed.kind
+          ),
+          ...remainingAccountsForReturn,
+        ])
+        .instruction()
+    );
+    return transaction;
+  };
+
+  it("Create price feed", async () => {
+    const transaction = new Transaction();
+    transaction.add(
+      await mockOracle.methods
+        .initPriceFeed({ price: new BN(100), exponent: 0 })
+        .accounts({
+          priceFeed: priceFeed.publicKey,
+          owner: provider.wallet.publicKey,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction()
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet,
+      { signers: [priceFeed] }
+    );
+  });
+
+  it("Fail to init with an out of range threshold exponent", async () => {
+    const [transaction] = await issueWithFloorPrice(19);
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(user))
+    ).rejects.toThrow();
+  });
+
+  it("Issue and claim", async () => {
+    const [transaction, issuedTokenManagerId] = await issueWithFloorPrice(0);
+    tokenManagerId = issuedTokenManagerId;
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+  });
+
+  it("Fail to invalidate above the threshold", async () => {
+    const transaction = await invalidateTransaction();
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet)
+    ).rejects.toThrow();
+  });
+
+  it("Fail to invalidate with an out of range feed exponent", async () => {
+    // previously panicked when scaling by 10^(exponent difference)
+    await setPrice(1, 40);
+    const transaction = await invalidateTransaction();
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet)
+    ).rejects.toThrow();
+  });
+
+  it("Fail to invalidate at a larger feed exponent", async () => {
+    await setPrice(1, 18);
+    const transaction = await invalidateTransaction();
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet)
+    ).rejects.toThrow();
+  });
+
+  it("Invalidate below the threshold", async () => {
+    await setPrice(THRESHOLD_PRICE * 100 - 1, -2);
+    const transaction = await invalidateTransaction();
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const tokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(provider.connection, tokenManagerId)
+    );
+    expect(tokenManagerData).toEqual(null);
+    const checkIssuerTokenAccount = await getAccount(
+      provider.connection,
+      issuerTokenAccountId
+    );
+    expect(checkIssuerTokenAccount.amount.toString()).toEqual("1");
+  });
+});
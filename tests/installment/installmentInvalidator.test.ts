@@ -0,0 +1,234 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+
+import {
+  claimToken,
+  issueToken,
+  withInitInstallmentInvalidator,
+  withInstallmentInvalidate,
+  withPayInstallment,
+} from "../../src";
+import { installmentInvalidator, tokenManager } from "../../src/programs";
+import {
+  InvalidationType,
+  TokenManagerKind,
+} from "../../src/programs/tokenManager";
+
+describe("Installment invalidator", () => {
+  const RECIPIENT_START_PAYMENT_AMOUNT = 1000;
+  const INSTALLMENT_AMOUNT = 10;
+
+  let provider: SolanaProvider;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let paymentMint: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    [, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+  });
+
+  const issueAndClaim = async (
+    installmentPeriodSeconds: number,
+    otherInvalidators: PublicKey[] = []
+  ) => {
+    const [issuerTokenAccountId, mintId] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+    const tokenManagerId = tokenManager.pda.tokenManagerAddressFromMint(mintId);
+    const [transaction] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        mint: mintId,
+        issuerTokenAccountId,
+        kind: TokenManagerKind.Managed,
+        invalidationType: InvalidationType.Return,
+        customInvalidators: [
+          installmentInvalidator.pda.findInstallmentInvalidatorAddress(
+            tokenManagerId
+          ),
+          ...otherInvalidators,
+        ],
+      }
+    );
+    // the invalidator must be initialized before the token is issued
+    const [initTransaction] = await withInitInstallmentInvalidator(
+      new Transaction(),
+      provider.connection,
+      new Wallet(user),
+      tokenManagerId,
+      {
+        paymentMint,
+        installmentAmount: INSTALLMENT_AMOUNT,
+        installmentPeriodSeconds,
+        numInstallments: 2,
+      }
+    );
+    transaction.instructions.splice(
+      transaction.instructions.length - 1,
+      0,
+      ...initTransaction.instructions
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+    return [issuerTokenAccountId, mintId, tokenManagerId] as const;
+  };
+
+  const payInstallment = async (tokenManagerId: PublicKey) => {
+    const transaction = new Transaction();
+    await withPayInstallment(
+      transaction,
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    return executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+  };
+
+  const invalidate = async (mintId: PublicKey) => {
+    const transaction = new Transaction();
+    await withInstallmentInvalidate(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      mintId
+    );
+    return executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet
+    );
+  };
+
+  it("Fail to init when it is not the only invalidator", async () => {
+    await expect(issueAndClaim(1000, [user.publicKey])).rejects.toThrow();
+  });
+
+  it("Releases the token once every installment is paid", async () => {
+    const [, mintId, tokenManagerId] = await issueAndClaim(1000);
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.releasable).toEqual(true);
+
+    // the issuer cannot switch away from return while the invalidator is attached
+    const reissueTransaction = new Transaction().add(
+      await tokenManager
+        .tokenManagerProgram(provider.connection, new Wallet(user))
+        .methods.updateInvalidationType(InvalidationType.Reissue)
+        .accounts({
+          tokenManager: tokenManagerId,
+          issuer: user.publicKey,
+        })
+        .instruction()
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        reissueTransaction,
+        new Wallet(user)
+      )
+    ).rejects.toThrow();
+
+    await expect(invalidate(mintId)).rejects.toThrow();
+    await payInstallment(tokenManagerId);
+    await expect(invalidate(mintId)).rejects.toThrow();
+    await payInstallment(tokenManagerId);
+    await expect(payInstallment(tokenManagerId)).rejects.toThrow();
+
+    const installmentInvalidatorData =
+      await installmentInvalidator.accounts.getInstallmentInvalidator(
+        provider.connection,
+        installmentInvalidator.pda.findInstallmentInvalidatorAddress(
+          tokenManagerId
+        )
+      );
+    expect(installmentInvalidatorData.parsed.installmentsPaid).toEqual(
+      new BN(2)
+    );
+
+    await invalidate(mintId);
+
+    const checkTokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(provider.connection, tokenManagerId)
+    );
+    expect(checkTokenManagerData).toEqual(null);
+    const checkRecipientTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(mintId, recipient.publicKey)
+    );
+    expect(checkRecipientTokenAccount.amount.toString()).toEqual("1");
+    expect(checkRecipientTokenAccount.isFrozen).toEqual(false);
+  });
+
+  it("Returns the token when an installment is missed", async () => {
+    const [issuerTokenAccountId, mintId, tokenManagerId] =
+      await issueAndClaim(1);
+
+    await new Promise((r) => setTimeout(r, 3000));
+    await expect(payInstallment(tokenManagerId)).rejects.toThrow();
+    await invalidate(mintId);
+
+    const checkTokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(provider.connection, tokenManagerId)
+    );
+    expect(checkTokenManagerData).toEqual(null);
+    const checkIssuerTokenAccount = await getAccount(
+      provider.connection,
+      issuerTokenAccountId
+    );
+    expect(checkIssuerTokenAccount.amount.toString()).toEqual("1");
+    const installmentInvalidatorInfo =
+      await provider.connection.getAccountInfo(
+        installmentInvalidator.pda.findInstallmentInvalidatorAddress(
+          tokenManagerId
+        )
+      );
+    expect(installmentInvalidatorInfo).toBeNull();
+  });
+});
@@ -0,0 +1,426 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  createAssociatedTokenAccountIdempotentInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+  transfer,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+
+import { claimToken, issueToken } from "../../src";
+import { loan, tokenManager } from "../../src/programs";
+import {
+  InvalidationType,
+  TOKEN_MANAGER_ADDRESS,
+  TokenManagerKind,
+} from "../../src/programs/tokenManager";
+import {
+  getRemainingAccountsForKind,
+  withRemainingAccountsForReturn,
+} from "../../src/programs/tokenManager/utils";
+
+describe("Loan", () => {
+  let provider: SolanaProvider;
+  const PRINCIPAL = 100;
+  const INTEREST_BPS = 1000;
+  const LENDER_START_PAYMENT_AMOUNT = 1000;
+  const borrower = Keypair.generate();
+  const lender = Keypair.generate();
+  const otherInvalidator = Keypair.generate();
+  let paymentMint: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [borrower, lender, otherInvalidator]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create payment mint
+    [, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(lender),
+      { amount: LENDER_START_PAYMENT_AMOUNT }
+    );
+  });
+
+  const ata = (mint: PublicKey, owner: PublicKey) =>
+    getAssociatedTokenAddressSync(mint, owner, true);
+
+  const issueAndClaim = async (extraInvalidators: PublicKey[] = []) => {
+    const [issuerTokenAccountId, mint] = await createMint(
+      provider.connection,
+      new Wallet(borrower)
+    );
+    const receiptMintKeypair = Keypair.generate();
+    const tokenManagerId = tokenManager.pda.tokenManagerAddressFromMint(mint);
+    const [transaction] = await issueToken(
+      provider.connection,
+      new Wallet(borrower),
+      {
+        mint,
+        issuerTokenAccountId,
+        kind: TokenManagerKind.Managed,
+        invalidationType: InvalidationType.Return,
+        customInvalidators: [
+          loan.pda.findLoanAddress(tokenManagerId),
+          ...extraInvalidators,
+        ],
+        receiptOptions: { receiptMintKeypair },
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(borrower),
+      { signers: [receiptMintKeypair] }
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(borrower),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(borrower)
+    );
+    return {
+      mint,
+      tokenManagerId,
+      receiptMint: receiptMintKeypair.publicKey,
+    };
+  };
+
+  const initLoanTransaction = async (
+    mint: PublicKey,
+    tokenManagerId: PublicKey,
+    receiptMint: PublicKey,
+    durationSeconds: number,
+    principal = new BN(PRINCIPAL),
+    interestBps = new BN(INTEREST_BPS)
+  ) => {
+    const loanId = loan.pda.findLoanAddress(tokenManagerId);
+    const transaction = new Transaction();
+    transaction.add(
+      createAssociatedTokenAccountIdempotentInstruction(
+        borrower.publicKey,
+        ata(receiptMint, loanId),
+        loanId,
+        receiptMint
+      ),
+      await loan
+        .loanProgram(provider.connection, new Wallet(borrower))
+        .methods.init({
+          paymentMint,
+          principal,
+          interestBps,
+          durationSeconds: new BN(durationSeconds),
+        })
+        .accounts({
+          tokenManager: tokenManagerId,
+          recipientTokenAccount: ata(mint, borrower.publicKey),
+          loan: loanId,
+          borrowerReceiptTokenAccount: ata(receiptMint, borrower.publicKey),
+          loanReceiptTokenAccount: ata(receiptMint, loanId),
+          borrower: borrower.publicKey,
+          solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction()
+    );
+    return transaction;
+  };
+
+  const fundLoan = async (
+    tokenManagerId: PublicKey,
+    receiptMint: PublicKey
+  ) => {
+    const loanId = loan.pda.findLoanAddress(tokenManagerId);
+    const transaction = new Transaction();
+    transaction.add(
+      createAssociatedTokenAccountIdempotentInstruction(
+        lender.publicKey,
+        ata(receiptMint, lender.publicKey),
+        lender.publicKey,
+        receiptMint
+      ),
+      createAssociatedTokenAccountIdempotentInstruction(
+        lender.publicKey,
+        ata(paymentMint, borrower.publicKey),
+        borrower.publicKey,
+        paymentMint
+      ),
+      await loan
+        .loanProgram(provider.connection, new Wallet(lender))
+        .methods.fund()
+        .accounts({
+          tokenManager: tokenManagerId,
+          loan: loanId,
+          loanReceiptTokenAccount: ata(receiptMint, loanId),
+          lenderReceiptTokenAccount: ata(receiptMint, lender.publicKey),
+          lenderPaymentTokenAccount: ata(paymentMint, lender.publicKey),
+          borrowerPaymentTokenAccount: ata(paymentMint, borrower.publicKey),
+          lender: lender.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction()
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lender)
+    );
+  };
+
+  const repayTransaction = async (mint: PublicKey, tokenManagerId: PublicKey) =>
+    new Transaction().add(
+      await loan
+        .loanProgram(provider.connection, new Wallet(borrower))
+        .methods.repay()
+        .accounts({
+          tokenManager: tokenManagerId,
+          loan: loan.pda.findLoanAddress(tokenManagerId),
+          borrower: borrower.publicKey,
+          payer: borrower.publicKey,
+          payerPaymentTokenAccount: ata(paymentMint, borrower.publicKey),
+          lenderPaymentTokenAccount: ata(paymentMint, lender.publicKey),
+          solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenManagerTokenAccount: ata(mint, tokenManagerId),
+          mint,
+          recipientTokenAccount: ata(mint, borrower.publicKey),
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .remainingAccounts(
+          getRemainingAccountsForKind(mint, TokenManagerKind.Managed)
+        )
+        .instruction()
+    );
+
+  it("Fail to init a loan alongside other invalidators", async () => {
+    const { mint, tokenManagerId, receiptMint } = await issueAndClaim([
+      otherInvalidator.publicKey,
+    ]);
+    const transaction = await initLoanTransaction(
+      mint,
+      tokenManagerId,
+      receiptMint,
+      60
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(borrower)
+      )
+    ).rejects.toThrow();
+
+    // only the sole invalidator of a releasable token manager can release the token
+    const releaseTransaction = new Transaction().add(
+      await tokenManager
+        .tokenManagerProgram(provider.connection, new Wallet(otherInvalidator))
+        .methods.updateInvalidationTypeByInvalidator(InvalidationType.Release)
+        .accounts({
+          tokenManager: tokenManagerId,
+          invalidator: otherInvalidator.publicKey,
+        })
+        .instruction()
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        releaseTransaction,
+        new Wallet(otherInvalidator)
+      )
+    ).rejects.toThrow();
+
+    // the issuer cannot switch away from return once a receipt exists
+    const reissueTransaction = new Transaction().add(
+      await tokenManager
+        .tokenManagerProgram(provider.connection, new Wallet(borrower))
+        .methods.updateInvalidationType(InvalidationType.Reissue)
+        .accounts({
+          tokenManager: tokenManagerId,
+          issuer: borrower.publicKey,
+        })
+        .instruction()
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        reissueTransaction,
+        new Wallet(borrower)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Fail to init a loan whose repayment overflows", async () => {
+    const { mint, tokenManagerId, receiptMint } = await issueAndClaim();
+    const transaction = await initLoanTransaction(
+      mint,
+      tokenManagerId,
+      receiptMint,
+      60,
+      new BN("18446744073709551615"),
+      new BN(10000)
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(borrower)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Repay before the due date releases the collateral", async () => {
+    const { mint, tokenManagerId, receiptMint } = await issueAndClaim();
+    const initTransaction = await initLoanTransaction(
+      mint,
+      tokenManagerId,
+      receiptMint,
+      60
+    );
+    await executeTransaction(
+      provider.connection,
+      initTransaction,
+      new Wallet(borrower)
+    );
+    await fundLoan(tokenManagerId, receiptMint);
+
+    const borrowerPaymentTokenAccount = await getAccount(
+      provider.connection,
+      ata(paymentMint, borrower.publicKey)
+    );
+    expect(borrowerPaymentTokenAccount.amount.toString()).toEqual(
+      PRINCIPAL.toString()
+    );
+    const lenderReceiptTokenAccount = await getAccount(
+      provider.connection,
+      ata(receiptMint, lender.publicKey)
+    );
+    expect(lenderReceiptTokenAccount.amount.toString()).toEqual("1");
+
+    // borrower holds the principal only, so top up the interest
+    const interest = (PRINCIPAL * INTEREST_BPS) / 10000;
+    await transfer(
+      provider.connection,
+      lender,
+      ata(paymentMint, lender.publicKey),
+      ata(paymentMint, borrower.publicKey),
+      lender,
+      interest
+    );
+
+    const transaction = await repayTransaction(mint, tokenManagerId);
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(borrower)
+    );
+
+    const tokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(provider.connection, tokenManagerId)
+    );
+    expect(tokenManagerData).toEqual(null);
+    const borrowerTokenAccount = await getAccount(
+      provider.connection,
+      ata(mint, borrower.publicKey)
+    );
+    expect(borrowerTokenAccount.amount.toString()).toEqual("1");
+    expect(borrowerTokenAccount.isFrozen).toEqual(false);
+  });
+
+  it("Fail to repay after the due date and return to the lender", async () => {
+    const { mint, tokenManagerId, receiptMint } = await issueAndClaim();
+    const initTransaction = await initLoanTransaction(
+      mint,
+      tokenManagerId,
+      receiptMint,
+      2
+    );
+    await executeTransaction(
+      provider.connection,
+      initTransaction,
+      new Wallet(borrower)
+    );
+    await fundLoan(tokenManagerId, receiptMint);
+    await new Promise((r) => setTimeout(r, 4000));
+
+    const transaction = await repayTransaction(mint, tokenManagerId);
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(borrower)
+      )
+    ).rejects.toThrow();
+
+    const invalidateTransaction = new Transaction();
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    const remainingAccountsForReturn = await withRemainingAccountsForReturn(
+      invalidateTransaction,
+      provider.connection,
+      new Wallet(lender),
+      tokenManagerData
+    );
+    invalidateTransaction.add(
+      await loan
+        .loanProgram(provider.connection, new Wallet(lender))
+        .methods.invalidate()
+        .accounts({
+          tokenManager: tokenManagerId,
+          loan: loan.pda.findLoanAddress(tokenManagerId),
+          borrower: borrower.publicKey,
+          lender: lender.publicKey,
+          solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenManagerTokenAccount: ata(mint, tokenManagerId),
+          mint,
+          recipientTokenAccount: ata(mint, borrower.publicKey),
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .remainingAccounts([
+          ...getRemainingAccountsForKind(mint, TokenManagerKind.Managed),
+          ...remainingAccountsForReturn,
+        ])
+        .instruction()
+    );
+    await executeTransaction(
+      provider.connection,
+      invalidateTransaction,
+      new Wallet(lender)
+    );
+
+    const lenderTokenAccount = await getAccount(
+      provider.connection,
+      ata(mint, lender.publicKey)
+    );
+    expect(lenderTokenAccount.amount.toString()).toEqual("1");
+  });
+});
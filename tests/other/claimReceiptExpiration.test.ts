@@ -0,0 +1,272 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import {
+  issueToken,
+  unissueToken,
+  withCloseClaimReceipt,
+  withCreateClaimReceiptWithExpiration,
+} from "../../src";
+import { tokenManager } from "../../src/programs";
+import { TokenManagerKind } from "../../src/programs/tokenManager";
+
+// loaded into the test validator from tests/fixtures/legacy-claim-receipt.json
+const LEGACY_CLAIM_RECEIPT_ID = new PublicKey(
+  "Dv8EP6zcxy9PSaCJAddJouWdU98SqG6UusiqtpSSTwjN"
+);
+
+describe("Claim receipt expiration", () => {
+  let provider: SolanaProvider;
+  const recipient = Keypair.generate();
+  const otherRecipient = Keypair.generate();
+  const user = Keypair.generate();
+  const claimApprover = Keypair.generate();
+  const payer = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+  let claimReceiptId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, payer]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Issue token with claim approver", async () => {
+    let transaction: Transaction;
+    [transaction, tokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        mint: rentalMint,
+        issuerTokenAccountId,
+        kind: TokenManagerKind.Managed,
+        visibility: "permissioned",
+        permissionedClaimApprover: claimApprover.publicKey,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.claimApprover?.toString()).toEqual(
+      claimApprover.publicKey.toString()
+    );
+  });
+
+  it("Create claim receipt with expiration", async () => {
+    const transaction = new Transaction();
+    await withCreateClaimReceiptWithExpiration(
+      transaction,
+      provider.connection,
+      new Wallet(claimApprover),
+      tokenManagerId,
+      recipient.publicKey,
+      Math.floor(Date.now() / 1000) + 3,
+      payer.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(payer),
+      { signers: [claimApprover] }
+    );
+
+    claimReceiptId = tokenManager.pda.findClaimReceiptId(
+      tokenManagerId,
+      recipient.publicKey
+    );
+    const claimReceipt = await tokenManager
+      .tokenManagerProgram(provider.connection)
+      .account.claimReceipt.fetch(claimReceiptId);
+    expect(claimReceipt.target.toString()).toEqual(
+      recipient.publicKey.toString()
+    );
+    expect(claimReceipt.payer?.toString()).toEqual(payer.publicKey.toString());
+    expect(claimReceipt.expiresAt).not.toBeNull();
+  });
+
+  it("Fail to close before expiration", async () => {
+    const transaction = new Transaction();
+    await withCloseClaimReceipt(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      claimReceiptId
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet)
+    ).rejects.toThrow();
+  });
+
+  it("Close after expiration refunds payer", async () => {
+    await new Promise((r) => setTimeout(r, 5000));
+    const beforePayerLamports =
+      (await provider.connection.getAccountInfo(payer.publicKey))?.lamports ||
+      0;
+    const claimReceiptLamports =
+      (await provider.connection.getAccountInfo(claimReceiptId))?.lamports ||
+      0;
+
+    const transaction = new Transaction();
+    await withCloseClaimReceipt(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      claimReceiptId
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const claimReceiptInfo = await provider.connection.getAccountInfo(
+      claimReceiptId
+    );
+    expect(claimReceiptInfo).toBeNull();
+    const afterPayerLamports =
+      (await provider.connection.getAccountInfo(payer.publicKey))?.lamports ||
+      0;
+    expect(afterPayerLamports).toEqual(
+      beforePayerLamports + claimReceiptLamports
+    );
+  });
+
+  it("Create claim receipt that has not expired", async () => {
+    const transaction = new Transaction();
+    await withCreateClaimReceiptWithExpiration(
+      transaction,
+      provider.connection,
+      new Wallet(claimApprover),
+      tokenManagerId,
+      otherRecipient.publicKey,
+      Math.floor(Date.now() / 1000) + 60 * 60,
+      payer.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(payer),
+      { signers: [claimApprover] }
+    );
+
+    claimReceiptId = tokenManager.pda.findClaimReceiptId(
+      tokenManagerId,
+      otherRecipient.publicKey
+    );
+    const claimReceipt = await tokenManager
+      .tokenManagerProgram(provider.connection)
+      .account.claimReceipt.fetch(claimReceiptId);
+    expect(claimReceipt.payer?.toString()).toEqual(payer.publicKey.toString());
+  });
+
+  it("Close stale claim receipt after unissue refunds payer", async () => {
+    const unissueTransaction = await unissueToken(
+      provider.connection,
+      new Wallet(user),
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      unissueTransaction,
+      new Wallet(user)
+    );
+    const tokenManagerInfo = await provider.connection.getAccountInfo(
+      tokenManagerId
+    );
+    expect(tokenManagerInfo).toBeNull();
+
+    const beforePayerLamports =
+      (await provider.connection.getAccountInfo(payer.publicKey))?.lamports ||
+      0;
+    const claimReceiptLamports =
+      (await provider.connection.getAccountInfo(claimReceiptId))?.lamports ||
+      0;
+
+    const transaction = new Transaction();
+    await withCloseClaimReceipt(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      claimReceiptId
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const claimReceiptInfo = await provider.connection.getAccountInfo(
+      claimReceiptId
+    );
+    expect(claimReceiptInfo).toBeNull();
+    const afterPayerLamports =
+      (await provider.connection.getAccountInfo(payer.publicKey))?.lamports ||
+      0;
+    expect(afterPayerLamports).toEqual(
+      beforePayerLamports + claimReceiptLamports
+    );
+  });
+
+  it("Fail to close claim receipt with no recorded payer", async () => {
+    const claimReceipt = await tokenManager
+      .tokenManagerProgram(provider.connection)
+      .account.claimReceipt.fetch(LEGACY_CLAIM_RECEIPT_ID);
+    expect(claimReceipt.payer).toBeNull();
+
+    await expect(
+      withCloseClaimReceipt(
+        new Transaction(),
+        provider.connection,
+        provider.wallet,
+        LEGACY_CLAIM_RECEIPT_ID
+      )
+    ).rejects.toEqual("Claim receipt payer not found");
+
+    // the receipt is stale but the missing payer still blocks the close
+    const closeClaimReceiptIx = await tokenManager
+      .tokenManagerProgram(provider.connection)
+      .methods.closeClaimReceipt()
+      .accountsStrict({
+        tokenManager: claimReceipt.tokenManager,
+        claimReceipt: LEGACY_CLAIM_RECEIPT_ID,
+        payer: provider.wallet.publicKey,
+      })
+      .instruction();
+    await expect(
+      executeTransaction(
+        provider.connection,
+        new Transaction().add(closeClaimReceiptIx),
+        provider.wallet
+      )
+    ).rejects.toThrow();
+
+    const claimReceiptInfo = await provider.connection.getAccountInfo(
+      LEGACY_CLAIM_RECEIPT_ID
+    );
+    expect(claimReceiptInfo).not.toBeNull();
+  });
+});
@@ -0,0 +1,508 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { PAYMENT_MANAGER_ADDRESS } from "@solana-nft-programs/payment-manager";
+import { withRemainingAccountsForPayment } from "@solana-nft-programs/payment-manager/dist/cjs/utils";
+
+import { claimToken, invalidate, issueToken } from "../../src";
+import { claimApprover, tokenManager } from "../../src/programs";
+import { TOKEN_MANAGER_ADDRESS } from "../../src/programs/tokenManager";
+
+describe("Security deposit", () => {
+  let provider: SolanaProvider;
+  const RECIPIENT_START_PAYMENT_AMOUNT = 1000;
+  const RENTAL_PAYMENT_AMOUNT = 10;
+  const DEPOSIT_AMOUNT = 0.1 * LAMPORTS_PER_SOL;
+  const CLAIMED_AMOUNT = 0.03 * LAMPORTS_PER_SOL;
+  const DISPUTE_WINDOW_SECONDS = 6;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+  let maxExpiration: number;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create payment mint
+    [, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  const claimDepositTransaction = async (
+    authority: Keypair,
+    amount: number
+  ) => {
+    const transaction = new Transaction();
+    transaction.add(
+      await claimApprover
+        .claimApproverProgram(provider.connection, new Wallet(authority))
+        .methods.claimDeposit(new BN(amount))
+        .accounts({
+          tokenManager: tokenManagerId,
+          securityDeposit:
+            claimApprover.pda.findSecurityDepositAddress(tokenManagerId),
+          depositTokenAccount:
+            claimApprover.pda.findSecurityDepositAddress(tokenManagerId),
+          destination: user.publicKey,
+          authority: authority.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction()
+    );
+    return transaction;
+  };
+
+  const refundDepositTransaction = async () => {
+    const transaction = new Transaction();
+    transaction.add(
+      await claimApprover
+        .claimApproverProgram(provider.connection, provider.wallet)
+        .methods.refundDeposit()
+        .accounts({
+          tokenManager: tokenManagerId,
+          securityDeposit:
+            claimApprover.pda.findSecurityDepositAddress(tokenManagerId),
+          depositTokenAccount:
+            claimApprover.pda.findSecurityDepositAddress(tokenManagerId),
+          destination: recipient.publicKey,
+          issuer: user.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction()
+    );
+    return transaction;
+  };
+
+  it("Create rental with security deposit", async () => {
+    const [transaction, issuedTokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        claimPayment: {
+          paymentAmount: RENTAL_PAYMENT_AMOUNT,
+          paymentMint: paymentMint,
+        },
+        timeInvalidation: { durationSeconds: 1 },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    tokenManagerId = issuedTokenManagerId;
+    const initSecurityDepositIx = await claimApprover
+      .claimApproverProgram(provider.connection, new Wallet(user))
+      .methods.initSecurityDeposit({
+        paymentMint: null,
+        amount: new BN(DEPOSIT_AMOUNT),
+        arbiter: null,
+        disputeWindowSeconds: new BN(DISPUTE_WINDOW_SECONDS),
+        maxExpiration: null,
+      })
+      .accounts({
+        tokenManager: tokenManagerId,
+        claimApprover: claimApprover.pda.findClaimApproverAddress(
+          tokenManagerId
+        ),
+        securityDeposit:
+          claimApprover.pda.findSecurityDepositAddress(tokenManagerId),
+        issuer: user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+    // the deposit must be initialized before the token is issued
+    transaction.instructions.splice(
+      transaction.instructions.length - 1,
+      0,
+      initSecurityDepositIx
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimApproverData = await claimApprover.accounts.getClaimApprover(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(claimApproverData.parsed.requiresDeposit).toEqual(true);
+
+    const securityDepositData =
+      await claimApprover.accounts.getSecurityDeposit(
+        provider.connection,
+        tokenManagerId
+      );
+    expect(securityDepositData.parsed.amount.toNumber()).toEqual(
+      DEPOSIT_AMOUNT
+    );
+    expect(securityDepositData.parsed.depositor).toEqual(null);
+    expect(securityDepositData.parsed.issuer.toString()).toEqual(
+      user.publicKey.toString()
+    );
+  });
+
+  it("Pay without deposit fails", async () => {
+    const transaction = new Transaction();
+    const claimApproverData = await claimApprover.accounts.getClaimApprover(
+      provider.connection,
+      tokenManagerId
+    );
+    const [
+      paymentTokenAccountId,
+      feeCollectorTokenAccountId,
+      remainingAccounts,
+    ] = await withRemainingAccountsForPayment(
+      transaction,
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint,
+      paymentMint,
+      user.publicKey,
+      claimApproverData.parsed.paymentManager
+    );
+    transaction.add(
+      await claimApprover
+        .claimApproverProgram(provider.connection, new Wallet(recipient))
+        .methods.pay()
+        .accounts({
+          tokenManager: tokenManagerId,
+          paymentTokenAccount: paymentTokenAccountId,
+          feeCollectorTokenAccount: feeCollectorTokenAccountId,
+          paymentManager: claimApproverData.parsed.paymentManager,
+          claimApprover: claimApproverData.pubkey,
+          payer: recipient.publicKey,
+          payerTokenAccount: getAssociatedTokenAddressSync(
+            paymentMint,
+            recipient.publicKey
+          ),
+          claimReceipt: tokenManager.pda.findClaimReceiptId(
+            tokenManagerId,
+            recipient.publicKey
+          ),
+          solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+          solanaNftProgramsPaymentManager: PAYMENT_MANAGER_ADDRESS,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .instruction()
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Claim with deposit", async () => {
+    const recipientLamportsBefore = await provider.connection.getBalance(
+      recipient.publicKey
+    );
+    const transaction = await claimToken(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const securityDepositData =
+      await claimApprover.accounts.getSecurityDeposit(
+        provider.connection,
+        tokenManagerId
+      );
+    expect(securityDepositData.parsed.depositor?.toString()).toEqual(
+      recipient.publicKey.toString()
+    );
+    expect(securityDepositData.parsed.remainingAmount.toNumber()).toEqual(
+      DEPOSIT_AMOUNT
+    );
+    const recipientLamportsAfter = await provider.connection.getBalance(
+      recipient.publicKey
+    );
+    expect(recipientLamportsBefore - recipientLamportsAfter).toBeGreaterThan(
+      DEPOSIT_AMOUNT
+    );
+
+    const checkRecipientTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(rentalMint, recipient.publicKey)
+    );
+    expect(checkRecipientTokenAccount.amount.toString()).toEqual("1");
+  });
+
+  it("Claim deposit before the rental ends fails", async () => {
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await claimDepositTransaction(user, CLAIMED_AMOUNT),
+        new Wallet(user)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Invalidate", async () => {
+    await new Promise((r) => setTimeout(r, 2000));
+
+    const transaction = await invalidate(
+      provider.connection,
+      new Wallet(user),
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const tokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(provider.connection, tokenManagerId)
+    );
+    expect(tokenManagerData).toEqual(null);
+  });
+
+  it("Claim deposit by the renter fails", async () => {
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await claimDepositTransaction(recipient, CLAIMED_AMOUNT),
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Claim more than the deposit fails", async () => {
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await claimDepositTransaction(user, DEPOSIT_AMOUNT + 1),
+        new Wallet(user)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Issuer claims part of the deposit", async () => {
+    const issuerLamportsBefore = await provider.connection.getBalance(
+      user.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      await claimDepositTransaction(user, CLAIMED_AMOUNT),
+      new Wallet(user)
+    );
+
+    const securityDepositData =
+      await claimApprover.accounts.getSecurityDeposit(
+        provider.connection,
+        tokenManagerId
+      );
+    expect(securityDepositData.parsed.remainingAmount.toNumber()).toEqual(
+      DEPOSIT_AMOUNT - CLAIMED_AMOUNT
+    );
+    expect(securityDepositData.parsed.endedAt).not.toEqual(null);
+    const issuerLamportsAfter = await provider.connection.getBalance(
+      user.publicKey
+    );
+    expect(issuerLamportsAfter - issuerLamportsBefore).toBeGreaterThan(
+      CLAIMED_AMOUNT - 0.001 * LAMPORTS_PER_SOL
+    );
+  });
+
+  it("Refund while the dispute window is open fails", async () => {
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await refundDepositTransaction(),
+        provider.wallet
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Claim deposit after the dispute window fails", async () => {
+    await new Promise((r) =>
+      setTimeout(r, (DISPUTE_WINDOW_SECONDS + 2) * 1000)
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await claimDepositTransaction(user, CLAIMED_AMOUNT),
+        new Wallet(user)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Refund the remaining deposit", async () => {
+    const recipientLamportsBefore = await provider.connection.getBalance(
+      recipient.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      await refundDepositTransaction(),
+      provider.wallet
+    );
+
+    const recipientLamportsAfter = await provider.connection.getBalance(
+      recipient.publicKey
+    );
+    expect(recipientLamportsAfter - recipientLamportsBefore).toEqual(
+      DEPOSIT_AMOUNT - CLAIMED_AMOUNT
+    );
+
+    const securityDepositData = await tryGetAccount(() =>
+      claimApprover.accounts.getSecurityDeposit(
+        provider.connection,
+        tokenManagerId
+      )
+    );
+    expect(securityDepositData).toEqual(null);
+  });
+
+  it("Create another rental with security deposit", async () => {
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+    const [transaction, issuedTokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        claimPayment: {
+          paymentAmount: RENTAL_PAYMENT_AMOUNT,
+          paymentMint: paymentMint,
+        },
+        timeInvalidation: { durationSeconds: 1 },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    tokenManagerId = issuedTokenManagerId;
+    // the refund happens after max expiration but the rental ended before it
+    maxExpiration = Math.floor(Date.now() / 1000) + 8;
+    const initSecurityDepositIx = await claimApprover
+      .claimApproverProgram(provider.connection, new Wallet(user))
+      .methods.initSecurityDeposit({
+        paymentMint: null,
+        amount: new BN(DEPOSIT_AMOUNT),
+        arbiter: null,
+        disputeWindowSeconds: new BN(DISPUTE_WINDOW_SECONDS),
+        maxExpiration: new BN(maxExpiration),
+      })
+      .accounts({
+        tokenManager: tokenManagerId,
+        claimApprover: claimApprover.pda.findClaimApproverAddress(
+          tokenManagerId
+        ),
+        securityDeposit:
+          claimApprover.pda.findSecurityDepositAddress(tokenManagerId),
+        issuer: user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+    transaction.instructions.splice(
+      transaction.instructions.length - 1,
+      0,
+      initSecurityDepositIx
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+    await executeTransaction(
+      provider.connection,
+      await claimToken(
+        provider.connection,
+        new Wallet(recipient),
+        tokenManagerId
+      ),
+      new Wallet(recipient)
+    );
+  });
+
+  it("Invalidate saves the end of the rental", async () => {
+    await new Promise((r) => setTimeout(r, 2000));
+    await executeTransaction(
+      provider.connection,
+      await invalidate(provider.connection, new Wallet(user), rentalMint),
+      new Wallet(user)
+    );
+    const securityDepositData =
+      await claimApprover.accounts.getSecurityDeposit(
+        provider.connection,
+        tokenManagerId
+      );
+    expect(securityDepositData.parsed.endedAt?.toNumber()).toBeLessThan(
+      maxExpiration
+    );
+  });
+
+  it("Refund without a dispute or a prior claim", async () => {
+    await new Promise((r) =>
+      setTimeout(r, (DISPUTE_WINDOW_SECONDS + 2) * 1000)
+    );
+    const recipientLamportsBefore = await provider.connection.getBalance(
+      recipient.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      await refundDepositTransaction(),
+      provider.wallet
+    );
+    const recipientLamportsAfter = await provider.connection.getBalance(
+      recipient.publicKey
+    );
+    expect(recipientLamportsAfter - recipientLamportsBefore).toEqual(
+      DEPOSIT_AMOUNT
+    );
+    const checkSecurityDepositData = await tryGetAccount(() =>
+      claimApprover.accounts.getSecurityDeposit(
+        provider.connection,
+        tokenManagerId
+      )
+    );
+    expect(checkSecurityDepositData).toEqual(null);
+  });
+});
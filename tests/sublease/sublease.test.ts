@@ -0,0 +1,339 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+  withFindOrInitAssociatedTokenAccount,
+} from "@solana-nft-programs/common";
+
+import { claimToken, invalidate, issueToken } from "../../src";
+import { timeInvalidator, tokenManager } from "../../src/programs";
+import {
+  TOKEN_MANAGER_ADDRESS,
+  TokenManagerKind,
+  TokenManagerState,
+} from "../../src/programs/tokenManager";
+
+describe("Sublease", () => {
+  let provider: SolanaProvider;
+  const DURATION_SECONDS = 14;
+  const lessor = Keypair.generate();
+  const lessee = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+  let lessorTokenAccountId: PublicKey;
+  let lesseeTokenAccountId: PublicKey;
+  let claimedAt: number;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, lessor, lessee]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+    lessorTokenAccountId = getAssociatedTokenAddressSync(
+      rentalMint,
+      lessor.publicKey
+    );
+    lesseeTokenAccountId = getAssociatedTokenAddressSync(
+      rentalMint,
+      lessee.publicKey
+    );
+  });
+
+  const createSubleaseTransaction = async (expiration: number) => {
+    const transaction = new Transaction();
+    await withFindOrInitAssociatedTokenAccount(
+      transaction,
+      provider.connection,
+      rentalMint,
+      lessee.publicKey,
+      lessee.publicKey
+    );
+    transaction.add(
+      await timeInvalidator
+        .timeInvalidatorProgram(provider.connection, new Wallet(lessee))
+        .methods.createSublease({ expiration: new BN(expiration) })
+        .accounts({
+          tokenManager: tokenManagerId,
+          timeInvalidator:
+            timeInvalidator.pda.findTimeInvalidatorAddress(tokenManagerId),
+          solanaNftProgramsTokenManager: TOKEN_MANAGER_ADDRESS,
+          mint: rentalMint,
+          subleaseTokenManager:
+            tokenManager.pda.findSubleaseTokenManagerAddress(tokenManagerId),
+          lessorTokenAccount: lessorTokenAccountId,
+          lessor: lessor.publicKey,
+          lesseeTokenAccount: lesseeTokenAccountId,
+          lessee: lessee.publicKey,
+          payer: lessor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          {
+            pubkey: tokenManager.pda.findMintManagerId(rentalMint),
+            isSigner: false,
+            isWritable: false,
+          },
+        ])
+        .instruction()
+    );
+    return transaction;
+  };
+
+  const endSubleaseTransaction = async () => {
+    const transaction = new Transaction();
+    transaction.add(
+      await tokenManager
+        .tokenManagerProgram(provider.connection, provider.wallet)
+        .methods.endSublease()
+        .accounts({
+          tokenManager: tokenManagerId,
+          mint: rentalMint,
+          subleaseTokenManager:
+            tokenManager.pda.findSubleaseTokenManagerAddress(tokenManagerId),
+          lesseeTokenAccount: lesseeTokenAccountId,
+          lessorTokenAccount: lessorTokenAccountId,
+          lessor: lessor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          {
+            pubkey: tokenManager.pda.findMintManagerId(rentalMint),
+            isSigner: false,
+            isWritable: false,
+          },
+        ])
+        .instruction()
+    );
+    return transaction;
+  };
+
+  it("Create rental", async () => {
+    const [transaction, issuedTokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        timeInvalidation: { durationSeconds: DURATION_SECONDS },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        kind: TokenManagerKind.Managed,
+        amount: new BN(1),
+      }
+    );
+    tokenManagerId = issuedTokenManagerId;
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(lessor),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(lessor)
+    );
+    claimedAt = Date.now();
+
+    const checkLessorTokenAccount = await getAccount(
+      provider.connection,
+      lessorTokenAccountId
+    );
+    expect(checkLessorTokenAccount.amount.toString()).toEqual("1");
+    expect(checkLessorTokenAccount.isFrozen).toEqual(true);
+  });
+
+  it("Sublease beyond the rental expiration fails", async () => {
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await createSubleaseTransaction(Math.floor(Date.now() / 1000) + 1000),
+        new Wallet(lessee),
+        { signers: [lessor] }
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Create sublease", async () => {
+    const expiration = Math.floor(Date.now() / 1000) + 3;
+    await executeTransaction(
+      provider.connection,
+      await createSubleaseTransaction(expiration),
+      new Wallet(lessee),
+      { signers: [lessor] }
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.subleased).toEqual(true);
+    expect(tokenManagerData.parsed.recipientTokenAccount.toString()).toEqual(
+      lessorTokenAccountId.toString()
+    );
+
+    const subleaseTokenManagerData =
+      await tokenManager.accounts.getTokenManager(
+        provider.connection,
+        tokenManager.pda.findSubleaseTokenManagerAddress(tokenManagerId)
+      );
+    expect(subleaseTokenManagerData.parsed.parent?.toString()).toEqual(
+      tokenManagerId.toString()
+    );
+    expect(subleaseTokenManagerData.parsed.expiration?.toNumber()).toEqual(
+      expiration
+    );
+    expect(subleaseTokenManagerData.parsed.issuer.toString()).toEqual(
+      lessor.publicKey.toString()
+    );
+    expect(
+      subleaseTokenManagerData.parsed.recipientTokenAccount.toString()
+    ).toEqual(lesseeTokenAccountId.toString());
+    expect(subleaseTokenManagerData.parsed.state).toEqual(
+      TokenManagerState.Claimed
+    );
+
+    const checkLessorTokenAccount = await getAccount(
+      provider.connection,
+      lessorTokenAccountId
+    );
+    expect(checkLessorTokenAccount.amount.toString()).toEqual("0");
+    expect(checkLessorTokenAccount.isFrozen).toEqual(true);
+
+    const checkLesseeTokenAccount = await getAccount(
+      provider.connection,
+      lesseeTokenAccountId
+    );
+    expect(checkLesseeTokenAccount.amount.toString()).toEqual("1");
+    expect(checkLesseeTokenAccount.isFrozen).toEqual(true);
+    expect(checkLesseeTokenAccount.delegate?.toString()).toEqual(
+      tokenManager.pda
+        .findSubleaseTokenManagerAddress(tokenManagerId)
+        .toString()
+    );
+  });
+
+  it("End sublease before expiration fails", async () => {
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await endSubleaseTransaction(),
+        provider.wallet
+      )
+    ).rejects.toThrow();
+  });
+
+  it("End sublease", async () => {
+    await new Promise((r) => setTimeout(r, 5000));
+    await executeTransaction(
+      provider.connection,
+      await endSubleaseTransaction(),
+      provider.wallet
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.subleased).toEqual(null);
+    expect(tokenManagerData.parsed.recipientTokenAccount.toString()).toEqual(
+      lessorTokenAccountId.toString()
+    );
+
+    const checkLessorTokenAccount = await getAccount(
+      provider.connection,
+      lessorTokenAccountId
+    );
+    expect(checkLessorTokenAccount.amount.toString()).toEqual("1");
+    expect(checkLessorTokenAccount.isFrozen).toEqual(true);
+
+    const subleaseTokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(
+        provider.connection,
+        tokenManager.pda.findSubleaseTokenManagerAddress(tokenManagerId)
+      )
+    );
+    expect(subleaseTokenManagerData).toEqual(null);
+  });
+
+  it("Create another sublease", async () => {
+    await executeTransaction(
+      provider.connection,
+      await createSubleaseTransaction(
+        Math.floor(claimedAt / 1000) + DURATION_SECONDS - 1
+      ),
+      new Wallet(lessee),
+      { signers: [lessor] }
+    );
+  });
+
+  it("Wait for the rental to expire", async () => {
+    await new Promise((r) => setTimeout(r, 8000));
+  });
+
+  it("Invalidating the parent cascades to the sublease", async () => {
+    await new Promise((r) =>
+      setTimeout(
+        r,
+        Math.max(0, claimedAt + (DURATION_SECONDS + 2) * 1000 - Date.now())
+      )
+    );
+    await executeTransaction(
+      provider.connection,
+      await invalidate(provider.connection, new Wallet(user), rentalMint),
+      new Wallet(user)
+    );
+
+    const checkIssuerTokenAccount = await getAccount(
+      provider.connection,
+      issuerTokenAccountId
+    );
+    expect(checkIssuerTokenAccount.amount.toString()).toEqual("1");
+
+    const checkLesseeTokenAccount = await getAccount(
+      provider.connection,
+      lesseeTokenAccountId
+    );
+    expect(checkLesseeTokenAccount.amount.toString()).toEqual("0");
+
+    const subleaseTokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(
+        provider.connection,
+        tokenManager.pda.findSubleaseTokenManagerAddress(tokenManagerId)
+      )
+    );
+    expect(subleaseTokenManagerData).toEqual(null);
+  });
+});
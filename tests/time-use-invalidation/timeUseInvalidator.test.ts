@@ -0,0 +1,205 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+
+import {
+  claimToken,
+  issueToken,
+  withInitTimeUseInvalidator,
+  withTimeUseIncrementUsages,
+  withTimeUseInvalidate,
+} from "../../src";
+import { timeUseInvalidator, tokenManager } from "../../src/programs";
+import type { TimeUseInvalidationParams } from "../../src/programs/timeUseInvalidator";
+import { ConditionMode } from "../../src/programs/timeUseInvalidator";
+import {
+  InvalidationType,
+  TokenManagerKind,
+  TokenManagerState,
+} from "../../src/programs/tokenManager";
+
+describe("Time use invalidator", () => {
+  let provider: SolanaProvider;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+  });
+
+  const issueAndClaim = async (
+    params: TimeUseInvalidationParams,
+    invalidationType: InvalidationType
+  ) => {
+    const [issuerTokenAccountId, mintId] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+    const tokenManagerId = tokenManager.pda.tokenManagerAddressFromMint(mintId);
+    const [transaction] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        mint: mintId,
+        issuerTokenAccountId,
+        kind: TokenManagerKind.Managed,
+        invalidationType,
+        customInvalidators: [
+          timeUseInvalidator.pda.findTimeUseInvalidatorAddress(tokenManagerId),
+        ],
+      }
+    );
+    // the invalidator must be initialized before the token is issued
+    const [initTransaction] = await withInitTimeUseInvalidator(
+      new Transaction(),
+      provider.connection,
+      new Wallet(user),
+      tokenManagerId,
+      params
+    );
+    transaction.instructions.splice(
+      transaction.instructions.length - 1,
+      0,
+      ...initTransaction.instructions
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+    return [issuerTokenAccountId, mintId, tokenManagerId] as const;
+  };
+
+  const incrementUsages = async (
+    wallet: Wallet,
+    tokenManagerId: PublicKey,
+    numUsages: number
+  ) => {
+    const transaction = new Transaction();
+    await withTimeUseIncrementUsages(
+      transaction,
+      provider.connection,
+      wallet,
+      tokenManagerId,
+      numUsages
+    );
+    return executeTransaction(provider.connection, transaction, wallet);
+  };
+
+  const invalidate = async (mintId: PublicKey) => {
+    const transaction = new Transaction();
+    await withTimeUseInvalidate(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      mintId
+    );
+    return executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet
+    );
+  };
+
+  it("Or mode invalidates once usages run out", async () => {
+    const [issuerTokenAccountId, mintId, tokenManagerId] = await issueAndClaim(
+      {
+        mode: ConditionMode.Or,
+        durationSeconds: 1000,
+        totalUsages: 1,
+      },
+      InvalidationType.Return
+    );
+
+    await expect(invalidate(mintId)).rejects.toThrow();
+    await expect(
+      incrementUsages(new Wallet(user), tokenManagerId, 1)
+    ).rejects.toThrow();
+    await expect(
+      incrementUsages(new Wallet(recipient), tokenManagerId, 2)
+    ).rejects.toThrow();
+    await incrementUsages(new Wallet(recipient), tokenManagerId, 1);
+
+    const timeUseInvalidatorId =
+      timeUseInvalidator.pda.findTimeUseInvalidatorAddress(tokenManagerId);
+    const timeUseInvalidatorData =
+      await timeUseInvalidator.accounts.getTimeUseInvalidator(
+        provider.connection,
+        timeUseInvalidatorId
+      );
+    expect(timeUseInvalidatorData.parsed.usages.toNumber()).toEqual(1);
+
+    await invalidate(mintId);
+
+    const tokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(provider.connection, tokenManagerId)
+    );
+    expect(tokenManagerData).toEqual(null);
+    const checkIssuerTokenAccount = await getAccount(
+      provider.connection,
+      issuerTokenAccountId
+    );
+    expect(checkIssuerTokenAccount.amount.toString()).toEqual("1");
+    const timeUseInvalidatorInfo = await provider.connection.getAccountInfo(
+      timeUseInvalidatorId
+    );
+    expect(timeUseInvalidatorInfo).toBeNull();
+  });
+
+  it("And mode waits for both conditions", async () => {
+    const [, mintId, tokenManagerId] = await issueAndClaim(
+      {
+        mode: ConditionMode.And,
+        durationSeconds: 1,
+        totalUsages: 1,
+      },
+      InvalidationType.Invalidate
+    );
+
+    await new Promise((r) => setTimeout(r, 3000));
+    await expect(invalidate(mintId)).rejects.toThrow();
+
+    await incrementUsages(new Wallet(recipient), tokenManagerId, 1);
+    await invalidate(mintId);
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(
+      TokenManagerState.Invalidated
+    );
+    // the invalidator is only closed once the token manager no longer needs it
+    const timeUseInvalidatorInfo = await provider.connection.getAccountInfo(
+      timeUseInvalidator.pda.findTimeUseInvalidatorAddress(tokenManagerId)
+    );
+    expect(timeUseInvalidatorInfo).not.toBeNull();
+  });
+});
@@ -30,6 +30,7 @@ import {
   withCreateListing,
   withInitMarketplace,
   withInitTransferAuthority,
+  withUpdateMarketplace,
   withWrapToken,
 } from "../../src";
 import { findTokenManagerAddress } from "../../src/programs/tokenManager/pda";
@@ -357,6 +358,59 @@ describe("Accept Listing", () => {
     ).rejects.toThrow();
   });
 
+  it("Accept Listing Above Max Taker Fee Fail", async () => {
+    const feeTransaction = new Transaction();
+    await withUpdateMarketplace(
+      feeTransaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName,
+      [PublicKey.default],
+      { feeCollector: feeCollector.publicKey, makerFeeBps: 0, takerFeeBps: 100 }
+    );
+    await executeTransaction(
+      provider.connection,
+      feeTransaction,
+      provider.wallet
+    );
+
+    const transaction = new Transaction();
+    const checkListing = await getListing(provider.connection, rentalMint);
+    await withAcceptListing(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      buyer.publicKey,
+      rentalMint,
+      checkListing.parsed.paymentAmount,
+      checkListing.parsed.paymentMint,
+      undefined,
+      buyer.publicKey,
+      0
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet, {
+        signers: [buyer],
+      })
+    ).rejects.toThrow();
+
+    const resetTransaction = new Transaction();
+    await withUpdateMarketplace(
+      resetTransaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName,
+      [PublicKey.default]
+    );
+    await executeTransaction(
+      provider.connection,
+      resetTransaction,
+      provider.wallet
+    );
+  });
+
   it("Accept Listing", async () => {
     const transaction = new Transaction();
     const checkListing = await getListing(provider.connection, rentalMint);
@@ -0,0 +1,263 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  emptyWallet,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  claimToken,
+  issueToken,
+  withAcceptListing,
+  withCreateListing,
+  withInitMarketplace,
+  withInitTransferAuthority,
+} from "../../src";
+import { tokenManager } from "../../src/programs";
+import {
+  InvalidationType,
+  TokenManagerKind,
+  TokenManagerState,
+} from "../../src/programs/tokenManager";
+import { findTokenManagerAddress } from "../../src/programs/tokenManager/pda";
+import { getListing } from "../../src/programs/transferAuthority/accounts";
+
+describe("Accept Listing Managed", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `lst-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+
+  const issuer = Keypair.generate();
+  const lister = Keypair.generate();
+  const buyer = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let mintId: PublicKey;
+  const rentalPaymentAmount = new BN(100);
+
+  const paymentManagerName = `pm-${Math.random()}`;
+  const feeCollector = Keypair.generate();
+  const MAKER_FEE = new BN(500);
+  const TAKER_FEE = new BN(0);
+  const BASIS_POINTS_DIVISOR = new BN(10000);
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+
+    for (const keypair of [feeCollector, issuer, lister, buyer]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    [issuerTokenAccountId, mintId] = await createMint(
+      provider.connection,
+      new Wallet(issuer)
+    );
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: MAKER_FEE.toNumber(),
+      takerFeeBasisPoints: TAKER_FEE.toNumber(),
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  it("Issue and claim token", async () => {
+    const [issueTransaction, tokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(issuer),
+      {
+        mint: mintId,
+        issuerTokenAccountId: issuerTokenAccountId,
+        kind: TokenManagerKind.Managed,
+        invalidationType: InvalidationType.Release,
+        transferAuthorityInfo: { transferAuthorityName: transferAuthorityName },
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      issueTransaction,
+      new Wallet(issuer)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(lister),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(lister)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Claimed);
+    expect(tokenManagerData.parsed.kind).toEqual(TokenManagerKind.Managed);
+
+    const listerTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(mintId, lister.publicKey)
+    );
+    expect(listerTokenAccount.amount.toString()).toEqual("1");
+    expect(listerTokenAccount.isFrozen).toBeTruthy();
+  });
+
+  it("Create Listing", async () => {
+    const transaction = new Transaction();
+
+    await withCreateListing(
+      transaction,
+      provider.connection,
+      emptyWallet(lister.publicKey),
+      mintId,
+      marketplaceName,
+      rentalPaymentAmount,
+      PublicKey.default
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+
+    const checkListing = await getListing(provider.connection, mintId);
+    expect(checkListing.parsed.lister.toString()).toEqual(
+      lister.publicKey.toString()
+    );
+    const tokenManagerId = findTokenManagerAddress(mintId);
+    expect(checkListing.parsed.tokenManager.toString()).toEqual(
+      tokenManagerId.toString()
+    );
+
+    const listerTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(mintId, lister.publicKey)
+    );
+    expect(listerTokenAccount.delegate?.toString()).toEqual(
+      tokenManagerId.toString()
+    );
+    expect(listerTokenAccount.amount.toString()).toEqual("1");
+  });
+
+  it("Accept Listing", async () => {
+    const transaction = new Transaction();
+    const checkListing = await getListing(provider.connection, mintId);
+
+    const listingInfo = await provider.connection.getAccountInfo(
+      checkListing.pubkey
+    );
+    const beforeListerAmount =
+      (await provider.connection.getAccountInfo(lister.publicKey))?.lamports ||
+      0;
+    const beforeFeeCollectorAmount =
+      (await provider.connection.getAccountInfo(feeCollector.publicKey))
+        ?.lamports || 0;
+
+    await withAcceptListing(
+      transaction,
+      provider.connection,
+      new Wallet(buyer),
+      buyer.publicKey,
+      mintId,
+      checkListing.parsed.paymentAmount,
+      checkListing.parsed.paymentMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(buyer)
+    );
+
+    const tokenManagerId = findTokenManagerAddress(mintId);
+    const buyerTokenAccountId = getAssociatedTokenAddressSync(
+      mintId,
+      buyer.publicKey
+    );
+    const buyerTokenAccount = await getAccount(
+      provider.connection,
+      buyerTokenAccountId
+    );
+    expect(buyerTokenAccount.amount.toString()).toEqual("1");
+    expect(buyerTokenAccount.isFrozen).toBeTruthy();
+    expect(buyerTokenAccount.delegate?.toString()).toEqual(
+      tokenManagerId.toString()
+    );
+
+    const listerTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(mintId, lister.publicKey)
+    );
+    expect(listerTokenAccount.amount.toString()).toEqual("0");
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.recipientTokenAccount.toString()).toEqual(
+      buyerTokenAccountId.toString()
+    );
+
+    const makerFee = rentalPaymentAmount
+      .mul(MAKER_FEE)
+      .div(BASIS_POINTS_DIVISOR);
+    const takerFee = rentalPaymentAmount
+      .mul(TAKER_FEE)
+      .div(BASIS_POINTS_DIVISOR);
+    const totalFees = makerFee.add(takerFee);
+
+    const listerInfo = await provider.connection.getAccountInfo(
+      lister.publicKey
+    );
+    expect(listerInfo?.lamports).toEqual(
+      beforeListerAmount +
+        rentalPaymentAmount.sub(makerFee).toNumber() +
+        (listingInfo?.lamports || 0)
+    );
+
+    const feeCollectorInfo = await provider.connection.getAccountInfo(
+      feeCollector.publicKey
+    );
+    expect(feeCollectorInfo?.lamports).toEqual(
+      beforeFeeCollectorAmount + totalFees.toNumber()
+    );
+  });
+});
@@ -280,6 +280,32 @@ describe("Allowed markeptlaces for transfer authority", () => {
     expect(marketplaces).toContain(randomMarketplaceId.toString());
   });
 
+  it("Whitelist beyond the default allowlist length", async () => {
+    const transaction = new Transaction();
+    const randomNames = Array.from({ length: 20 }, (_, i) => `random-${i}`);
+
+    await withWhitelistMarektplaces(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName,
+      [marketplaceName, ...randomNames]
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const checkTransferAuthority = await getTransferAuthorityByName(
+      provider.connection,
+      transferAuthorityName
+    );
+    const marketplaceId = findMarketplaceAddress(marketplaceName);
+
+    const marketplaces = (
+      checkTransferAuthority.parsed.allowedMarketplaces as PublicKey[]
+    ).map((m) => m.toString());
+    expect(marketplaces.length).toEqual(21);
+    expect(marketplaces).toContain(marketplaceId.toString());
+  });
+
   it("Create Listing", async () => {
     const transaction = new Transaction();
 
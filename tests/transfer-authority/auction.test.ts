@@ -0,0 +1,336 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  createCreateMasterEditionV3Instruction,
+  createCreateMetadataAccountV3Instruction,
+} from "@metaplex-foundation/mpl-token-metadata";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  findMintEditionId,
+  findMintMetadataId,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  withClaimAuction,
+  withCloseStaleAuction,
+  withCreateAuction,
+  withInitMarketplace,
+  withInitTransferAuthority,
+  withPlaceBid,
+  withRelease,
+  withWrapToken,
+} from "../../src";
+import { findTokenManagerAddress } from "../../src/programs/tokenManager/pda";
+import { getAuction } from "../../src/programs/transferAuthority/accounts";
+import {
+  findAuctionAddress,
+  findTransferAuthorityAddress,
+} from "../../src/programs/transferAuthority/pda";
+
+describe("Auctions", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `auc-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const holder = Keypair.generate();
+  const bidder = Keypair.generate();
+  const winner = Keypair.generate();
+  const closer = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  const reservePrice = new BN(0.1 * LAMPORTS_PER_SOL);
+  const minIncrement = new BN(0.01 * LAMPORTS_PER_SOL);
+  const winningBid = reservePrice.add(minIncrement);
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [holder, bidder, winner, closer, feeCollector]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [, rentalMint] = await createMint(provider.connection, new Wallet(holder));
+    tokenManagerId = findTokenManagerAddress(rentalMint);
+
+    const metadataId = findMintMetadataId(rentalMint);
+    const metadataIx = createCreateMetadataAccountV3Instruction(
+      {
+        metadata: metadataId,
+        updateAuthority: holder.publicKey,
+        mint: rentalMint,
+        mintAuthority: holder.publicKey,
+        payer: holder.publicKey,
+      },
+      {
+        createMetadataAccountArgsV3: {
+          data: {
+            name: "test",
+            symbol: "TST",
+            uri: "http://test/",
+            sellerFeeBasisPoints: 0,
+            creators: null,
+            collection: null,
+            uses: null,
+          },
+          isMutable: true,
+          collectionDetails: null,
+        },
+      }
+    );
+    const masterEditionIx = createCreateMasterEditionV3Instruction(
+      {
+        edition: findMintEditionId(rentalMint),
+        metadata: metadataId,
+        updateAuthority: holder.publicKey,
+        mint: rentalMint,
+        mintAuthority: holder.publicKey,
+        payer: holder.publicKey,
+      },
+      {
+        createMasterEditionArgs: {
+          maxSupply: new BN(0),
+        },
+      }
+    );
+    const tx = new Transaction();
+    tx.instructions = [metadataIx, masterEditionIx];
+    await executeTransaction(provider.connection, tx, new Wallet(holder));
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: 0,
+      takerFeeBasisPoints: 0,
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const wrapTransaction = new Transaction();
+    await withWrapToken(
+      wrapTransaction,
+      provider.connection,
+      new Wallet(holder),
+      rentalMint,
+      { transferAuthorityName: transferAuthorityName }
+    );
+    await executeTransaction(
+      provider.connection,
+      wrapTransaction,
+      new Wallet(holder)
+    );
+  });
+
+  it("Create auction", async () => {
+    const transaction = new Transaction();
+    await withCreateAuction(
+      transaction,
+      provider.connection,
+      new Wallet(holder),
+      rentalMint,
+      marketplaceName,
+      reservePrice,
+      minIncrement,
+      new BN(Math.floor(Date.now() / 1000) + 8)
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(holder)
+    );
+
+    const checkAuction = await getAuction(
+      provider.connection,
+      findAuctionAddress(rentalMint)
+    );
+    expect(checkAuction.parsed.lister.toString()).toEqual(
+      holder.publicKey.toString()
+    );
+    expect(checkAuction.parsed.tokenManager.toString()).toEqual(
+      tokenManagerId.toString()
+    );
+    expect(checkAuction.parsed.topBidder).toBeNull();
+  });
+
+  it("Outbid bidder is refunded", async () => {
+    const bidTransaction = new Transaction();
+    await withPlaceBid(
+      bidTransaction,
+      provider.connection,
+      new Wallet(bidder),
+      rentalMint,
+      reservePrice
+    );
+    await executeTransaction(
+      provider.connection,
+      bidTransaction,
+      new Wallet(bidder)
+    );
+    const beforeBidderAmount = await provider.connection.getBalance(
+      bidder.publicKey
+    );
+
+    const outbidTransaction = new Transaction();
+    await withPlaceBid(
+      outbidTransaction,
+      provider.connection,
+      new Wallet(winner),
+      rentalMint,
+      winningBid
+    );
+    await executeTransaction(
+      provider.connection,
+      outbidTransaction,
+      new Wallet(winner)
+    );
+
+    const afterBidderAmount = await provider.connection.getBalance(
+      bidder.publicKey
+    );
+    expect(afterBidderAmount - beforeBidderAmount).toEqual(
+      reservePrice.toNumber()
+    );
+    const checkAuction = await getAuction(
+      provider.connection,
+      findAuctionAddress(rentalMint)
+    );
+    expect(checkAuction.parsed.topBidder?.toString()).toEqual(
+      winner.publicKey.toString()
+    );
+    expect(checkAuction.parsed.topBid.toString()).toEqual(
+      winningBid.toString()
+    );
+  });
+
+  it("Release during auction fails", async () => {
+    const transaction = new Transaction();
+    await withRelease(
+      transaction,
+      provider.connection,
+      new Wallet(holder),
+      rentalMint,
+      findTransferAuthorityAddress(transferAuthorityName),
+      await findAta(rentalMint, holder.publicKey, true)
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(holder))
+    ).rejects.toThrow();
+  });
+
+  it("Claim before end fails", async () => {
+    const transaction = new Transaction();
+    await withClaimAuction(
+      transaction,
+      provider.connection,
+      new Wallet(winner),
+      rentalMint
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(winner))
+    ).rejects.toThrow();
+  });
+
+  it("Close stale auction with a live token manager fails", async () => {
+    await new Promise((r) => setTimeout(r, 8000));
+
+    const transaction = new Transaction();
+    await withCloseStaleAuction(
+      transaction,
+      provider.connection,
+      new Wallet(closer),
+      rentalMint
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(closer))
+    ).rejects.toThrow();
+  });
+
+  it("Other bidder cannot claim", async () => {
+    const transaction = new Transaction();
+    await withClaimAuction(
+      transaction,
+      provider.connection,
+      new Wallet(bidder),
+      rentalMint
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(bidder))
+    ).rejects.toThrow();
+  });
+
+  it("Winner claims token and pays the lister", async () => {
+    const auctionInfo = await provider.connection.getAccountInfo(
+      findAuctionAddress(rentalMint)
+    );
+    const beforeHolderAmount = await provider.connection.getBalance(
+      holder.publicKey
+    );
+    const transaction = new Transaction();
+    await withClaimAuction(
+      transaction,
+      provider.connection,
+      new Wallet(winner),
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(winner)
+    );
+
+    // the winning bid and the rent of the closed auction account
+    const afterHolderAmount = await provider.connection.getBalance(
+      holder.publicKey
+    );
+    expect(afterHolderAmount - beforeHolderAmount).toEqual(
+      auctionInfo?.lamports
+    );
+
+    const winnerMintTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(rentalMint, winner.publicKey, true)
+    );
+    expect(winnerMintTokenAccount.amount.toString()).toEqual("1");
+    expect(winnerMintTokenAccount.isFrozen).toBeTruthy();
+
+    const checkAuction = await tryGetAccount(() =>
+      getAuction(provider.connection, findAuctionAddress(rentalMint))
+    );
+    expect(checkAuction).toBeNull();
+  });
+});
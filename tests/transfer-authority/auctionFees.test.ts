@@ -0,0 +1,336 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  claimToken,
+  invalidate,
+  issueToken,
+  withClaimAuction,
+  withCloseStaleAuction,
+  withCreateAuction,
+  withInitMarketplace,
+  withInitTransferAuthority,
+  withPlaceBid,
+} from "../../src";
+import {
+  InvalidationType,
+  TokenManagerKind,
+} from "../../src/programs/tokenManager";
+import { getAuction } from "../../src/programs/transferAuthority/accounts";
+import { findAuctionAddress } from "../../src/programs/transferAuthority/pda";
+
+describe("Auction fees", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `auc-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const issuer = Keypair.generate();
+  const holder = Keypair.generate();
+  const winner = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  const marketplaceFeeCollector = Keypair.generate();
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+  let invalidatedMint: PublicKey;
+
+  const BASIS_POINTS_DIVISOR = new BN(10000);
+  const MAKER_FEE = new BN(200);
+  const TAKER_FEE = new BN(300);
+  const MARKETPLACE_MAKER_FEE = new BN(100);
+  const MARKETPLACE_TAKER_FEE = new BN(150);
+  const winnerAmount = new BN(1000000);
+  const winningBid = new BN(100000);
+  const escrowAmount = (bid: BN) =>
+    bid
+      .add(bid.mul(MARKETPLACE_TAKER_FEE).div(BASIS_POINTS_DIVISOR))
+      .add(bid.mul(TAKER_FEE).div(BASIS_POINTS_DIVISOR));
+
+  const issueAndClaim = async () => {
+    const [issuerTokenAccountId, mintId] = await createMint(
+      provider.connection,
+      new Wallet(issuer)
+    );
+    const [issueTransaction, tokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(issuer),
+      {
+        mint: mintId,
+        issuerTokenAccountId: issuerTokenAccountId,
+        kind: TokenManagerKind.Managed,
+        invalidationType: InvalidationType.Return,
+        customInvalidators: [issuer.publicKey],
+        transferAuthorityInfo: { transferAuthorityName: transferAuthorityName },
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      issueTransaction,
+      new Wallet(issuer)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(holder),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(holder)
+    );
+    return mintId;
+  };
+
+  const winnerPaymentAmount = async () =>
+    (
+      await getAccount(
+        provider.connection,
+        getAssociatedTokenAddressSync(paymentMint, winner.publicKey)
+      )
+    ).amount.toString();
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [issuer, holder, winner, feeCollector]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    [, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(winner),
+      { amount: winnerAmount.toNumber() }
+    );
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: MAKER_FEE.toNumber(),
+      takerFeeBasisPoints: TAKER_FEE.toNumber(),
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName,
+      undefined,
+      provider.wallet.publicKey,
+      {
+        feeCollector: marketplaceFeeCollector.publicKey,
+        makerFeeBps: MARKETPLACE_MAKER_FEE.toNumber(),
+        takerFeeBps: MARKETPLACE_TAKER_FEE.toNumber(),
+      }
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    rentalMint = await issueAndClaim();
+    invalidatedMint = await issueAndClaim();
+  });
+
+  it("Bids escrow the taker fees", async () => {
+    for (const mintId of [rentalMint, invalidatedMint]) {
+      const transaction = new Transaction();
+      await withCreateAuction(
+        transaction,
+        provider.connection,
+        new Wallet(holder),
+        mintId,
+        marketplaceName,
+        winningBid,
+        new BN(1),
+        new BN(Math.floor(Date.now() / 1000) + 8),
+        paymentMint
+      );
+      await executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(holder)
+      );
+
+      const bidTransaction = new Transaction();
+      await withPlaceBid(
+        bidTransaction,
+        provider.connection,
+        new Wallet(winner),
+        mintId,
+        winningBid
+      );
+      await executeTransaction(
+        provider.connection,
+        bidTransaction,
+        new Wallet(winner)
+      );
+
+      const auctionId = findAuctionAddress(mintId);
+      const checkAuction = await getAuction(provider.connection, auctionId);
+      expect(checkAuction.parsed.escrowAmount.toString()).toEqual(
+        escrowAmount(winningBid).toString()
+      );
+      const auctionPaymentTokenAccount = await getAccount(
+        provider.connection,
+        getAssociatedTokenAddressSync(paymentMint, auctionId, true)
+      );
+      expect(auctionPaymentTokenAccount.amount.toString()).toEqual(
+        escrowAmount(winningBid).toString()
+      );
+    }
+  });
+
+  it("Claim before end fails", async () => {
+    const transaction = new Transaction();
+    await withClaimAuction(
+      transaction,
+      provider.connection,
+      new Wallet(winner),
+      rentalMint,
+      feeCollector.publicKey
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(winner))
+    ).rejects.toThrow();
+  });
+
+  it("Invalidated token manager refunds the escrowed bid", async () => {
+    const invalidateTransaction = await invalidate(
+      provider.connection,
+      new Wallet(issuer),
+      invalidatedMint
+    );
+    await executeTransaction(
+      provider.connection,
+      invalidateTransaction,
+      new Wallet(issuer)
+    );
+
+    const beforeWinnerAmount = await winnerPaymentAmount();
+    const transaction = new Transaction();
+    await withCloseStaleAuction(
+      transaction,
+      provider.connection,
+      new Wallet(holder),
+      invalidatedMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(holder)
+    );
+
+    expect(await winnerPaymentAmount()).toEqual(
+      new BN(beforeWinnerAmount).add(escrowAmount(winningBid)).toString()
+    );
+    const checkAuction = await tryGetAccount(() =>
+      getAuction(provider.connection, findAuctionAddress(invalidatedMint))
+    );
+    expect(checkAuction).toBeNull();
+  });
+
+  it("Wait for the auction to end", async () => {
+    await new Promise((r) => setTimeout(r, 8000));
+  });
+
+  it("Winner claims and pays the fees from the escrow", async () => {
+    const transaction = new Transaction();
+    await withClaimAuction(
+      transaction,
+      provider.connection,
+      new Wallet(winner),
+      rentalMint,
+      feeCollector.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(winner)
+    );
+
+    const marketplaceMakerFee = winningBid
+      .mul(MARKETPLACE_MAKER_FEE)
+      .div(BASIS_POINTS_DIVISOR);
+    const marketplaceTakerFee = winningBid
+      .mul(MARKETPLACE_TAKER_FEE)
+      .div(BASIS_POINTS_DIVISOR);
+    // the payment manager is paid what is left after the marketplace maker fee
+    const paymentManagerAmount = winningBid.sub(marketplaceMakerFee);
+    const paymentManagerTakerFee = paymentManagerAmount
+      .mul(TAKER_FEE)
+      .div(BASIS_POINTS_DIVISOR);
+
+    const marketplaceFeeCollectorTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(
+        paymentMint,
+        marketplaceFeeCollector.publicKey,
+        true
+      )
+    );
+    expect(marketplaceFeeCollectorTokenAccount.amount.toString()).toEqual(
+      marketplaceMakerFee.add(marketplaceTakerFee).toString()
+    );
+
+    // buy side fees also go to the payment manager fee collector
+    const holderPaymentTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(paymentMint, holder.publicKey)
+    );
+    const feeCollectorTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(paymentMint, feeCollector.publicKey, true)
+    );
+    expect(
+      (
+        holderPaymentTokenAccount.amount + feeCollectorTokenAccount.amount
+      ).toString()
+    ).toEqual(paymentManagerAmount.add(paymentManagerTakerFee).toString());
+
+    // unused taker fees go back to the winner
+    expect(await winnerPaymentAmount()).toEqual(
+      winnerAmount
+        .sub(winningBid)
+        .sub(marketplaceTakerFee)
+        .sub(paymentManagerTakerFee)
+        .toString()
+    );
+
+    const winnerMintTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(rentalMint, winner.publicKey, true)
+    );
+    expect(winnerMintTokenAccount.amount.toString()).toEqual("1");
+
+    const checkAuction = await tryGetAccount(() =>
+      getAuction(provider.connection, findAuctionAddress(rentalMint))
+    );
+    expect(checkAuction).toBeNull();
+  });
+});
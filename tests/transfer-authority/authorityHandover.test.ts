@@ -0,0 +1,377 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  emptyWallet,
+  executeTransaction,
+  findAta,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  withAcceptMarketplaceAuthority,
+  withAcceptTransfer,
+  withAcceptTransferAuthorityAuthority,
+  withCloseMarketplace,
+  withCloseTransferAuthority,
+  withCreateListing,
+  withInitMarketplace,
+  withInitTransfer,
+  withInitTransferAuthority,
+  withProposeMarketplaceAuthority,
+  withProposeTransferAuthorityAuthority,
+  withRelease,
+  withWrapToken,
+} from "../../src";
+import {
+  getListing,
+  getMarketplaceByName,
+  getTransferAuthorityByName,
+} from "../../src/programs/transferAuthority/accounts";
+import { findTransferAuthorityAddress } from "../../src/programs/transferAuthority/pda";
+
+describe("Authority handover and closing", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `lst-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const newAuthority = Keypair.generate();
+  const otherAuthority = Keypair.generate();
+  const holder = Keypair.generate();
+  const recipient = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  let holderTokenAccountId: PublicKey;
+  let tokenMint: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [newAuthority, otherAuthority, holder, recipient]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: 0,
+      takerFeeBasisPoints: 0,
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    [holderTokenAccountId, tokenMint] = await createMint(
+      provider.connection,
+      new Wallet(holder)
+    );
+    const wrapTransaction = new Transaction();
+    await withWrapToken(
+      wrapTransaction,
+      provider.connection,
+      new Wallet(holder),
+      tokenMint,
+      {
+        transferAuthorityName: transferAuthorityName,
+        creator: findTransferAuthorityAddress(transferAuthorityName),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      wrapTransaction,
+      new Wallet(holder)
+    );
+  });
+
+  it("Only the pending authority accepts the transfer authority", async () => {
+    const transaction = new Transaction();
+    await withProposeTransferAuthorityAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName,
+      newAuthority.publicKey
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const otherTransaction = new Transaction();
+    await withAcceptTransferAuthorityAuthority(
+      otherTransaction,
+      provider.connection,
+      new Wallet(otherAuthority),
+      transferAuthorityName
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        otherTransaction,
+        new Wallet(otherAuthority)
+      )
+    ).rejects.toThrow();
+
+    const acceptTransaction = new Transaction();
+    await withAcceptTransferAuthorityAuthority(
+      acceptTransaction,
+      provider.connection,
+      new Wallet(newAuthority),
+      transferAuthorityName
+    );
+    await executeTransaction(
+      provider.connection,
+      acceptTransaction,
+      new Wallet(newAuthority)
+    );
+
+    const checkTransferAuthority = await getTransferAuthorityByName(
+      provider.connection,
+      transferAuthorityName
+    );
+    expect(checkTransferAuthority.parsed.authority.toString()).toEqual(
+      newAuthority.publicKey.toString()
+    );
+    expect(checkTransferAuthority.parsed.pendingAuthority).toBeNull();
+  });
+
+  it("Only the pending authority accepts the marketplace", async () => {
+    const transaction = new Transaction();
+    await withProposeMarketplaceAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      newAuthority.publicKey
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const otherTransaction = new Transaction();
+    await withAcceptMarketplaceAuthority(
+      otherTransaction,
+      provider.connection,
+      new Wallet(otherAuthority),
+      marketplaceName
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        otherTransaction,
+        new Wallet(otherAuthority)
+      )
+    ).rejects.toThrow();
+
+    const acceptTransaction = new Transaction();
+    await withAcceptMarketplaceAuthority(
+      acceptTransaction,
+      provider.connection,
+      new Wallet(newAuthority),
+      marketplaceName
+    );
+    await executeTransaction(
+      provider.connection,
+      acceptTransaction,
+      new Wallet(newAuthority)
+    );
+
+    const checkMarketplace = await getMarketplaceByName(
+      provider.connection,
+      marketplaceName
+    );
+    expect(checkMarketplace.parsed.authority.toString()).toEqual(
+      newAuthority.publicKey.toString()
+    );
+    expect(checkMarketplace.parsed.pendingAuthority).toBeNull();
+  });
+
+  it("Accept transfer decrements the listing count", async () => {
+    const listingTransaction = new Transaction();
+    await withCreateListing(
+      listingTransaction,
+      provider.connection,
+      emptyWallet(holder.publicKey),
+      tokenMint,
+      marketplaceName,
+      new BN(100000)
+    );
+    await executeTransaction(
+      provider.connection,
+      listingTransaction,
+      new Wallet(holder)
+    );
+    let checkMarketplace = await getMarketplaceByName(
+      provider.connection,
+      marketplaceName
+    );
+    expect(checkMarketplace.parsed.listingCount.toString()).toEqual("1");
+
+    const closeTransaction = new Transaction();
+    await withCloseMarketplace(
+      closeTransaction,
+      provider.connection,
+      new Wallet(newAuthority),
+      marketplaceName
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        closeTransaction,
+        new Wallet(newAuthority)
+      )
+    ).rejects.toThrow();
+
+    const transferTransaction = new Transaction();
+    await withInitTransfer(
+      transferTransaction,
+      provider.connection,
+      emptyWallet(holder.publicKey),
+      recipient.publicKey,
+      tokenMint,
+      holderTokenAccountId
+    );
+    await executeTransaction(
+      provider.connection,
+      transferTransaction,
+      new Wallet(holder)
+    );
+
+    const acceptTransaction = new Transaction();
+    await withAcceptTransfer(
+      acceptTransaction,
+      provider.connection,
+      emptyWallet(recipient.publicKey),
+      tokenMint,
+      recipient.publicKey,
+      holder.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      acceptTransaction,
+      new Wallet(recipient)
+    );
+
+    const checkListing = await tryGetAccount(() =>
+      getListing(provider.connection, tokenMint)
+    );
+    expect(checkListing).toBeNull();
+    checkMarketplace = await getMarketplaceByName(
+      provider.connection,
+      marketplaceName
+    );
+    expect(checkMarketplace.parsed.listingCount.toString()).toEqual("0");
+  });
+
+  it("Close marketplace", async () => {
+    const transaction = new Transaction();
+    await withCloseMarketplace(
+      transaction,
+      provider.connection,
+      new Wallet(newAuthority),
+      marketplaceName
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(newAuthority)
+    );
+
+    const checkMarketplace = await tryGetAccount(() =>
+      getMarketplaceByName(provider.connection, marketplaceName)
+    );
+    expect(checkMarketplace).toBeNull();
+  });
+
+  it("Close transfer authority", async () => {
+    const otherTransaction = new Transaction();
+    await withCloseTransferAuthority(
+      otherTransaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await expect(
+      executeTransaction(provider.connection, otherTransaction, provider.wallet)
+    ).rejects.toThrow();
+
+    const transaction = new Transaction();
+    await withCloseTransferAuthority(
+      transaction,
+      provider.connection,
+      new Wallet(newAuthority),
+      transferAuthorityName
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(newAuthority)
+    );
+
+    const checkTransferAuthority = await getTransferAuthorityByName(
+      provider.connection,
+      transferAuthorityName
+    );
+    expect(checkTransferAuthority.parsed.name).toEqual(transferAuthorityName);
+    expect(checkTransferAuthority.parsed.authority.toString()).toEqual(
+      PublicKey.default.toString()
+    );
+    expect(checkTransferAuthority.parsed.allowedMarketplaces).toEqual([]);
+  });
+
+  it("Release after the transfer authority is closed", async () => {
+    const recipientTokenAccountId = await findAta(
+      tokenMint,
+      recipient.publicKey,
+      true
+    );
+    const transaction = new Transaction();
+    await withRelease(
+      transaction,
+      provider.connection,
+      new Wallet(recipient),
+      tokenMint,
+      findTransferAuthorityAddress(transferAuthorityName),
+      recipientTokenAccountId
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const recipientTokenAccount = await getAccount(
+      provider.connection,
+      recipientTokenAccountId
+    );
+    expect(recipientTokenAccount.amount.toString()).toEqual("1");
+    expect(recipientTokenAccount.isFrozen).toBeFalsy();
+  });
+});
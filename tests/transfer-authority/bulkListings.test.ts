@@ -0,0 +1,216 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  withCreateAuction,
+  withCreateListings,
+  withInitMarketplace,
+  withInitTransferAuthority,
+  withRemoveListings,
+  withUpdateListings,
+  withWrapToken,
+} from "../../src";
+import {
+  getListing,
+  getMarketplaceByName,
+} from "../../src/programs/transferAuthority/accounts";
+import { findMarketplaceAddress } from "../../src/programs/transferAuthority/pda";
+
+describe("Bulk listings", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `lst-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const lister = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  const mintIds: PublicKey[] = [];
+  let auctionMint: PublicKey;
+
+  const wrapToken = async () => {
+    const [, mintId] = await createMint(
+      provider.connection,
+      new Wallet(lister)
+    );
+    const transaction = new Transaction();
+    await withWrapToken(
+      transaction,
+      provider.connection,
+      new Wallet(lister),
+      mintId,
+      { transferAuthorityName: transferAuthorityName }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+    return mintId;
+  };
+
+  const listingCount = async () =>
+    (
+      await getMarketplaceByName(provider.connection, marketplaceName)
+    ).parsed.listingCount.toString();
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    const airdrop = await provider.connection.requestAirdrop(
+      lister.publicKey,
+      LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdrop);
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: 0,
+      takerFeeBasisPoints: 0,
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    mintIds.push(await wrapToken(), await wrapToken());
+    auctionMint = await wrapToken();
+  });
+
+  it("Create listings", async () => {
+    const transaction = new Transaction();
+    await withCreateListings(
+      transaction,
+      provider.connection,
+      new Wallet(lister),
+      marketplaceName,
+      mintIds.map((mintId) => ({ mintId, paymentAmount: new BN(100) }))
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+
+    for (const mintId of mintIds) {
+      const checkListing = await getListing(provider.connection, mintId);
+      expect(checkListing.parsed.paymentAmount.toString()).toEqual("100");
+      expect(checkListing.parsed.counted).toBeTruthy();
+    }
+    expect(await listingCount()).toEqual("2");
+  });
+
+  it("Update listings", async () => {
+    const transaction = new Transaction();
+    await withUpdateListings(
+      transaction,
+      provider.connection,
+      new Wallet(lister),
+      findMarketplaceAddress(marketplaceName),
+      mintIds.map((mintId) => ({ mintId, paymentAmount: new BN(200) }))
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+
+    for (const mintId of mintIds) {
+      const checkListing = await getListing(provider.connection, mintId);
+      expect(checkListing.parsed.paymentAmount.toString()).toEqual("200");
+    }
+    expect(await listingCount()).toEqual("2");
+  });
+
+  it("Remove listings", async () => {
+    const transaction = new Transaction();
+    await withRemoveListings(
+      transaction,
+      provider.connection,
+      new Wallet(lister),
+      mintIds
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+
+    for (const mintId of mintIds) {
+      const checkListing = await tryGetAccount(() =>
+        getListing(provider.connection, mintId)
+      );
+      expect(checkListing).toBeNull();
+    }
+    expect(await listingCount()).toEqual("0");
+  });
+
+  it("Create listings during an auction fails", async () => {
+    const auctionTransaction = new Transaction();
+    await withCreateAuction(
+      auctionTransaction,
+      provider.connection,
+      new Wallet(lister),
+      auctionMint,
+      marketplaceName,
+      new BN(100),
+      new BN(1),
+      new BN(Math.floor(Date.now() / 1000) + 60)
+    );
+    await executeTransaction(
+      provider.connection,
+      auctionTransaction,
+      new Wallet(lister)
+    );
+
+    const transaction = new Transaction();
+    await withCreateListings(
+      transaction,
+      provider.connection,
+      new Wallet(lister),
+      marketplaceName,
+      [...mintIds, auctionMint].map((mintId) => ({
+        mintId,
+        paymentAmount: new BN(100),
+      }))
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(lister))
+    ).rejects.toThrow();
+
+    for (const mintId of [...mintIds, auctionMint]) {
+      const checkListing = await tryGetAccount(() =>
+        getListing(provider.connection, mintId)
+      );
+      expect(checkListing).toBeNull();
+    }
+    expect(await listingCount()).toEqual("0");
+  });
+});
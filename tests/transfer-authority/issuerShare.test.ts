@@ -0,0 +1,275 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  createTransferInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  emptyWallet,
+  executeTransaction,
+  getTestProvider,
+  withFindOrInitAssociatedTokenAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  claimToken,
+  issueToken,
+  withAcceptListing,
+  withCreateListing,
+  withInitMarketplace,
+  withInitTransferAuthority,
+} from "../../src";
+import {
+  InvalidationType,
+  TokenManagerKind,
+} from "../../src/programs/tokenManager";
+import { getListing } from "../../src/programs/transferAuthority/accounts";
+
+describe("Issuer share", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `lst-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const issuer = Keypair.generate();
+  const lister = Keypair.generate();
+  const buyer = Keypair.generate();
+  const receiptHolder = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  let paymentMint: PublicKey;
+  const rentalPaymentAmount = new BN(100000);
+  const ISSUER_SHARE_BPS = 1000;
+  const BASIS_POINTS_DIVISOR = new BN(10000);
+  const issuerShare = rentalPaymentAmount
+    .muln(ISSUER_SHARE_BPS)
+    .div(BASIS_POINTS_DIVISOR);
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+
+    for (const keypair of [
+      feeCollector,
+      issuer,
+      lister,
+      buyer,
+      receiptHolder,
+    ]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    [, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(buyer),
+      { amount: rentalPaymentAmount.toNumber() }
+    );
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: 0,
+      takerFeeBasisPoints: 0,
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName,
+      provider.wallet.publicKey,
+      provider.wallet.publicKey,
+      undefined,
+      ISSUER_SHARE_BPS
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+  });
+
+  const issueAndList = async (
+    listingPaymentMint: PublicKey,
+    receiptMintKeypair?: Keypair
+  ) => {
+    const [issuerTokenAccountId, mintId] = await createMint(
+      provider.connection,
+      new Wallet(issuer)
+    );
+    const [issueTransaction, tokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(issuer),
+      {
+        mint: mintId,
+        issuerTokenAccountId: issuerTokenAccountId,
+        kind: TokenManagerKind.Managed,
+        invalidationType: InvalidationType.Release,
+        transferAuthorityInfo: { transferAuthorityName: transferAuthorityName },
+        receiptOptions: receiptMintKeypair ? { receiptMintKeypair } : undefined,
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      issueTransaction,
+      new Wallet(issuer),
+      { signers: receiptMintKeypair ? [receiptMintKeypair] : [] }
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(lister),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(lister)
+    );
+
+    const listingTransaction = new Transaction();
+    await withCreateListing(
+      listingTransaction,
+      provider.connection,
+      emptyWallet(lister.publicKey),
+      mintId,
+      marketplaceName,
+      rentalPaymentAmount,
+      listingPaymentMint
+    );
+    await executeTransaction(
+      provider.connection,
+      listingTransaction,
+      new Wallet(lister)
+    );
+    return mintId;
+  };
+
+  const acceptListing = async (mintId: PublicKey) => {
+    const checkListing = await getListing(provider.connection, mintId);
+    const transaction = new Transaction();
+    await withAcceptListing(
+      transaction,
+      provider.connection,
+      new Wallet(buyer),
+      buyer.publicKey,
+      mintId,
+      checkListing.parsed.paymentAmount,
+      checkListing.parsed.paymentMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(buyer)
+    );
+  };
+
+  const lamports = async (publicKey: PublicKey) =>
+    (await provider.connection.getAccountInfo(publicKey))?.lamports || 0;
+
+  it("Pays the issuer share to the issuer wallet", async () => {
+    const mintId = await issueAndList(PublicKey.default);
+    const checkListing = await getListing(provider.connection, mintId);
+    const listingInfo = await provider.connection.getAccountInfo(
+      checkListing.pubkey
+    );
+    const beforeIssuerAmount = await lamports(issuer.publicKey);
+    const beforeListerAmount = await lamports(lister.publicKey);
+
+    await acceptListing(mintId);
+
+    expect(await lamports(issuer.publicKey)).toEqual(
+      beforeIssuerAmount + issuerShare.toNumber()
+    );
+    expect(await lamports(lister.publicKey)).toEqual(
+      beforeListerAmount +
+        rentalPaymentAmount.sub(issuerShare).toNumber() +
+        (listingInfo?.lamports || 0)
+    );
+  });
+
+  it("Pays the issuer share to the receipt mint holder", async () => {
+    const receiptMintKeypair = Keypair.generate();
+    const mintId = await issueAndList(PublicKey.default, receiptMintKeypair);
+
+    // move the receipt away from the issuer
+    const transaction = new Transaction();
+    const receiptHolderTokenAccountId =
+      await withFindOrInitAssociatedTokenAccount(
+        transaction,
+        provider.connection,
+        receiptMintKeypair.publicKey,
+        receiptHolder.publicKey,
+        issuer.publicKey
+      );
+    transaction.add(
+      createTransferInstruction(
+        getAssociatedTokenAddressSync(
+          receiptMintKeypair.publicKey,
+          issuer.publicKey
+        ),
+        receiptHolderTokenAccountId,
+        issuer.publicKey,
+        1
+      )
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(issuer)
+    );
+
+    const beforeIssuerAmount = await lamports(issuer.publicKey);
+    const beforeReceiptHolderAmount = await lamports(receiptHolder.publicKey);
+
+    await acceptListing(mintId);
+
+    expect(await lamports(receiptHolder.publicKey)).toEqual(
+      beforeReceiptHolderAmount + issuerShare.toNumber()
+    );
+    expect(await lamports(issuer.publicKey)).toEqual(beforeIssuerAmount);
+  });
+
+  it("Pays the issuer share to the issuer token account", async () => {
+    const mintId = await issueAndList(paymentMint);
+
+    await acceptListing(mintId);
+
+    const issuerPaymentTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(paymentMint, issuer.publicKey)
+    );
+    expect(issuerPaymentTokenAccount.amount.toString()).toEqual(
+      issuerShare.toString()
+    );
+    const listerPaymentTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(paymentMint, lister.publicKey)
+    );
+    expect(listerPaymentTokenAccount.amount.toString()).toEqual(
+      rentalPaymentAmount.sub(issuerShare).toString()
+    );
+  });
+});
@@ -0,0 +1,293 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  createCreateMasterEditionV3Instruction,
+  createCreateMetadataAccountV3Instruction,
+} from "@metaplex-foundation/mpl-token-metadata";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  findAta,
+  findMintEditionId,
+  findMintMetadataId,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  withAcceptOffer,
+  withCancelOffer,
+  withClaimOffer,
+  withCreateOffer,
+  withInitMarketplace,
+  withInitTransferAuthority,
+  withWrapToken,
+} from "../../src";
+import { findTokenManagerAddress } from "../../src/programs/tokenManager/pda";
+import { getOffer } from "../../src/programs/transferAuthority/accounts";
+import {
+  findMarketplaceAddress,
+  findTransferAuthorityAddress,
+} from "../../src/programs/transferAuthority/pda";
+
+describe("Offers", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `ofr-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const holder = Keypair.generate();
+  const buyer = Keypair.generate();
+  const otherBuyer = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  const offerAmount = new BN(0.1 * LAMPORTS_PER_SOL);
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+  let offerId: PublicKey;
+  let otherOfferId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [holder, buyer, otherBuyer, feeCollector]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [, rentalMint] = await createMint(provider.connection, new Wallet(holder));
+    tokenManagerId = findTokenManagerAddress(rentalMint);
+
+    const metadataId = findMintMetadataId(rentalMint);
+    const metadataIx = createCreateMetadataAccountV3Instruction(
+      {
+        metadata: metadataId,
+        updateAuthority: holder.publicKey,
+        mint: rentalMint,
+        mintAuthority: holder.publicKey,
+        payer: holder.publicKey,
+      },
+      {
+        createMetadataAccountArgsV3: {
+          data: {
+            name: "test",
+            symbol: "TST",
+            uri: "http://test/",
+            sellerFeeBasisPoints: 0,
+            creators: null,
+            collection: null,
+            uses: null,
+          },
+          isMutable: true,
+          collectionDetails: null,
+        },
+      }
+    );
+    const masterEditionIx = createCreateMasterEditionV3Instruction(
+      {
+        edition: findMintEditionId(rentalMint),
+        metadata: metadataId,
+        updateAuthority: holder.publicKey,
+        mint: rentalMint,
+        mintAuthority: holder.publicKey,
+        payer: holder.publicKey,
+      },
+      {
+        createMasterEditionArgs: {
+          maxSupply: new BN(0),
+        },
+      }
+    );
+    const tx = new Transaction();
+    tx.instructions = [metadataIx, masterEditionIx];
+    await executeTransaction(provider.connection, tx, new Wallet(holder));
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: 0,
+      takerFeeBasisPoints: 0,
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const wrapTransaction = new Transaction();
+    await withWrapToken(
+      wrapTransaction,
+      provider.connection,
+      new Wallet(holder),
+      rentalMint,
+      { transferAuthorityName: transferAuthorityName }
+    );
+    await executeTransaction(
+      provider.connection,
+      wrapTransaction,
+      new Wallet(holder)
+    );
+  });
+
+  it("Create offers", async () => {
+    for (const offerBuyer of [buyer, otherBuyer]) {
+      const transaction = new Transaction();
+      const [, createdOfferId] = await withCreateOffer(
+        transaction,
+        provider.connection,
+        new Wallet(offerBuyer),
+        tokenManagerId,
+        findTransferAuthorityAddress(transferAuthorityName),
+        findMarketplaceAddress(marketplaceName),
+        offerAmount
+      );
+      await executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(offerBuyer)
+      );
+      if (offerBuyer === buyer) {
+        offerId = createdOfferId;
+      } else {
+        otherOfferId = createdOfferId;
+      }
+    }
+
+    const checkOffer = await getOffer(provider.connection, offerId);
+    expect(checkOffer.parsed.buyer.toString()).toEqual(
+      buyer.publicKey.toString()
+    );
+    expect(checkOffer.parsed.paymentAmount.toString()).toEqual(
+      offerAmount.toString()
+    );
+    expect(checkOffer.parsed.acceptedBy).toBeNull();
+  });
+
+  it("Cancel offer before acceptance", async () => {
+    const beforeBuyerAmount = await provider.connection.getBalance(
+      otherBuyer.publicKey
+    );
+    const transaction = new Transaction();
+    await withCancelOffer(
+      transaction,
+      provider.connection,
+      new Wallet(otherBuyer),
+      otherOfferId
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(otherBuyer)
+    );
+
+    const afterBuyerAmount = await provider.connection.getBalance(
+      otherBuyer.publicKey
+    );
+    expect(afterBuyerAmount - beforeBuyerAmount).toBeGreaterThan(
+      offerAmount.toNumber()
+    );
+    const checkOffer = await tryGetAccount(() =>
+      getOffer(provider.connection, otherOfferId)
+    );
+    expect(checkOffer).toBeNull();
+  });
+
+  it("Accept offer", async () => {
+    const transaction = new Transaction();
+    await withAcceptOffer(
+      transaction,
+      provider.connection,
+      new Wallet(holder),
+      offerId,
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(holder)
+    );
+
+    const checkOffer = await getOffer(provider.connection, offerId);
+    expect(checkOffer.parsed.acceptedBy?.toString()).toEqual(
+      holder.publicKey.toString()
+    );
+    expect(checkOffer.parsed.acceptedTokenManager?.toString()).toEqual(
+      tokenManagerId.toString()
+    );
+  });
+
+  it("Cancel accepted offer fails", async () => {
+    const transaction = new Transaction();
+    await withCancelOffer(
+      transaction,
+      provider.connection,
+      new Wallet(buyer),
+      offerId
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(buyer))
+    ).rejects.toThrow();
+  });
+
+  it("Claim offer", async () => {
+    const beforeHolderAmount = await provider.connection.getBalance(
+      holder.publicKey
+    );
+    const transaction = new Transaction();
+    await withClaimOffer(
+      transaction,
+      provider.connection,
+      new Wallet(buyer),
+      offerId
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(buyer)
+    );
+
+    const afterHolderAmount = await provider.connection.getBalance(
+      holder.publicKey
+    );
+    expect(afterHolderAmount - beforeHolderAmount).toEqual(
+      offerAmount.toNumber()
+    );
+
+    const buyerMintTokenAccount = await getAccount(
+      provider.connection,
+      await findAta(rentalMint, buyer.publicKey, true)
+    );
+    expect(buyerMintTokenAccount.amount.toString()).toEqual("1");
+    expect(buyerMintTokenAccount.isFrozen).toBeTruthy();
+
+    const checkOffer = await tryGetAccount(() =>
+      getOffer(provider.connection, offerId)
+    );
+    expect(checkOffer).toBeNull();
+  });
+});
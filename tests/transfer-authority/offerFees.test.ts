@@ -0,0 +1,276 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  claimToken,
+  issueToken,
+  withAcceptOffer,
+  withClaimOffer,
+  withCreateOffer,
+  withInitMarketplace,
+  withInitTransferAuthority,
+} from "../../src";
+import {
+  InvalidationType,
+  TokenManagerKind,
+} from "../../src/programs/tokenManager";
+import { getOffer } from "../../src/programs/transferAuthority/accounts";
+import {
+  findMarketplaceAddress,
+  findTransferAuthorityAddress,
+} from "../../src/programs/transferAuthority/pda";
+
+describe("Offer fees", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `ofr-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const issuer = Keypair.generate();
+  const holder = Keypair.generate();
+  const buyer = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  const marketplaceFeeCollector = Keypair.generate();
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+  let offerId: PublicKey;
+
+  const BASIS_POINTS_DIVISOR = new BN(10000);
+  const MAKER_FEE = new BN(200);
+  const TAKER_FEE = new BN(300);
+  const MARKETPLACE_MAKER_FEE = new BN(100);
+  const MARKETPLACE_TAKER_FEE = new BN(150);
+  const buyerAmount = new BN(1000000);
+  const offerAmount = new BN(100000);
+  const marketplaceMakerFee = offerAmount
+    .mul(MARKETPLACE_MAKER_FEE)
+    .div(BASIS_POINTS_DIVISOR);
+  const marketplaceTakerFee = offerAmount
+    .mul(MARKETPLACE_TAKER_FEE)
+    .div(BASIS_POINTS_DIVISOR);
+  const escrowAmount = offerAmount
+    .add(marketplaceTakerFee)
+    .add(offerAmount.mul(TAKER_FEE).div(BASIS_POINTS_DIVISOR));
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [issuer, holder, buyer, feeCollector]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    [, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(buyer),
+      { amount: buyerAmount.toNumber() }
+    );
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: MAKER_FEE.toNumber(),
+      takerFeeBasisPoints: TAKER_FEE.toNumber(),
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName,
+      undefined,
+      provider.wallet.publicKey,
+      {
+        feeCollector: marketplaceFeeCollector.publicKey,
+        makerFeeBps: MARKETPLACE_MAKER_FEE.toNumber(),
+        takerFeeBps: MARKETPLACE_TAKER_FEE.toNumber(),
+      }
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const [issuerTokenAccountId, mintId] = await createMint(
+      provider.connection,
+      new Wallet(issuer)
+    );
+    rentalMint = mintId;
+    const [issueTransaction, issuedTokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(issuer),
+      {
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        kind: TokenManagerKind.Managed,
+        invalidationType: InvalidationType.Release,
+        transferAuthorityInfo: { transferAuthorityName: transferAuthorityName },
+      }
+    );
+    tokenManagerId = issuedTokenManagerId;
+    await executeTransaction(
+      provider.connection,
+      issueTransaction,
+      new Wallet(issuer)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(holder),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(holder)
+    );
+  });
+
+  it("Create offer escrows the taker fees", async () => {
+    const transaction = new Transaction();
+    [, offerId] = await withCreateOffer(
+      transaction,
+      provider.connection,
+      new Wallet(buyer),
+      tokenManagerId,
+      findTransferAuthorityAddress(transferAuthorityName),
+      findMarketplaceAddress(marketplaceName),
+      offerAmount,
+      paymentMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(buyer)
+    );
+
+    const checkOffer = await getOffer(provider.connection, offerId);
+    expect(checkOffer.parsed.paymentAmount.toString()).toEqual(
+      offerAmount.toString()
+    );
+    expect(checkOffer.parsed.escrowAmount.toString()).toEqual(
+      escrowAmount.toString()
+    );
+    const offerPaymentTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(paymentMint, offerId, true)
+    );
+    expect(offerPaymentTokenAccount.amount.toString()).toEqual(
+      escrowAmount.toString()
+    );
+  });
+
+  it("Accept offer", async () => {
+    const transaction = new Transaction();
+    await withAcceptOffer(
+      transaction,
+      provider.connection,
+      new Wallet(holder),
+      offerId,
+      rentalMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(holder)
+    );
+  });
+
+  it("Claim offer pays the fees from the escrow", async () => {
+    const transaction = new Transaction();
+    await withClaimOffer(
+      transaction,
+      provider.connection,
+      new Wallet(buyer),
+      offerId,
+      feeCollector.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(buyer)
+    );
+
+    // the payment manager is paid what is left after the marketplace maker fee
+    const paymentManagerAmount = offerAmount.sub(marketplaceMakerFee);
+    const paymentManagerTakerFee = paymentManagerAmount
+      .mul(TAKER_FEE)
+      .div(BASIS_POINTS_DIVISOR);
+
+    const marketplaceFeeCollectorTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(
+        paymentMint,
+        marketplaceFeeCollector.publicKey,
+        true
+      )
+    );
+    expect(marketplaceFeeCollectorTokenAccount.amount.toString()).toEqual(
+      marketplaceMakerFee.add(marketplaceTakerFee).toString()
+    );
+
+    // buy side fees also go to the payment manager fee collector
+    const holderPaymentTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(paymentMint, holder.publicKey)
+    );
+    const feeCollectorTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(paymentMint, feeCollector.publicKey, true)
+    );
+    expect(
+      (
+        holderPaymentTokenAccount.amount + feeCollectorTokenAccount.amount
+      ).toString()
+    ).toEqual(paymentManagerAmount.add(paymentManagerTakerFee).toString());
+
+    // unused taker fees go back to the buyer
+    const buyerPaymentTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(paymentMint, buyer.publicKey)
+    );
+    expect(buyerPaymentTokenAccount.amount.toString()).toEqual(
+      buyerAmount
+        .sub(offerAmount)
+        .sub(marketplaceTakerFee)
+        .sub(paymentManagerTakerFee)
+        .toString()
+    );
+
+    const buyerMintTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(rentalMint, buyer.publicKey, true)
+    );
+    expect(buyerMintTokenAccount.amount.toString()).toEqual("1");
+
+    const checkOffer = await tryGetAccount(() =>
+      getOffer(provider.connection, offerId)
+    );
+    expect(checkOffer).toBeNull();
+  });
+});
@@ -0,0 +1,194 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  emptyWallet,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transaction";
+import { BN } from "bn.js";
+
+import {
+  claimToken,
+  issueToken,
+  withAcceptListing,
+  withCreateListing,
+  withInitMarketplace,
+  withInitTransferAuthority,
+} from "../../src";
+import {
+  InvalidationType,
+  TokenManagerKind,
+} from "../../src/programs/tokenManager";
+import { getListing } from "../../src/programs/transferAuthority/accounts";
+
+describe("Private listing", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `lst-auth-${Math.random()}`;
+  const marketplaceName = `mrkt-${Math.random()}`;
+  const paymentManagerName = `pm-${Math.random()}`;
+
+  const issuer = Keypair.generate();
+  const lister = Keypair.generate();
+  const allowedBuyer = Keypair.generate();
+  const otherBuyer = Keypair.generate();
+  const feeCollector = Keypair.generate();
+  const rentalPaymentAmount = new BN(100000);
+  let rentalMint: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [
+      issuer,
+      lister,
+      allowedBuyer,
+      otherBuyer,
+      feeCollector,
+    ]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    const pmtx = new Transaction();
+    await withInit(pmtx, provider.connection, provider.wallet, {
+      paymentManagerName: paymentManagerName,
+      feeCollectorId: feeCollector.publicKey,
+      makerFeeBasisPoints: 0,
+      takerFeeBasisPoints: 0,
+      includeSellerFeeBasisPoints: false,
+      royaltyFeeShare: new BN(0),
+      payer: provider.wallet.publicKey,
+    });
+    await executeTransaction(provider.connection, pmtx, provider.wallet);
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await withInitMarketplace(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      marketplaceName,
+      paymentManagerName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const [issuerTokenAccountId, mintId] = await createMint(
+      provider.connection,
+      new Wallet(issuer)
+    );
+    rentalMint = mintId;
+    const [issueTransaction, tokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(issuer),
+      {
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        kind: TokenManagerKind.Managed,
+        invalidationType: InvalidationType.Release,
+        transferAuthorityInfo: { transferAuthorityName: transferAuthorityName },
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      issueTransaction,
+      new Wallet(issuer)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(lister),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(lister)
+    );
+  });
+
+  const acceptListing = async (buyer: Keypair) => {
+    const transaction = new Transaction();
+    await withAcceptListing(
+      transaction,
+      provider.connection,
+      new Wallet(buyer),
+      buyer.publicKey,
+      rentalMint,
+      rentalPaymentAmount,
+      PublicKey.default
+    );
+    return executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(buyer)
+    );
+  };
+
+  it("Create private listing", async () => {
+    const transaction = new Transaction();
+    await withCreateListing(
+      transaction,
+      provider.connection,
+      emptyWallet(lister.publicKey),
+      rentalMint,
+      marketplaceName,
+      rentalPaymentAmount,
+      undefined,
+      undefined,
+      undefined,
+      allowedBuyer.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(lister)
+    );
+
+    const checkListing = await getListing(provider.connection, rentalMint);
+    expect(checkListing.parsed.allowedBuyer?.toString()).toEqual(
+      allowedBuyer.publicKey.toString()
+    );
+  });
+
+  it("Other buyer cannot accept", async () => {
+    await expect(acceptListing(otherBuyer)).rejects.toThrow();
+
+    const checkListing = await tryGetAccount(() =>
+      getListing(provider.connection, rentalMint)
+    );
+    expect(checkListing).not.toBeNull();
+  });
+
+  it("Allowed buyer accepts", async () => {
+    await acceptListing(allowedBuyer);
+
+    const buyerMintTokenAccount = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(rentalMint, allowedBuyer.publicKey, true)
+    );
+    expect(buyerMintTokenAccount.amount.toString()).toEqual("1");
+
+    const checkListing = await tryGetAccount(() =>
+      getListing(provider.connection, rentalMint)
+    );
+    expect(checkListing).toBeNull();
+  });
+});
@@ -25,6 +25,7 @@ import { withInit } from "@solana-nft-programs/payment-manager/dist/cjs/transact
 import { BN } from "bn.js";
 
 import {
+  withCloseStaleListing,
   withCreateListing,
   withInitMarketplace,
   withInitTransferAuthority,
@@ -256,4 +257,56 @@ describe("Remove Listing", () => {
     );
     expect(checkListing).toBeNull();
   });
+
+  it("Create expiring listing", async () => {
+    const transaction = new Transaction();
+
+    await withCreateListing(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      rentalMint,
+      marketplaceName,
+      rentalPaymentAmount,
+      rentalPaymentMint,
+      provider.wallet.publicKey,
+      new BN(Math.floor(Date.now() / 1000) + 3)
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const checkListing = await getListing(provider.connection, rentalMint);
+    expect(checkListing.parsed.expiresAt).not.toBeNull();
+  });
+
+  it("Close listing before expiration fails", async () => {
+    const transaction = new Transaction();
+
+    await withCloseStaleListing(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      rentalMint
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet)
+    ).rejects.toThrow();
+  });
+
+  it("Close stale listing", async () => {
+    await new Promise((r) => setTimeout(r, 5000));
+    const transaction = new Transaction();
+
+    await withCloseStaleListing(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      rentalMint
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    const checkListing = await tryGetAccount(() =>
+      getListing(provider.connection, rentalMint)
+    );
+    expect(checkListing).toBeNull();
+  });
 });
@@ -0,0 +1,197 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  emptyWallet,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+import { BN } from "bn.js";
+
+import {
+  withAcceptTransfer,
+  withCloseExpiredTransfer,
+  withInitTransfer,
+  withInitTransferAuthority,
+  withRejectTransfer,
+  withWrapToken,
+} from "../../src";
+import { getTransfer } from "../../src/programs/transferAuthority/accounts";
+
+describe("Reject and expire transfers", () => {
+  let provider: SolanaProvider;
+  const transferAuthorityName = `lst-auth-${Math.random()}`;
+
+  const from = Keypair.generate();
+  const to = Keypair.generate();
+  const other = Keypair.generate();
+  let fromTokenAccountId: PublicKey;
+  let tokenMint: PublicKey;
+
+  const initTransfer = async (expiresAt?: BN) => {
+    const transaction = new Transaction();
+    await withInitTransfer(
+      transaction,
+      provider.connection,
+      emptyWallet(from.publicKey),
+      to.publicKey,
+      tokenMint,
+      fromTokenAccountId,
+      from.publicKey,
+      expiresAt
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(from)
+    );
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [from, to, other]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    const transaction = new Transaction();
+    await withInitTransferAuthority(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      transferAuthorityName
+    );
+    await executeTransaction(provider.connection, transaction, provider.wallet);
+
+    [fromTokenAccountId, tokenMint] = await createMint(
+      provider.connection,
+      new Wallet(from)
+    );
+    const wrapTransaction = new Transaction();
+    await withWrapToken(
+      wrapTransaction,
+      provider.connection,
+      new Wallet(from),
+      tokenMint,
+      { transferAuthorityName: transferAuthorityName }
+    );
+    await executeTransaction(
+      provider.connection,
+      wrapTransaction,
+      new Wallet(from)
+    );
+  });
+
+  it("Only the recipient rejects a transfer", async () => {
+    await initTransfer();
+
+    const otherTransaction = new Transaction();
+    await withRejectTransfer(
+      otherTransaction,
+      provider.connection,
+      new Wallet(other),
+      tokenMint
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        otherTransaction,
+        new Wallet(other)
+      )
+    ).rejects.toThrow();
+
+    const transaction = new Transaction();
+    await withRejectTransfer(
+      transaction,
+      provider.connection,
+      new Wallet(to),
+      tokenMint
+    );
+    await executeTransaction(provider.connection, transaction, new Wallet(to));
+
+    const checkTransfer = await tryGetAccount(() =>
+      getTransfer(provider.connection, tokenMint)
+    );
+    expect(checkTransfer).toBeNull();
+    const fromTokenAccount = await getAccount(
+      provider.connection,
+      fromTokenAccountId
+    );
+    expect(fromTokenAccount.amount.toString()).toEqual("1");
+  });
+
+  it("Close expired transfer before expiry fails", async () => {
+    await initTransfer(new BN(Math.floor(Date.now() / 1000) + 3));
+
+    const checkTransfer = await getTransfer(provider.connection, tokenMint);
+    expect(checkTransfer.parsed.expiresAt).not.toBeNull();
+
+    const transaction = new Transaction();
+    await withCloseExpiredTransfer(
+      transaction,
+      provider.connection,
+      new Wallet(other),
+      tokenMint
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(other))
+    ).rejects.toThrow();
+  });
+
+  it("Wait for the transfer to expire", async () => {
+    await new Promise((r) => setTimeout(r, 5000));
+  });
+
+  it("Accept expired transfer fails", async () => {
+    const transaction = new Transaction();
+    await withAcceptTransfer(
+      transaction,
+      provider.connection,
+      emptyWallet(to.publicKey),
+      tokenMint,
+      to.publicKey,
+      from.publicKey
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(to))
+    ).rejects.toThrow();
+
+    const checkTransfer = await tryGetAccount(() =>
+      getTransfer(provider.connection, tokenMint)
+    );
+    expect(checkTransfer).not.toBeNull();
+  });
+
+  it("Anyone closes an expired transfer", async () => {
+    const transaction = new Transaction();
+    await withCloseExpiredTransfer(
+      transaction,
+      provider.connection,
+      new Wallet(other),
+      tokenMint
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(other)
+    );
+
+    const checkTransfer = await tryGetAccount(() =>
+      getTransfer(provider.connection, tokenMint)
+    );
+    expect(checkTransfer).toBeNull();
+    const fromTokenAccount = await getAccount(
+      provider.connection,
+      fromTokenAccountId
+    );
+    expect(fromTokenAccount.amount.toString()).toEqual("1");
+  });
+});
@@ -0,0 +1,177 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import {
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+  transfer,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { claimToken, issueToken, withUse } from "../../src";
+import { useInvalidator } from "../../src/programs";
+
+describe("Pay per use", () => {
+  let provider: SolanaProvider;
+  const RECIPIENT_START_PAYMENT_AMOUNT = 1000;
+  const FEE_PER_USE = 10;
+  const recipient = Keypair.generate();
+  const useAuthority = Keypair.generate();
+  const user = Keypair.generate();
+  let recipientPaymentTokenAccountId: PublicKey;
+  let issuerTokenAccountId: PublicKey;
+  let paymentMint: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient, useAuthority]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create payment mint
+    [recipientPaymentTokenAccountId, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Issue and claim pay per use rental", async () => {
+    const [transaction, issuedTokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          useAuthority: useAuthority.publicKey,
+          totalUsages: 10,
+          extension: {
+            extensionUsages: 1,
+            extensionPaymentMint: paymentMint,
+            extensionPaymentAmount: FEE_PER_USE,
+          },
+          feePerUse: FEE_PER_USE,
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+      }
+    );
+    tokenManagerId = issuedTokenManagerId;
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+  });
+
+  it("Use authority pays for its own usages", async () => {
+    const useAuthorityPaymentTokenAccount =
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        recipient,
+        paymentMint,
+        useAuthority.publicKey
+      );
+    await transfer(
+      provider.connection,
+      recipient,
+      recipientPaymentTokenAccountId,
+      useAuthorityPaymentTokenAccount.address,
+      recipient,
+      FEE_PER_USE * 2
+    );
+
+    const transaction = new Transaction();
+    await withUse(
+      transaction,
+      provider.connection,
+      new Wallet(useAuthority),
+      rentalMint,
+      1
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(useAuthority)
+    );
+
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+    );
+    expect(useInvalidatorData.parsed.usages.toNumber()).toEqual(1);
+
+    const checkUseAuthorityPaymentTokenAccount = await getAccount(
+      provider.connection,
+      useAuthorityPaymentTokenAccount.address
+    );
+    const useAuthorityPaymentAmount = Number(
+      checkUseAuthorityPaymentTokenAccount.amount
+    );
+    expect(useAuthorityPaymentAmount).toBeLessThanOrEqual(FEE_PER_USE);
+  });
+
+  it("Use authority increments usages paid by the recipient", async () => {
+    const transaction = new Transaction();
+    await withUse(
+      transaction,
+      provider.connection,
+      new Wallet(useAuthority),
+      rentalMint,
+      1,
+      undefined,
+      { payer: recipient.publicKey }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(useAuthority),
+      { signers: [recipient] }
+    );
+
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+    );
+    expect(useInvalidatorData.parsed.usages.toNumber()).toEqual(2);
+
+    const checkRecipientPaymentTokenAccount = await getAccount(
+      provider.connection,
+      recipientPaymentTokenAccountId
+    );
+    const recipientPaymentAmount = Number(
+      checkRecipientPaymentTokenAccount.amount
+    );
+    expect(recipientPaymentAmount).toBeLessThanOrEqual(
+      RECIPIENT_START_PAYMENT_AMOUNT - FEE_PER_USE * 3
+    );
+  });
+});
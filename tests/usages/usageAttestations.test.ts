@@ -0,0 +1,268 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { ed25519 } from "@noble/curves/ed25519";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Ed25519Program,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import {
+  issueToken,
+  rentals,
+  withAddUseAuthority,
+  withApplyUsageAttestations,
+  withRemoveUseAuthority,
+} from "../../src";
+import { tokenManager, useInvalidator } from "../../src/programs";
+import { TokenManagerState } from "../../src/programs/tokenManager";
+import { useInvalidatorProgram } from "../../src/programs/useInvalidator";
+import { usageAttestationMessage } from "../../src/programs/useInvalidator/utils";
+
+describe("Usage attestations", () => {
+  let provider: SolanaProvider;
+  const recipient = Keypair.generate();
+  const useAuthority = Keypair.generate();
+  const otherUseAuthority = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+  let useInvalidatorId: PublicKey;
+
+  const signAttestation = async (
+    authority: Keypair,
+    numUsages: number,
+    nonce: number,
+    mintCountOffset = 0
+  ) => {
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    const attestation = {
+      tokenManager: tokenManagerId,
+      useInvalidator: useInvalidatorId,
+      mintCount: tokenManagerData.parsed.count.addn(mintCountOffset),
+      numUsages: new BN(numUsages),
+      nonce: new BN(nonce),
+    };
+    const message = usageAttestationMessage(attestation);
+    return {
+      attestation,
+      message,
+      signature: ed25519.sign(message, authority.secretKey.slice(0, 32)),
+    };
+  };
+
+  const applyAttestation = async (
+    authority: Keypair,
+    numUsages: number,
+    nonce: number
+  ) => {
+    const { signature } = await signAttestation(authority, numUsages, nonce);
+    const transaction = new Transaction();
+    await withApplyUsageAttestations(
+      transaction,
+      provider.connection,
+      provider.wallet,
+      tokenManagerId,
+      [
+        {
+          authority: authority.publicKey,
+          numUsages: new BN(numUsages),
+          nonce: new BN(nonce),
+          signature,
+        },
+      ]
+    );
+    return executeTransaction(
+      provider.connection,
+      transaction,
+      provider.wallet
+    );
+  };
+
+  const getUsages = async () => {
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidatorId
+    );
+    return useInvalidatorData.parsed.usages.toNumber();
+  };
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create and claim rental", async () => {
+    let transaction: Transaction;
+    [transaction, tokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          useAuthority: useAuthority.publicKey,
+          totalUsages: 10,
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+    useInvalidatorId =
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId);
+
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Claimed);
+  });
+
+  it("Apply attestation from use authority", async () => {
+    await applyAttestation(useAuthority, 2, 1);
+    expect(await getUsages()).toEqual(2);
+  });
+
+  it("Cannot replay attestation nonce", async () => {
+    await expect(applyAttestation(useAuthority, 2, 1)).rejects.toThrow();
+    expect(await getUsages()).toEqual(2);
+  });
+
+  it("Cannot apply attestation signed by non use authority", async () => {
+    await expect(applyAttestation(recipient, 1, 1)).rejects.toThrow();
+  });
+
+  it("Cannot apply attestation signed for another mint count", async () => {
+    const { attestation, message, signature } = await signAttestation(
+      useAuthority,
+      1,
+      2,
+      1
+    );
+    const applyUsageAttestationsIx = await useInvalidatorProgram(
+      provider.connection
+    )
+      .methods.applyUsageAttestations({ attestations: [attestation] })
+      .accountsStrict({
+        tokenManager: tokenManagerId,
+        useInvalidator: useInvalidatorId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .instruction();
+    const transaction = new Transaction().add(
+      Ed25519Program.createInstructionWithPublicKey({
+        publicKey: useAuthority.publicKey.toBytes(),
+        message,
+        signature,
+      }),
+      applyUsageAttestationsIx
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, provider.wallet)
+    ).rejects.toThrow();
+  });
+
+  it("Re-added use authority cannot replay old attestations", async () => {
+    const addTransaction = new Transaction();
+    await withAddUseAuthority(
+      addTransaction,
+      provider.connection,
+      new Wallet(user),
+      tokenManagerId,
+      otherUseAuthority.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      addTransaction,
+      new Wallet(user)
+    );
+    await applyAttestation(otherUseAuthority, 1, 1);
+    expect(await getUsages()).toEqual(3);
+
+    const removeTransaction = new Transaction();
+    await withRemoveUseAuthority(
+      removeTransaction,
+      provider.connection,
+      new Wallet(user),
+      tokenManagerId,
+      otherUseAuthority.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      removeTransaction,
+      new Wallet(user)
+    );
+    await expect(applyAttestation(otherUseAuthority, 1, 2)).rejects.toThrow();
+
+    const readdTransaction = new Transaction();
+    await withAddUseAuthority(
+      readdTransaction,
+      provider.connection,
+      new Wallet(user),
+      tokenManagerId,
+      otherUseAuthority.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      readdTransaction,
+      new Wallet(user)
+    );
+    await expect(applyAttestation(otherUseAuthority, 1, 1)).rejects.toThrow();
+    await applyAttestation(otherUseAuthority, 1, 2);
+    expect(await getUsages()).toEqual(4);
+
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidatorId
+    );
+    expect(
+      useInvalidatorData.parsed.usageNonces.map((n) => [
+        n.authority.toString(),
+        n.nonce.toNumber(),
+      ])
+    ).toEqual([
+      [useAuthority.publicKey.toString(), 1],
+      [otherUseAuthority.publicKey.toString(), 2],
+    ]);
+  });
+});
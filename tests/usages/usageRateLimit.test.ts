@@ -0,0 +1,139 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import { claimToken, issueToken, useTransaction } from "../../src";
+import { tokenManager, useInvalidator } from "../../src/programs";
+import { TokenManagerState } from "../../src/programs/tokenManager";
+
+describe("Usage rate limit", () => {
+  let provider: SolanaProvider;
+  const MAX_USAGES_PER_PERIOD = 2;
+  const USAGE_PERIOD_SECONDS = 3;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  const getUsages = async () => {
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+    );
+    return useInvalidatorData.parsed.usages.toNumber();
+  };
+
+  it("Issue and claim rate limited rental", async () => {
+    const [transaction, issuedTokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          totalUsages: 10,
+          rateLimit: {
+            maxUsagesPerPeriod: MAX_USAGES_PER_PERIOD,
+            usagePeriodSeconds: USAGE_PERIOD_SECONDS,
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+      }
+    );
+    tokenManagerId = issuedTokenManagerId;
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Claimed);
+  });
+
+  it("Use up to the period cap", async () => {
+    const transaction = await useTransaction(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint,
+      MAX_USAGES_PER_PERIOD
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+    expect(await getUsages()).toEqual(MAX_USAGES_PER_PERIOD);
+  });
+
+  it("Fail to use beyond the period cap", async () => {
+    const transaction = await useTransaction(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint,
+      1
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+    expect(await getUsages()).toEqual(MAX_USAGES_PER_PERIOD);
+  });
+
+  it("Use again in the next period", async () => {
+    await new Promise((r) => setTimeout(r, (USAGE_PERIOD_SECONDS + 2) * 1000));
+    const transaction = await useTransaction(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint,
+      1
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+    expect(await getUsages()).toEqual(MAX_USAGES_PER_PERIOD + 1);
+  });
+});
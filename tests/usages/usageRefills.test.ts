@@ -0,0 +1,287 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import { getAccount } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+  tryGetAccount,
+} from "@solana-nft-programs/common";
+
+import {
+  claimToken,
+  issueToken,
+  useTransaction,
+  withRefillUsages,
+} from "../../src";
+import { tokenManager, useInvalidator } from "../../src/programs";
+import { TokenManagerState } from "../../src/programs/tokenManager";
+
+describe("Usage refills", () => {
+  let provider: SolanaProvider;
+  const REFILL_PERIOD_SECONDS = 2;
+  const REFILL_PAYMENT_AMOUNT = 10;
+  const RECIPIENT_START_PAYMENT_AMOUNT = 1000;
+  const recipient = Keypair.generate();
+  const user = Keypair.generate();
+  let paymentMint: PublicKey;
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let paidIssuerTokenAccountId: PublicKey;
+  let paidRentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+  let paidTokenManagerId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create payment mint
+    [, paymentMint] = await createMint(
+      provider.connection,
+      new Wallet(recipient),
+      { amount: RECIPIENT_START_PAYMENT_AMOUNT }
+    );
+
+    // create rental mints
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+    [paidIssuerTokenAccountId, paidRentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  const getUseInvalidatorData = (id: PublicKey) =>
+    useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(id)
+    );
+
+  it("Fail to issue unpaid refills without max usages", async () => {
+    const [transaction] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          totalUsages: 1,
+          refill: {
+            refillPeriodSeconds: REFILL_PERIOD_SECONDS,
+            refillUsages: 1,
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+      }
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(user))
+    ).rejects.toThrow();
+  });
+
+  it("Issue and claim unpaid refills", async () => {
+    const [transaction, issuedTokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          totalUsages: 1,
+          refill: {
+            refillPeriodSeconds: REFILL_PERIOD_SECONDS,
+            refillUsages: 1,
+            maxUsages: 2,
+          },
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+      }
+    );
+    tokenManagerId = issuedTokenManagerId;
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Claimed);
+  });
+
+  it("Use available usages", async () => {
+    const transaction = await useTransaction(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint,
+      1
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+    const useInvalidatorData = await getUseInvalidatorData(tokenManagerId);
+    expect(useInvalidatorData.parsed.usages.toNumber()).toEqual(1);
+  });
+
+  it("Fail to use before refill", async () => {
+    const transaction = await useTransaction(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint,
+      1
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Use refilled usages up to max usages and invalidate", async () => {
+    await new Promise((r) => setTimeout(r, (REFILL_PERIOD_SECONDS + 2) * 1000));
+    const transaction = await useTransaction(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint,
+      1
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerData = await tryGetAccount(() =>
+      tokenManager.accounts.getTokenManager(provider.connection, tokenManagerId)
+    );
+    expect(tokenManagerData).toEqual(null);
+    const checkIssuerTokenAccount = await getAccount(
+      provider.connection,
+      issuerTokenAccountId
+    );
+    expect(checkIssuerTokenAccount.amount.toString()).toEqual("1");
+  });
+
+  it("Issue and claim paid refills", async () => {
+    const [transaction, issuedTokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          totalUsages: 1,
+          extension: {
+            extensionUsages: 1,
+            extensionPaymentMint: paymentMint,
+            extensionPaymentAmount: REFILL_PAYMENT_AMOUNT,
+          },
+          refill: {
+            refillPeriodSeconds: REFILL_PERIOD_SECONDS,
+            refillUsages: 1,
+            refillPaymentAmount: REFILL_PAYMENT_AMOUNT,
+          },
+        },
+        mint: paidRentalMint,
+        issuerTokenAccountId: paidIssuerTokenAccountId,
+      }
+    );
+    paidTokenManagerId = issuedTokenManagerId;
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await claimToken(
+      provider.connection,
+      new Wallet(recipient),
+      paidTokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+  });
+
+  it("Fail to pay for a refill before the period elapses", async () => {
+    const transaction = new Transaction();
+    await withRefillUsages(
+      transaction,
+      provider.connection,
+      new Wallet(recipient),
+      paidTokenManagerId
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Pay for the current period only", async () => {
+    // wait for more than one period to pass
+    await new Promise((r) =>
+      setTimeout(r, (2 * REFILL_PERIOD_SECONDS + 1) * 1000)
+    );
+    const transaction = new Transaction();
+    await withRefillUsages(
+      transaction,
+      provider.connection,
+      new Wallet(recipient),
+      paidTokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const useInvalidatorData = await getUseInvalidatorData(paidTokenManagerId);
+    expect(useInvalidatorData.parsed.totalUsages?.toNumber()).toEqual(2);
+
+    // missed periods cannot be bought afterwards
+    const backlogTransaction = new Transaction();
+    await withRefillUsages(
+      backlogTransaction,
+      provider.connection,
+      new Wallet(recipient),
+      paidTokenManagerId
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        backlogTransaction,
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+  });
+});
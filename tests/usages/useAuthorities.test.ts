@@ -0,0 +1,247 @@
+import { BN, Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMint,
+  executeTransaction,
+  getTestProvider,
+} from "@solana-nft-programs/common";
+
+import {
+  issueToken,
+  rentals,
+  useTransaction,
+  withAddUseAuthority,
+  withRemoveUseAuthority,
+} from "../../src";
+import { tokenManager, useInvalidator } from "../../src/programs";
+import { TokenManagerState } from "../../src/programs/tokenManager";
+
+describe("Add and remove use authorities", () => {
+  let provider: SolanaProvider;
+  const recipient = Keypair.generate();
+  const useAuthority = Keypair.generate();
+  const user = Keypair.generate();
+  let issuerTokenAccountId: PublicKey;
+  let rentalMint: PublicKey;
+  let tokenManagerId: PublicKey;
+
+  beforeAll(async () => {
+    provider = await getTestProvider();
+    for (const keypair of [user, recipient, useAuthority]) {
+      const airdrop = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdrop);
+    }
+
+    // create rental mint
+    [issuerTokenAccountId, rentalMint] = await createMint(
+      provider.connection,
+      new Wallet(user)
+    );
+  });
+
+  it("Create and claim rental", async () => {
+    let transaction: Transaction;
+    [transaction, tokenManagerId] = await issueToken(
+      provider.connection,
+      new Wallet(user),
+      {
+        useInvalidation: {
+          totalUsages: 5,
+        },
+        mint: rentalMint,
+        issuerTokenAccountId: issuerTokenAccountId,
+        amount: new BN(1),
+      }
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const claimTransaction = await rentals.claimRental(
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId
+    );
+    await executeTransaction(
+      provider.connection,
+      claimTransaction,
+      new Wallet(recipient)
+    );
+
+    const tokenManagerData = await tokenManager.accounts.getTokenManager(
+      provider.connection,
+      tokenManagerId
+    );
+    expect(tokenManagerData.parsed.state).toEqual(TokenManagerState.Claimed);
+  });
+
+  it("Non issuer cannot add use authority", async () => {
+    const transaction = new Transaction();
+    await withAddUseAuthority(
+      transaction,
+      provider.connection,
+      new Wallet(recipient),
+      tokenManagerId,
+      recipient.publicKey
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Add use authority", async () => {
+    const transaction = new Transaction();
+    await withAddUseAuthority(
+      transaction,
+      provider.connection,
+      new Wallet(user),
+      tokenManagerId,
+      useAuthority.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+    );
+    expect(
+      useInvalidatorData.parsed.useAuthorities.map((k) => k.toString())
+    ).toEqual([useAuthority.publicKey.toString()]);
+  });
+
+  it("Cannot add the same use authority twice", async () => {
+    const transaction = new Transaction();
+    await withAddUseAuthority(
+      transaction,
+      provider.connection,
+      new Wallet(user),
+      tokenManagerId,
+      useAuthority.publicKey
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(user))
+    ).rejects.toThrow();
+  });
+
+  it("Holder cannot use once a use authority is set", async () => {
+    const transaction = await useTransaction(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint,
+      1
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        transaction,
+        new Wallet(recipient)
+      )
+    ).rejects.toThrow();
+  });
+
+  it("Use by added use authority", async () => {
+    const transaction = await useTransaction(
+      provider.connection,
+      new Wallet(useAuthority),
+      rentalMint,
+      1
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(useAuthority)
+    );
+
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+    );
+    expect(useInvalidatorData.parsed.usages.toNumber()).toEqual(1);
+  });
+
+  it("Remove use authority", async () => {
+    const transaction = new Transaction();
+    await withRemoveUseAuthority(
+      transaction,
+      provider.connection,
+      new Wallet(user),
+      tokenManagerId,
+      useAuthority.publicKey
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(user)
+    );
+
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+    );
+    expect(useInvalidatorData.parsed.useAuthorities.length).toEqual(0);
+  });
+
+  it("Cannot remove a missing use authority", async () => {
+    const transaction = new Transaction();
+    await withRemoveUseAuthority(
+      transaction,
+      provider.connection,
+      new Wallet(user),
+      tokenManagerId,
+      useAuthority.publicKey
+    );
+    await expect(
+      executeTransaction(provider.connection, transaction, new Wallet(user))
+    ).rejects.toThrow();
+  });
+
+  it("Removed use authority cannot use and holder can again", async () => {
+    const useAuthorityTransaction = await useTransaction(
+      provider.connection,
+      new Wallet(useAuthority),
+      rentalMint,
+      1
+    );
+    await expect(
+      executeTransaction(
+        provider.connection,
+        useAuthorityTransaction,
+        new Wallet(useAuthority)
+      )
+    ).rejects.toThrow();
+
+    const transaction = await useTransaction(
+      provider.connection,
+      new Wallet(recipient),
+      rentalMint,
+      1
+    );
+    await executeTransaction(
+      provider.connection,
+      transaction,
+      new Wallet(recipient)
+    );
+
+    const useInvalidatorData = await useInvalidator.accounts.getUseInvalidator(
+      provider.connection,
+      useInvalidator.pda.findUseInvalidatorAddress(tokenManagerId)
+    );
+    expect(useInvalidatorData.parsed.usages.toNumber()).toEqual(2);
+  });
+});
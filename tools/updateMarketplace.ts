@@ -1,5 +1,5 @@
 import { utils, Wallet } from "@coral-xyz/anchor";
-import { PublicKey } from "@solana/web3.js";
+import type { PublicKey } from "@solana/web3.js";
 import * as web3Js from "@solana/web3.js";
 import { executeTransaction, tryGetAccount } from "@solana-nft-programs/common";
 
@@ -31,7 +31,6 @@ const main = async (
     new Wallet(wallet),
     marketplaceName,
     paymentManagerName,
-    new PublicKey("cpmaMZyBQiPxpeuxNsQhW7N8z1o9yaNdLgiPhWGUEiX"),
     []
   );
   try {
@@ -1,5 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Keypair, PublicKey, Transaction } from "@solana/web3.js";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, Transaction } from "@solana/web3.js";
 import { executeTransaction } from "@solana-nft-programs/common";
 
 import { withUpdateTransferAuthority } from "../src";
@@ -23,8 +24,7 @@ const main = async (transferAuthorityName: string, cluster = "devnet") => {
     transaction,
     connection,
     new anchor.Wallet(wallet),
-    transferAuthorityName,
-    new PublicKey("cpmaMZyBQiPxpeuxNsQhW7N8z1o9yaNdLgiPhWGUEiX")
+    transferAuthorityName
   );
   try {
     await executeTransaction(